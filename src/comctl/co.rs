@@ -1,6 +1,6 @@
 #![allow(non_camel_case_types, non_upper_case_globals)]
 
-use crate::co::{CB, EM, WM};
+use crate::co::{CB, EM, EN, WM};
 
 const_ordinary! { ADRF: u32;
 	/// [`NMTVASYNCDRAW`](crate::NMTVASYNCDRAW) `dwRetFlags` (`u32`).
@@ -14,6 +14,20 @@ const_ordinary! { ADRF: u32;
 	DRAWIMAGE 3
 }
 
+const_bitflag! { AURL: u32;
+	/// [`em::AutoUrlDetect`](crate::msg::em::AutoUrlDetect) `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ENABLEURL 1
+	ENABLEEMAILADDR 2
+	ENABLETELNO 4
+	ENABLEEAURLS 8
+	ENABLEDRIVELETTERS 16
+	DISABLEMIXEDLGC 32
+}
+
 const_wm! { BCM;
 	/// Button control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-button-control-reference-messages)
@@ -200,6 +214,27 @@ const_wm! { CCM;
 	DPISCALE Self::FIRST.0 + 0xc
 }
 
+const_ws! { CCS: u32;
+	/// Common control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/common-control-styles)
+	/// (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOP 0x0000_0001
+	NOMOVEY 0x0000_0002
+	BOTTOM 0x0000_0003
+	NORESIZE 0x0000_0004
+	NOPARENTALIGN 0x0000_0008
+	ADJUSTABLE 0x0000_0020
+	NODIVIDER 0x0000_0040
+	VERT 0x0000_0080
+	LEFT Self::VERT.0 | Self::TOP.0
+	RIGHT Self::VERT.0 | Self::BOTTOM.0
+	NOMOVEX Self::VERT.0 | Self::NOMOVEY.0
+}
+
 const_bitflag! { CDDS: u32;
 	/// [`NMCUSTOMDRAW`](crate::NMCUSTOMDRAW) `dwDrawStage` (`u32`).
 	=>
@@ -251,6 +286,72 @@ const_ordinary! { CDRF: u32;
 	NOTIFYPOSTERASE 0x0000_0040
 }
 
+const_bitflag! { CFE: u32;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `dwEffects` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	BOLD 0x0000_0001
+	ITALIC 0x0000_0002
+	UNDERLINE 0x0000_0004
+	STRIKEOUT 0x0000_0008
+	PROTECTED 0x0000_0010
+	LINK 0x0000_0020
+	SMALLCAPS 0x0000_0040
+	ALLCAPS 0x0000_0080
+	HIDDEN 0x0000_0100
+	OUTLINE 0x0000_0200
+	SHADOW 0x0000_0400
+	EMBOSS 0x0000_0800
+	IMPRINT 0x0000_1000
+	DISABLED 0x0000_2000
+	REVISED 0x0000_4000
+	SUBSCRIPT 0x0001_0000
+	SUPERSCRIPT 0x0002_0000
+	AUTOBACKCOLOR 0x0400_0000
+	AUTOCOLOR 0x4000_0000
+}
+
+const_bitflag! { CFM: u32;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `dwMask` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	BOLD 0x0000_0001
+	ITALIC 0x0000_0002
+	UNDERLINE 0x0000_0004
+	STRIKEOUT 0x0000_0008
+	PROTECTED 0x0000_0010
+	LINK 0x0000_0020
+	SMALLCAPS 0x0000_0040
+	ALLCAPS 0x0000_0080
+	HIDDEN 0x0000_0100
+	OUTLINE 0x0000_0200
+	SHADOW 0x0000_0400
+	EMBOSS 0x0000_0800
+	IMPRINT 0x0000_1000
+	DISABLED 0x0000_2000
+	REVISED 0x0000_4000
+	REVAUTHOR 0x0000_8000
+	SUBSCRIPT 0x0003_0000
+	SUPERSCRIPT 0x0003_0000
+	ANIMATION 0x0004_0000
+	STYLE 0x0008_0000
+	KERNING 0x0010_0000
+	SPACING 0x0020_0000
+	WEIGHT 0x0040_0000
+	UNDERLINETYPE 0x0080_0000
+	LCID 0x0200_0000
+	BACKCOLOR 0x0400_0000
+	CHARSET 0x0800_0000
+	OFFSET 0x1000_0000
+	FACE 0x2000_0000
+	COLOR 0x4000_0000
+	SIZE 0x8000_0000
+}
+
 const_wm! { DTM;
 	/// Date and time picker control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-date-and-time-picker-control-reference-messages)
@@ -336,6 +437,94 @@ const_values! { EM;
 	FILELINELENGTH Self::FIRST.0 + 21
 	GETFILELINE Self::FIRST.0 + 22
 	GETFILELINECOUNT Self::FIRST.0 + 23
+
+	CANPASTE WM::USER.0 + 50
+	DISPLAYBAND WM::USER.0 + 51
+	EXGETSEL WM::USER.0 + 52
+	EXLIMITTEXT WM::USER.0 + 53
+	EXLINEFROMCHAR WM::USER.0 + 54
+	EXSETSEL WM::USER.0 + 55
+	FINDTEXT WM::USER.0 + 56
+	FORMATRANGE WM::USER.0 + 57
+	GETCHARFORMAT WM::USER.0 + 58
+	GETEVENTMASK WM::USER.0 + 59
+	GETOLEINTERFACE WM::USER.0 + 60
+	GETPARAFORMAT WM::USER.0 + 61
+	GETSELTEXT WM::USER.0 + 62
+	HIDESELECTION WM::USER.0 + 63
+	PASTESPECIAL WM::USER.0 + 64
+	REQUESTRESIZE WM::USER.0 + 65
+	SELECTIONTYPE WM::USER.0 + 66
+	SETBKGNDCOLOR WM::USER.0 + 67
+	SETCHARFORMAT WM::USER.0 + 68
+	SETEVENTMASK WM::USER.0 + 69
+	SETOLECALLBACK WM::USER.0 + 70
+	SETPARAFORMAT WM::USER.0 + 71
+	SETTARGETDEVICE WM::USER.0 + 72
+	STREAMIN WM::USER.0 + 73
+	STREAMOUT WM::USER.0 + 74
+	GETTEXTRANGE WM::USER.0 + 75
+	FINDWORDBREAK WM::USER.0 + 76
+	SETOPTIONS WM::USER.0 + 77
+	GETOPTIONS WM::USER.0 + 78
+	FINDTEXTEX WM::USER.0 + 79
+	GETWORDBREAKPROCEX WM::USER.0 + 80
+	SETWORDBREAKPROCEX WM::USER.0 + 81
+	SETUNDOLIMIT WM::USER.0 + 82
+	REDO WM::USER.0 + 84
+	CANREDO WM::USER.0 + 85
+	GETUNDONAME WM::USER.0 + 86
+	GETREDONAME WM::USER.0 + 87
+	STOPGROUPTYPING WM::USER.0 + 88
+	SETTEXTMODE WM::USER.0 + 89
+	GETTEXTMODE WM::USER.0 + 90
+	AUTOURLDETECT WM::USER.0 + 91
+	GETAUTOURLDETECT WM::USER.0 + 92
+	SETPALETTE WM::USER.0 + 93
+	GETTEXTEX WM::USER.0 + 94
+	GETTEXTLENGTHEX WM::USER.0 + 95
+	SHOWSCROLLBAR WM::USER.0 + 96
+	SETTEXTEX WM::USER.0 + 97
+}
+
+const_values! { EN;
+	=>
+	/// Rich edit
+	/// [`EN_MSGFILTER`](https://learn.microsoft.com/en-us/windows/win32/controls/en-msgfilter)
+	/// `WM_NOTIFY` notification.
+	MSGFILTER 0x0700
+	/// Rich edit
+	/// [`EN_REQUESTRESIZE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-requestresize)
+	/// `WM_NOTIFY` notification.
+	REQUESTRESIZE 0x0701
+	/// Rich edit
+	/// [`EN_SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-selchange)
+	/// `WM_NOTIFY` notification.
+	SELCHANGE 0x0702
+	/// Rich edit
+	/// [`EN_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/controls/en-dropfiles)
+	/// `WM_NOTIFY` notification.
+	DROPFILES 0x0703
+	/// Rich edit
+	/// [`EN_PROTECTED`](https://learn.microsoft.com/en-us/windows/win32/controls/en-protected)
+	/// `WM_NOTIFY` notification.
+	PROTECTED 0x0704
+	/// Rich edit
+	/// [`EN_LINK`](https://learn.microsoft.com/en-us/windows/win32/controls/en-link)
+	/// `WM_NOTIFY` notification.
+	LINK 0x070b
+	/// Rich edit
+	/// [`EN_DRAGDROPDONE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-dragdropdone)
+	/// `WM_NOTIFY` notification.
+	DRAGDROPDONE 0x070c
+}
+
+/// Rich edit controls send some of their notifications through
+/// [`wm::Notify`](crate::msg::wm::Notify), with the same `EN` prefix.
+impl From<EN> for NM {
+	fn from(v: EN) -> Self {
+		Self(v.0 as _)
+	}
 }
 
 const_bitflag! { EMF: u32;
@@ -346,6 +535,49 @@ const_bitflag! { EMF: u32;
 	CENTERED 0x0000_0001
 }
 
+const_bitflag! { ENM: u32;
+	/// Rich edit
+	/// [event mask](https://learn.microsoft.com/en-us/windows/win32/controls/rich-edit-control-event-mask-flags)
+	/// (`u32`).
+	=>
+	=>
+	NONE 0x0000_0000
+	CHANGE 0x0000_0001
+	UPDATE 0x0000_0002
+	SCROLL 0x0000_0004
+	SCROLLEVENTS 0x0000_0008
+	DRAGDROPDONE 0x0000_0010
+	PARAGRAPHEXPANDED 0x0000_0020
+	PAGECHANGE 0x0000_0040
+	CLIPFORMAT 0x0000_0080
+	KEYEVENTS 0x0001_0000
+	MOUSEEVENTS 0x0002_0000
+	REQUESTRESIZE 0x0004_0000
+	SELCHANGE 0x0008_0000
+	DROPFILES 0x0010_0000
+	PROTECTED 0x0020_0000
+	CORRECTTEXT 0x0040_0000
+	IMECHANGE 0x0080_0000
+	LANGCHANGE 0x0100_0000
+	OBJECTPOSITIONS 0x0200_0000
+	LINK 0x0400_0000
+	LOWFIRTF 0x0800_0000
+}
+
+const_bitflag! { FR: u32;
+	/// [`em::FindTextEx`](crate::msg::em::FindTextEx) `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	DOWN 0x0000_0001
+	WHOLEWORD 0x0000_0002
+	MATCHCASE 0x0000_0004
+	MATCHDIAC 0x2000_0000
+	MATCHKASHIDA 0x4000_0000
+	MATCHALEFHAMZA 0x8000_0000
+}
+
 const_bitflag! { GDT: u32;
 	/// [`NMDATETIMECHANGE`](crate::NMDATETIMECHANGE) and
 	/// [`NMDATETIMESTRING`](crate::NMDATETIMESTRING) `dwFlags` (`u32`).
@@ -371,7 +603,8 @@ const_ordinary! { GMR: u32;
 }
 
 const_bitflag! { HICF: u32;
-	/// [NMBCHOTITEM](crate::NMBCHOTITEM) `dwFlags` (`u32`).
+	/// [`NMBCHOTITEM`](crate::NMBCHOTITEM) and
+	/// [`NMTBHOTITEM`](crate::NMTBHOTITEM) `dwFlags` (`u32`).
 	=>
 	=>
 	OTHER 0x0000_0000
@@ -455,7 +688,7 @@ const_wm! { HDM;
 	=>
 	GETITEMCOUNT Self::FIRST.0 + 0
 	INSERTITEM Self::FIRST.0 + 10
-	DELETEITEM Self::FIRST.0 + 11
+	DELETEITEM Self::FIRST.0 + 2
 	GETITEM Self::FIRST.0 + 11
 	SETITEM Self::FIRST.0 + 12
 	LAYOUT Self::FIRST.0 + 5
@@ -1606,6 +1839,50 @@ const_ordinary! { PBST: u32;
 	PAUSED 0x0003
 }
 
+const_ordinary! { PFA: u16;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `wAlignment` (`u16`).
+	=>
+	=>
+	LEFT 1
+	RIGHT 2
+	CENTER 3
+	JUSTIFY 4
+	FULL_INTERWORD 4
+}
+
+const_bitflag! { PFM: u32;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `dwMask` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	STARTINDENT 0x0000_0001
+	RIGHTINDENT 0x0000_0002
+	OFFSET 0x0000_0004
+	ALIGNMENT 0x0000_0008
+	TABSTOPS 0x0000_0010
+	NUMBERING 0x0000_0020
+	SPACEBEFORE 0x0000_0040
+	SPACEAFTER 0x0000_0080
+	LINESPACING 0x0000_0100
+	STYLE 0x0000_0400
+	BORDER 0x0000_0800
+	SHADING 0x0000_1000
+	NUMBERINGSTYLE 0x0000_2000
+	NUMBERINGTAB 0x0000_4000
+	NUMBERINGSTART 0x0000_8000
+	RTLPARA 0x0001_0000
+	KEEP 0x0002_0000
+	KEEPNEXT 0x0004_0000
+	PAGEBREAKBEFORE 0x0008_0000
+	NOLINENUMBER 0x0010_0000
+	NOWIDOWCONTROL 0x0020_0000
+	DONOTHYPHEN 0x0040_0000
+	SIDEBYSIDE 0x0080_0000
+	OUTLINELEVEL 0x0200_0000
+	OFFSETINDENT 0x8000_0000
+}
+
 const_wm! { RB;
 	/// Rebar control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-messages)
@@ -1757,6 +2034,50 @@ const_ordinary! { SBT: u16;
 	NOTABPARSING 0x0800
 }
 
+const_bitflag! { SCF: u32;
+	/// [`em::GetCharFormat`](crate::msg::em::GetCharFormat) and
+	/// [`em::SetCharFormat`](crate::msg::em::SetCharFormat) `range` (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000
+	SELECTION 0x0001
+	WORD 0x0002
+	ALL 0x0004
+	ASSOCIATEFONT 0x0010
+	NOKBUPDATE 0x0020
+}
+
+const_bitflag! { SEL: u16;
+	/// [`SELCHANGE`](crate::SELCHANGE) `seltyp` (`u16`).
+	=>
+	=>
+	EMPTY 0x0000
+	TEXT 0x0001
+	OBJECT 0x0002
+	MULTICHAR 0x0004
+	MULTIOBJECT 0x0008
+}
+
+const_bitflag! { SF: u32;
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) and
+	/// [`em::StreamOut`](crate::msg::em::StreamOut) `format` (`u32`).
+	///
+	/// Also includes the `SFF` values.
+	=>
+	=>
+	TEXT 0x0001
+	RTF 0x0002
+	RTFNOOBJS 0x0003
+	TEXTIZED 0x0004
+	UNICODE 0x0010
+	USECODEPAGE 0x0020
+	NCRFORNONASCII 0x0040
+	/// `SFF_PLAINRTF`.
+	PLAINRTF 0x4000
+	/// `SFF_SELECTION`.
+	SELECTION 0x8000
+}
+
 const_wm! { STM;
 	/// Static control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-static-control-reference-messages)
@@ -1991,6 +2312,16 @@ const_ws! { TBS: u32;
 	TRANSPARENTBKGND 0x1000
 }
 
+const_ordinary! { TBDDRET: u32;
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// return value (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	NODEFAULT 1
+	TREATPRESSED 2
+}
+
 const_bitflag! { TBSTATE: u8;
 	/// Toolbar button
 	/// [states](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-button-states)
@@ -2009,6 +2340,26 @@ const_bitflag! { TBSTATE: u8;
 	MARKED 0x80
 }
 
+const_ws! { TBSTYLE: u32;
+	/// Toolbar control window
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-control-and-button-styles)
+	/// (`u32`).
+	///
+	/// The styles of the individual buttons are in [`BTNS`](crate::co::BTNS).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOOLTIPS 0x0100
+	WRAPABLE 0x0200
+	ALTDRAG 0x0400
+	FLAT 0x0800
+	LIST 0x1000
+	CUSTOMERASE 0x2000
+	REGISTERDROP 0x4000
+	TRANSPARENT 0x8000
+}
+
 const_wsex! { TBSTYLE_EX;
 	/// Extended toolbar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-extended-styles)
@@ -2269,8 +2620,35 @@ const_nm! { TRBN;
 	THUMBPOSCHANGING Self::FIRST.0 - 1
 }
 
+const_ordinary! { TTDT: u32;
+	/// [`ttm::GetDelayTime`](crate::msg::ttm::GetDelayTime) and
+	/// [`ttm::SetDelayTime`](crate::msg::ttm::SetDelayTime) `which` (`u32`).
+	=>
+	=>
+	AUTOMATIC 0
+	RESHOW 1
+	AUTOPOP 2
+	INITIAL 3
+}
+
+const_bitflag! { TTF: u32;
+	/// [`TTTOOLINFO`](crate::TTTOOLINFO) `uFlags` (`u32`).
+	=>
+	=>
+	IDISHWND 0x0001
+	CENTERTIP 0x0002
+	RTLREADING 0x0004
+	SUBCLASS 0x0010
+	TRACK 0x0020
+	ABSOLUTE 0x0080
+	TRANSPARENT 0x0100
+	PARSELINKS 0x1000
+	DI_SETITEM 0x8000
+}
+
 const_ordinary! { TTI: i32;
-	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) ttiIcon (`i32`).
+	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) ttiIcon, and
+	/// [`ttm::SetTitle`](crate::msg::ttm::SetTitle) `icon` (`i32`).
 	=>
 	=>
 	ERROR 3
@@ -2282,6 +2660,78 @@ const_ordinary! { TTI: i32;
 	ERROR_LARGE 6
 }
 
+const_wm! { TTM;
+	/// Tooltip control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages)
+	/// (`u32`).
+	=>
+	=>
+	ACTIVATE WM::USER.0 + 1
+	SETDELAYTIME WM::USER.0 + 3
+	ADDTOOL WM::USER.0 + 50
+	DELTOOL WM::USER.0 + 51
+	NEWTOOLRECT WM::USER.0 + 52
+	RELAYEVENT WM::USER.0 + 7
+	GETTOOLINFO WM::USER.0 + 53
+	SETTOOLINFO WM::USER.0 + 54
+	HITTEST WM::USER.0 + 55
+	GETTEXT WM::USER.0 + 56
+	UPDATETIPTEXT WM::USER.0 + 57
+	GETTOOLCOUNT WM::USER.0 + 13
+	ENUMTOOLS WM::USER.0 + 58
+	GETCURRENTTOOL WM::USER.0 + 59
+	WINDOWFROMPOINT WM::USER.0 + 16
+	TRACKACTIVATE WM::USER.0 + 17
+	TRACKPOSITION WM::USER.0 + 18
+	SETTIPBKCOLOR WM::USER.0 + 19
+	SETTIPTEXTCOLOR WM::USER.0 + 20
+	GETDELAYTIME WM::USER.0 + 21
+	GETTIPBKCOLOR WM::USER.0 + 22
+	GETTIPTEXTCOLOR WM::USER.0 + 23
+	SETMAXTIPWIDTH WM::USER.0 + 24
+	GETMAXTIPWIDTH WM::USER.0 + 25
+	SETMARGIN WM::USER.0 + 26
+	GETMARGIN WM::USER.0 + 27
+	POP WM::USER.0 + 28
+	UPDATE WM::USER.0 + 29
+	GETBUBBLESIZE WM::USER.0 + 30
+	ADJUSTRECT WM::USER.0 + 31
+	SETTITLE WM::USER.0 + 33
+	POPUP WM::USER.0 + 34
+	GETTITLE WM::USER.0 + 35
+	SETWINDOWTHEME CCM::SETWINDOWTHEME.0
+}
+
+const_nm! { TTN;
+	/// Tooltip control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-notifications)
+	/// (`i32`).
+	=>
+	FIRST -520
+	=>
+	GETDISPINFO Self::FIRST.0 - 10
+	SHOW Self::FIRST.0 - 1
+	POP Self::FIRST.0 - 2
+	LINKCLICK Self::FIRST.0 - 3
+}
+
+const_ws! { TTS: u32;
+	/// Tooltip control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-styles)
+	/// (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ALWAYSTIP 0x01
+	NOPREFIX 0x02
+	NOANIMATE 0x10
+	NOFADE 0x20
+	BALLOON 0x40
+	CLOSE 0x80
+	USEVISUALSTYLE 0x100
+}
+
 const_ordinary! { TVC: u32;
 	/// [`NMTREEVIEW`](crate::NMTREEVIEW) `action` (`u32`).
 	=>
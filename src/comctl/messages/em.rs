@@ -1,9 +1,174 @@
 use crate::co;
-use crate::comctl::decl::EDITBALLOONTIP;
+use crate::comctl::decl::{
+	CHARFORMAT2, CHARRANGE, EDITBALLOONTIP, EDITSTREAM, FINDTEXTEX, PARAFORMAT2,
+};
 use crate::kernel::decl::{SysResult, WString};
 use crate::msg::WndMsg;
 use crate::prelude::MsgSend;
-use crate::user::privs::zero_as_badargs;
+use crate::user::decl::COLORREF;
+use crate::user::privs::{minus1_as_none, zero_as_badargs};
+
+/// [`EM_AUTOURLDETECT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-autourldetect)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `SysResult<()>`.
+pub struct AutoUrlDetect {
+	pub flags: co::AURL,
+}
+
+unsafe impl MsgSend for AutoUrlDetect {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match v {
+			0 => Ok(()),
+			_ => Err(co::ERROR::BAD_ARGUMENTS),
+		}
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::AUTOURLDETECT.into(),
+			wparam: self.flags.0 as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_EXGETSEL`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exgetsel)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `()`.
+pub struct ExGetSel<'a> {
+	pub range: &'a mut CHARRANGE,
+}
+
+unsafe impl<'a> MsgSend for ExGetSel<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXGETSEL.into(),
+			wparam: 0,
+			lparam: self.range as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_EXLIMITTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exlimittext)
+/// message parameters.
+///
+/// Rich edit controls only. If `max_chars` is `None`, the default limit of
+/// 64K characters is set.
+///
+/// Return type: `()`.
+pub struct ExLimitText {
+	pub max_chars: Option<u32>,
+}
+
+unsafe impl MsgSend for ExLimitText {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXLIMITTEXT.into(),
+			wparam: 0,
+			lparam: self.max_chars.unwrap_or(0) as _,
+		}
+	}
+}
+
+/// [`EM_EXSETSEL`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exsetsel)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `u32`.
+pub struct ExSetSel<'a> {
+	pub range: &'a CHARRANGE,
+}
+
+unsafe impl<'a> MsgSend for ExSetSel<'a> {
+	type RetType = u32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXSETSEL.into(),
+			wparam: 0,
+			lparam: self.range as *const _ as _,
+		}
+	}
+}
+
+/// [`EM_FINDTEXTEX`](https://learn.microsoft.com/en-us/windows/win32/controls/em-findtextex)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `Option<u32>`.
+pub struct FindTextEx<'a, 'b> {
+	pub flags: co::FR,
+	pub info: &'b mut FINDTEXTEX<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for FindTextEx<'a, 'b> {
+	type RetType = Option<u32>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_none(v).map(|i| i as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::FINDTEXTEX.into(),
+			wparam: self.flags.0 as _,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_GETCHARFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getcharformat)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `co::CFM`.
+pub struct GetCharFormat<'a> {
+	pub range: co::SCF,
+	pub format: &'a mut CHARFORMAT2,
+}
+
+unsafe impl<'a> MsgSend for GetCharFormat<'a> {
+	type RetType = co::CFM;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		co::CFM(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETCHARFORMAT.into(),
+			wparam: self.range.0 as _,
+			lparam: self.format as *mut _ as _,
+		}
+	}
+}
 
 /// [`EM_GETCUEBANNER`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getcuebanner)
 /// message parameters.
@@ -32,6 +197,56 @@ unsafe impl<'a> MsgSend for GetCueBanner<'a> {
 	}
 }
 
+/// [`EM_GETEVENTMASK`](https://learn.microsoft.com/en-us/windows/win32/controls/em-geteventmask)
+/// message, which has no parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `co::ENM`.
+pub struct GetEventMask {}
+
+unsafe impl MsgSend for GetEventMask {
+	type RetType = co::ENM;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		co::ENM(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETEVENTMASK.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_GETPARAFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getparaformat)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `co::PFM`.
+pub struct GetParaFormat<'a> {
+	pub format: &'a mut PARAFORMAT2,
+}
+
+unsafe impl<'a> MsgSend for GetParaFormat<'a> {
+	type RetType = co::PFM;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		co::PFM(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETPARAFORMAT.into(),
+			wparam: 0,
+			lparam: self.format as *mut _ as _,
+		}
+	}
+}
+
 /// [`EM_HIDEBALLOONTIP`](https://learn.microsoft.com/en-us/windows/win32/controls/em-hideballoontip)
 /// message, which has no parameters.
 ///
@@ -54,6 +269,85 @@ unsafe impl MsgSend for HideBalloonTip {
 	}
 }
 
+/// [`EM_HIDESELECTION`](https://learn.microsoft.com/en-us/windows/win32/controls/em-hideselection)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `()`.
+pub struct HideSelection {
+	pub hide: bool,
+}
+
+unsafe impl MsgSend for HideSelection {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::HIDESELECTION.into(),
+			wparam: self.hide as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_SETBKGNDCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setbkgndcolor)
+/// message parameters.
+///
+/// Rich edit controls only. If `color` is `None`, the system color is used.
+///
+/// Return type: `COLORREF`.
+pub struct SetBkgndColor {
+	pub color: Option<COLORREF>,
+}
+
+unsafe impl MsgSend for SetBkgndColor {
+	type RetType = COLORREF;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		COLORREF(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETBKGNDCOLOR.into(),
+			wparam: self.color.is_none() as _,
+			lparam: self.color.map_or(0, |c| c.0) as _,
+		}
+	}
+}
+
+/// [`EM_SETCHARFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setcharformat)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetCharFormat<'a> {
+	pub range: co::SCF,
+	pub format: &'a CHARFORMAT2,
+}
+
+unsafe impl<'a> MsgSend for SetCharFormat<'a> {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETCHARFORMAT.into(),
+			wparam: self.range.0 as _,
+			lparam: self.format as *const _ as _,
+		}
+	}
+}
+
 /// [`EM_SETCUEBANNER`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setcuebanner)
 /// message parameters..
 ///
@@ -79,6 +373,58 @@ unsafe impl MsgSend for SetCueBanner {
 	}
 }
 
+/// [`EM_SETEVENTMASK`](https://learn.microsoft.com/en-us/windows/win32/controls/em-seteventmask)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `co::ENM`.
+pub struct SetEventMask {
+	pub mask: co::ENM,
+}
+
+unsafe impl MsgSend for SetEventMask {
+	type RetType = co::ENM;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		co::ENM(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETEVENTMASK.into(),
+			wparam: 0,
+			lparam: self.mask.0 as _,
+		}
+	}
+}
+
+/// [`EM_SETPARAFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setparaformat)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetParaFormat<'a> {
+	pub format: &'a PARAFORMAT2,
+}
+
+unsafe impl<'a> MsgSend for SetParaFormat<'a> {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETPARAFORMAT.into(),
+			wparam: 0,
+			lparam: self.format as *const _ as _,
+		}
+	}
+}
+
 /// [`EM_SHOWBALLOONTIP`](https://learn.microsoft.com/en-us/windows/win32/controls/em-showballoontip)
 /// message parameters.
 ///
@@ -102,3 +448,57 @@ unsafe impl<'a, 'b, 'c> MsgSend for ShowBalloonTip<'a, 'b, 'c> {
 		}
 	}
 }
+
+/// [`EM_STREAMIN`](https://learn.microsoft.com/en-us/windows/win32/controls/em-streamin)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `u32`, the number of characters read.
+pub struct StreamIn<'a> {
+	pub format: co::SF,
+	pub stream: &'a mut EDITSTREAM,
+}
+
+unsafe impl<'a> MsgSend for StreamIn<'a> {
+	type RetType = u32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::STREAMIN.into(),
+			wparam: self.format.0 as _,
+			lparam: self.stream as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_STREAMOUT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-streamout)
+/// message parameters.
+///
+/// Rich edit controls only.
+///
+/// Return type: `u32`, the number of characters written.
+pub struct StreamOut<'a> {
+	pub format: co::SF,
+	pub stream: &'a mut EDITSTREAM,
+}
+
+unsafe impl<'a> MsgSend for StreamOut<'a> {
+	type RetType = u32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::STREAMOUT.into(),
+			wparam: self.format.0 as _,
+			lparam: self.stream as *mut _ as _,
+		}
+	}
+}
@@ -11,6 +11,7 @@ pub mod stm;
 pub mod tbm;
 pub mod tcm;
 pub mod trbm;
+pub mod ttm;
 pub mod tvm;
 pub mod udm;
 pub mod wm;
@@ -4,4 +4,7 @@ pub(crate) const HINST_COMMCTRL: isize = -1;
 pub(crate) const I_IMAGECALLBACK: isize = -1;
 pub(crate) const I_IMAGENONE: isize = -2;
 pub(crate) const L_MAX_URL_LENGTH: usize = 2048 + 32 + 4;
+pub(crate) const LF_FACESIZE: usize = 32;
+pub(crate) const LPSTR_TEXTCALLBACK: isize = -1;
 pub(crate) const MAX_LINKID_TEXT: usize = 48;
+pub(crate) const MAX_TAB_STOPS: usize = 32;
@@ -4,10 +4,13 @@ use std::marker::PhantomData;
 
 use crate::co;
 use crate::comctl::decl::{
-	BmpIdbRes, BmpInstId, HIMAGELIST, HTREEITEM, IdxStr, PFNLVGROUPCOMPARE,
-	PFNTVCOMPARE, TreeitemTvi,
+	BmpIdbRes, BmpInstId, EDITSTREAMCALLBACK, HIMAGELIST, HTREEITEM, IdxStr,
+	PFNLVGROUPCOMPARE, PFNTVCOMPARE, TreeitemTvi,
+};
+use crate::comctl::privs::{
+	HINST_COMMCTRL, L_MAX_URL_LENGTH, LF_FACESIZE, LPSTR_TEXTCALLBACK,
+	MAX_LINKID_TEXT, MAX_TAB_STOPS,
 };
-use crate::comctl::privs::{HINST_COMMCTRL, L_MAX_URL_LENGTH, MAX_LINKID_TEXT};
 use crate::kernel::decl::{HINSTANCE, HKEY, IdStr, SYSTEMTIME, WString};
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::privs::IS_INTRESOURCE;
@@ -35,6 +38,47 @@ pub struct BUTTON_SPLITINFO {
 	pub size: SIZE,
 }
 
+/// [`CHARFORMAT2`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-charformat2w_1)
+/// struct.
+#[repr(C)]
+pub struct CHARFORMAT2 {
+	cbSize: u32,
+	pub dwMask: co::CFM,
+	pub dwEffects: co::CFE,
+	pub yHeight: i32,
+	pub yOffset: i32,
+	pub crTextColor: COLORREF,
+	pub bCharSet: u8,
+	pub bPitchAndFamily: u8,
+	szFaceName: [u16; LF_FACESIZE],
+	pub wWeight: u16,
+	pub sSpacing: i16,
+	pub crBackColor: COLORREF,
+	pub lcid: u32,
+	pub dwCookie: u32,
+	pub sStyle: i16,
+	pub wKerning: u16,
+	pub bUnderlineType: u8,
+	pub bAnimation: u8,
+	pub bRevAuthor: u8,
+	pub bUnderlineColor: u8,
+}
+
+impl_default_with_size!(CHARFORMAT2, cbSize);
+
+impl CHARFORMAT2 {
+	pub_fn_string_arr_get_set!(szFaceName, set_szFaceName);
+}
+
+/// [`CHARRANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-charrange)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct CHARRANGE {
+	pub cpMin: i32,
+	pub cpMax: i32,
+}
+
 /// [`COLORSCHEME`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-colorscheme)
 /// struct.
 #[repr(C)]
@@ -82,6 +126,47 @@ impl<'a, 'b> EDITBALLOONTIP<'a, 'b> {
 	pub_fn_string_ptr_get_set!('b, pszText, set_pszText);
 }
 
+/// [`EDITSTREAM`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-editstream)
+/// struct.
+#[repr(C, packed(4))]
+pub struct EDITSTREAM {
+	pub dwCookie: usize,
+	pub dwError: u32,
+	pub pfnCallback: Option<EDITSTREAMCALLBACK>,
+}
+
+impl_default!(EDITSTREAM);
+
+/// [`ENLINK`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-enlink)
+/// struct.
+#[repr(C, packed(4))]
+pub struct ENLINK {
+	pub nmhdr: NMHDR,
+	pub msg: co::WM,
+	pub wParam: usize,
+	pub lParam: isize,
+	pub chrg: CHARRANGE,
+}
+
+impl_default!(ENLINK);
+
+/// [`FINDTEXTEX`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-findtextexw)
+/// struct.
+#[repr(C, packed(4))]
+pub struct FINDTEXTEX<'a> {
+	pub chrg: CHARRANGE,
+	lpstrText: *mut u16,
+	pub chrgText: CHARRANGE,
+
+	_lpstrText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(FINDTEXTEX, 'a);
+
+impl<'a> FINDTEXTEX<'a> {
+	pub_fn_string_ptr_get_set!('a, lpstrText, set_lpstrText);
+}
+
 /// [`HDITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-hditemw)
 /// struct.
 #[repr(C)]
@@ -407,6 +492,20 @@ impl_default!(LVITEM, 'a);
 
 impl<'a> LVITEM<'a> {
 	pub_fn_string_buf_get_set!('a, pszText, set_pszText, cchTextMax);
+
+	/// Copies the text into the buffer pointed by `pszText`, whose size is
+	/// given by `cchTextMax`. If the buffer is smaller, the text is truncated.
+	///
+	/// This is how the text is returned when answering an
+	/// [`LVN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/lvn-getdispinfo)
+	/// notification, whose buffer is owned by the list view.
+	pub fn copy_to_pszText(&mut self, text: &str) {
+		if !self.pszText.is_null() && self.cchTextMax > 0 {
+			WString::from_str(text).copy_to_slice(unsafe {
+				std::slice::from_raw_parts_mut(self.pszText, self.cchTextMax as _)
+			});
+		}
+	}
 }
 
 /// [`LVITEMINDEX`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-lvitemindex)
@@ -733,6 +832,15 @@ impl<'a> NMDAYSTATE<'a> {
 	pub_fn_array_buf_get_set!('a, prgDayState, set_prgDayState, cDayState, MONTHDAYSTATE);
 }
 
+/// [`NMHDFILTERBTNCLICK`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmhdfilterbtnclick)
+/// struct.
+#[repr(C)]
+pub struct NMHDFILTERBTNCLICK {
+	pub hdr: NMHDR,
+	pub iItem: i32,
+	pub rc: RECT,
+}
+
 /// [`NMHDR`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-nmhdr)
 /// struct.
 #[repr(C)]
@@ -761,6 +869,24 @@ impl NMHDR {
 	}
 }
 
+/// [`NMHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmheaderw)
+/// struct.
+#[repr(C)]
+pub struct NMHEADER<'a, 'b> {
+	pub hdr: NMHDR,
+	pub iItem: i32,
+	pub iButton: i32,
+	pitem: *mut HDITEM<'a>,
+
+	_pitem: PhantomData<&'b mut HDITEM<'a>>,
+}
+
+impl_default!(NMHEADER, 'a, 'b);
+
+impl<'a, 'b> NMHEADER<'a, 'b> {
+	pub_fn_ptr_get_set!('b, pitem, set_pitem, HDITEM<'a>);
+}
+
 /// [`NMITEMACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmitemactivate)
 /// struct.
 #[repr(C)]
@@ -966,6 +1092,16 @@ pub struct NMSELCHANGE {
 	pub stSelEnd: SYSTEMTIME,
 }
 
+/// [`NMTBHOTITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtbhotitem)
+/// struct.
+#[repr(C)]
+pub struct NMTBHOTITEM {
+	pub hdr: NMHDR,
+	pub idOld: i32,
+	pub idNew: i32,
+	pub dwFlags: co::HICF,
+}
+
 /// [`NMTCKEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtckeydown)
 /// struct.
 #[repr(C)]
@@ -977,6 +1113,26 @@ pub struct NMTCKEYDOWN {
 
 impl_default!(NMTCKEYDOWN);
 
+/// [`NMTOOLBAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtoolbarw)
+/// struct.
+#[repr(C)]
+pub struct NMTOOLBAR<'a> {
+	pub hdr: NMHDR,
+	pub iItem: i32,
+	pub tbButton: TBBUTTON<'a>,
+	cchText: i32,
+	pszText: *mut u16,
+	pub rcButton: RECT,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTOOLBAR, 'a);
+
+impl<'a> NMTOOLBAR<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, cchText);
+}
+
 /// [`NMTREEVIEW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtreevieww)
 /// struct.
 #[repr(C)]
@@ -988,6 +1144,27 @@ pub struct NMTREEVIEW<'a, 'b> {
 	pub ptDrag: POINT,
 }
 
+/// [`NMTTDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmttdispinfow)
+/// struct.
+#[repr(C)]
+pub struct NMTTDISPINFO<'a> {
+	pub hdr: NMHDR,
+	lpszText: *mut u16,
+	szText: [u16; 80],
+	pub hinst: HINSTANCE,
+	pub uFlags: co::TTF,
+	pub lParam: isize,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTTDISPINFO, 'a);
+
+impl<'a> NMTTDISPINFO<'a> {
+	pub_fn_string_ptr_get_set!('a, lpszText, set_lpszText);
+	pub_fn_string_arr_get_set!(szText, set_szText);
+}
+
 /// [`NMTVCUSTOMDRAW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtvcustomdraw)
 /// stuct.
 #[repr(C)]
@@ -1028,6 +1205,70 @@ pub struct NMVIEWCHANGE {
 	pub dwNewView: co::MCMV,
 }
 
+/// [`PARAFORMAT2`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-paraformat2_1)
+/// struct.
+#[repr(C)]
+pub struct PARAFORMAT2 {
+	cbSize: u32,
+	pub dwMask: co::PFM,
+	pub wNumbering: u16,
+	pub wEffects: u16,
+	pub dxStartIndent: i32,
+	pub dxRightIndent: i32,
+	pub dxOffset: i32,
+	pub wAlignment: co::PFA,
+	cTabCount: i16,
+	rgxTabs: [i32; MAX_TAB_STOPS],
+	pub dySpaceBefore: i32,
+	pub dySpaceAfter: i32,
+	pub dyLineSpacing: i32,
+	pub sStyle: i16,
+	pub bLineSpacingRule: u8,
+	pub bOutlineLevel: u8,
+	pub wShadingWeight: u16,
+	pub wShadingStyle: u16,
+	pub wNumberingStart: u16,
+	pub wNumberingStyle: u16,
+	pub wNumberingTab: u16,
+	pub wBorderSpace: u16,
+	pub wBorderWidth: u16,
+	pub wBorders: u16,
+}
+
+impl_default_with_size!(PARAFORMAT2, cbSize);
+
+impl PARAFORMAT2 {
+	/// Returns the `rgxTabs` field, limited by `cTabCount`.
+	#[must_use]
+	pub fn rgxTabs(&self) -> &[i32] {
+		&self.rgxTabs[..(self.cTabCount as usize).min(MAX_TAB_STOPS)]
+	}
+
+	/// Sets the `rgxTabs` and `cTabCount` fields.
+	///
+	/// # Panics
+	///
+	/// Panics if `tabs` has more than 32 elements.
+	pub fn set_rgxTabs(&mut self, tabs: &[i32]) {
+		if tabs.len() > MAX_TAB_STOPS {
+			panic!("Too many tab stops: {}.", tabs.len());
+		}
+		self.rgxTabs[..tabs.len()].copy_from_slice(tabs);
+		self.cTabCount = tabs.len() as _;
+	}
+}
+
+/// [`SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-selchange)
+/// struct.
+#[repr(C, packed(4))]
+pub struct SELCHANGE {
+	pub nmhdr: NMHDR,
+	pub chrg: CHARRANGE,
+	pub seltyp: co::SEL,
+}
+
+impl_default!(SELCHANGE);
+
 /// [`TBADDBITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tbaddbitmap)
 /// struct.
 #[repr(C)]
@@ -1278,6 +1519,56 @@ impl<'a> TCITEM<'a> {
 	pub_fn_string_buf_get_set!('a, pszText, set_pszText, cchTextMax);
 }
 
+/// [`TTTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tttoolinfow)
+/// struct.
+#[repr(C)]
+pub struct TTTOOLINFO<'a> {
+	cbSize: u32,
+	pub uFlags: co::TTF,
+	pub hwnd: HWND,
+	pub uId: usize,
+	pub rect: RECT,
+	pub hinst: HINSTANCE,
+	lpszText: *mut u16,
+	pub lParam: isize,
+	lpReserved: *mut std::ffi::c_void,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(TTTOOLINFO, cbSize, 'a);
+
+impl<'a> TTTOOLINFO<'a> {
+	/// Returns the `lpszText` field, if any.
+	///
+	/// Returns `None` if the text is retrieved through
+	/// [`TTN_GETDISPINFO`](crate::co::TTN::GETDISPINFO)
+	/// notifications.
+	#[must_use]
+	pub fn lpszText(&self) -> Option<String> {
+		if self.lpszText as isize == LPSTR_TEXTCALLBACK {
+			None
+		} else {
+			unsafe { self.lpszText.as_mut() }.map(|psz| {
+				WString::from_wchars_nullt(psz).to_string()
+			})
+		}
+	}
+
+	/// Sets the `lpszText` field.
+	pub fn set_lpszText(&mut self, buf: Option<&'a mut WString>) {
+		self.lpszText = buf.map_or(std::ptr::null_mut(), |buf| unsafe { buf.as_mut_ptr() });
+	}
+
+	/// Sets the `lpszText` field to `LPSTR_TEXTCALLBACK`, so the text will be
+	/// retrieved through
+	/// [`TTN_GETDISPINFO`](crate::co::TTN::GETDISPINFO)
+	/// notifications.
+	pub fn set_lpszText_callback(&mut self) {
+		self.lpszText = LPSTR_TEXTCALLBACK as _;
+	}
+}
+
 /// [`TVHITTESTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tvhittestinfo)
 /// struct.
 #[repr(C)]
@@ -1,7 +1,11 @@
+use std::cell::UnsafeCell;
+use std::future::Future;
 use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::events::{ProcessResult, WindowEventsAll};
+use crate::gui::executor::{poll_task, spawn_task, WM_TASK_WAKE};
+use crate::gui::layout::Layout;
 use crate::gui::layout_arranger::{Horz, LayoutArranger, Vert};
 use crate::gui::privs::{post_quit_error, QUIT_ERROR};
 use crate::kernel::decl::{AnyResult, HINSTANCE, SysResult};
@@ -10,6 +14,7 @@ use crate::prelude::{GuiEvents, GuiParent, Handle, kernel_Hinstance, user_Hwnd};
 use crate::user::decl::{
 	DispatchMessage, GetMessage, HACCEL, HWND, MSG, TranslateMessage,
 };
+use crate::user::guard::DestroyAcceleratorTableGuard;
 
 /// Base to `RawBase` and `DlgBase`, which means all container windows.
 pub(in crate::gui) struct Base {
@@ -19,6 +24,7 @@ pub(in crate::gui) struct Base {
 	user_events: WindowEventsAll, // ordinary window events, inserted by user: only last added is executed (overwrite previous)
 	privileged_events: WindowEventsAll, // inserted internally to automate tasks: all will be executed
 	layout_arranger: LayoutArranger,
+	menu_accel_table: UnsafeCell<Option<DestroyAcceleratorTableGuard>>, // built by MenuBuilder
 }
 
 impl Base {
@@ -41,6 +47,7 @@ impl Base {
 			user_events: WindowEventsAll::new(),
 			privileged_events: WindowEventsAll::new(),
 			layout_arranger: LayoutArranger::new(),
+			menu_accel_table: UnsafeCell::new(None),
 		};
 		new_self.default_message_handlers();
 		new_self
@@ -73,6 +80,18 @@ impl Base {
 		)
 	}
 
+	/// Accelerator table built along with the menu, used by the main loop if
+	/// no other accelerator table was given.
+	pub(in crate::gui) fn menu_accel_table(&self) -> Option<&HACCEL> {
+		unsafe { &*self.menu_accel_table.get() }.as_deref()
+	}
+
+	pub(in crate::gui) fn set_menu_accel_table(&self,
+		haccel: DestroyAcceleratorTableGuard)
+	{
+		*unsafe { &mut *self.menu_accel_table.get() } = Some(haccel);
+	}
+
 	/// User events can be overriden; only the last one is executed.
 	pub(in crate::gui) fn on(&self) -> &WindowEventsAll {
 		if self.hwnd != HWND::NULL {
@@ -116,6 +135,10 @@ impl Base {
 		Ok(())
 	}
 
+	pub(in crate::gui) fn set_layout(&self, layout: Layout) -> SysResult<()> {
+		self.layout_arranger.set_layout(&self.hwnd, layout)
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
@@ -138,6 +161,14 @@ impl Base {
 
 	pub(in crate::gui) fn run_ui_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		Self::run_ui_thread_of(&self.hwnd, func)
+	}
+
+	/// Like [`run_ui_thread`](Self::run_ui_thread), but takes only the window
+	/// handle, which can be sent to another thread, unlike `Base` itself.
+	pub(in crate::gui) fn run_ui_thread_of<F>(hwnd: &HWND, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
 		// This method is analog to SendMessage (synchronous), but intended to
 		// be called from another thread, so a callback function can, tunelled
@@ -151,7 +182,7 @@ impl Base {
 
 		// Bypass any modals and send straight to main window. This avoids any
 		// blind spots of unhandled messages by a modal being created/destroyed.
-		self.hwnd.GetAncestor(co::GA::ROOTOWNER)
+		hwnd.GetAncestor(co::GA::ROOTOWNER)
 			.map(|hwnd| {
 				hwnd.SendMessage(WndMsg {
 					msg_id: Self::WM_UI_THREAD,
@@ -161,6 +192,19 @@ impl Base {
 			});
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		if self.hwnd == HWND::NULL {
+			panic!("Cannot spawn a task before window creation.");
+		}
+
+		// Wake messages go straight to main window, like run_ui_thread.
+		let hwnd = self.hwnd.GetAncestor(co::GA::ROOTOWNER)
+			.unwrap_or(unsafe { self.hwnd.raw_copy() });
+		spawn_task(&hwnd, future);
+	}
+
 	fn default_message_handlers(&self) {
 		// We cant pass a pointer to Self because at this moment the parent
 		// struct isn't created and pinned yet, so we make LayoutArranger
@@ -179,6 +223,13 @@ impl Base {
 			}
 			Ok(None) // not meaningful
 		});
+
+		self.privileged_events.wm(WM_TASK_WAKE, |p| {
+			if co::WM(p.wparam as _) == WM_TASK_WAKE { // additional safety check
+				poll_task(p.lparam as _).unwrap_or_else(|err| post_quit_error(p, err));
+			}
+			Ok(None) // not meaningful
+		});
 	}
 
 	pub(in crate::gui) fn run_main_loop(
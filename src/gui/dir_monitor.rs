@@ -0,0 +1,178 @@
+use std::cell::{RefCell, UnsafeCell};
+use std::collections::HashMap;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::{
+	AnyResult, DirChange, DirWatcher, DirWatcherStopper, SysResult,
+};
+use crate::prelude::{GuiEvents, GuiParent, Handle};
+use crate::user::decl::HWND;
+
+type MonitorFunc = dyn Fn(SysResult<Vec<DirChange>>) -> AnyResult<()>;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+	// The closures never leave the UI thread; the background threads refer to
+	// them by ID, and they're looked up only when the changes arrive back.
+	static FUNCS: RefCell<HashMap<usize, Weak<MonitorFunc>>> = RefCell::new(HashMap::new());
+}
+
+struct Obj { // actual fields of DirMonitor
+	parent_ptr: NonNull<Base>,
+	id: usize, // key in FUNCS
+	watcher: UnsafeCell<Option<DirWatcher>>, // taken by the thread when started
+	stopper: DirWatcherStopper,
+	func: Rc<MonitorFunc>,
+	_pin: PhantomPinned,
+}
+
+impl Drop for Obj {
+	fn drop(&mut self) {
+		let _ = FUNCS.try_with(|funcs| funcs.borrow_mut().remove(&self.id));
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Runs a [`DirWatcher`](crate::DirWatcher) in a background thread, calling a
+/// closure in the UI thread of the parent window whenever changes are
+/// reported.
+///
+/// Only the watcher is sent to the background thread: the closure stays in the
+/// UI thread, and changes reported after the monitor is dropped are discarded.
+///
+/// If created before the parent window, the monitor starts when the window is
+/// created, and stops when it's destroyed. If created afterwards – say, in an
+/// event closure –, it starts immediately, and you must call
+/// [`stop`](crate::gui::DirMonitor::stop) when it's no longer needed.
+///
+/// The closure receives an
+/// [`ERROR::NOTIFY_ENUM_DIR`](crate::co::ERROR::NOTIFY_ENUM_DIR) error when
+/// the changes overflowed the buffer and were lost, and the monitor keeps
+/// running. Any other error stops the monitor. If the closure itself returns an
+/// error, it will be forwarded to
+/// [`WindowMain::run_main`](crate::gui::WindowMain::run_main), terminating the
+/// application.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui, AnyResult, DirWatcher, SysResult};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let wnd2 = wnd.clone(); // to pass into the closure
+///
+/// let _monitor = gui::DirMonitor::new(
+///     &wnd,
+///     DirWatcher::open("C:\\Temp", false, co::FILE_NOTIFY_CHANGE::FILE_NAME)?,
+///     move |changes| -> AnyResult<()> {
+///         let changes = changes?;
+///         wnd2.hwnd().SetWindowText(&format!("{} changes", changes.len()))?;
+///         Ok(())
+///     },
+/// );
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone)]
+pub struct DirMonitor(Pin<Arc<Obj>>);
+
+unsafe impl Send for DirMonitor {}
+
+impl DirMonitor {
+	/// Instantiates a new `DirMonitor` object, which takes ownership of the
+	/// watcher.
+	#[must_use]
+	pub fn new<F>(parent: &impl GuiParent, watcher: DirWatcher, func: F) -> Self
+		where F: Fn(SysResult<Vec<DirChange>>) -> AnyResult<()> + 'static,
+	{
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+					stopper: watcher.stopper(),
+					watcher: UnsafeCell::new(Some(watcher)),
+					func: Rc::new(func),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		if *parent_ref.hwnd() == HWND::NULL {
+			new_self.default_message_handlers(parent_ref);
+		} else {
+			new_self.start(); // parent already created
+		}
+		new_self
+	}
+
+	fn default_message_handlers(&self, parent_ref: &Base) {
+		let self2 = self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.start();
+			Ok(None) // not meaningful
+		});
+
+		let self2 = self.clone();
+		parent_ref.privileged_on().wm_destroy(move || {
+			self2.stop()?;
+			Ok(())
+		});
+	}
+
+	fn start(&self) {
+		let mut watcher = match unsafe { &mut *self.0.watcher.get() }.take() {
+			Some(watcher) => watcher,
+			None => return, // already started
+		};
+
+		let id = self.0.id;
+		FUNCS.with(|funcs| funcs.borrow_mut().insert(id, Rc::downgrade(&self.0.func)));
+		let hparent = unsafe { self.0.parent_ptr.as_ref().hwnd().raw_copy() };
+
+		std::thread::spawn(move || {
+			loop {
+				let (changes, keep_going) = match watcher.read() {
+					Ok(Some(changes)) => (Ok(changes), true),
+					Ok(None) => break, // stopped
+					Err(co::ERROR::NOTIFY_ENUM_DIR) => (Err(co::ERROR::NOTIFY_ENUM_DIR), true),
+					Err(e) => (Err(e), false),
+				};
+
+				Base::run_ui_thread_of(&hparent, move || Self::forward(id, changes));
+				if !keep_going {
+					break;
+				}
+			}
+		});
+	}
+
+	/// Runs in the UI thread, calling the closure if the monitor still exists.
+	fn forward(id: usize, changes: SysResult<Vec<DirChange>>) -> AnyResult<()> {
+		let func = FUNCS.with(|funcs| {
+			funcs.borrow().get(&id).and_then(|func| func.upgrade())
+		}); // not borrowed while the closure runs
+		match func {
+			Some(func) => func(changes),
+			None => Ok(()), // monitor dropped meanwhile
+		}
+	}
+
+	/// Stops the monitor. No further changes will be reported.
+	pub fn stop(&self) -> SysResult<()> {
+		self.0.stopper.stop()
+	}
+}
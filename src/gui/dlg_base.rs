@@ -1,3 +1,5 @@
+use std::future::Future;
+
 use crate::co;
 use crate::gui::base::Base;
 use crate::gui::events::{ProcessResult, WindowEventsAll};
@@ -5,7 +7,7 @@ use crate::gui::privs::{post_quit_error, ui_font};
 use crate::kernel::decl::{AnyResult, IdStr, SysResult};
 use crate::msg::{wm, WndMsg};
 use crate::prelude::{Handle, MsgSendRecv, user_Hinstance, user_Hwnd};
-use crate::user::decl::HWND;
+use crate::user::decl::{HACCEL, HWND};
 
 /// Base to all dialog windows.
 ///
@@ -52,6 +54,10 @@ impl DlgBase {
 		self.base.parent()
 	}
 
+	pub(in crate::gui) fn menu_accel_table(&self) -> Option<&HACCEL> {
+		self.base.menu_accel_table()
+	}
+
 	pub(in crate::gui) fn create_dialog_param(&self) -> SysResult<()> {
 		if *self.base.hwnd() != HWND::NULL {
 			panic!("Cannot create dialog twice.");
@@ -106,6 +112,12 @@ impl DlgBase {
 		self.base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.base.spawn_local(future);
+	}
+
 	extern "system" fn dialog_proc(
 		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
 	{
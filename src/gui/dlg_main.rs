@@ -1,3 +1,4 @@
+use std::future::Future;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -72,6 +73,12 @@ impl DlgMain {
 		self.0.dlg_base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.0.dlg_base.spawn_local(future);
+	}
+
 	pub(in crate::gui) fn run_main(&self,
 		cmd_show: Option<co::SW>) -> AnyResult<i32>
 	{
@@ -85,7 +92,9 @@ impl DlgMain {
 		self.set_icon_if_any(&hinst).unwrap();
 		self.hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 
-		Base::run_main_loop(haccel.as_ref()) // blocks until window is closed
+		Base::run_main_loop(
+			haccel.as_ref().or(self.0.dlg_base.menu_accel_table()),
+		) // blocks until window is closed
 	}
 
 	fn default_message_handlers(&self) {
@@ -1,3 +1,4 @@
+use std::future::Future;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -59,6 +60,12 @@ impl DlgModal {
 		self.0.dlg_base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.0.dlg_base.spawn_local(future);
+	}
+
 	pub(in crate::gui) fn show_modal(&self) -> SysResult<i32> {
 		self.0.dlg_base.dialog_box_param()
 	}
@@ -0,0 +1,142 @@
+use crate::co;
+use crate::comctl::decl::{NMHDFILTERBTNCLICK, NMHEADER};
+use crate::gui::base::Base;
+use crate::gui::events::base_events_proxy::BaseEventsProxy;
+use crate::kernel::decl::AnyResult;
+
+/// Exposes header control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-header-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct HeaderEvents(BaseEventsProxy);
+
+impl HeaderEvents {
+	pub(in crate::gui) fn new(parent_base: &Base, ctrl_id: u16) -> Self {
+		Self(BaseEventsProxy::new(parent_base, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_boolret! { hdn_begin_drag, co::HDN::BEGINDRAG, NMHEADER;
+		/// [`HDN_BEGINDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-begindrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_begin_filter_edit, co::HDN::BEGINFILTEREDIT, NMHEADER;
+		/// [`HDN_BEGINFILTEREDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-beginfilteredit)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { hdn_begin_track, co::HDN::BEGINTRACK, NMHEADER;
+		/// [`HDN_BEGINTRACK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-begintrack)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_divider_dbl_click, co::HDN::DIVIDERDBLCLICK, NMHEADER;
+		/// [`HDN_DIVIDERDBLCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-dividerdblclick)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_drop_down, co::HDN::DROPDOWN, NMHEADER;
+		/// [`HDN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-dropdown)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { hdn_end_drag, co::HDN::ENDDRAG, NMHEADER;
+		/// [`HDN_ENDDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-enddrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_end_filter_edit, co::HDN::ENDFILTEREDIT, NMHEADER;
+		/// [`HDN_ENDFILTEREDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-endfilteredit)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_end_track, co::HDN::ENDTRACK, NMHEADER;
+		/// [`HDN_ENDTRACK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-endtrack)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { hdn_filter_btn_click, co::HDN::FILTERBTNCLICK, NMHDFILTERBTNCLICK;
+		/// [`HDN_FILTERBTNCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-filterbtnclick)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_filter_change, co::HDN::FILTERCHANGE, NMHEADER;
+		/// [`HDN_FILTERCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-filterchange)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_item_changed, co::HDN::ITEMCHANGED, NMHEADER;
+		/// [`HDN_ITEMCHANGED`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-itemchanged)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { hdn_item_changing, co::HDN::ITEMCHANGING, NMHEADER;
+		/// [`HDN_ITEMCHANGING`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-itemchanging)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_item_click, co::HDN::ITEMCLICK, NMHEADER;
+		/// [`HDN_ITEMCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-itemclick)
+		/// notification.
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{gui, NMHEADER};
+		///
+		/// let header: gui::Header; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let header = gui::Header::new(&wnd, gui::HeaderOpts::default());
+		///
+		/// header.on().hdn_item_click({
+		///     let header = header.clone();
+		///     move |p: &NMHEADER| -> winsafe::AnyResult<()> {
+		///         let item = header.items().get(p.iItem as _);
+		///         println!("Clicked: {}", item.text());
+		///         Ok(())
+		///     }
+		/// });
+		/// ```
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_item_dbl_click, co::HDN::ITEMDBLCLICK, NMHEADER;
+		/// [`HDN_ITEMDBLCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-itemdblclick)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_item_key_down, co::HDN::ITEMKEYDOWN, NMHEADER;
+		/// [`HDN_ITEMKEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-itemkeydown)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_item_state_icon_click, co::HDN::ITEMSTATEICONCLICK, NMHEADER;
+		/// [`HDN_ITEMSTATEICONCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-itemstateiconclick)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { hdn_overflow_click, co::HDN::OVERFLOWCLICK, NMHEADER;
+		/// [`HDN_OVERFLOWCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-overflowclick)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { hdn_track, co::HDN::TRACK, NMHEADER;
+		/// [`HDN_TRACK`](https://learn.microsoft.com/en-us/windows/win32/controls/hdn-track)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_i32ret! { nm_r_click, co::NM::RCLICK;
+		/// [`NM_RCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-rclick-header-)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { nm_released_capture, co::NM::RELEASEDCAPTURE;
+		/// [`NM_RELEASEDCAPTURE`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-releasedcapture-header-)
+		/// notification.
+	}
+}
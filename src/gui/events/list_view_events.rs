@@ -117,9 +117,40 @@ impl ListViewEvents {
 		/// notification.
 	}
 
-	pub_fn_nfy_withparm_noret! { lvn_get_disp_info, co::LVN::GETDISPINFO, NMLVDISPINFO;
+	pub_fn_nfy_withmutparm_noret! { lvn_get_disp_info, co::LVN::GETDISPINFO, NMLVDISPINFO;
 		/// [`LVN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/lvn-getdispinfo)
 		/// notification.
+		///
+		/// This is how a virtual list view – created with
+		/// [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) – retrieves the data
+		/// of its items. Only the data requested in `item.mask` must be filled.
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{co, gui, NMLVDISPINFO};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// let my_list = gui::ListView::new(
+		///     &wnd,
+		///     gui::ListViewOpts {
+		///         list_view_style: co::LVS::REPORT | co::LVS::OWNERDATA,
+		///         columns: vec![("Number".to_owned(), 120)],
+		///         item_count: 500_000,
+		///         ..Default::default()
+		///     },
+		/// );
+		///
+		/// my_list.on().lvn_get_disp_info(|p: &mut NMLVDISPINFO| {
+		///     if p.item.mask.has(co::LVIF::TEXT) {
+		///         p.item.copy_to_pszText(&format!("Row {}", p.item.iItem));
+		///     }
+		///     Ok(())
+		/// });
+		/// ```
 	}
 
 	/// [`LVN_GETEMPTYMARKUP`](https://learn.microsoft.com/en-us/windows/win32/controls/lvn-getemptymarkup)
@@ -7,14 +7,19 @@ mod combo_box_events;
 mod date_time_picker_events;
 mod edit_events;
 mod func_store;
+mod header_events;
 mod label_events;
 mod list_box_events;
 mod list_view_events;
 mod month_calendar_events;
 mod radio_group_events;
+mod rich_edit_events;
 mod status_bar_events;
 mod tab_events;
+mod toolbar_events;
+mod tooltip_events;
 mod trackbar_events;
+mod tray_icon_events;
 mod tree_view_events;
 mod up_down_events;
 mod window_events_all;
@@ -24,14 +29,19 @@ pub use button_events::ButtonEvents;
 pub use combo_box_events::ComboBoxEvents;
 pub use date_time_picker_events::DateTimePickerEvents;
 pub use edit_events::EditEvents;
+pub use header_events::HeaderEvents;
 pub use label_events::LabelEvents;
 pub use list_box_events::ListBoxEvents;
 pub use list_view_events::ListViewEvents;
 pub use month_calendar_events::MonthCalendarEvents;
 pub use radio_group_events::RadioGroupEvents;
+pub use rich_edit_events::RichEditEvents;
 pub use status_bar_events::StatusBarEvents;
 pub use tab_events::TabEvents;
+pub use toolbar_events::ToolbarEvents;
+pub use tooltip_events::TooltipEvents;
 pub use trackbar_events::TrackbarEvents;
+pub use tray_icon_events::TrayIconEvents;
 pub use tree_view_events::TreeViewEvents;
 pub use up_down_events::UpDownEvents;
 pub use window_events_all::WindowEventsAll;
@@ -0,0 +1,113 @@
+use crate::co;
+use crate::comctl::decl::{ENLINK, SELCHANGE};
+use crate::gui::base::Base;
+use crate::gui::events::base_events_proxy::BaseEventsProxy;
+use crate::kernel::decl::AnyResult;
+
+/// Exposes rich edit control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rich-edit-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct RichEditEvents(BaseEventsProxy);
+
+impl RichEditEvents {
+	pub(in crate::gui) fn new(parent_base: &Base, ctrl_id: u16) -> Self {
+		Self(BaseEventsProxy::new(parent_base, ctrl_id))
+	}
+
+	pub_fn_cmd_noparm_noret! { en_change, co::EN::CHANGE;
+		/// [`EN_CHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-change)
+		/// command notification.
+		///
+		/// Only sent if [`ENM::CHANGE`](crate::co::ENM::CHANGE) is in the event
+		/// mask.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_h_scroll, co::EN::HSCROLL;
+		/// [`EN_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/en-hscroll)
+		/// command notification.
+		///
+		/// Only sent if [`ENM::SCROLL`](crate::co::ENM::SCROLL) is in the event
+		/// mask.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_kill_focus, co::EN::KILLFOCUS;
+		/// [`EN_KILLFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/en-killfocus)
+		/// command notification.
+	}
+
+	pub_fn_nfy_withparm_i32ret! { en_link, co::EN::LINK, ENLINK;
+		/// [`EN_LINK`](https://learn.microsoft.com/en-us/windows/win32/controls/en-link)
+		/// notification.
+		///
+		/// Only sent if [`ENM::LINK`](crate::co::ENM::LINK) is in the event
+		/// mask. Return zero to let the control process the mouse message.
+		///
+		/// # Examples
+		///
+		/// Opening a clicked URL detected with
+		/// [`RichEdit::auto_url_detect`](crate::gui::RichEdit::auto_url_detect):
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{co, gui, AnyResult};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// let rich: gui::RichEdit;
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let rich = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+		///
+		/// let rich2 = rich.clone(); // to pass into the closure
+		///
+		/// rich.on().en_link(
+		///     move |p| -> AnyResult<i32> {
+		///         if p.msg == co::WM::LBUTTONUP {
+		///             let chrg = p.chrg; // packed struct, copy the field
+		///             rich2.set_selection(chrg);
+		///             println!("Link from {} to {}", chrg.cpMin, chrg.cpMax);
+		///         }
+		///         Ok(0)
+		///     },
+		/// );
+		/// ```
+	}
+
+	pub_fn_cmd_noparm_noret! { en_max_text, co::EN::MAXTEXT;
+		/// [`EN_MAXTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/en-maxtext)
+		/// command notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { en_sel_change, co::EN::SELCHANGE, SELCHANGE;
+		/// [`EN_SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-selchange)
+		/// notification.
+		///
+		/// Only sent if [`ENM::SELCHANGE`](crate::co::ENM::SELCHANGE) is in the
+		/// event mask.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_set_focus, co::EN::SETFOCUS;
+		/// [`EN_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/en-setfocus)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_update, co::EN::UPDATE;
+		/// [`EN_UPDATE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-update)
+		/// command notification.
+		///
+		/// Only sent if [`ENM::UPDATE`](crate::co::ENM::UPDATE) is in the event
+		/// mask.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_v_scroll, co::EN::VSCROLL;
+		/// [`EN_VSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/en-vscroll)
+		/// command notification.
+		///
+		/// Only sent if [`ENM::SCROLL`](crate::co::ENM::SCROLL) is in the event
+		/// mask.
+	}
+}
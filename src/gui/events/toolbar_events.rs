@@ -0,0 +1,98 @@
+use crate::co;
+use crate::comctl::decl::{NMMOUSE, NMTBHOTITEM, NMTOOLBAR};
+use crate::gui::base::Base;
+use crate::gui::events::base_events_proxy::BaseEventsProxy;
+use crate::kernel::decl::AnyResult;
+
+/// Exposes toolbar control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-toolbar-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+///
+/// **Note:** Button clicks are sent to the parent window as
+/// [`WM_COMMAND`](crate::gui::events::WindowEvents::wm_command) messages, with
+/// the command ID of the button.
+pub struct ToolbarEvents(BaseEventsProxy);
+
+impl ToolbarEvents {
+	pub(in crate::gui) fn new(parent_base: &Base, ctrl_id: u16) -> Self {
+		Self(BaseEventsProxy::new(parent_base, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_click, co::NM::CLICK, NMMOUSE;
+		/// [`NM_CLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-click-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_dbl_clk, co::NM::DBLCLK, NMMOUSE;
+		/// [`NM_DBLCLK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-dblclk-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_rclick, co::NM::RCLICK, NMMOUSE;
+		/// [`NM_RCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-rclick-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_r_dbl_clk, co::NM::RDBLCLK, NMMOUSE;
+		/// [`NM_RDBLCLK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-rdblclk-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_begin_drag, co::TBN::BEGINDRAG, NMTOOLBAR;
+		/// [`TBN_BEGINDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-begindrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_deleting_button, co::TBN::DELETINGBUTTON, NMTOOLBAR;
+		/// [`TBN_DELETINGBUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-deletingbutton)
+		/// notification.
+	}
+
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// notification.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui};
+	///
+	/// let toolbar: gui::Toolbar; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	///
+	/// toolbar.on().tbn_drop_down(|p| {
+	///     println!("Dropdown of button {}", p.iItem);
+	///     Ok(co::TBDDRET::DEFAULT)
+	/// });
+	/// ```
+	pub fn tbn_drop_down<F>(&self, func: F)
+		where F: Fn(&NMTOOLBAR) -> AnyResult<co::TBDDRET> + 'static,
+	{
+		self.0.wm_notify(co::TBN::DROPDOWN,
+			move |p| Ok(Some(func(unsafe { p.cast_nmhdr::<NMTOOLBAR>() })?.0 as _)));
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_end_drag, co::TBN::ENDDRAG, NMTOOLBAR;
+		/// [`TBN_ENDDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-enddrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { tbn_hot_item_change, co::TBN::HOTITEMCHANGE, NMTBHOTITEM;
+		/// [`TBN_HOTITEMCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-hotitemchange)
+		/// notification.
+		///
+		/// Return `true` to prevent the hot item from changing.
+	}
+
+	pub_fn_nfy_noparm_noret! { tbn_toolbar_change, co::TBN::TOOLBARCHANGE;
+		/// [`TBN_TOOLBARCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-toolbarchange)
+		/// notification.
+	}
+}
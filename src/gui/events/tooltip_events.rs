@@ -0,0 +1,100 @@
+use std::cell::UnsafeCell;
+
+use crate::co;
+use crate::comctl::decl::NMTTDISPINFO;
+use crate::gui::events::func_store::FuncStore;
+use crate::kernel::decl::AnyResult;
+use crate::msg::wm;
+
+/// Exposes tooltip control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-notifications).
+///
+/// Unlike the other native controls, these events are not proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window:
+/// tooltip notifications are identified by the tool which triggered them, not
+/// by a control ID, so the tooltip itself dispatches them.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct TooltipEvents(TooltipNfys);
+
+impl TooltipEvents {
+	pub(in crate::gui) fn new() -> Self {
+		Self(TooltipNfys::new())
+	}
+
+	/// Runs the last added user function for the given notification, if any.
+	pub(in crate::gui) fn process(&self, wm_nfy: wm::Notify) -> AnyResult<()> {
+		self.0.process(wm_nfy)
+	}
+
+	pub_fn_nfy_withmutparm_noret! { ttn_get_disp_info, co::TTN::GETDISPINFO, NMTTDISPINFO;
+		/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo)
+		/// notification.
+		///
+		/// Sent for tools whose text was not given when they were added.
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::gui;
+		///
+		/// let tooltip: gui::Tooltip; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let tooltip = gui::Tooltip::new(&wnd, gui::TooltipOpts::default());
+		///
+		/// tooltip.on().ttn_get_disp_info(|p| {
+		///     p.set_szText("Dynamic text");
+		///     Ok(())
+		/// });
+		/// ```
+	}
+
+	pub_fn_nfy_noparm_noret! { ttn_link_click, co::TTN::LINKCLICK;
+		/// [`TTN_LINKCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-linkclick)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { ttn_pop, co::TTN::POP;
+		/// [`TTN_POP`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-pop)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { ttn_show, co::TTN::SHOW;
+		/// [`TTN_SHOW`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-show)
+		/// notification.
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Stores the tooltip notifications, keyed by notification code.
+struct TooltipNfys(
+	UnsafeCell<
+		FuncStore<
+			co::NM,
+			Box<dyn Fn(wm::Notify) -> AnyResult<Option<isize>>>, // return value is never meaningful
+		>,
+	>,
+);
+
+impl TooltipNfys {
+	fn new() -> Self {
+		Self(UnsafeCell::new(FuncStore::new()))
+	}
+
+	fn wm_notify<F>(&self, code: impl Into<co::NM>, func: F)
+		where F: Fn(wm::Notify) -> AnyResult<Option<isize>> + 'static,
+	{
+		unsafe { &mut *self.0.get() }.push(code.into(), Box::new(func));
+	}
+
+	fn process(&self, wm_nfy: wm::Notify) -> AnyResult<()> {
+		let nfys = unsafe { &*self.0.get() };
+		if let Some(func) = nfys.find(wm_nfy.nmhdr.code) {
+			func(wm_nfy)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,170 @@
+use std::cell::UnsafeCell;
+
+use crate::co;
+use crate::gui::events::func_store::FuncStore;
+use crate::kernel::decl::AnyResult;
+use crate::user::decl::POINT;
+
+/// Exposes [`TrayIcon`](crate::gui::TrayIcon)
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw#remarks).
+///
+/// The mouse events receive the screen coordinates where the event occurred,
+/// which can be used to display a popup menu with
+/// [`TrayIcon::show_menu`](crate::gui::TrayIcon::show_menu).
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// tray icon.
+pub struct TrayIconEvents {
+	events: UnsafeCell<
+		FuncStore<
+			u32, // NIN or WM value
+			Box<dyn Fn(POINT) -> AnyResult<()>>,
+		>,
+	>,
+}
+
+impl TrayIconEvents {
+	pub(in crate::gui) fn new() -> Self {
+		Self { events: UnsafeCell::new(FuncStore::new()) }
+	}
+
+	/// Searches for the last added user function for the given event, and runs
+	/// it, if any.
+	pub(in crate::gui) fn process_event(&self,
+		event: u32, pos: POINT) -> AnyResult<()>
+	{
+		let events = unsafe { &*self.events.get() };
+		match events.find(event) {
+			Some(func) => func(pos),
+			None => Ok(()),
+		}
+	}
+
+	fn add<F>(&self, event: u32, func: F)
+		where F: Fn(POINT) -> AnyResult<()> + 'static,
+	{
+		unsafe { &mut *self.events.get() }.push(event, Box::new(func));
+	}
+
+	/// [`NIN_BALLOONTIMEOUT`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shell_notifyiconw)
+	/// notification.
+	///
+	/// Sent when the balloon is closed, either by the timeout or by the user
+	/// clicking the close button.
+	pub fn nin_balloon_timeout<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.add(co::NIN::BALLOONTIMEOUT.0, move |_| func());
+	}
+
+	/// [`NIN_BALLOONUSERCLICK`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shell_notifyiconw)
+	/// notification.
+	///
+	/// Sent when the user clicks the balloon.
+	pub fn nin_balloon_user_click<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.add(co::NIN::BALLOONUSERCLICK.0, move |_| func());
+	}
+
+	/// [`NIN_KEYSELECT`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shell_notifyiconw)
+	/// notification.
+	///
+	/// Sent when the user selects the icon with the keyboard, pressing
+	/// spacebar or enter.
+	pub fn nin_key_select<F>(&self, func: F)
+		where F: Fn(POINT) -> AnyResult<()> + 'static,
+	{
+		self.add(co::NIN::KEYSELECT.0, func);
+	}
+
+	/// [`NIN_SELECT`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shell_notifyiconw)
+	/// notification.
+	///
+	/// Sent when the user clicks the icon with the left mouse button.
+	///
+	/// # Examples
+	///
+	/// Restoring the window when the icon is clicked:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, AnyResult, POINT};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let tray: gui::TrayIcon;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let tray = gui::TrayIcon::new(&wnd, gui::TrayIconOpts::default());
+	///
+	/// let wnd2 = wnd.clone(); // to pass into the closure
+	///
+	/// tray.on().nin_select(
+	///     move |_: POINT| -> AnyResult<()> {
+	///         wnd2.hwnd().ShowWindow(co::SW::RESTORE);
+	///         wnd2.hwnd().SetForegroundWindow();
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	pub fn nin_select<F>(&self, func: F)
+		where F: Fn(POINT) -> AnyResult<()> + 'static,
+	{
+		self.add(co::NIN::SELECT.0, func);
+	}
+
+	/// [`WM_CONTEXTMENU`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-contextmenu)
+	/// notification.
+	///
+	/// Sent when the user right-clicks the icon, or presses the context menu
+	/// key while the icon is selected.
+	///
+	/// # Examples
+	///
+	/// Showing a popup menu:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult, HMENU, POINT};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let tray: gui::TrayIcon;
+	/// let hmenu: HMENU;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let tray = gui::TrayIcon::new(&wnd, gui::TrayIconOpts::default());
+	/// # let hmenu = HMENU::NULL;
+	///
+	/// let tray2 = tray.clone(); // to pass into the closure
+	///
+	/// tray.on().wm_context_menu(
+	///     move |pos: POINT| -> AnyResult<()> {
+	///         tray2.show_menu(&hmenu, pos)?; // commands go to the parent window
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	pub fn wm_context_menu<F>(&self, func: F)
+		where F: Fn(POINT) -> AnyResult<()> + 'static,
+	{
+		self.add(co::WM::CONTEXTMENU.0, func);
+	}
+
+	/// [`WM_LBUTTONDBLCLK`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-lbuttondblclk)
+	/// notification.
+	///
+	/// Sent when the user double-clicks the icon with the left mouse button.
+	pub fn wm_l_button_dbl_clk<F>(&self, func: F)
+		where F: Fn(POINT) -> AnyResult<()> + 'static,
+	{
+		self.add(co::WM::LBUTTONDBLCLK.0, func);
+	}
+
+	/// [`WM_MBUTTONUP`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mbuttonup)
+	/// notification.
+	///
+	/// Sent when the user clicks the icon with the middle mouse button.
+	pub fn wm_m_button_up<F>(&self, func: F)
+		where F: Fn(POINT) -> AnyResult<()> + 'static,
+	{
+		self.add(co::WM::MBUTTONUP.0, func);
+	}
+}
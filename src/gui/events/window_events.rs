@@ -167,6 +167,43 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_noparm_noret! { wm_clipboard_update, co::WM::CLIPBOARDUPDATE;
+		/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+		/// message.
+		///
+		/// This message is sent only after the window is registered with
+		/// [`HWND::AddClipboardFormatListener`](crate::prelude::user_Hwnd::AddClipboardFormatListener).
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{gui, Clipboard};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_create({
+		///     let wnd = wnd.clone();
+		///     move |_| {
+		///         wnd.hwnd().AddClipboardFormatListener()?;
+		///         Ok(0)
+		///     }
+		/// });
+		///
+		/// wnd.on().wm_clipboard_update({
+		///     let wnd = wnd.clone();
+		///     move || {
+		///         let clip = Clipboard::open(wnd.hwnd())?;
+		///         if let Some(text) = clip.text()? {
+		///             println!("New text: {}", text);
+		///         }
+		///         Ok(())
+		///     }
+		/// });
+		/// ```
+	}
+
 	fn_wm_noparm_noret! { wm_close, co::WM::CLOSE;
 		/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 		/// message.
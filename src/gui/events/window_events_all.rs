@@ -116,6 +116,7 @@ impl WindowEventsAll {
 				for func in nfys.find_all(key) {
 					func(wm_nfy)?; // execute stored function
 				}
+				self.window_events.process_all_messages(wm_any)?; // generic WM_NOTIFY handlers, like tooltip ones
 			},
 			co::WM::COMMAND => {
 				let wm_cmd = wm::Command::from_generic_wm(wm_any);
@@ -0,0 +1,297 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::co;
+use crate::kernel::decl::{AnyResult, SysResult};
+use crate::msg::WndMsg;
+use crate::prelude::{Handle, user_Hwnd};
+use crate::user::decl::HWND;
+
+/// Posted to the window when a task must be polled; `lparam` has the task ID.
+pub(in crate::gui) const WM_TASK_WAKE: co::WM = co::WM(co::WM::APP.0 + 0x3ffd);
+
+static NEXT_TASK_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// A future spawned in the UI thread.
+struct Task {
+	hwnd: HWND, // window which receives the wake messages
+	future: Option<Pin<Box<dyn Future<Output = AnyResult<()>>>>>, // None while being polled
+	woken: bool, // woken while being polled
+}
+
+thread_local! {
+	static TASKS: RefCell<HashMap<usize, Task>> = RefCell::new(HashMap::new());
+}
+
+/// Wakes a task by posting a message to its window, so it can be called from
+/// any thread.
+struct TaskWaker {
+	hwnd: HWND,
+	task_id: usize,
+}
+
+unsafe impl Send for TaskWaker {} // the handle is only used to post messages
+unsafe impl Sync for TaskWaker {}
+
+impl Wake for TaskWaker {
+	fn wake(self: Arc<Self>) {
+		self.wake_by_ref();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		post_wake(&self.hwnd, self.task_id);
+	}
+}
+
+fn post_wake(hwnd: &HWND, task_id: usize) {
+	let _ = hwnd.PostMessage(WndMsg { // the window may be already gone
+		msg_id: WM_TASK_WAKE,
+		wparam: WM_TASK_WAKE.0 as _,
+		lparam: task_id as _,
+	});
+}
+
+/// Stores the future in the UI thread, and schedules its first poll.
+pub(in crate::gui) fn spawn_task<F>(hwnd: &HWND, future: F)
+	where F: Future<Output = AnyResult<()>> + 'static,
+{
+	let task_id = NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed);
+	TASKS.with(|tasks| {
+		tasks.borrow_mut().insert(task_id, Task {
+			hwnd: unsafe { hwnd.raw_copy() },
+			future: Some(Box::pin(future)),
+			woken: false,
+		});
+	});
+	post_wake(hwnd, task_id);
+}
+
+/// Polls the task once, upon a wake message. If the task is complete, it's
+/// removed and its result is returned.
+pub(in crate::gui) fn poll_task(task_id: usize) -> AnyResult<()> {
+	let taken = TASKS.with(|tasks| {
+		tasks.borrow_mut().get_mut(&task_id).and_then(|task| {
+			match task.future.take() {
+				Some(future) => Some((unsafe { task.hwnd.raw_copy() }, future)),
+				None => {
+					task.woken = true; // being polled up in the stack, by a nested message loop
+					None
+				},
+			}
+		})
+	});
+	let (hwnd, mut future) = match taken {
+		Some(taken) => taken,
+		None => return Ok(()), // task already complete, or being polled
+	};
+
+	let waker = Waker::from(Arc::new(TaskWaker { hwnd, task_id }));
+	match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+		Poll::Ready(res) => {
+			TASKS.with(|tasks| tasks.borrow_mut().remove(&task_id));
+			res
+		},
+		Poll::Pending => {
+			let woken = TASKS.with(|tasks| {
+				let mut tasks = tasks.borrow_mut();
+				let task = tasks.get_mut(&task_id).unwrap();
+				task.future = Some(future);
+				std::mem::take(&mut task.woken)
+			});
+			if woken {
+				waker.wake();
+			}
+			Ok(())
+		},
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct ThreadTaskState<T> {
+	result: Option<AnyResult<T>>,
+	waker: Option<Waker>,
+}
+
+/// A future which runs a closure in a new thread, resolving to its result.
+///
+/// It's intended to be awaited in a task spawned with
+/// [`GuiThread::spawn_local`](crate::prelude::GuiThread::spawn_local), so a
+/// long operation can be performed without freezing the UI.
+///
+/// If the closure panics, the future resolves to an error.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, AnyResult};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let wnd2 = wnd.clone(); // to pass into the future
+///
+/// wnd.spawn_local(async move {
+///     let total = gui::ThreadTask::spawn(|| {
+///         Ok((0..1_000_000u64).sum::<u64>()) // runs in another thread
+///     }).await?;
+///
+///     wnd2.hwnd().SetWindowText(&format!("Total: {}", total))?; // back in the UI thread
+///     AnyResult::Ok(())
+/// });
+/// ```
+pub struct ThreadTask<T> {
+	shared: Arc<Mutex<ThreadTaskState<T>>>,
+}
+
+impl<T> Future for ThreadTask<T> {
+	type Output = AnyResult<T>;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut state = self.shared.lock().unwrap();
+		match state.result.take() {
+			Some(result) => Poll::Ready(result),
+			None => {
+				state.waker = Some(cx.waker().clone());
+				Poll::Pending
+			},
+		}
+	}
+}
+
+impl<T: Send + 'static> ThreadTask<T> {
+	/// Spawns a new thread, which immediately starts running the closure.
+	#[must_use]
+	pub fn spawn<F>(func: F) -> Self
+		where F: FnOnce() -> AnyResult<T> + Send + 'static,
+	{
+		let shared = Arc::new(Mutex::new(
+			ThreadTaskState { result: None, waker: None }));
+
+		let shared2 = shared.clone();
+		std::thread::spawn(move || {
+			let result = std::panic::catch_unwind(AssertUnwindSafe(func))
+				.unwrap_or_else(|_| Err("The thread task panicked.".into()));
+
+			let mut state = shared2.lock().unwrap();
+			state.result = Some(result);
+			if let Some(waker) = state.waker.take() {
+				waker.wake();
+			}
+		});
+
+		Self { shared }
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct DelayState {
+	fired: Cell<bool>,
+	waker: RefCell<Option<Waker>>,
+}
+
+thread_local! {
+	static DELAYS: RefCell<HashMap<usize, Rc<DelayState>>> = RefCell::new(HashMap::new());
+}
+
+extern "system" fn delay_proc(_: HWND, _: co::WM, timer_id: usize, _: u32) {
+	let _ = HWND::NULL.KillTimer(timer_id); // one-shot
+	if let Some(state) = DELAYS.with(|delays| delays.borrow_mut().remove(&timer_id)) {
+		state.fired.set(true);
+		if let Some(waker) = state.waker.borrow_mut().take() {
+			waker.wake();
+		}
+	}
+}
+
+/// A future which completes after the given number of milliseconds, using a
+/// thread timer from
+/// [`HWND::SetTimer`](crate::prelude::user_Hwnd::SetTimer).
+///
+/// The timer is driven by the message loop, therefore it must be awaited in a
+/// task spawned with
+/// [`GuiThread::spawn_local`](crate::prelude::GuiThread::spawn_local).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, AnyResult};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let wnd2 = wnd.clone(); // to pass into the future
+///
+/// wnd.spawn_local(async move {
+///     for countdown in (1..=3).rev() {
+///         wnd2.hwnd().SetWindowText(&format!("{}...", countdown))?;
+///         gui::Delay::new(1000).await?;
+///     }
+///     wnd2.hwnd().SetWindowText("Done")?;
+///     AnyResult::Ok(())
+/// });
+/// ```
+pub struct Delay {
+	ms: u32,
+	timer_id: Option<usize>,
+	state: Rc<DelayState>,
+}
+
+impl Drop for Delay {
+	fn drop(&mut self) {
+		if let Some(timer_id) = self.timer_id {
+			if !self.state.fired.get() { // cancelled before completion
+				let _ = HWND::NULL.KillTimer(timer_id);
+				DELAYS.with(|delays| delays.borrow_mut().remove(&timer_id));
+			}
+		}
+	}
+}
+
+impl Future for Delay {
+	type Output = SysResult<()>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.state.fired.get() {
+			return Poll::Ready(Ok(()));
+		}
+		*self.state.waker.borrow_mut() = Some(cx.waker().clone());
+
+		if self.timer_id.is_none() { // first poll, start the timer
+			let timer_id = match HWND::NULL.SetTimer(0, self.ms, Some(delay_proc)) {
+				Ok(timer_id) => timer_id,
+				Err(e) => return Poll::Ready(Err(e)),
+			};
+			DELAYS.with(|delays| {
+				delays.borrow_mut().insert(timer_id, self.state.clone());
+			});
+			self.timer_id = Some(timer_id);
+		}
+		Poll::Pending
+	}
+}
+
+impl Delay {
+	/// Creates the future; the timer starts when it's first awaited.
+	#[must_use]
+	pub fn new(ms: u32) -> Self {
+		Self {
+			ms,
+			timer_id: None,
+			state: Rc::new(DelayState {
+				fired: Cell::new(false),
+				waker: RefCell::new(None),
+			}),
+		}
+	}
+}
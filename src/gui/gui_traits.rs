@@ -1,9 +1,11 @@
 use std::any::Any;
+use std::future::Future;
 
 use crate::co;
 use crate::gui::events::{WindowEvents, WindowEventsAll};
+use crate::gui::native_controls::Tooltip;
 use crate::gui::window_control::WindowControl;
-use crate::kernel::decl::AnyResult;
+use crate::kernel::decl::{AnyResult, SysResult};
 use crate::msg::wm;
 use crate::prelude::{Handle, user_Hwnd};
 use crate::user::decl::{HWND, HwndFocus};
@@ -223,6 +225,61 @@ pub trait GuiThread: GuiParent {
 	/// ```
 	fn run_ui_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static;
+
+	/// Spawns a future to be run in the window's original UI thread, driven by
+	/// the message loop. This allows event handlers to `.await` long
+	/// operations, which resume in the UI thread, instead of nesting
+	/// [`spawn_new_thread`](crate::prelude::GuiThread::spawn_new_thread) and
+	/// [`run_ui_thread`](crate::prelude::GuiThread::run_ui_thread) callbacks.
+	///
+	/// The future is first polled when the current event returns. It's woken
+	/// by a private window message, so its waker can be called from any
+	/// thread. Useful futures are [`ThreadTask`](crate::gui::ThreadTask) and
+	/// [`Delay`](crate::gui::Delay).
+	///
+	/// If the future returns an error, it will be forwarded to
+	/// [`WindowMain::run_main`](crate::gui::WindowMain::run_main), terminating
+	/// the application.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// let btn: gui::Button;
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// btn.on().bn_clicked({
+	///     let wnd = wnd.clone();
+	///     move || -> AnyResult<()> {
+	///         let wnd2 = wnd.clone(); // to pass into the future
+	///
+	///         wnd.spawn_local(async move {
+	///             wnd2.hwnd().SetWindowText("Working...")?;
+	///
+	///             let total = gui::ThreadTask::spawn(|| {
+	///                 Ok((0..1_000_000u64).sum::<u64>()) // runs in another thread
+	///             }).await?;
+	///
+	///             wnd2.hwnd().SetWindowText(&format!("Total: {}", total))?;
+	///             gui::Delay::new(2000).await?;
+	///             wnd2.hwnd().SetWindowText("Ready")?;
+	///             AnyResult::Ok(())
+	///         });
+	///
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static;
 }
 
 /// Any child window.
@@ -232,6 +289,15 @@ pub trait GuiChild: GuiWindow {
 	/// The control ID should be unique within a parent.
 	#[must_use]
 	fn ctrl_id(&self) -> u16;
+
+	/// Sets the text displayed by the given [`Tooltip`](crate::gui::Tooltip)
+	/// when the mouse hovers this control, adding the control as a tool if it
+	/// wasn't already.
+	///
+	/// Must be called after the control is created.
+	fn set_tooltip_text(&self, tooltip: &Tooltip, text: &str) -> SysResult<()> {
+		tooltip.set_tool_text_hwnd(self.hwnd(), text)
+	}
 }
 
 /// Any child window which can be focused.
@@ -0,0 +1,705 @@
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::SysResult;
+use crate::prelude::{GuiParent, GuiWindow, Handle, user_Hdwp, user_Hwnd};
+use crate::user::decl::{HDWP, HWND, HwndPlace, POINT, RECT, SIZE};
+
+/// Sizing rule of a [`Layout`](crate::gui::Layout) node, along one axis.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum LayoutSize {
+	/// The node takes the size it needs: for a control, its original size; for
+	/// a container, the sum of its children.
+	///
+	/// Across a stack, or inside a grid cell, the node is stretched to fill the
+	/// available space.
+	#[default]
+	Auto,
+	/// The node has a fixed size, in pixels, and it's aligned at the top/left
+	/// of the available space.
+	Fixed(i32),
+	/// The node takes a share of the remaining space, proportional to the given
+	/// weight, after all `Auto` and `Fixed` nodes are sized.
+	Star(f32),
+}
+
+/// Left, top, right and bottom distances, in pixels, used as margins and
+/// paddings of a [`Layout`](crate::gui::Layout) node.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Thickness {
+	pub left: i32,
+	pub top: i32,
+	pub right: i32,
+	pub bottom: i32,
+}
+
+impl Thickness {
+	/// Creates a new `Thickness`.
+	#[must_use]
+	pub const fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+		Self { left, top, right, bottom }
+	}
+
+	/// Creates a new `Thickness` with the same value in all four sides.
+	#[must_use]
+	pub const fn uniform(val: i32) -> Self {
+		Self::new(val, val, val, val)
+	}
+
+	const fn horz(&self) -> i32 {
+		self.left + self.right
+	}
+
+	const fn vert(&self) -> i32 {
+		self.top + self.bottom
+	}
+
+	const fn deflate(&self, rc: RECT) -> RECT {
+		RECT {
+			left: rc.left + self.left,
+			top: rc.top + self.top,
+			right: rc.right - self.right,
+			bottom: rc.bottom - self.bottom,
+		}
+	}
+}
+
+enum LayoutKind {
+	Control { hwnd: HWND, desired: SIZE },
+	Spacer,
+	Stack { horizontal: bool, spacing: i32, children: Vec<Layout> },
+	Grid {
+		rows: Vec<LayoutSize>,
+		cols: Vec<LayoutSize>,
+		spacing: i32,
+		cells: Vec<GridCell>,
+	},
+}
+
+struct GridCell {
+	row: usize,
+	col: usize,
+	row_span: usize,
+	col_span: usize,
+	child: Layout,
+}
+
+//------------------------------------------------------------------------------
+
+/// A declarative layout tree, which computes the positions and sizes of child
+/// controls from the client area of their parent window.
+///
+/// A layout is composed of controls, stacks, grids and spacers, each one with
+/// its own [`LayoutSize`](crate::gui::LayoutSize) rules, margins, and minimum
+/// and maximum sizes. Containers also have paddings.
+///
+/// This is an alternative to the [`Horz`](crate::gui::Horz) and
+/// [`Vert`](crate::gui::Vert) anchoring of each control.
+///
+/// # Examples
+///
+/// A list filling the window, with a row of buttons at the bottom, aligned at
+/// right. Since the layout needs the control handles, it must be built after
+/// the controls are created:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let list: gui::ListView;
+/// # let list = gui::ListView::new(&wnd, gui::ListViewOpts::default());
+/// let btn_ok: gui::Button;
+/// # let btn_ok = gui::Button::new(&wnd, gui::ButtonOpts::default());
+/// let btn_cancel: gui::Button;
+/// # let btn_cancel = gui::Button::new(&wnd, gui::ButtonOpts::default());
+///
+/// wnd.on().wm_create({
+///     let wnd = wnd.clone();
+///     let (list, btn_ok, btn_cancel) = (list.clone(), btn_ok.clone(), btn_cancel.clone());
+///     move |_| {
+///         gui::Layout::vstack(vec![
+///             gui::Layout::control(&list)?
+///                 .height(gui::LayoutSize::Star(1.0)),
+///             gui::Layout::hstack(vec![
+///                 gui::Layout::spacer()
+///                     .width(gui::LayoutSize::Star(1.0)),
+///                 gui::Layout::control(&btn_ok)?,
+///                 gui::Layout::control(&btn_cancel)?,
+///             ]).spacing(8),
+///         ])
+///             .spacing(8)
+///             .padding(gui::Thickness::uniform(10))
+///             .attach(&wnd)?;
+///         Ok(0)
+///     }
+/// });
+/// ```
+pub struct Layout {
+	kind: LayoutKind,
+	width: LayoutSize,
+	height: LayoutSize,
+	margin: Thickness,
+	padding: Thickness,
+	min_sz: SIZE,
+	max_sz: SIZE,
+}
+
+impl Layout {
+	fn new(kind: LayoutKind) -> Self {
+		Self {
+			kind,
+			width: LayoutSize::Auto,
+			height: LayoutSize::Auto,
+			margin: Thickness::default(),
+			padding: Thickness::default(),
+			min_sz: SIZE::new(0, 0),
+			max_sz: SIZE::new(i32::MAX, i32::MAX),
+		}
+	}
+
+	/// Creates a node with a control, whose `Auto` size is its current size.
+	///
+	/// # Panics
+	///
+	/// Panics if the control is not created yet.
+	pub fn control(ctrl: &impl GuiWindow) -> SysResult<Self> {
+		if *ctrl.hwnd() == HWND::NULL {
+			panic!("Cannot add a control to a layout before its creation.");
+		}
+		let rc = ctrl.hwnd().GetWindowRect()?;
+		Ok(Self::control_sized(ctrl, SIZE::new(rc.right - rc.left, rc.bottom - rc.top)))
+	}
+
+	/// Creates a node with a control, whose `Auto` size is the given one.
+	#[must_use]
+	pub fn control_sized(ctrl: &impl GuiWindow, desired_sz: SIZE) -> Self {
+		Self::new(LayoutKind::Control {
+			hwnd: unsafe { ctrl.hwnd().raw_copy() },
+			desired: desired_sz,
+		})
+	}
+
+	/// Creates a node which takes space like a control with the given `Auto`
+	/// size, but has no window. Its rectangle is still returned by
+	/// [`compute`](crate::gui::Layout::compute).
+	#[must_use]
+	pub fn placeholder(desired_sz: SIZE) -> Self {
+		Self::new(LayoutKind::Control { hwnd: HWND::NULL, desired: desired_sz })
+	}
+
+	/// Creates an empty node, which just takes space.
+	#[must_use]
+	pub fn spacer() -> Self {
+		Self::new(LayoutKind::Spacer)
+	}
+
+	/// Creates a container which places its children side by side, from left
+	/// to right.
+	#[must_use]
+	pub fn hstack(children: Vec<Layout>) -> Self {
+		Self::new(LayoutKind::Stack { horizontal: true, spacing: 0, children })
+	}
+
+	/// Creates a container which places its children one below the other,
+	/// from top to bottom.
+	#[must_use]
+	pub fn vstack(children: Vec<Layout>) -> Self {
+		Self::new(LayoutKind::Stack { horizontal: false, spacing: 0, children })
+	}
+
+	/// Creates a container with the given rows and columns. The children are
+	/// added with [`cell`](crate::gui::Layout::cell) and
+	/// [`cell_span`](crate::gui::Layout::cell_span).
+	#[must_use]
+	pub fn grid(rows: Vec<LayoutSize>, cols: Vec<LayoutSize>) -> Self {
+		Self::new(LayoutKind::Grid { rows, cols, spacing: 0, cells: Vec::default() })
+	}
+
+	/// Adds a child to a cell of a grid.
+	///
+	/// # Panics
+	///
+	/// Panics if this node is not a grid, or if the cell is out of bounds.
+	#[must_use]
+	pub fn cell(self, row: usize, col: usize, child: Layout) -> Self {
+		self.cell_span(row, col, 1, 1, child)
+	}
+
+	/// Adds a child to a grid, spanning multiple rows and columns.
+	///
+	/// `Auto` rows and columns are sized considering only the children which
+	/// don't span.
+	///
+	/// # Panics
+	///
+	/// Panics if this node is not a grid, or if the cells are out of bounds.
+	#[must_use]
+	pub fn cell_span(mut self,
+		row: usize, col: usize, row_span: usize, col_span: usize, child: Layout) -> Self
+	{
+		match &mut self.kind {
+			LayoutKind::Grid { rows, cols, cells, .. } => {
+				if row_span == 0 || col_span == 0
+					|| row + row_span > rows.len() || col + col_span > cols.len()
+				{
+					panic!("Grid cell out of bounds: row {}, col {}.", row, col);
+				}
+				cells.push(GridCell { row, col, row_span, col_span, child });
+			},
+			_ => panic!("Cells can be added only to a grid."),
+		}
+		self
+	}
+
+	/// Sets the horizontal sizing rule. Default is `Auto`.
+	#[must_use]
+	pub const fn width(mut self, width: LayoutSize) -> Self {
+		self.width = width;
+		self
+	}
+
+	/// Sets the vertical sizing rule. Default is `Auto`.
+	#[must_use]
+	pub const fn height(mut self, height: LayoutSize) -> Self {
+		self.height = height;
+		self
+	}
+
+	/// Sets the space around the node.
+	#[must_use]
+	pub const fn margin(mut self, margin: Thickness) -> Self {
+		self.margin = margin;
+		self
+	}
+
+	/// Sets the space between the borders of a container and its children.
+	/// Ignored by controls and spacers.
+	#[must_use]
+	pub const fn padding(mut self, padding: Thickness) -> Self {
+		self.padding = padding;
+		self
+	}
+
+	/// Sets the space between the children of a stack, or between the rows
+	/// and columns of a grid. Ignored by controls and spacers.
+	#[must_use]
+	pub fn spacing(mut self, spacing: i32) -> Self {
+		match &mut self.kind {
+			LayoutKind::Stack { spacing: s, .. }
+				| LayoutKind::Grid { spacing: s, .. } => *s = spacing,
+			_ => {},
+		}
+		self
+	}
+
+	/// Sets the minimum size of the node, not counting the margin.
+	#[must_use]
+	pub const fn min_size(mut self, min_sz: SIZE) -> Self {
+		self.min_sz = min_sz;
+		self
+	}
+
+	/// Sets the maximum size of the node, not counting the margin.
+	#[must_use]
+	pub const fn max_size(mut self, max_sz: SIZE) -> Self {
+		self.max_sz = max_sz;
+		self
+	}
+
+	/// Computes the rectangles of all the controls and placeholders, in the
+	/// order they appear in the tree, so that the whole layout fits the given
+	/// area.
+	///
+	/// This is a pure function: no window is touched.
+	#[must_use]
+	pub fn compute(&self, area: RECT) -> Vec<RECT> {
+		let mut rcs = Vec::<RECT>::default();
+		self.arrange(area, &mut rcs);
+		rcs
+	}
+
+	/// Attaches the layout to the parent window, immediately arranging the
+	/// controls. From now on, the controls will be rearranged whenever the
+	/// parent window is resized. A previously attached layout is replaced.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn attach(self, parent: &impl GuiParent) -> SysResult<()> {
+		let base = unsafe { Base::from_guiparent(parent) };
+		base.set_layout(self)
+	}
+
+	/// Returns the handles of all the controls and placeholders, in the order
+	/// they appear in the tree.
+	pub(in crate::gui) fn hwnds(&self) -> Vec<&HWND> {
+		let mut hwnds = Vec::<&HWND>::default();
+		self.collect_hwnds(&mut hwnds);
+		hwnds
+	}
+
+	/// Computes the rectangles and defers the positioning of all the controls.
+	pub(in crate::gui) fn defer(&self,
+		hdwp: &mut HDWP, client_area: SIZE) -> SysResult<()>
+	{
+		let rcs = self.compute(RECT {
+			left: 0, top: 0, right: client_area.cx, bottom: client_area.cy,
+		});
+		for (hwnd, rc) in self.hwnds().iter().zip(rcs.iter()) {
+			if **hwnd == HWND::NULL {
+				continue; // placeholder
+			}
+			hdwp.DeferWindowPos(
+				hwnd,
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER,
+			)?;
+		}
+		Ok(())
+	}
+
+	fn collect_hwnds<'a>(&'a self, hwnds: &mut Vec<&'a HWND>) {
+		match &self.kind {
+			LayoutKind::Control { hwnd, .. } => hwnds.push(hwnd),
+			LayoutKind::Spacer => {},
+			LayoutKind::Stack { children, .. } => children.iter()
+				.for_each(|child| child.collect_hwnds(hwnds)),
+			LayoutKind::Grid { cells, .. } => cells.iter()
+				.for_each(|cell| cell.child.collect_hwnds(hwnds)),
+		}
+	}
+
+	/// Returns the size needed by the node, including the margin.
+	fn measure(&self) -> SIZE {
+		let content = match &self.kind {
+			LayoutKind::Control { desired, .. } => *desired,
+			LayoutKind::Spacer => SIZE::default(),
+			LayoutKind::Stack { horizontal, spacing, children } => {
+				let (mut main, mut cross) = (0, 0);
+				for child in children.iter() {
+					let (child_main, child_cross) = split_axes(child.measure(), *horizontal);
+					main += child_main;
+					cross = cross.max(child_cross);
+				}
+				main += spacing * (children.len().max(1) - 1) as i32;
+				let (cx, cy) = join_axes(main, cross, *horizontal);
+				SIZE::new(cx + self.padding.horz(), cy + self.padding.vert())
+			},
+			LayoutKind::Grid { rows, cols, spacing, cells } => {
+				let sum_tracks = |tracks: &[i32]| -> i32 {
+					tracks.iter().sum::<i32>() + spacing * (tracks.len().max(1) - 1) as i32
+				};
+				let col_szs = measure_tracks(cols, cells, true);
+				let row_szs = measure_tracks(rows, cells, false);
+				SIZE::new(
+					sum_tracks(&col_szs) + self.padding.horz(),
+					sum_tracks(&row_szs) + self.padding.vert(),
+				)
+			},
+		};
+
+		let cx = match self.width {
+			LayoutSize::Fixed(cx) => cx,
+			_ => content.cx,
+		};
+		let cy = match self.height {
+			LayoutSize::Fixed(cy) => cy,
+			_ => content.cy,
+		};
+		SIZE::new(
+			clamp(cx, self.min_sz.cx, self.max_sz.cx) + self.margin.horz(),
+			clamp(cy, self.min_sz.cy, self.max_sz.cy) + self.margin.vert(),
+		)
+	}
+
+	/// Places the node within the given slot, which includes the margin.
+	fn arrange(&self, slot: RECT, rcs: &mut Vec<RECT>) {
+		let slot = self.margin.deflate(slot);
+		let cx = match self.width {
+			LayoutSize::Fixed(cx) => cx,
+			_ => slot.right - slot.left,
+		};
+		let cy = match self.height {
+			LayoutSize::Fixed(cy) => cy,
+			_ => slot.bottom - slot.top,
+		};
+		let rc = RECT {
+			left: slot.left,
+			top: slot.top,
+			right: slot.left + clamp(cx, self.min_sz.cx, self.max_sz.cx).max(0),
+			bottom: slot.top + clamp(cy, self.min_sz.cy, self.max_sz.cy).max(0),
+		};
+
+		match &self.kind {
+			LayoutKind::Control { .. } => rcs.push(rc),
+			LayoutKind::Spacer => {},
+			LayoutKind::Stack { horizontal, spacing, children } => {
+				let content = self.padding.deflate(rc);
+				let (content_main, _) = split_axes(
+					SIZE::new(content.right - content.left, content.bottom - content.top),
+					*horizontal,
+				);
+				let tracks = children.iter()
+					.map(|child| {
+						let main_sz = if *horizontal { child.width } else { child.height };
+						let (child_main, _) = split_axes(child.measure(), *horizontal);
+						(main_sz, child_main)
+					})
+					.collect::<Vec<_>>();
+				let main_szs = solve_tracks(&tracks, content_main, *spacing);
+
+				let mut pos = if *horizontal { content.left } else { content.top };
+				for (child, main_sz) in children.iter().zip(main_szs.iter()) {
+					child.arrange(
+						if *horizontal {
+							RECT { left: pos, top: content.top, right: pos + main_sz, bottom: content.bottom }
+						} else {
+							RECT { left: content.left, top: pos, right: content.right, bottom: pos + main_sz }
+						},
+						rcs,
+					);
+					pos += main_sz + spacing;
+				}
+			},
+			LayoutKind::Grid { rows, cols, spacing, cells } => {
+				let content = self.padding.deflate(rc);
+				let col_offs = track_offsets(
+					&solve_tracks(
+						&cols.iter().copied().zip(measure_tracks(cols, cells, true)).collect::<Vec<_>>(),
+						content.right - content.left,
+						*spacing,
+					),
+					content.left,
+					*spacing,
+				);
+				let row_offs = track_offsets(
+					&solve_tracks(
+						&rows.iter().copied().zip(measure_tracks(rows, cells, false)).collect::<Vec<_>>(),
+						content.bottom - content.top,
+						*spacing,
+					),
+					content.top,
+					*spacing,
+				);
+
+				for cell in cells.iter() {
+					cell.child.arrange(
+						RECT {
+							left: col_offs[cell.col].0,
+							top: row_offs[cell.row].0,
+							right: col_offs[cell.col + cell.col_span - 1].1,
+							bottom: row_offs[cell.row + cell.row_span - 1].1,
+						},
+						rcs,
+					);
+				}
+			},
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const fn split_axes(sz: SIZE, horizontal: bool) -> (i32, i32) {
+	if horizontal { (sz.cx, sz.cy) } else { (sz.cy, sz.cx) }
+}
+
+const fn join_axes(main: i32, cross: i32, horizontal: bool) -> (i32, i32) {
+	if horizontal { (main, cross) } else { (cross, main) }
+}
+
+fn clamp(val: i32, min: i32, max: i32) -> i32 {
+	val.min(max).max(min)
+}
+
+/// Returns the size needed by each row or column of a grid, considering only
+/// the cells which don't span.
+fn measure_tracks(tracks: &[LayoutSize], cells: &[GridCell], is_col: bool) -> Vec<i32> {
+	tracks.iter()
+		.enumerate()
+		.map(|(idx, track)| match track {
+			LayoutSize::Fixed(sz) => *sz,
+			LayoutSize::Auto | LayoutSize::Star(_) => cells.iter()
+				.filter(|cell| if is_col {
+					cell.col == idx && cell.col_span == 1
+				} else {
+					cell.row == idx && cell.row_span == 1
+				})
+				.map(|cell| split_axes(cell.child.measure(), is_col).0)
+				.max()
+				.unwrap_or(0),
+		})
+		.collect()
+}
+
+/// Given the sizing rule and the needed size of each track, distributes the
+/// available length among them. `Star` tracks share what's left after the
+/// `Auto` and `Fixed` ones, proportionally to their weights.
+fn solve_tracks(tracks: &[(LayoutSize, i32)], avail: i32, spacing: i32) -> Vec<i32> {
+	let avail = avail - spacing * (tracks.len().max(1) - 1) as i32;
+	let used: i32 = tracks.iter()
+		.filter(|(rule, _)| !matches!(rule, LayoutSize::Star(_)))
+		.map(|(_, needed)| *needed)
+		.sum();
+	let tot_weight: f32 = tracks.iter()
+		.filter_map(|(rule, _)| match rule {
+			LayoutSize::Star(w) => Some(w.max(0.0)),
+			_ => None,
+		})
+		.sum();
+	let star_avail = (avail - used).max(0);
+
+	let last_star = tracks.iter()
+		.rposition(|(rule, _)| matches!(rule, LayoutSize::Star(_)));
+
+	let mut star_left = star_avail;
+	tracks.iter()
+		.enumerate()
+		.map(|(idx, (rule, needed))| match rule {
+			LayoutSize::Star(w) => {
+				let sz = if tot_weight <= 0.0 {
+					0
+				} else if Some(idx) == last_star { // last one takes the rounding leftovers
+					star_left
+				} else {
+					(star_avail as f32 * w.max(0.0) / tot_weight) as i32
+				};
+				star_left -= sz;
+				sz
+			},
+			_ => *needed,
+		})
+		.collect()
+}
+
+/// Converts track sizes into start/end offsets.
+fn track_offsets(szs: &[i32], start: i32, spacing: i32) -> Vec<(i32, i32)> {
+	let mut pos = start;
+	szs.iter()
+		.map(|sz| {
+			let offs = (pos, pos + sz);
+			pos += sz + spacing;
+			offs
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn area(cx: i32, cy: i32) -> RECT {
+		RECT { left: 0, top: 0, right: cx, bottom: cy }
+	}
+
+	fn ltrb(rcs: &[RECT]) -> Vec<(i32, i32, i32, i32)> {
+		rcs.iter()
+			.map(|rc| (rc.left, rc.top, rc.right, rc.bottom))
+			.collect()
+	}
+
+	fn cxcy(sz: SIZE) -> (i32, i32) {
+		(sz.cx, sz.cy)
+	}
+
+	fn ph(cx: i32, cy: i32) -> Layout {
+		Layout::placeholder(SIZE::new(cx, cy))
+	}
+
+	#[test]
+	fn solve_star_tracks() {
+		use LayoutSize::*;
+		assert_eq!(solve_tracks(&[(Fixed(10), 10), (Auto, 20), (Star(1.0), 0)], 100, 0), [10, 20, 70]);
+		assert_eq!(solve_tracks(&[(Star(1.0), 0), (Star(2.0), 0)], 90, 0), [30, 60]);
+		assert_eq!(solve_tracks(&[(Star(1.0), 0), (Star(1.0), 0), (Star(1.0), 0)], 100, 5), [30, 30, 30]);
+		assert_eq!(solve_tracks(&[(Star(1.0), 0), (Star(1.0), 0), (Star(1.0), 0)], 101, 0), [33, 33, 35]); // leftovers
+		assert_eq!(solve_tracks(&[(Auto, 80), (Star(1.0), 0)], 50, 0), [80, 0]); // no room
+		assert_eq!(solve_tracks(&[(Star(0.0), 0), (Auto, 10)], 50, 0), [0, 10]); // no weight
+		assert_eq!(solve_tracks(&[], 50, 8), Vec::<i32>::default());
+	}
+
+	#[test]
+	fn track_offsets_with_spacing() {
+		assert_eq!(track_offsets(&[10, 20, 0], 5, 2), [(5, 15), (17, 37), (39, 39)]);
+	}
+
+	#[test]
+	fn hstack_sizes() {
+		let layout = Layout::hstack(vec![
+			ph(30, 10),
+			ph(20, 10).width(LayoutSize::Star(1.0)),
+			ph(20, 10).width(LayoutSize::Fixed(40)),
+			Layout::spacer().width(LayoutSize::Star(1.0)),
+		]).spacing(5);
+
+		assert_eq!(cxcy(layout.measure()), (30 + 20 + 40 + 15, 10));
+		assert_eq!(ltrb(&layout.compute(area(200, 50))), [
+			(0, 0, 30, 50),
+			(35, 0, 35 + 57, 50), // 200 - 15 spacing - 30 - 40 = 115 to share
+			(97, 0, 137, 50), // fixed size is top aligned, full height
+			// spacer is not returned
+		]);
+	}
+
+	#[test]
+	fn vstack_margin_padding_clamp() {
+		let layout = Layout::vstack(vec![
+			ph(50, 20).margin(Thickness::new(1, 2, 3, 4)),
+			ph(50, 20).height(LayoutSize::Star(1.0)).max_size(SIZE::new(60, 30)),
+			ph(50, 20).min_size(SIZE::new(0, 25)),
+		]).padding(Thickness::uniform(10));
+
+		assert_eq!(cxcy(layout.measure()), (20 + 54, 20 + 26 + 20 + 25));
+		assert_eq!(ltrb(&layout.compute(area(100, 200))), [
+			(11, 12, 100 - 13, 12 + 20),
+			(10, 36, 10 + 60, 36 + 30), // star clamped to max size
+			(10, 36 + 180 - 26 - 25, 90, 36 + 180 - 26), // after the star slot
+		]);
+	}
+
+	#[test]
+	fn nested_and_empty() {
+		assert!(Layout::vstack(Vec::default()).compute(area(100, 100)).is_empty());
+		assert_eq!(cxcy(Layout::hstack(Vec::default()).spacing(10).measure()), (0, 0));
+
+		let layout = Layout::vstack(vec![
+			Layout::hstack(vec![ph(10, 10), ph(10, 30)]),
+			ph(5, 5),
+		]);
+		assert_eq!(cxcy(layout.measure()), (20, 35));
+		assert_eq!(ltrb(&layout.compute(area(40, 40))), [
+			(0, 0, 10, 30),
+			(10, 0, 20, 30), // auto along the main axis, stretched along the cross one
+			(0, 30, 40, 35),
+		]);
+	}
+
+	#[test]
+	fn grid_cells_and_spans() {
+		use LayoutSize::*;
+		let layout = Layout::grid(vec![Auto, Star(1.0)], vec![Fixed(50), Auto, Star(1.0)])
+			.spacing(4)
+			.cell(0, 0, ph(10, 20))
+			.cell(0, 1, ph(30, 10))
+			.cell_span(1, 0, 1, 3, ph(500, 500)) // spanning cells don't size the auto tracks
+			.cell(0, 2, ph(1, 1));
+
+		assert_eq!(cxcy(layout.measure()), (50 + 30 + 1 + 8, 20 + 500 + 4));
+		assert_eq!(ltrb(&layout.compute(area(200, 100))), [
+			(0, 0, 50, 20),
+			(54, 0, 84, 20),
+			(0, 24, 200, 100),
+			(88, 0, 200, 20),
+		]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn grid_cell_out_of_bounds() {
+		let _ = Layout::grid(vec![LayoutSize::Auto], vec![LayoutSize::Auto])
+			.cell_span(0, 0, 1, 2, ph(1, 1));
+	}
+}
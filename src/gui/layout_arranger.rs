@@ -4,6 +4,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
+use crate::gui::layout::Layout;
 use crate::kernel::decl::SysResult;
 use crate::msg::wm;
 use crate::prelude::{Handle, user_Hdwp, user_Hwnd};
@@ -51,6 +52,7 @@ struct ChildInfo {
 struct Obj { // actual fields of LayoutArranger
 	ctrls: UnsafeCell<Vec<ChildInfo>>,
 	sz_parent_orig: UnsafeCell<SIZE>, // original parent client area
+	layout: UnsafeCell<Option<Layout>>,
 	_pin: PhantomPinned,
 }
 
@@ -67,6 +69,7 @@ impl LayoutArranger {
 				Obj {
 					ctrls: UnsafeCell::new(Vec::with_capacity(10)), // arbitrary
 					sz_parent_orig: UnsafeCell::new(SIZE::default()),
+					layout: UnsafeCell::new(None),
 					_pin: PhantomPinned,
 				},
 			),
@@ -107,16 +110,43 @@ impl LayoutArranger {
 		Ok(())
 	}
 
+	/// Sets the layout, replacing any previous one, and immediately arranges
+	/// its controls to the current parent client area.
+	pub(in crate::gui) fn set_layout(&self,
+		hparent: &HWND, layout: Layout) -> SysResult<()>
+	{
+		if *hparent == HWND::NULL {
+			panic!("Cannot attach a layout before window creation.");
+		}
+
+		let rc_parent = hparent.GetClientRect()?;
+		let num_hwnds = layout.hwnds().len();
+		if num_hwnds > 0 {
+			let mut hdwp = HDWP::BeginDeferWindowPos(num_hwnds as _)?;
+			layout.defer(&mut hdwp, SIZE::new(rc_parent.right, rc_parent.bottom))?;
+		}
+
+		*unsafe { &mut *self.0.layout.get() } = Some(layout);
+		Ok(())
+	}
+
 	/// Rearranges all child controls to fit the new width/height of parent
 	/// window.
 	pub(in crate::gui) fn rearrange(&self, p: &wm::Size) -> SysResult<()> {
 		let ctrls = unsafe { &mut *self.0.ctrls.get() };
-		if ctrls.is_empty() // no controls
+		let layout = unsafe { &*self.0.layout.get() };
+		let num_layout_hwnds = layout.as_ref().map_or(0, |layout| layout.hwnds().len());
+
+		if (ctrls.is_empty() && num_layout_hwnds == 0) // no controls
 			|| p.request == co::SIZE_R::MINIMIZED { // we're minimized
 			return Ok(());
 		}
 
-		let mut hdwp = HDWP::BeginDeferWindowPos(ctrls.len() as _)?;
+		let mut hdwp = HDWP::BeginDeferWindowPos((ctrls.len() + num_layout_hwnds) as _)?;
+
+		if let Some(layout) = layout {
+			layout.defer(&mut hdwp, p.client_area)?;
+		}
 
 		for ctrl in ctrls.iter() {
 			let mut uflags = co::SWP::NOZORDER;
@@ -0,0 +1,368 @@
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::WindowEventsAll;
+use crate::gui::privs::auto_ctrl_id;
+use crate::kernel::decl::{AnyResult, SysResult};
+use crate::prelude::{
+	GuiEvents, GuiEventsAll, GuiParent, Handle, NativeBitflag, user_Haccel,
+	user_Hmenu, user_Hwnd,
+};
+use crate::user::decl::{
+	ACCEL, AdjustWindowRectEx, BmpPtrStr, HACCEL, HMENU, HwndPlace, IdMenu,
+	IdPos, POINT, SIZE,
+};
+use crate::user::guard::DestroyMenuGuard;
+
+/// A menu entry being built.
+enum Entry {
+	Item {
+		text: String,
+		accel: Option<(co::ACCELF, co::VK)>,
+		func: Box<dyn Fn() -> AnyResult<()>>,
+	},
+	Check {
+		text: String,
+		accel: Option<(co::ACCELF, co::VK)>,
+		checked: bool,
+		func: Box<dyn Fn(bool) -> AnyResult<()>>,
+	},
+	Radio {
+		texts: Vec<String>,
+		selected: usize,
+		func: Box<dyn Fn(usize) -> AnyResult<()>>,
+	},
+	Separator,
+	Submenu {
+		text: String,
+		submenu: MenuBuilder,
+	},
+}
+
+//------------------------------------------------------------------------------
+
+/// Declarative builder of a menu tree, whose command IDs are automatically
+/// assigned and bound to closures in the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window.
+///
+/// The accelerators of the items are displayed after their text, and also
+/// used to build the accelerator table of the window.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui, msg};
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let wnd2 = wnd.clone(); // to pass into the closure
+///
+/// gui::MenuBuilder::new()
+///     .submenu("&File", gui::MenuBuilder::new()
+///         .item("&Open...", Some((co::ACCELF::CONTROL, co::VK::CHAR_O)),
+///             || { println!("Open"); Ok(()) })
+///         .separator()
+///         .item("E&xit", None,
+///             move || { wnd2.hwnd().SendMessage(msg::wm::Close {}); Ok(()) }),
+///     )
+///     .submenu("&View", gui::MenuBuilder::new()
+///         .check_item("&Word wrap", None, true,
+///             |checked| { println!("Wrap: {}", checked); Ok(()) })
+///         .separator()
+///         .radio_group(&["&Small", "&Medium", "&Large"], 1,
+///             |index| { println!("Size: {}", index); Ok(()) }),
+///     )
+///     .attach(&wnd)?;
+///
+/// wnd.run_main(None)?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Default)]
+pub struct MenuBuilder {
+	entries: Vec<Entry>,
+}
+
+impl MenuBuilder {
+	/// Creates a new, empty builder.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a checkable item, whose check mark is toggled when it's clicked.
+	/// The closure receives the new check state.
+	#[must_use]
+	pub fn check_item<F>(mut self,
+		text: &str,
+		accel: Option<(co::ACCELF, co::VK)>,
+		checked: bool,
+		func: F,
+	) -> Self
+		where F: Fn(bool) -> AnyResult<()> + 'static,
+	{
+		self.entries.push(Entry::Check {
+			text: text.to_owned(),
+			accel,
+			checked,
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds a clickable item, with an optional accelerator key combination.
+	#[must_use]
+	pub fn item<F>(mut self,
+		text: &str,
+		accel: Option<(co::ACCELF, co::VK)>,
+		func: F,
+	) -> Self
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.entries.push(Entry::Item {
+			text: text.to_owned(),
+			accel,
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds a group of mutually exclusive items, displayed with a radio bullet.
+	/// The closure receives the zero-based index of the clicked item.
+	///
+	/// # Panics
+	///
+	/// Panics if `texts` is empty, or if `selected` is out of bounds.
+	#[must_use]
+	pub fn radio_group<F>(mut self,
+		texts: &[&str],
+		selected: usize,
+		func: F,
+	) -> Self
+		where F: Fn(usize) -> AnyResult<()> + 'static,
+	{
+		if selected >= texts.len() {
+			panic!("Selected radio index {} out of bounds.", selected);
+		}
+		self.entries.push(Entry::Radio {
+			texts: texts.iter().map(|text| text.to_string()).collect(),
+			selected,
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds a separator.
+	#[must_use]
+	pub fn separator(mut self) -> Self {
+		self.entries.push(Entry::Separator);
+		self
+	}
+
+	/// Adds a submenu, built by another `MenuBuilder`.
+	#[must_use]
+	pub fn submenu(mut self, text: &str, submenu: MenuBuilder) -> Self {
+		self.entries.push(Entry::Submenu { text: text.to_owned(), submenu });
+		self
+	}
+
+	/// Builds the menu bar and binds the item closures to the parent window.
+	///
+	/// The menu is assigned to the window when it's created, keeping the size
+	/// of its client area, and destroyed along with it. If the menu has
+	/// accelerators, and no other accelerator table was given to a
+	/// [`WindowMain`](crate::gui::WindowMain), the table built from the menu
+	/// is used by its main loop.
+	///
+	/// The parent must be a top-level window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created.
+	pub fn attach(self, parent: &impl GuiParent) -> SysResult<()> {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let hmenu = unsafe { DestroyMenuGuard::new(HMENU::CreateMenu()?) };
+
+		let mut accels = Vec::<ACCEL>::default();
+		self.populate(&hmenu, parent.on(), &mut accels)?;
+		if !accels.is_empty() {
+			parent_ref.set_menu_accel_table(
+				HACCEL::CreateAcceleratorTable(&mut accels)?);
+		}
+
+		let parent_ptr = NonNull::from(parent_ref);
+		let hmenu = Cell::new(Some(hmenu)); // destroyed if never assigned
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			let hwnd = unsafe { parent_ptr.as_ref() }.hwnd();
+			let mut rc = hwnd.GetClientRect()?; // client area to be kept
+			if let Some(mut hmenu) = hmenu.take() {
+				hwnd.SetMenu(&hmenu)?;
+				let _ = hmenu.leak(); // now the window owns the menu
+			}
+
+			AdjustWindowRectEx(&mut rc,
+				co::WS(hwnd.GetWindowLongPtr(co::GWLP::STYLE) as _),
+				true,
+				co::WS_EX(hwnd.GetWindowLongPtr(co::GWLP::EXSTYLE) as _))?;
+			hwnd.SetWindowPos(HwndPlace::None,
+				POINT::default(),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOMOVE)?;
+			Ok(None) // not meaningful
+		});
+
+		Ok(())
+	}
+
+	/// Builds a popup menu and binds the item closures to the parent window,
+	/// which will receive the commands. The popup menu can then be displayed
+	/// with
+	/// [`HMENU::TrackPopupMenuAtPoint`](crate::prelude::user_Hmenu::TrackPopupMenuAtPoint)
+	/// or [`TrayIcon::show_menu`](crate::gui::TrayIcon::show_menu).
+	///
+	/// Accelerators are displayed, but not bound to the window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created.
+	#[must_use]
+	pub fn build_popup(self,
+		parent: &impl GuiParent) -> SysResult<DestroyMenuGuard>
+	{
+		let hmenu = HMENU::CreatePopupMenu()?;
+		let hmenu = unsafe { DestroyMenuGuard::new(hmenu) };
+		self.populate(&hmenu, parent.on(), &mut Vec::default())?;
+		Ok(hmenu)
+	}
+
+	fn populate(self,
+		hmenu: &HMENU,
+		events: &WindowEventsAll,
+		accels: &mut Vec<ACCEL>,
+	) -> SysResult<()>
+	{
+		for entry in self.entries.into_iter() {
+			match entry {
+				Entry::Item { text, accel, func } => {
+					let cmd_id = auto_ctrl_id();
+					hmenu.AppendMenu(co::MF::STRING, IdMenu::Id(cmd_id),
+						BmpPtrStr::from_str(&item_text(&text, accel)))?;
+					push_accel(accels, accel, cmd_id);
+					events.wm_command_accel_menu(cmd_id, func);
+				},
+				Entry::Check { text, accel, checked, func } => {
+					let cmd_id = auto_ctrl_id();
+					hmenu.AppendMenu(
+						co::MF::STRING | if checked { co::MF::CHECKED } else { co::MF::UNCHECKED },
+						IdMenu::Id(cmd_id),
+						BmpPtrStr::from_str(&item_text(&text, accel)))?;
+					push_accel(accels, accel, cmd_id);
+
+					let hmenu2 = unsafe { hmenu.raw_copy() };
+					events.wm_command_accel_menu(cmd_id, move || {
+						let checked = !hmenu2.GetMenuState(IdPos::Id(cmd_id))?
+							.has(co::MF::CHECKED);
+						hmenu2.CheckMenuItem(IdPos::Id(cmd_id), checked)?;
+						func(checked)
+					});
+				},
+				Entry::Radio { texts, selected, func } => {
+					let cmd_ids = texts.iter()
+						.map(|_| auto_ctrl_id()) // sequential IDs
+						.collect::<Vec<_>>();
+					for (text, cmd_id) in texts.iter().zip(cmd_ids.iter()) {
+						hmenu.AppendMenu(co::MF::STRING, IdMenu::Id(*cmd_id),
+							BmpPtrStr::from_str(text))?;
+					}
+
+					let (first, last) = (cmd_ids[0], cmd_ids[cmd_ids.len() - 1]);
+					hmenu.CheckMenuRadioItem(IdPos::Id(first), IdPos::Id(last),
+						IdPos::Id(cmd_ids[selected]))?;
+
+					let func = Rc::new(func);
+					for (index, cmd_id) in cmd_ids.iter().enumerate() {
+						let hmenu2 = unsafe { hmenu.raw_copy() };
+						let func = func.clone();
+						let cmd_id = *cmd_id;
+						events.wm_command_accel_menu(cmd_id, move || {
+							hmenu2.CheckMenuRadioItem(IdPos::Id(first),
+								IdPos::Id(last), IdPos::Id(cmd_id))?;
+							func(index)
+						});
+					}
+				},
+				Entry::Separator => {
+					hmenu.AppendMenu(co::MF::SEPARATOR, IdMenu::None,
+						BmpPtrStr::None)?;
+				},
+				Entry::Submenu { text, submenu } => {
+					let mut hsubmenu = unsafe { DestroyMenuGuard::new(HMENU::CreatePopupMenu()?) };
+					submenu.populate(&hsubmenu, events, accels)?;
+					hmenu.AppendMenu(co::MF::POPUP, IdMenu::Menu(&hsubmenu),
+						BmpPtrStr::from_str(&text))?;
+					let _ = hsubmenu.leak(); // now owned by the parent menu
+				},
+			}
+		}
+		Ok(())
+	}
+}
+
+fn push_accel(
+	accels: &mut Vec<ACCEL>,
+	accel: Option<(co::ACCELF, co::VK)>,
+	cmd_id: u16,
+) {
+	if let Some((flags, key)) = accel {
+		accels.push(ACCEL {
+			fVirt: flags | co::ACCELF::VIRTKEY,
+			key,
+			cmd: cmd_id,
+		});
+	}
+}
+
+/// Appends the accelerator description to the item text, if any.
+fn item_text(text: &str, accel: Option<(co::ACCELF, co::VK)>) -> String {
+	let (flags, key) = match accel {
+		Some(accel) => accel,
+		None => return text.to_owned(),
+	};
+	let key_name = match key {
+		co::VK::BACK => "Backspace".to_owned(),
+		co::VK::TAB => "Tab".to_owned(),
+		co::VK::RETURN => "Enter".to_owned(),
+		co::VK::ESCAPE => "Esc".to_owned(),
+		co::VK::SPACE => "Space".to_owned(),
+		co::VK::PRIOR => "PgUp".to_owned(),
+		co::VK::NEXT => "PgDn".to_owned(),
+		co::VK::END => "End".to_owned(),
+		co::VK::HOME => "Home".to_owned(),
+		co::VK::LEFT => "Left".to_owned(),
+		co::VK::UP => "Up".to_owned(),
+		co::VK::RIGHT => "Right".to_owned(),
+		co::VK::DOWN => "Down".to_owned(),
+		co::VK::INSERT => "Ins".to_owned(),
+		co::VK::DELETE => "Del".to_owned(),
+		co::VK::ADD => "Num +".to_owned(),
+		co::VK::SUBTRACT => "Num -".to_owned(),
+		co::VK::MULTIPLY => "Num *".to_owned(),
+		co::VK::DIVIDE => "Num /".to_owned(),
+		key if (co::VK::CHAR_0.0..=co::VK::CHAR_9.0).contains(&key.0)
+			|| (co::VK::CHAR_A.0..=co::VK::CHAR_Z.0).contains(&key.0)
+			=> char::from(key.0 as u8).to_string(),
+		key if (co::VK::F1.0..=co::VK::F24.0).contains(&key.0)
+			=> format!("F{}", key.0 - co::VK::F1.0 + 1),
+		_ => return text.to_owned(), // no known description
+	};
+
+	let mut full_text = String::from(text);
+	full_text.push('\t');
+	if flags.has(co::ACCELF::CONTROL) { full_text.push_str("Ctrl+"); }
+	if flags.has(co::ACCELF::SHIFT) { full_text.push_str("Shift+"); }
+	if flags.has(co::ACCELF::ALT) { full_text.push_str("Alt+"); }
+	full_text.push_str(&key_name);
+	full_text
+}
@@ -9,13 +9,17 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "gui")))]
 
 mod base;
+mod dir_monitor;
 mod dlg_base;
 mod dlg_control;
 mod dlg_main;
 mod dlg_modal;
 mod dlg_modeless;
+mod executor;
 mod gui_traits;
+mod layout;
 mod layout_arranger;
+mod menu_builder;
 mod msg_error;
 mod native_controls;
 mod privs;
@@ -24,6 +28,7 @@ mod raw_control;
 mod raw_main;
 mod raw_modal;
 mod raw_modeless;
+mod tray_icon;
 mod window_control;
 mod window_main;
 mod window_modal;
@@ -31,7 +36,11 @@ mod window_modeless;
 
 pub mod events;
 
+pub use dir_monitor::DirMonitor;
+pub use executor::{Delay, ThreadTask};
+pub use layout::{Layout, LayoutSize, Thickness};
 pub use layout_arranger::{Horz, Vert};
+pub use menu_builder::MenuBuilder;
 pub use msg_error::MsgError;
 pub use native_controls::*;
 pub use raw_base::{Brush, Cursor, Icon};
@@ -39,6 +48,7 @@ pub use raw_control::WindowControlOpts;
 pub use raw_main::WindowMainOpts;
 pub use raw_modal::WindowModalOpts;
 pub use raw_modeless::WindowModelessOpts;
+pub use tray_icon::{TrayIcon, TrayIconOpts};
 pub use window_control::WindowControl;
 pub use window_main::WindowMain;
 pub use window_modal::WindowModal;
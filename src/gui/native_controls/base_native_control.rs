@@ -7,7 +7,7 @@ use crate::gui::events::{ProcessResult, WindowEvents};
 use crate::gui::privs::post_quit_error;
 use crate::kernel::decl::{AnyResult, SysResult};
 use crate::msg::WndMsg;
-use crate::prelude::{comctl_Hwnd, Handle, user_Hwnd};
+use crate::prelude::{comctl_Hwnd, Handle, NativeBitflag, user_Hwnd};
 use crate::user::decl::{AtomStr, HWND, IdMenu, POINT, SIZE};
 
 static mut BASE_SUBCLASS_ID: usize = 0;
@@ -90,7 +90,11 @@ impl BaseNativeControl {
 				title, styles,
 				pos, sz,
 				Some(hparent),
-				IdMenu::Id(ctrl_id),
+				if styles.has(co::WS::CHILD) {
+					IdMenu::Id(ctrl_id)
+				} else {
+					IdMenu::None // popup windows, like tooltips, are just owned by the parent
+				},
 				&hparent.hinstance(),
 				None,
 			)?;
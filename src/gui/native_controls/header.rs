@@ -0,0 +1,289 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::{HeaderEvents, WindowEvents};
+use crate::gui::layout_arranger::{Horz, Vert};
+use crate::gui::native_controls::base_native_control::{
+	BaseNativeControl, OptsId,
+};
+use crate::gui::native_controls::header_items::HeaderItems;
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font};
+use crate::kernel::decl::SysResult;
+use crate::msg::wm;
+use crate::prelude::{
+	GuiChild, GuiEvents, GuiNativeControl, GuiNativeControlEvents, GuiParent,
+	GuiWindow, Handle, user_Hwnd,
+};
+use crate::user::decl::{HWND, POINT, SIZE};
+
+struct Obj { // actual fields of Header
+	base: BaseNativeControl,
+	opts_id: OptsId<HeaderOpts>,
+	events: HeaderEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [header](https://learn.microsoft.com/en-us/windows/win32/controls/header-controls)
+/// control.
+///
+/// The header of a [`ListView`](crate::gui::ListView) is managed by the list
+/// view itself, and can be accessed with
+/// [`ListView::header`](crate::gui::ListView::header).
+#[derive(Clone)]
+pub struct Header(Pin<Arc<Obj>>);
+
+unsafe impl Send for Header {}
+
+impl GuiWindow for Header {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Header {
+	fn ctrl_id(&self) -> u16 {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => opts.ctrl_id,
+			OptsId::Dlg(ctrl_id) => *ctrl_id,
+		}
+	}
+}
+
+impl GuiNativeControl for Header {
+	fn on_subclass(&self) -> &WindowEvents {
+		self.0.base.on_subclass()
+	}
+}
+
+impl GuiNativeControlEvents<HeaderEvents> for Header {
+	fn on(&self) -> &HeaderEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Header {
+	/// Instantiates a new `Header` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Header` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: HeaderOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let opts = HeaderOpts::define_ctrl_id(opts);
+		let (ctrl_id, horz, vert) = (opts.ctrl_id, opts.horz_resize, opts.vert_resize);
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Wnd(opts),
+					events: HeaderEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.create(horz, vert)?;
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `Header` object, to be loaded from a dialog resource
+	/// with [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `Header` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Dlg(ctrl_id),
+					events: HeaderEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_init_dialog(move |_| {
+			self2.create(resize_behavior.0, resize_behavior.1)?;
+			Ok(true) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn create(&self, horz: Horz, vert: Vert) -> SysResult<()> {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				self.0.base.create_window( // may panic
+					"SysHeader32", None, pos, sz,
+					opts.ctrl_id,
+					opts.window_ex_style,
+					opts.window_style | opts.header_style.into(),
+				)?;
+
+				self.hwnd().SendMessage(wm::SetFont {
+					hfont: unsafe { ui_font().raw_copy() },
+					redraw: true,
+				});
+
+				for (text, width) in opts.items.iter() {
+					self.items().add(text, *width);
+				}
+			},
+			OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id)?,
+		}
+
+		self.0.base.parent().add_to_layout_arranger(self.hwnd(), horz, vert)
+	}
+
+	/// Exposes the item methods.
+	#[must_use]
+	pub fn items(&self) -> HeaderItems {
+		HeaderItems::new(self.0.base.hwnd())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Header`](crate::gui::Header) programmatically with
+/// [`Header::new`](crate::gui::Header::new).
+pub struct HeaderOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(200, 23)`.
+	pub size: (u32, u32),
+	/// Header styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `HDS::HORZ | HDS::BUTTONS | HDS::HOTTRACK`.
+	pub header_style: co::HDS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::NoValue`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal behavior when the parent is resized.
+	///
+	/// Defaults to `Horz::None`.
+	pub horz_resize: Horz,
+	/// Vertical behavior when the parent is resized.
+	///
+	/// Defaults to `Vert::None`.
+	pub vert_resize: Vert,
+
+	/// Items to be added as soon as the control is created. The tuple contains
+	/// the item text and its width in pixels, which will be multiplied to match
+	/// current system DPI.
+	///
+	/// Defaults to none.
+	pub items: Vec<(String, u32)>,
+}
+
+impl Default for HeaderOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (200, 23),
+			header_style: co::HDS::HORZ | co::HDS::BUTTONS | co::HDS::HOTTRACK,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::NoValue,
+			ctrl_id: 0,
+			horz_resize: Horz::None,
+			vert_resize: Vert::None,
+			items: Vec::default(),
+		}
+	}
+}
+
+impl HeaderOpts {
+	fn define_ctrl_id(mut self) -> Self {
+		if self.ctrl_id == 0 {
+			self.ctrl_id = auto_ctrl_id();
+		}
+		self
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Sort arrow displayed in a header item, used in
+/// [`HeaderItem::set_sort_arrow`](crate::gui::spec::HeaderItem::set_sort_arrow)
+/// and [`ListViewColumns::sort`](crate::gui::spec::ListViewColumns::sort).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortArrow {
+	/// No arrow.
+	None,
+	/// Arrow pointing up, with
+	/// [`HDF::SORTUP`](crate::co::HDF::SORTUP), meaning ascending order.
+	Up,
+	/// Arrow pointing down, with
+	/// [`HDF::SORTDOWN`](crate::co::HDF::SORTDOWN), meaning descending order.
+	Down,
+}
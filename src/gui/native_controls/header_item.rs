@@ -0,0 +1,193 @@
+use crate::co;
+use crate::comctl::decl::HDITEM;
+use crate::gui::native_controls::header::SortArrow;
+use crate::kernel::decl::WString;
+use crate::msg::hdm;
+use crate::prelude::{NativeBitflag, user_Hwnd};
+use crate::user::decl::{HWND, RECT};
+
+/// A single item of a [`Header`](crate::gui::Header) control, or of the header
+/// of a [`ListView`](crate::gui::ListView).
+///
+/// **Note:** Each object keeps the zero-based index of an item. If new items
+/// are added/removed from the header control, the object may then point to a
+/// different item.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+#[derive(Clone, Copy)]
+pub struct HeaderItem<'a> {
+	hwnd: &'a HWND,
+	index: u32,
+}
+
+impl<'a> HeaderItem<'a> {
+	pub(in crate::gui) const fn new(hwnd: &'a HWND, index: u32) -> Self {
+		Self { hwnd, index }
+	}
+
+	/// Returns the zero-based index of the item.
+	#[must_use]
+	pub const fn index(&self) -> u32 {
+		self.index
+	}
+
+	/// Clears the filter of the item by sending an
+	/// [`hdm::ClearFilter`](crate::msg::hdm::ClearFilter) message.
+	///
+	/// The header must have the
+	/// [`HDS::FILTERBAR`](crate::co::HDS::FILTERBAR) style.
+	pub fn clear_filter(&self) {
+		self.hwnd.SendMessage(hdm::ClearFilter { filter: Some(self.index) });
+	}
+
+	/// Deletes the item by sending an
+	/// [`hdm::DeleteItem`](crate::msg::hdm::DeleteItem) message.
+	pub fn delete(&self) {
+		self.hwnd
+			.SendMessage(hdm::DeleteItem { index: self.index })
+			.unwrap();
+	}
+
+	/// Retrieves the format flags by sending an
+	/// [`hdm::GetItem`](crate::msg::hdm::GetItem) message.
+	#[must_use]
+	pub fn format(&self) -> co::HDF {
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::FORMAT;
+		self.get_item(&mut hdi);
+		hdi.fmt
+	}
+
+	/// Retrieves the user-defined value by sending an
+	/// [`hdm::GetItem`](crate::msg::hdm::GetItem) message.
+	#[must_use]
+	pub fn lparam(&self) -> isize {
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::LPARAM;
+		self.get_item(&mut hdi);
+		hdi.lParam
+	}
+
+	/// Retrieves the bounding rectangle of the item by sending an
+	/// [`hdm::GetItemRect`](crate::msg::hdm::GetItemRect) message.
+	#[must_use]
+	pub fn rect(&self) -> RECT {
+		let mut rc = RECT::default();
+		self.hwnd
+			.SendMessage(hdm::GetItemRect {
+				index: self.index,
+				rect: &mut rc,
+			})
+			.unwrap();
+		rc
+	}
+
+	/// Sets the format flags by sending an
+	/// [`hdm::SetItem`](crate::msg::hdm::SetItem) message.
+	pub fn set_format(&self, format: co::HDF) {
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::FORMAT;
+		hdi.fmt = format;
+		self.set_item(&hdi);
+	}
+
+	/// Sets the user-defined value by sending an
+	/// [`hdm::SetItem`](crate::msg::hdm::SetItem) message.
+	pub fn set_lparam(&self, lparam: isize) {
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::LPARAM;
+		hdi.lParam = lparam;
+		self.set_item(&hdi);
+	}
+
+	/// Shows or hides the sort arrow by setting the
+	/// [`HDF::SORTUP`](crate::co::HDF::SORTUP) and
+	/// [`HDF::SORTDOWN`](crate::co::HDF::SORTDOWN) format flags, keeping the
+	/// other flags.
+	pub fn set_sort_arrow(&self, arrow: SortArrow) {
+		let fmt = self.format() & !(co::HDF::SORTUP | co::HDF::SORTDOWN);
+		self.set_format(match arrow {
+			SortArrow::None => fmt,
+			SortArrow::Up => fmt | co::HDF::SORTUP,
+			SortArrow::Down => fmt | co::HDF::SORTDOWN,
+		});
+	}
+
+	/// Sets the text by sending an [`hdm::SetItem`](crate::msg::hdm::SetItem)
+	/// message.
+	pub fn set_text(&self, text: &str) {
+		let mut wtext = WString::from_str(text);
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::TEXT;
+		hdi.set_pszText(Some(&mut wtext));
+		self.set_item(&hdi);
+	}
+
+	/// Sets the width by sending an [`hdm::SetItem`](crate::msg::hdm::SetItem)
+	/// message.
+	///
+	/// Unlike [`HeaderItems::add`](crate::gui::spec::HeaderItems::add), the
+	/// width is not adjusted to the system DPI.
+	pub fn set_width(&self, width: u32) {
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::WIDTH;
+		hdi.cxy = width as _;
+		self.set_item(&hdi);
+	}
+
+	/// Retrieves the sort arrow currently shown, by checking the
+	/// [`HDF::SORTUP`](crate::co::HDF::SORTUP) and
+	/// [`HDF::SORTDOWN`](crate::co::HDF::SORTDOWN) format flags.
+	#[must_use]
+	pub fn sort_arrow(&self) -> SortArrow {
+		let fmt = self.format();
+		if fmt.has(co::HDF::SORTUP) {
+			SortArrow::Up
+		} else if fmt.has(co::HDF::SORTDOWN) {
+			SortArrow::Down
+		} else {
+			SortArrow::None
+		}
+	}
+
+	/// Retrieves the text by sending an
+	/// [`hdm::GetItem`](crate::msg::hdm::GetItem) message.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let mut buf = WString::new_alloc_buf(128); // arbitrary
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::TEXT;
+		hdi.set_pszText(Some(&mut buf));
+		self.get_item(&mut hdi);
+		buf.to_string()
+	}
+
+	/// Retrieves the width by sending an
+	/// [`hdm::GetItem`](crate::msg::hdm::GetItem) message.
+	#[must_use]
+	pub fn width(&self) -> u32 {
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::WIDTH;
+		self.get_item(&mut hdi);
+		hdi.cxy as _
+	}
+
+	fn get_item(&self, hdi: &mut HDITEM) {
+		self.hwnd
+			.SendMessage(hdm::GetItem {
+				index: self.index,
+				hditem: hdi,
+			})
+			.unwrap();
+	}
+
+	fn set_item(&self, hdi: &HDITEM) {
+		self.hwnd
+			.SendMessage(hdm::SetItem {
+				index: self.index,
+				hditem: hdi,
+			})
+			.unwrap();
+	}
+}
@@ -0,0 +1,126 @@
+use crate::co;
+use crate::comctl::decl::HDITEM;
+use crate::gui::native_controls::header_item::HeaderItem;
+use crate::gui::privs::multiply_dpi;
+use crate::kernel::decl::WString;
+use crate::msg::hdm;
+use crate::prelude::user_Hwnd;
+use crate::user::decl::{HWND, SIZE};
+
+/// Exposes the item methods of a [`Header`](crate::gui::Header) control, or
+/// the header of a [`ListView`](crate::gui::ListView).
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct HeaderItems<'a> {
+	hwnd: &'a HWND,
+}
+
+impl<'a> HeaderItems<'a> {
+	pub(in crate::gui) const fn new(hwnd: &'a HWND) -> Self {
+		Self { hwnd }
+	}
+
+	/// Appends a new item by sending an
+	/// [`hdm::InsertItem`](crate::msg::hdm::InsertItem) message, and returns
+	/// the newly added item.
+	///
+	/// The width will be adjusted to match current system DPI.
+	pub fn add(&self, text: &str, width: u32) -> HeaderItem<'a> {
+		let mut cx = SIZE::new(width as _, 0);
+		multiply_dpi(None, Some(&mut cx)).unwrap();
+
+		let mut wtext = WString::from_str(text);
+		let mut hdi = HDITEM::default();
+		hdi.mask = co::HDI::TEXT | co::HDI::WIDTH | co::HDI::FORMAT;
+		hdi.cxy = cx.cx;
+		hdi.fmt = co::HDF::STRING | co::HDF::LEFT;
+		hdi.set_pszText(Some(&mut wtext));
+
+		self.get(
+			self.hwnd
+				.SendMessage(hdm::InsertItem {
+					index_after: 0x0fff_ffff, // insert as the last item
+					item: &hdi,
+				})
+				.unwrap(),
+		)
+	}
+
+	/// Retrieves the total number of items by sending an
+	/// [`hdm::GetItemCount`](crate::msg::hdm::GetItemCount) message.
+	#[must_use]
+	pub fn count(&self) -> u32 {
+		self.hwnd
+			.SendMessage(hdm::GetItemCount {})
+			.unwrap()
+	}
+
+	/// Deletes all items by sending
+	/// [`hdm::DeleteItem`](crate::msg::hdm::DeleteItem) messages.
+	pub fn delete_all(&self) {
+		for index in (0..self.count()).rev() {
+			self.get(index).delete();
+		}
+	}
+
+	/// Returns the focused item by sending an
+	/// [`hdm::GetFocusedItem`](crate::msg::hdm::GetFocusedItem) message.
+	#[must_use]
+	pub fn focused(&self) -> HeaderItem<'a> {
+		self.get(self.hwnd.SendMessage(hdm::GetFocusedItem {}))
+	}
+
+	/// Retrieves the item at the given zero-based position.
+	///
+	/// **Note:** This method is cheap – even if `index` is beyond the range of
+	/// existing items, an object will still be returned. However, operations
+	/// upon this object will fail.
+	#[must_use]
+	pub const fn get(&self, index: u32) -> HeaderItem<'a> {
+		HeaderItem::new(self.hwnd, index)
+	}
+
+	/// Returns an iterator over all items.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::gui;
+	///
+	/// let my_header: gui::Header; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_header = gui::Header::new(&wnd, gui::HeaderOpts::default());
+	///
+	/// for item in my_header.items().iter() {
+	///     println!("Item {}: {}", item.index(), item.text());
+	/// }
+	/// ```
+	#[must_use]
+	pub fn iter(&self) -> impl Iterator<Item = HeaderItem<'a>> + 'a {
+		let hwnd = self.hwnd;
+		(0..self.count()).map(move |index| HeaderItem::new(hwnd, index))
+	}
+
+	/// Retrieves the zero-based indexes of the items, in the order they are
+	/// displayed, by sending an
+	/// [`hdm::GetOrderArray`](crate::msg::hdm::GetOrderArray) message.
+	#[must_use]
+	pub fn order(&self) -> Vec<u32> {
+		let mut buf = vec![0u32; self.count() as _];
+		self.hwnd
+			.SendMessage(hdm::GetOrderArray { buffer: &mut buf })
+			.unwrap();
+		buf
+	}
+
+	/// Sets the order the items are displayed, from their zero-based indexes,
+	/// by sending an [`hdm::SetOrderArray`](crate::msg::hdm::SetOrderArray)
+	/// message.
+	pub fn set_order(&self, indexes: &[u32]) {
+		self.hwnd
+			.SendMessage(hdm::SetOrderArray { buffer: indexes })
+			.unwrap();
+	}
+}
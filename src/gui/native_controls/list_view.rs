@@ -1,20 +1,24 @@
 use std::any::Any;
+use std::cell::UnsafeCell;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
-use crate::comctl::decl::{HIMAGELIST, NMITEMACTIVATE, NMLVKEYDOWN};
+use crate::comctl::decl::{
+	HIMAGELIST, LVITEM, NMITEMACTIVATE, NMLVDISPINFO, NMLVKEYDOWN,
+};
 use crate::gui::base::Base;
 use crate::gui::events::{ListViewEvents, WindowEvents};
 use crate::gui::layout_arranger::{Horz, Vert};
 use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
+use crate::gui::native_controls::header_items::HeaderItems;
 use crate::gui::native_controls::list_view_columns::ListViewColumns;
 use crate::gui::native_controls::list_view_items::ListViewItems;
 use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu};
-use crate::kernel::decl::SysResult;
+use crate::kernel::decl::{AnyResult, SysResult};
 use crate::msg::{lvm, wm};
 use crate::prelude::{
 	GuiChild, GuiChildFocus, GuiEvents, GuiEventsAll, GuiNativeControl,
@@ -30,6 +34,8 @@ struct Obj { // atual fields of ListView
 	opts_id: OptsId<ListViewOpts>,
 	events: ListViewEvents,
 	context_menu: Option<HMENU>,
+	item_count: u32, // for virtual list views
+	hheader: UnsafeCell<HWND>, // retrieved after creation
 	_pin: PhantomPinned,
 }
 
@@ -97,6 +103,7 @@ impl ListView {
 		let opts = ListViewOpts::define_ctrl_id(opts);
 		let (ctrl_id, horz, vert) = (opts.ctrl_id, opts.horz_resize, opts.vert_resize);
 		let context_menu = opts.context_menu.as_ref().map(|h| unsafe { h.raw_copy() });
+		let item_count = opts.item_count;
 
 		let new_self = Self(
 			Arc::pin(
@@ -105,6 +112,8 @@ impl ListView {
 					opts_id: OptsId::Wnd(opts),
 					events: ListViewEvents::new(parent_ref, ctrl_id),
 					context_menu,
+					item_count,
+					hheader: UnsafeCell::new(HWND::NULL),
 					_pin: PhantomPinned,
 				},
 			),
@@ -128,6 +137,12 @@ impl ListView {
 	/// manually after the control is destroyed. But note that menus loaded from
 	/// resources don't need to be destroyed.
 	///
+	/// If the resource has the [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA)
+	/// style, set the number of items with
+	/// [`ListViewItems::set_count`](crate::gui::spec::ListViewItems::set_count)
+	/// after the dialog is created, in the parent's
+	/// [`wm_init_dialog`](crate::prelude::GuiEvents::wm_init_dialog) event.
+	///
 	/// # Panics
 	///
 	/// Panics if the parent dialog was already created – that is, you cannot
@@ -149,6 +164,8 @@ impl ListView {
 					opts_id: OptsId::Dlg(ctrl_id),
 					events: ListViewEvents::new(parent_ref, ctrl_id),
 					context_menu,
+					item_count: 0, // set by the user after creation
+					hheader: UnsafeCell::new(HWND::NULL),
 					_pin: PhantomPinned,
 				},
 			),
@@ -188,6 +205,15 @@ impl ListView {
 			OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id)?,
 		}
 
+		let styles = co::LVS(self.hwnd().GetWindowLongPtr(co::GWLP::STYLE) as _);
+		if styles.has(co::LVS::OWNERDATA) && self.0.item_count > 0 {
+			self.items().set_count(self.0.item_count, co::LVSICF::NoValue);
+		}
+
+		if let Ok(hheader) = self.hwnd().SendMessage(lvm::GetHeader {}) {
+			*unsafe { &mut *self.0.hheader.get() } = hheader;
+		}
+
 		self.0.base.parent().add_to_layout_arranger(self.hwnd(), horz, vert)
 	}
 
@@ -233,6 +259,22 @@ impl ListView {
 		self.0.context_menu.as_ref()
 	}
 
+	/// Exposes the item methods of the header control, which is automatically
+	/// created by the list view, and returned by
+	/// [`lvm::GetHeader`](crate::msg::lvm::GetHeader).
+	///
+	/// Returns `None` if the control was not created yet, or if it has no
+	/// header.
+	#[must_use]
+	pub fn header(&self) -> Option<HeaderItems> {
+		let hheader = unsafe { &*self.0.hheader.get() };
+		if *hheader == HWND::NULL {
+			None
+		} else {
+			Some(HeaderItems::new(hheader))
+		}
+	}
+
 	/// Retrieves one of the associated image lists by sending an
 	/// [`lvm::GetImageList`](crate::msg::lvm::GetImageList) message.
 	#[must_use]
@@ -269,6 +311,62 @@ impl ListView {
 		});
 	}
 
+	/// Sets the closure which provides the data of the items of a virtual list
+	/// view – created with [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) – by
+	/// answering the
+	/// [`lvn_get_disp_info`](crate::gui::events::ListViewEvents::lvn_get_disp_info)
+	/// notification.
+	///
+	/// The closure is called whenever the list view needs to display an item,
+	/// once for each column, and it must provide only the requested data.
+	///
+	/// # Panics
+	///
+	/// Panics if the control or the parent window are already created.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let my_list = gui::ListView::new(
+	///     &wnd,
+	///     gui::ListViewOpts {
+	///         list_view_style: co::LVS::REPORT | co::LVS::OWNERDATA,
+	///         columns: vec![("Number".to_owned(), 120), ("Square".to_owned(), 120)],
+	///         item_count: 500_000,
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// my_list.set_data_provider(|item: &mut gui::ListViewItemData| {
+	///     let n = item.index() as u64;
+	///     match item.column_index() {
+	///         0 => item.set_text(&n.to_string()),
+	///         _ => item.set_text(&(n * n).to_string()),
+	///     }
+	///     Ok(())
+	/// });
+	/// ```
+	pub fn set_data_provider<F>(&self, func: F)
+		where F: Fn(&mut ListViewItemData) -> AnyResult<()> + 'static,
+	{
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot set the data provider after the control creation.");
+		}
+
+		self.0.base.parent().privileged_on().wm_notify(
+			self.ctrl_id(), co::LVN::GETDISPINFO, move |p| {
+				let nmdi = unsafe { p.cast_nmhdr_mut::<NMLVDISPINFO>() };
+				func(&mut ListViewItemData { lvi: &mut nmdi.item })?;
+				Ok(None) // not meaningful
+			});
+	}
+
 	/// Sets the one of the associated image lists by sending an
 	/// [`lvm::SetImageList`](crate::msg::lvm::SetImageList) message.
 	///
@@ -332,6 +430,71 @@ impl ListView {
 
 //------------------------------------------------------------------------------
 
+/// An item of a virtual list view whose data is being requested, passed to the
+/// closure given to
+/// [`ListView::set_data_provider`](crate::gui::ListView::set_data_provider).
+///
+/// Only the requested data must be set; anything else is ignored.
+pub struct ListViewItemData<'a, 'b> {
+	lvi: &'a mut LVITEM<'b>,
+}
+
+impl<'a, 'b> ListViewItemData<'a, 'b> {
+	/// Returns the zero-based index of the item.
+	#[must_use]
+	pub const fn index(&self) -> u32 {
+		self.lvi.iItem as _
+	}
+
+	/// Returns the zero-based index of the column whose data is requested.
+	#[must_use]
+	pub const fn column_index(&self) -> u32 {
+		self.lvi.iSubItem as _
+	}
+
+	/// Tells whether the text is requested.
+	#[must_use]
+	pub fn wants_text(&self) -> bool {
+		self.lvi.mask.has(co::LVIF::TEXT)
+	}
+
+	/// Tells whether the image is requested.
+	#[must_use]
+	pub fn wants_image(&self) -> bool {
+		self.lvi.mask.has(co::LVIF::IMAGE)
+	}
+
+	/// Tells whether the state is requested.
+	#[must_use]
+	pub fn wants_state(&self) -> bool {
+		self.lvi.mask.has(co::LVIF::STATE)
+	}
+
+	/// Sets the text, which is truncated if longer than the buffer provided
+	/// by the list view.
+	pub fn set_text(&mut self, text: &str) {
+		if self.wants_text() {
+			self.lvi.copy_to_pszText(text);
+		}
+	}
+
+	/// Sets the zero-based index of the image, within the image list.
+	pub fn set_image(&mut self, image_index: u32) {
+		if self.wants_image() {
+			self.lvi.iImage = image_index as _;
+		}
+	}
+
+	/// Sets the state, of which only the requested bits are kept.
+	pub fn set_state(&mut self, state: co::LVIS) {
+		if self.wants_state() {
+			self.lvi.state = state & self.lvi.stateMask;
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// Options to create a [`ListView`](crate::gui::ListView) programmatically with
 /// [`ListView::new`](crate::gui::ListView::new).
 pub struct ListViewOpts {
@@ -401,6 +564,18 @@ pub struct ListViewOpts {
 	///
 	/// Defaults to none.
 	pub columns: Vec<(String, u32)>,
+	/// Number of items of a virtual list view, to be set right after the
+	/// creation. Only meaningful if `list_view_style` has
+	/// [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA).
+	///
+	/// The data of each item is provided by the closure given to
+	/// [`ListView::set_data_provider`](crate::gui::ListView::set_data_provider),
+	/// or through the
+	/// [`lvn_get_disp_info`](crate::gui::events::ListViewEvents::lvn_get_disp_info)
+	/// event.
+	///
+	/// Defaults to `0`.
+	pub item_count: u32,
 }
 
 impl Default for ListViewOpts {
@@ -417,6 +592,7 @@ impl Default for ListViewOpts {
 			vert_resize: Vert::None,
 			context_menu: None,
 			columns: Vec::<(String, u32)>::default(),
+			item_count: 0,
 		}
 	}
 }
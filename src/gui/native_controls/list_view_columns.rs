@@ -1,8 +1,12 @@
+use std::cmp::Ordering;
+
 use crate::co;
 use crate::comctl::decl::LVCOLUMN;
+use crate::gui::native_controls::header::SortArrow;
+use crate::gui::native_controls::list_view_item::ListViewItem;
 use crate::gui::native_controls::list_view::ListView;
 use crate::gui::privs::multiply_dpi;
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{SysResult, WString};
 use crate::msg::{hdm, lvm};
 use crate::prelude::{GuiWindow, user_Hwnd};
 use crate::user::decl::SIZE;
@@ -146,6 +150,81 @@ impl<'a> ListViewColumns<'a> {
 			.unwrap();
 	}
 
+	/// Sorts the items according to the given comparison function, which must
+	/// return the ascending order, then shows the sort arrow on the column
+	/// header, removing it from the other columns.
+	///
+	/// If `arrow` is [`SortArrow::Down`](crate::gui::SortArrow::Down), the
+	/// order is reversed. If it's [`SortArrow::None`](crate::gui::SortArrow::None),
+	/// the items are sorted in ascending order, and no arrow is shown.
+	///
+	/// The sorting is made by
+	/// [`ListViewItems::sort`](crate::gui::spec::ListViewItems::sort), whose
+	/// error is returned, in which case the arrows are left untouched.
+	///
+	/// # Examples
+	///
+	/// Sorting the items when a column header is clicked, toggling the order.
+	/// Note that the list view must not have the
+	/// [`LVS::NOSORTHEADER`](crate::co::LVS::NOSORTHEADER) style:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, NMLISTVIEW};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let my_list = gui::ListView::new(
+	///     &wnd,
+	///     gui::ListViewOpts {
+	///         list_view_style: co::LVS::REPORT | co::LVS::SHOWSELALWAYS,
+	///         columns: vec![("Name".to_owned(), 200)],
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// my_list.on().lvn_column_click({
+	///     let my_list = my_list.clone();
+	///     move |p: &NMLISTVIEW| {
+	///         let col = p.iSubItem as u32;
+	///         let arrow = match my_list.columns().sort_arrow(col) {
+	///             gui::SortArrow::Up => gui::SortArrow::Down,
+	///             _ => gui::SortArrow::Up,
+	///         };
+	///         my_list.columns().sort(col, arrow,
+	///             |a, b| a.text(col).cmp(&b.text(col)))?;
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	pub fn sort<F>(&self,
+		column_index: u32, arrow: SortArrow, mut compare: F) -> SysResult<()>
+		where F: FnMut(ListViewItem<'a>, ListViewItem<'a>) -> Ordering,
+	{
+		if arrow == SortArrow::Down {
+			self.owner.items().sort(|a, b| compare(a, b).reverse())?;
+		} else {
+			self.owner.items().sort(compare)?;
+		}
+
+		if let Some(header) = self.owner.header() {
+			for item in header.iter() {
+				item.set_sort_arrow(
+					if item.index() == column_index { arrow } else { SortArrow::None });
+			}
+		}
+		Ok(())
+	}
+
+	/// Retrieves the sort arrow currently shown on the column header.
+	#[must_use]
+	pub fn sort_arrow(&self, column_index: u32) -> SortArrow {
+		self.owner.header()
+			.map(|header| header.get(column_index).sort_arrow())
+			.unwrap_or(SortArrow::None)
+	}
+
 	/// Retrieves the title of the column by calling
 	/// [`info`](crate::gui::spec::ListViewColumns::info).
 	#[must_use]
@@ -1,10 +1,12 @@
+use std::cmp::Ordering;
+
 use crate::co;
 use crate::comctl::decl::{LVFINDINFO, LVHITTESTINFO, LVITEM};
 use crate::gui::native_controls::list_view_item::ListViewItem;
 use crate::gui::native_controls::list_view::ListView;
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{SysResult, WString};
 use crate::msg::lvm;
-use crate::prelude::{GuiWindow, user_Hwnd};
+use crate::prelude::{GuiWindow, NativeBitflag, user_Hwnd};
 use crate::user::decl::POINT;
 
 /// Exposes item methods of a [`ListView`](crate::gui::ListView) control.
@@ -118,6 +120,10 @@ impl<'a> ListViewItems<'a> {
 
 	/// Searches for an item with the given text, case-insensitive, by sending
 	/// an [`lvm::FindItem`](crate::msg::lvm::FindItem) message.
+	///
+	/// In a virtual list view, the search is answered by the
+	/// [`lvn_od_find_item`](crate::gui::events::ListViewEvents::lvn_od_find_item)
+	/// event.
 	#[must_use]
 	pub fn find(&self, text: &str) -> Option<ListViewItem<'a>> {
 		let mut buf = WString::from_str(text);
@@ -247,6 +253,80 @@ impl<'a> ListViewItems<'a> {
 			.unwrap();
 	}
 
+	/// Sets the number of items of a virtual list view – created with
+	/// [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) – by sending an
+	/// [`lvm::SetItemCount`](crate::msg::lvm::SetItemCount) message.
+	///
+	/// The data of each item is then retrieved by the list view through the
+	/// [`lvn_get_disp_info`](crate::gui::events::ListViewEvents::lvn_get_disp_info)
+	/// event.
+	pub fn set_count(&self, count: u32, behavior: co::LVSICF) {
+		self.owner.hwnd()
+			.SendMessage(lvm::SetItemCount { count, behavior })
+			.unwrap();
+	}
+
+	/// Sorts the items according to the given comparison function, by sending
+	/// an [`lvm::SortItems`](crate::msg::lvm::SortItems) message.
+	///
+	/// The sorting is stable. The comparison function is not called during the
+	/// message processing, so it can freely retrieve item data.
+	///
+	/// During the sorting, the final position of each item is temporarily
+	/// stored in its [`lparam`](crate::gui::spec::ListViewItem::lparam), which
+	/// is restored afterwards.
+	///
+	/// Virtual list views – created with
+	/// [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) – cannot be sorted by the
+	/// control, so [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED)
+	/// is returned; the underlying data must be sorted instead.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::gui;
+	///
+	/// let my_list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ListView::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// my_list.items().sort(|a, b| a.text(0).cmp(&b.text(0)))?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn sort<F>(&self, mut compare: F) -> SysResult<()>
+		where F: FnMut(ListViewItem<'a>, ListViewItem<'a>) -> Ordering,
+	{
+		let styles = co::LVS(
+			self.owner.hwnd().GetWindowLongPtr(co::GWLP::STYLE) as _,
+		);
+		if styles.has(co::LVS::OWNERDATA) {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		}
+
+		let mut indexes = (0..self.count()).collect::<Vec<_>>();
+		indexes.sort_by(|a, b| compare(self.get(*a), self.get(*b)));
+
+		let lparams = indexes.iter()
+			.map(|index| self.get(*index).lparam())
+			.collect::<Vec<_>>(); // in final order
+		for (rank, index) in indexes.iter().enumerate() {
+			self.get(*index).set_lparam(rank as _); // final position of each item
+		}
+
+		let res = self.owner.hwnd()
+			.SendMessage(lvm::SortItems {
+				param: 0,
+				callback: sort_by_rank_proc,
+			});
+
+		for index in 0..self.count() { // even if the sorting failed
+			let item = self.get(index);
+			item.set_lparam(lparams[item.lparam() as usize]);
+		}
+		res
+	}
+
 	/// Retrieves the number of selected items by sending an
 	/// [`lvm::GetSelectedCount`](crate::msg::lvm::GetSelectedCount) message.
 	#[must_use]
@@ -288,3 +368,9 @@ impl<'a> ListViewItemIter<'a> {
 		}
 	}
 }
+
+extern "system" fn sort_by_rank_proc(
+	rank1: isize, rank2: isize, _: isize) -> i32
+{
+	rank1.cmp(&rank2) as _
+}
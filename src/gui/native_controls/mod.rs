@@ -7,6 +7,9 @@ mod combo_box_items;
 mod combo_box;
 mod date_time_picker;
 mod edit;
+mod header_item;
+mod header_items;
+mod header;
 mod label;
 mod list_box_items;
 mod list_box;
@@ -18,11 +21,16 @@ mod month_calendar;
 mod progress_bar;
 mod radio_button;
 mod radio_group;
+mod rich_edit;
 mod status_bar_parts;
 mod status_bar;
 mod tab_item;
 mod tab_items;
 mod tab;
+mod toolbar_item;
+mod toolbar_items;
+mod toolbar;
+mod tooltip;
 mod trackbar;
 mod tree_view_item;
 mod tree_view_items;
@@ -34,15 +42,19 @@ pub use check_box::{CheckBox, CheckBoxOpts, CheckState};
 pub use combo_box::{ComboBox, ComboBoxOpts};
 pub use date_time_picker::{DateTimePicker, DateTimePickerOpts};
 pub use edit::{Edit, EditOpts};
+pub use header::{Header, HeaderOpts, SortArrow};
 pub use label::{Label, LabelOpts};
 pub use list_box::{ListBox, ListBoxOpts};
-pub use list_view::{ListView, ListViewOpts};
+pub use list_view::{ListView, ListViewItemData, ListViewOpts};
 pub use month_calendar::{MonthCalendar, MonthCalendarOpts};
 pub use progress_bar::{ProgressBar, ProgressBarOpts};
 pub use radio_button::{RadioButton, RadioButtonOpts};
 pub use radio_group::RadioGroup;
+pub use rich_edit::{RichEdit, RichEditOpts};
 pub use status_bar::{StatusBar, StatusBarPart};
 pub use tab::{Tab, TabOpts};
+pub use toolbar::{Toolbar, ToolbarOpts};
+pub use tooltip::{Tooltip, TooltipOpts};
 pub use trackbar::{Trackbar, TrackbarOpts};
 pub use tree_view::{TreeView, TreeViewOpts};
 pub use up_down::{UpDown, UpDownOpts};
@@ -50,6 +62,8 @@ pub use up_down::{UpDown, UpDownOpts};
 /// Structs which expose specialized methods of controls.
 pub mod spec {
 	pub use super::combo_box_items::ComboBoxItems;
+	pub use super::header_item::HeaderItem;
+	pub use super::header_items::HeaderItems;
 	pub use super::list_box_items::ListBoxItems;
 	pub use super::list_view_columns::ListViewColumns;
 	pub use super::list_view_item::ListViewItem;
@@ -57,6 +71,8 @@ pub mod spec {
 	pub use super::status_bar_parts::StatusBarParts;
 	pub use super::tab_item::TabItem;
 	pub use super::tab_items::TabItems;
+	pub use super::toolbar_item::ToolbarItem;
+	pub use super::toolbar_items::ToolbarItems;
 	pub use super::tree_view_item::TreeViewItem;
 	pub use super::tree_view_items::TreeViewItems;
 }
@@ -0,0 +1,598 @@
+use std::any::Any;
+use std::error::Error;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::decl::{
+	CHARFORMAT2, CHARRANGE, EDITSTREAM, FINDTEXTEX, PARAFORMAT2,
+};
+use crate::gui::base::Base;
+use crate::gui::events::{RichEditEvents, WindowEvents};
+use crate::gui::layout_arranger::{Horz, Vert};
+use crate::gui::native_controls::base_native_control::{
+	BaseNativeControl, OptsId,
+};
+use crate::gui::privs::{
+	auto_ctrl_id, load_msftedit, multiply_dpi_or_dtu, ui_font,
+};
+use crate::kernel::decl::{AnyResult, SysResult, WString};
+use crate::msg::{em, wm};
+use crate::prelude::{
+	GuiChild, GuiChildFocus, GuiEvents, GuiNativeControl,
+	GuiNativeControlEvents, GuiParent, GuiWindow, GuiWindowText, Handle,
+	NativeBitflag, user_Hwnd,
+};
+use crate::user::decl::{COLORREF, HWND, POINT, SIZE};
+
+struct Obj { // actual fields of RichEdit
+	base: BaseNativeControl,
+	opts_id: OptsId<RichEditOpts>,
+	events: RichEditEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [rich edit](https://learn.microsoft.com/en-us/windows/win32/controls/about-rich-edit-controls)
+/// control, version 4.1, from the `RICHEDIT50W` class of Msftedit.dll, which is
+/// automatically loaded.
+///
+/// Unlike the ordinary [`Edit`](crate::gui::Edit), the text can be formatted,
+/// and loaded and saved as RTF.
+#[derive(Clone)]
+pub struct RichEdit(Pin<Arc<Obj>>);
+
+unsafe impl Send for RichEdit {}
+
+impl GuiWindow for RichEdit {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for RichEdit {}
+
+impl GuiChild for RichEdit {
+	fn ctrl_id(&self) -> u16 {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => opts.ctrl_id,
+			OptsId::Dlg(ctrl_id) => *ctrl_id,
+		}
+	}
+}
+
+impl GuiChildFocus for RichEdit {}
+
+impl GuiNativeControl for RichEdit {
+	fn on_subclass(&self) -> &WindowEvents {
+		self.0.base.on_subclass()
+	}
+}
+
+impl GuiNativeControlEvents<RichEditEvents> for RichEdit {
+	fn on(&self) -> &RichEditEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl RichEdit {
+	/// Instantiates a new `RichEdit` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `RichEdit` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: RichEditOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let opts = RichEditOpts::define_ctrl_id(opts);
+		let (ctrl_id, horz, vert) = (opts.ctrl_id, opts.horz_resize, opts.vert_resize);
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Wnd(opts),
+					events: RichEditEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.create(horz, vert)?;
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `RichEdit` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// **Note:** Msftedit.dll must be loaded before the dialog is created,
+	/// since the dialog resource references the `RICHEDIT50W` class. This is
+	/// done when this method is called.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `RichEdit` in an event closure.
+	///
+	/// Panics if Msftedit.dll cannot be loaded.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		load_msftedit().unwrap();
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Dlg(ctrl_id),
+					events: RichEditEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_init_dialog(move |_| {
+			self2.create(resize_behavior.0, resize_behavior.1)?;
+			Ok(true) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn create(&self, horz: Horz, vert: Vert) -> SysResult<()> {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				load_msftedit()?;
+				self.0.base.create_window(
+					"RICHEDIT50W", Some(&opts.text), pos, sz,
+					opts.ctrl_id,
+					opts.window_ex_style,
+					opts.window_style | opts.edit_style.into(),
+				)?;
+
+				self.hwnd().SendMessage(wm::SetFont {
+					hfont: unsafe { ui_font().raw_copy() },
+					redraw: true,
+				});
+				self.hwnd().SendMessage(em::SetEventMask { mask: opts.event_mask });
+			},
+			OptsId::Dlg(ctrl_id) => {
+				self.0.base.create_dlg(*ctrl_id)?;
+				self.hwnd().SendMessage(em::SetEventMask {
+					mask: RichEditOpts::default().event_mask,
+				});
+			},
+		}
+
+		self.0.base.parent().add_to_layout_arranger(self.hwnd(), horz, vert)
+	}
+
+	/// Appends text at the end of the control, optionally with the given
+	/// character format, and scrolls it into view.
+	///
+	/// # Examples
+	///
+	/// Appending a red line to a log viewer:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, CHARFORMAT2, COLORREF};
+	///
+	/// let my_rich: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_rich = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut fmt = CHARFORMAT2::default();
+	/// fmt.dwMask = co::CFM::COLOR;
+	/// fmt.crTextColor = COLORREF::new(0xff, 0x00, 0x00);
+	///
+	/// my_rich.append_text("Something went wrong.\r\n", Some(&fmt));
+	/// ```
+	pub fn append_text(&self, text: &str, format: Option<&CHARFORMAT2>) {
+		self.set_selection(CHARRANGE { cpMin: -1, cpMax: -1 });
+
+		if let Some(format) = format {
+			self.set_char_format(co::SCF::SELECTION, format);
+		} else {
+			let default_fmt = self.char_format(co::SCF::DEFAULT);
+			self.set_char_format(co::SCF::SELECTION, &default_fmt);
+		}
+
+		self.hwnd().SendMessage(em::ReplaceSel {
+			can_be_undone: false,
+			replacement_text: WString::from_str(text),
+		});
+		self.hwnd().SendMessage(em::ScrollCaret {});
+	}
+
+	/// Enables or disables the automatic detection of URLs by sending an
+	/// [`em::AutoUrlDetect`](crate::msg::em::AutoUrlDetect) message.
+	///
+	/// Clicks on the detected URLs are notified through the
+	/// [`en_link`](crate::gui::events::RichEditEvents::en_link) event.
+	pub fn auto_url_detect(&self, flags: co::AURL) {
+		self.hwnd()
+			.SendMessage(em::AutoUrlDetect { flags })
+			.unwrap();
+	}
+
+	/// Retrieves the character format of the selection or the default format
+	/// by sending an [`em::GetCharFormat`](crate::msg::em::GetCharFormat)
+	/// message.
+	#[must_use]
+	pub fn char_format(&self, range: co::SCF) -> CHARFORMAT2 {
+		let mut fmt = CHARFORMAT2::default();
+		self.hwnd().SendMessage(em::GetCharFormat { range, format: &mut fmt });
+		fmt
+	}
+
+	/// Searches for a text by sending an
+	/// [`em::FindTextEx`](crate::msg::em::FindTextEx) message, starting at the
+	/// given character position, and returns the range of the found text.
+	///
+	/// To search forward, `flags` must have
+	/// [`FR::DOWN`](crate::co::FR::DOWN).
+	#[must_use]
+	pub fn find_text(&self,
+		text: &str, start: u32, flags: co::FR) -> Option<CHARRANGE>
+	{
+		let mut wtext = WString::from_str(text);
+		let mut ftx = FINDTEXTEX::default();
+		ftx.chrg = CHARRANGE {
+			cpMin: start as _,
+			cpMax: if flags.has(co::FR::DOWN) { -1 } else { 0 },
+		};
+		ftx.set_lpstrText(Some(&mut wtext));
+
+		self.hwnd()
+			.SendMessage(em::FindTextEx { flags, info: &mut ftx })
+			.map(|_| ftx.chrgText)
+	}
+
+	/// Limits the number of characters by sending an
+	/// [`em::ExLimitText`](crate::msg::em::ExLimitText) message.
+	///
+	/// Note that, by default, a rich edit control is limited to 32,767
+	/// characters.
+	pub fn limit_text(&self, max_chars: Option<u32>) {
+		self.hwnd().SendMessage(em::ExLimitText { max_chars });
+	}
+
+	/// Retrieves the paragraph format of the current selection by sending an
+	/// [`em::GetParaFormat`](crate::msg::em::GetParaFormat) message.
+	#[must_use]
+	pub fn para_format(&self) -> PARAFORMAT2 {
+		let mut fmt = PARAFORMAT2::default();
+		self.hwnd().SendMessage(em::GetParaFormat { format: &mut fmt });
+		fmt
+	}
+
+	/// Retrieves the whole content as RTF by calling
+	/// [`stream_out`](crate::gui::RichEdit::stream_out).
+	#[must_use]
+	pub fn rtf(&self) -> String {
+		let mut buf = Vec::<u8>::default();
+		self.stream_out(co::SF::RTF, |chunk| {
+			buf.extend_from_slice(chunk);
+			Ok(())
+		}).unwrap(); // the closure never fails
+		String::from_utf8_lossy(&buf).into_owned()
+	}
+
+	/// Retrieves the current selection by sending an
+	/// [`em::ExGetSel`](crate::msg::em::ExGetSel) message.
+	#[must_use]
+	pub fn selection(&self) -> CHARRANGE {
+		let mut range = CHARRANGE::default();
+		self.hwnd().SendMessage(em::ExGetSel { range: &mut range });
+		range
+	}
+
+	/// Sets the background color by sending an
+	/// [`em::SetBkgndColor`](crate::msg::em::SetBkgndColor) message. If `None`,
+	/// the system color is used.
+	pub fn set_bkgnd_color(&self, color: Option<COLORREF>) {
+		self.hwnd().SendMessage(em::SetBkgndColor { color });
+	}
+
+	/// Sets the character format of the selection, the word or the whole text
+	/// by sending an [`em::SetCharFormat`](crate::msg::em::SetCharFormat)
+	/// message.
+	pub fn set_char_format(&self, range: co::SCF, format: &CHARFORMAT2) {
+		self.hwnd()
+			.SendMessage(em::SetCharFormat { range, format })
+			.unwrap();
+	}
+
+	/// Sets the paragraph format of the current selection by sending an
+	/// [`em::SetParaFormat`](crate::msg::em::SetParaFormat) message.
+	pub fn set_para_format(&self, format: &PARAFORMAT2) {
+		self.hwnd()
+			.SendMessage(em::SetParaFormat { format })
+			.unwrap();
+	}
+
+	/// Replaces the whole content with the given RTF by calling
+	/// [`stream_in`](crate::gui::RichEdit::stream_in).
+	pub fn set_rtf(&self, rtf: &str) {
+		let mut remaining = rtf.as_bytes();
+		self.stream_in(co::SF::RTF, |buf| {
+			let num_read = remaining.len().min(buf.len());
+			buf[..num_read].copy_from_slice(&remaining[..num_read]);
+			remaining = &remaining[num_read..];
+			Ok(num_read)
+		}).unwrap(); // the closure never fails
+	}
+
+	/// Sets the selection by sending an
+	/// [`em::ExSetSel`](crate::msg::em::ExSetSel) message.
+	///
+	/// A range from `0` to `-1` selects all text; a range from `-1` to `-1`
+	/// places the caret at the end.
+	pub fn set_selection(&self, range: CHARRANGE) {
+		self.hwnd().SendMessage(em::ExSetSel { range: &range });
+	}
+
+	/// Replaces the content – or the selection, if `format` has
+	/// [`SF::SELECTION`](crate::co::SF::SELECTION) – with data provided by the
+	/// given closure, by sending an
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) message.
+	///
+	/// The closure receives a buffer to be filled, and must return how many
+	/// bytes were written. Returning zero ends the operation. If the closure
+	/// returns an error, the operation is stopped and the error is returned.
+	///
+	/// Returns the number of characters read.
+	///
+	/// # Examples
+	///
+	/// Loading an RTF file:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui};
+	/// use std::io::Read;
+	///
+	/// let my_rich: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_rich = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut file = std::fs::File::open("C:\\Temp\\doc.rtf")?;
+	///
+	/// my_rich.stream_in(co::SF::RTF, |buf| Ok(file.read(buf)?))?;
+	/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+	/// ```
+	pub fn stream_in<F>(&self, format: co::SF, mut func: F) -> AnyResult<u32>
+		where F: FnMut(&mut [u8]) -> AnyResult<usize>,
+	{
+		let mut state = StreamInState { func: &mut func, err: None };
+		let mut es = EDITSTREAM::default();
+		es.dwCookie = &mut state as *mut _ as _;
+		es.pfnCallback = Some(stream_in_proc);
+
+		let num_read = self.hwnd()
+			.SendMessage(em::StreamIn { format, stream: &mut es });
+
+		match state.err {
+			Some(err) => Err(err),
+			None => Ok(num_read),
+		}
+	}
+
+	/// Retrieves the content – or the selection, if `format` has
+	/// [`SF::SELECTION`](crate::co::SF::SELECTION) – by sending an
+	/// [`em::StreamOut`](crate::msg::em::StreamOut) message, which calls the
+	/// given closure with successive chunks of data.
+	///
+	/// If the closure returns an error, the operation is stopped and the error
+	/// is returned.
+	///
+	/// Returns the number of characters written.
+	///
+	/// # Examples
+	///
+	/// Saving the plain text as UTF-16:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui};
+	/// use std::io::Write;
+	///
+	/// let my_rich: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_rich = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut file = std::fs::File::create("C:\\Temp\\doc.txt")?;
+	///
+	/// my_rich.stream_out(co::SF::TEXT | co::SF::UNICODE,
+	///     |chunk| Ok(file.write_all(chunk)?))?;
+	/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+	/// ```
+	pub fn stream_out<F>(&self, format: co::SF, mut func: F) -> AnyResult<u32>
+		where F: FnMut(&[u8]) -> AnyResult<()>,
+	{
+		let mut state = StreamOutState { func: &mut func, err: None };
+		let mut es = EDITSTREAM::default();
+		es.dwCookie = &mut state as *mut _ as _;
+		es.pfnCallback = Some(stream_out_proc);
+
+		let num_written = self.hwnd()
+			.SendMessage(em::StreamOut { format, stream: &mut es });
+
+		match state.err {
+			Some(err) => Err(err),
+			None => Ok(num_written),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct StreamInState<'a> {
+	func: &'a mut dyn FnMut(&mut [u8]) -> AnyResult<usize>,
+	err: Option<Box<dyn Error + Send + Sync>>,
+}
+
+struct StreamOutState<'a> {
+	func: &'a mut dyn FnMut(&[u8]) -> AnyResult<()>,
+	err: Option<Box<dyn Error + Send + Sync>>,
+}
+
+extern "system" fn stream_in_proc(
+	cookie: usize, buf: *mut u8, cb: i32, pcb: *mut i32) -> u32
+{
+	let state = unsafe { &mut *(cookie as *mut StreamInState) };
+	let buf = unsafe { std::slice::from_raw_parts_mut(buf, cb as _) };
+	match (state.func)(buf) {
+		Ok(num_read) => {
+			unsafe { *pcb = num_read.min(buf.len()) as _; }
+			0
+		},
+		Err(err) => {
+			state.err = Some(err);
+			1 // nonzero stops the operation
+		},
+	}
+}
+
+extern "system" fn stream_out_proc(
+	cookie: usize, buf: *mut u8, cb: i32, pcb: *mut i32) -> u32
+{
+	let state = unsafe { &mut *(cookie as *mut StreamOutState) };
+	let buf = unsafe { std::slice::from_raw_parts(buf, cb as _) };
+	match (state.func)(buf) {
+		Ok(_) => {
+			unsafe { *pcb = cb; }
+			0
+		},
+		Err(err) => {
+			state.err = Some(err);
+			1 // nonzero stops the operation
+		},
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`RichEdit`](crate::gui::RichEdit) programmatically
+/// with [`RichEdit::new`](crate::gui::RichEdit::new).
+pub struct RichEditOpts {
+	/// Text of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub text: String,
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(200, 100)`.
+	pub size: (u32, u32),
+	/// Edit styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `ES::MULTILINE | ES::WANTRETURN | ES::AUTOVSCROLL | ES::NOHIDESEL`.
+	///
+	/// Suggestions:
+	/// * add `ES::READONLY` for a log viewer.
+	pub edit_style: co::ES,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP | WS::GROUP | WS::VSCROLL`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT | WS_EX::CLIENTEDGE`.
+	pub window_ex_style: co::WS_EX,
+	/// Notifications to be sent by the control, set with
+	/// [`em::SetEventMask`](crate::msg::em::SetEventMask).
+	///
+	/// Defaults to `ENM::CHANGE | ENM::SELCHANGE | ENM::LINK`.
+	pub event_mask: co::ENM,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal behavior when the parent is resized.
+	///
+	/// Defaults to `Horz::None`.
+	pub horz_resize: Horz,
+	/// Vertical behavior when the parent is resized.
+	///
+	/// Defaults to `Vert::None`.
+	pub vert_resize: Vert,
+}
+
+impl Default for RichEditOpts {
+	fn default() -> Self {
+		Self {
+			text: "".to_owned(),
+			position: (0, 0),
+			size: (200, 100),
+			edit_style: co::ES::MULTILINE | co::ES::WANTRETURN | co::ES::AUTOVSCROLL | co::ES::NOHIDESEL,
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP | co::WS::GROUP | co::WS::VSCROLL,
+			window_ex_style: co::WS_EX::LEFT | co::WS_EX::CLIENTEDGE,
+			event_mask: co::ENM::CHANGE | co::ENM::SELCHANGE | co::ENM::LINK,
+			ctrl_id: 0,
+			horz_resize: Horz::None,
+			vert_resize: Vert::None,
+		}
+	}
+}
+
+impl RichEditOpts {
+	fn define_ctrl_id(mut self) -> Self {
+		if self.ctrl_id == 0 {
+			self.ctrl_id = auto_ctrl_id();
+		}
+		self
+	}
+}
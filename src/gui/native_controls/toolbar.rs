@@ -0,0 +1,347 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::decl::{HIMAGELIST, TBBUTTON};
+use crate::gui::base::Base;
+use crate::gui::events::{ToolbarEvents, WindowEvents};
+use crate::gui::layout_arranger::{Horz, Vert};
+use crate::gui::native_controls::base_native_control::{
+	BaseNativeControl, OptsId,
+};
+use crate::gui::native_controls::toolbar_items::ToolbarItems;
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu};
+use crate::kernel::decl::SysResult;
+use crate::msg::tbm;
+use crate::prelude::{
+	GuiChild, GuiEvents, GuiNativeControl, GuiNativeControlEvents, GuiParent,
+	GuiWindow, Handle, user_Hwnd,
+};
+use crate::user::decl::{HWND, POINT, SIZE};
+
+struct Obj { // actual fields of Toolbar
+	base: BaseNativeControl,
+	opts_id: OptsId<ToolbarOpts>,
+	events: ToolbarEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [toolbar](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-controls-overview)
+/// control.
+///
+/// By default, the toolbar is docked at the top of the parent window, and it is
+/// automatically resized when the parent is resized.
+#[derive(Clone)]
+pub struct Toolbar(Pin<Arc<Obj>>);
+
+unsafe impl Send for Toolbar {}
+
+impl GuiWindow for Toolbar {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Toolbar {
+	fn ctrl_id(&self) -> u16 {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => opts.ctrl_id,
+			OptsId::Dlg(ctrl_id) => *ctrl_id,
+		}
+	}
+}
+
+impl GuiNativeControl for Toolbar {
+	fn on_subclass(&self) -> &WindowEvents {
+		self.0.base.on_subclass()
+	}
+}
+
+impl GuiNativeControlEvents<ToolbarEvents> for Toolbar {
+	fn on(&self) -> &ToolbarEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Toolbar {
+	/// Instantiates a new `Toolbar` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Toolbar` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::gui;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	///
+	/// let toolbar2 = toolbar.clone(); // to pass into the closure
+	/// wnd.on().wm_create(move |_| {
+	///     toolbar2.items().add(1001, "Open", None);
+	///     toolbar2.items().add_separator();
+	///     toolbar2.items().add_dropdown(1002, "Export", None, false);
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: ToolbarOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let opts = ToolbarOpts::define_ctrl_id(opts);
+		let (ctrl_id, horz, vert) = (opts.ctrl_id, opts.horz_resize, opts.vert_resize);
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Wnd(opts),
+					events: ToolbarEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.create(horz, vert)?;
+			Ok(None) // not meaningful
+		});
+
+		new_self.add_resize_handler(parent_ref);
+		new_self
+	}
+
+	/// Instantiates a new `Toolbar` object, to be loaded from a dialog resource
+	/// with [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `Toolbar` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Dlg(ctrl_id),
+					events: ToolbarEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_init_dialog(move |_| {
+			self2.create(resize_behavior.0, resize_behavior.1)?;
+			Ok(true) // not meaningful
+		});
+
+		new_self.add_resize_handler(parent_ref);
+		new_self
+	}
+
+	fn create(&self, horz: Horz, vert: Vert) -> SysResult<()> {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				self.0.base.create_window( // may panic
+					"ToolbarWindow32", None, pos, sz,
+					opts.ctrl_id,
+					opts.window_ex_style,
+					opts.window_style | opts.toolbar_style.into()
+						| opts.common_style.into(),
+				)?;
+
+				self.hwnd().SendMessage(tbm::ButtonStructSize {
+					size: std::mem::size_of::<TBBUTTON>() as _,
+				});
+
+				if opts.toolbar_ex_style != co::TBSTYLE_EX::NoValue {
+					self.set_extended_style(opts.toolbar_ex_style);
+				}
+			},
+			OptsId::Dlg(ctrl_id) => {
+				self.0.base.create_dlg(*ctrl_id)?;
+
+				self.hwnd().SendMessage(tbm::ButtonStructSize {
+					size: std::mem::size_of::<TBBUTTON>() as _,
+				});
+			},
+		}
+
+		self.hwnd().SendMessage(tbm::AutoSize {});
+		self.0.base.parent().add_to_layout_arranger(self.hwnd(), horz, vert)
+	}
+
+	fn add_resize_handler(&self, parent_ref: &Base) {
+		let self2 = self.clone();
+		parent_ref.privileged_on().wm_size(move |p| {
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				self2.hwnd().SendMessage(tbm::AutoSize {}); // toolbar resizes itself to fit parent
+			}
+			Ok(())
+		});
+	}
+
+	/// Retrieves the image list used to display the buttons by sending a
+	/// [`tbm::GetImageList`](crate::msg::tbm::GetImageList) message.
+	#[must_use]
+	pub fn image_list(&self) -> Option<HIMAGELIST> {
+		self.hwnd().SendMessage(tbm::GetImageList {})
+	}
+
+	/// Exposes the item methods.
+	#[must_use]
+	pub const fn items(&self) -> ToolbarItems {
+		ToolbarItems::new(self)
+	}
+
+	/// Sets the extended toolbar styles by sending a
+	/// [`tbm::SetExtendedStyle`](crate::msg::tbm::SetExtendedStyle) message.
+	/// Returns the previous styles.
+	pub fn set_extended_style(&self, style: co::TBSTYLE_EX) -> co::TBSTYLE_EX {
+		self.hwnd().SendMessage(tbm::SetExtendedStyle { style })
+	}
+
+	/// Sets the image list used to display the buttons by sending a
+	/// [`tbm::SetImageList`](crate::msg::tbm::SetImageList) message. Returns
+	/// the previous image list, if any.
+	///
+	/// The image list is not owned by the toolbar, so it must be destroyed by
+	/// you, after the toolbar is destroyed.
+	pub fn set_image_list(&self, himagelist: &HIMAGELIST) -> Option<HIMAGELIST> {
+		let prev = self.hwnd().SendMessage(tbm::SetImageList { himagelist });
+		self.hwnd().SendMessage(tbm::AutoSize {});
+		prev
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Toolbar`](crate::gui::Toolbar) programmatically with
+/// [`Toolbar::new`](crate::gui::Toolbar::new).
+pub struct ToolbarOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Only meaningful with `CCS::NOPARENTALIGN`, otherwise the toolbar is
+	/// positioned by the system.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Only meaningful with `CCS::NORESIZE`, otherwise the toolbar is sized by
+	/// the system.
+	///
+	/// Defaults to `(0, 0)`.
+	pub size: (u32, u32),
+	/// Toolbar styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `TBSTYLE::FLAT | TBSTYLE::TOOLTIPS`.
+	pub toolbar_style: co::TBSTYLE,
+	/// Common control styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `CCS::TOP`.
+	pub common_style: co::CCS,
+	/// Extended toolbar styles to be
+	/// [set](https://learn.microsoft.com/en-us/windows/win32/controls/tb-setextendedstyle).
+	///
+	/// Defaults to `TBSTYLE_EX::DRAWDDARROWS`.
+	pub toolbar_ex_style: co::TBSTYLE_EX,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal behavior when the parent is resized.
+	///
+	/// Defaults to `Horz::None`.
+	pub horz_resize: Horz,
+	/// Vertical behavior when the parent is resized.
+	///
+	/// Defaults to `Vert::None`.
+	pub vert_resize: Vert,
+}
+
+impl Default for ToolbarOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (0, 0),
+			toolbar_style: co::TBSTYLE::FLAT | co::TBSTYLE::TOOLTIPS,
+			common_style: co::CCS::TOP,
+			toolbar_ex_style: co::TBSTYLE_EX::DRAWDDARROWS,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			horz_resize: Horz::None,
+			vert_resize: Vert::None,
+		}
+	}
+}
+
+impl ToolbarOpts {
+	fn define_ctrl_id(mut self) -> Self {
+		if self.ctrl_id == 0 {
+			self.ctrl_id = auto_ctrl_id();
+		}
+		self
+	}
+}
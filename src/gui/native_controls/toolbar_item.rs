@@ -0,0 +1,203 @@
+use crate::co;
+use crate::comctl::decl::TBBUTTONINFO;
+use crate::gui::native_controls::toolbar::Toolbar;
+use crate::kernel::decl::WString;
+use crate::msg::tbm;
+use crate::prelude::{GuiWindow, user_Hwnd};
+use crate::user::decl::RECT;
+
+/// A single button of a [`Toolbar`](crate::gui::Toolbar) control.
+///
+/// **Note:** Each object keeps the command ID of a button, which is not
+/// affected when other buttons are added/removed from the toolbar.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+#[derive(Clone, Copy)]
+pub struct ToolbarItem<'a> {
+	owner: &'a Toolbar,
+	cmd_id: u16,
+}
+
+impl<'a> ToolbarItem<'a> {
+	pub(in crate::gui) const fn new(owner: &'a Toolbar, cmd_id: u16) -> Self {
+		Self { owner, cmd_id }
+	}
+
+	/// Returns the command ID of the button.
+	#[must_use]
+	pub const fn cmd_id(&self) -> u16 {
+		self.cmd_id
+	}
+
+	/// Sets or clears the checked state of the button by sending a
+	/// [`tbm::CheckButton`](crate::msg::tbm::CheckButton) message.
+	pub fn check(&self, check: bool) {
+		self.owner.hwnd()
+			.SendMessage(tbm::CheckButton { btn_cmd_id: self.cmd_id, check })
+			.unwrap();
+	}
+
+	/// Deletes the button by sending a
+	/// [`tbm::DeleteButton`](crate::msg::tbm::DeleteButton) message.
+	pub fn delete(&self) {
+		if let Some(btn_index) = self.index() {
+			self.owner.hwnd()
+				.SendMessage(tbm::DeleteButton { btn_index })
+				.unwrap();
+			self.owner.hwnd().SendMessage(tbm::AutoSize {});
+		}
+	}
+
+	/// Retrieves the bounding rectangle of the dropdown arrow by sending a
+	/// [`tbm::GetItemDropdownRect`](crate::msg::tbm::GetItemDropdownRect)
+	/// message.
+	///
+	/// The coordinates are relative to the toolbar client area.
+	#[must_use]
+	pub fn dropdown_rect(&self) -> RECT {
+		let mut rect = RECT::default();
+		if let Some(item_index) = self.index() {
+			self.owner.hwnd()
+				.SendMessage(tbm::GetItemDropdownRect { item_index, rect: &mut rect });
+		}
+		rect
+	}
+
+	/// Enables or disables the button by sending a
+	/// [`tbm::EnableButton`](crate::msg::tbm::EnableButton) message.
+	pub fn enable(&self, enable: bool) {
+		self.owner.hwnd()
+			.SendMessage(tbm::EnableButton { btn_cmd_id: self.cmd_id, enable })
+			.unwrap();
+	}
+
+	/// Hides or shows the button by sending a
+	/// [`tbm::HideButton`](crate::msg::tbm::HideButton) message.
+	pub fn hide(&self, hide: bool) {
+		self.owner.hwnd()
+			.SendMessage(tbm::HideButton { cmd_id: self.cmd_id, hide })
+			.unwrap();
+		self.owner.hwnd().SendMessage(tbm::AutoSize {});
+	}
+
+	/// Retrieves the current zero-based index of the button by sending a
+	/// [`tbm::CommandToIndex`](crate::msg::tbm::CommandToIndex) message.
+	///
+	/// Returns `None` if the command ID doesn't belong to any button.
+	#[must_use]
+	pub fn index(&self) -> Option<u32> {
+		self.owner.hwnd()
+			.SendMessage(tbm::CommandToIndex { btn_cmd_id: self.cmd_id })
+	}
+
+	/// Tells whether the button is checked by sending a
+	/// [`tbm::IsButtonChecked`](crate::msg::tbm::IsButtonChecked) message.
+	#[must_use]
+	pub fn is_checked(&self) -> bool {
+		self.owner.hwnd()
+			.SendMessage(tbm::IsButtonChecked { cmd_id: self.cmd_id })
+	}
+
+	/// Tells whether the button is enabled by sending a
+	/// [`tbm::IsButtonEnabled`](crate::msg::tbm::IsButtonEnabled) message.
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		self.owner.hwnd()
+			.SendMessage(tbm::IsButtonEnabled { cmd_id: self.cmd_id })
+	}
+
+	/// Retrieves the user-defined value by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn lparam(&self) -> usize {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::LPARAM;
+
+		self.owner.hwnd()
+			.SendMessage(tbm::GetButtonInfo {
+				btn_cmd_id: self.cmd_id,
+				info: &mut tbbi,
+			})
+			.unwrap();
+		tbbi.lParam
+	}
+
+	/// Retrieves the bounding rectangle of the button by sending a
+	/// [`tbm::GetRect`](crate::msg::tbm::GetRect) message.
+	///
+	/// The coordinates are relative to the toolbar client area.
+	#[must_use]
+	pub fn rect(&self) -> RECT {
+		let mut rect = RECT::default();
+		self.owner.hwnd()
+			.SendMessage(tbm::GetRect { cmd_id: self.cmd_id, rect: &mut rect })
+			.unwrap();
+		rect
+	}
+
+	/// Sets the zero-based index of the image in the image list by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	pub fn set_icon_index(&self, icon_index: u32) {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::IMAGE;
+		tbbi.iImage = icon_index as _;
+
+		self.owner.hwnd()
+			.SendMessage(tbm::SetButtonInfo {
+				btn_cmd_id: self.cmd_id,
+				info: &tbbi,
+			})
+			.unwrap();
+	}
+
+	/// Sets the user-defined value by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	pub fn set_lparam(&self, lparam: usize) {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::LPARAM;
+		tbbi.lParam = lparam;
+
+		self.owner.hwnd()
+			.SendMessage(tbm::SetButtonInfo {
+				btn_cmd_id: self.cmd_id,
+				info: &tbbi,
+			})
+			.unwrap();
+	}
+
+	/// Sets the text by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	pub fn set_text(&self, text: &str) {
+		let mut wtext = WString::from_str(text);
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::TEXT;
+		tbbi.set_pszText(Some(&mut wtext));
+
+		self.owner.hwnd()
+			.SendMessage(tbm::SetButtonInfo {
+				btn_cmd_id: self.cmd_id,
+				info: &tbbi,
+			})
+			.unwrap();
+		self.owner.hwnd().SendMessage(tbm::AutoSize {});
+	}
+
+	/// Retrieves the text by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let mut buf = WString::new_alloc_buf(128); // arbitrary
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::TEXT;
+		tbbi.set_pszText(Some(&mut buf));
+
+		self.owner.hwnd()
+			.SendMessage(tbm::GetButtonInfo {
+				btn_cmd_id: self.cmd_id,
+				info: &mut tbbi,
+			})
+			.unwrap();
+		buf.to_string()
+	}
+}
@@ -0,0 +1,142 @@
+use crate::co;
+use crate::comctl::decl::{IdxStr, TBBUTTON};
+use crate::comctl::privs::I_IMAGENONE;
+use crate::gui::native_controls::toolbar_item::ToolbarItem;
+use crate::gui::native_controls::toolbar::Toolbar;
+use crate::kernel::decl::WString;
+use crate::msg::tbm;
+use crate::prelude::{GuiWindow, user_Hwnd};
+
+/// Exposes the item methods of a [`Toolbar`](crate::gui::Toolbar) control.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct ToolbarItems<'a> {
+	owner: &'a Toolbar,
+}
+
+impl<'a> ToolbarItems<'a> {
+	pub(in crate::gui) const fn new(owner: &'a Toolbar) -> Self {
+		Self { owner }
+	}
+
+	/// Appends a new button by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message, and returns
+	/// the newly added item.
+	///
+	/// The `icon_index` is the zero-based index of the image in the image list
+	/// set with [`Toolbar::set_image_list`](crate::gui::Toolbar::set_image_list).
+	/// If `None`, the button will have no image.
+	pub fn add(&self,
+		cmd_id: u16, text: &str, icon_index: Option<u32>) -> ToolbarItem<'a>
+	{
+		self.add_button(cmd_id, text, icon_index, co::BTNS::BUTTON)
+	}
+
+	/// Appends a new check button, which toggles between the pressed and
+	/// non-pressed states each time it's clicked, by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message. Returns the
+	/// newly added item.
+	pub fn add_check(&self,
+		cmd_id: u16, text: &str, icon_index: Option<u32>) -> ToolbarItem<'a>
+	{
+		self.add_button(cmd_id, text, icon_index, co::BTNS::CHECK)
+	}
+
+	/// Appends a new dropdown button by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message, and returns
+	/// the newly added item.
+	///
+	/// If `whole` is true, the entire button will fire the
+	/// [`tbn_drop_down`](crate::gui::events::ToolbarEvents::tbn_drop_down)
+	/// event; otherwise only the arrow will.
+	pub fn add_dropdown(&self,
+		cmd_id: u16, text: &str, icon_index: Option<u32>, whole: bool) -> ToolbarItem<'a>
+	{
+		self.add_button(cmd_id, text, icon_index,
+			if whole { co::BTNS::WHOLEDROPDOWN } else { co::BTNS::DROPDOWN })
+	}
+
+	/// Appends a new separator by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message.
+	pub fn add_separator(&self) {
+		let mut tbb = TBBUTTON::default();
+		tbb.fsStyle = co::BTNS::SEP;
+
+		self.owner.hwnd()
+			.SendMessage(tbm::AddButtons { buttons: &mut [tbb] })
+			.unwrap();
+		self.owner.hwnd().SendMessage(tbm::AutoSize {});
+	}
+
+	fn add_button(&self,
+		cmd_id: u16, text: &str, icon_index: Option<u32>, style: co::BTNS) -> ToolbarItem<'a>
+	{
+		let mut wtext = IdxStr::Str(WString::from_str(text));
+		let mut tbb = TBBUTTON::default();
+		tbb.iBitmap = icon_index.map_or(I_IMAGENONE as _, |idx| idx as _);
+		tbb.idCommand = cmd_id as _;
+		tbb.fsState = co::TBSTATE::ENABLED;
+		tbb.fsStyle = style | co::BTNS::AUTOSIZE;
+		tbb.set_iString(&mut wtext);
+
+		self.owner.hwnd()
+			.SendMessage(tbm::AddButtons { buttons: &mut [tbb] })
+			.unwrap();
+		self.owner.hwnd().SendMessage(tbm::AutoSize {});
+		self.get(cmd_id)
+	}
+
+	/// Retrieves the total number of items, including separators, by sending
+	/// a [`tbm::ButtonCount`](crate::msg::tbm::ButtonCount) message.
+	#[must_use]
+	pub fn count(&self) -> u32 {
+		self.owner.hwnd().SendMessage(tbm::ButtonCount {})
+	}
+
+	/// Deletes all items, including separators, by sending
+	/// [`tbm::DeleteButton`](crate::msg::tbm::DeleteButton) messages.
+	pub fn delete_all(&self) {
+		for _ in 0..self.count() {
+			self.owner.hwnd()
+				.SendMessage(tbm::DeleteButton { btn_index: 0 })
+				.unwrap();
+		}
+		self.owner.hwnd().SendMessage(tbm::AutoSize {});
+	}
+
+	/// Retrieves the item with the given command ID.
+	///
+	/// **Note:** This method is cheap – even if `cmd_id` doesn't belong to any
+	/// existing item, an object will still be returned. However, operations
+	/// upon this object will fail.
+	#[must_use]
+	pub const fn get(&self, cmd_id: u16) -> ToolbarItem<'a> {
+		ToolbarItem::new(self.owner, cmd_id)
+	}
+
+	/// Retrieves the hot item, if any, by sending a
+	/// [`tbm::GetHotItem`](crate::msg::tbm::GetHotItem) message.
+	#[must_use]
+	pub fn hot(&self) -> Option<ToolbarItem<'a>> {
+		self.owner.hwnd()
+			.SendMessage(tbm::GetHotItem {})
+			.and_then(|btn_index| {
+				let mut tbb = TBBUTTON::default();
+				self.owner.hwnd()
+					.SendMessage(tbm::GetButton { btn_index, info: &mut tbb })
+					.ok()
+					.map(|_| self.get(tbb.idCommand as _))
+			})
+	}
+
+	/// Sets the hot item by sending a
+	/// [`tbm::SetHotItem`](crate::msg::tbm::SetHotItem) message. If `None`,
+	/// the hot item is removed.
+	pub fn set_hot(&self, item: Option<&ToolbarItem>) {
+		self.owner.hwnd()
+			.SendMessage(tbm::SetHotItem {
+				index: item.and_then(|item| item.index()),
+			});
+	}
+}
@@ -0,0 +1,308 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::decl::TTTOOLINFO;
+use crate::gui::base::Base;
+use crate::gui::events::TooltipEvents;
+use crate::gui::native_controls::base_native_control::BaseNativeControl;
+use crate::kernel::decl::{SysResult, WString};
+use crate::msg::{ttm, wm};
+use crate::prelude::{
+	GuiChild, GuiEvents, GuiNativeControlEvents, GuiParent, GuiWindow, Handle,
+	MsgSendRecv, user_Hwnd,
+};
+use crate::user::decl::{HWND, POINT, SIZE};
+
+struct Obj { // actual fields of Tooltip
+	base: BaseNativeControl,
+	opts: TooltipOpts,
+	events: TooltipEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [tooltip](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-controls)
+/// control.
+///
+/// A single tooltip can display the tips of many controls of the same parent
+/// window, which are added as tools after the controls are created. Each tool
+/// is identified by its control handle.
+///
+/// The tooltip is a popup window owned by the parent, therefore it doesn't
+/// have a control ID.
+#[derive(Clone)]
+pub struct Tooltip(Pin<Arc<Obj>>);
+
+unsafe impl Send for Tooltip {}
+
+impl GuiWindow for Tooltip {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiNativeControlEvents<TooltipEvents> for Tooltip {
+	fn on(&self) -> &TooltipEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Tooltip {
+	/// Instantiates a new `Tooltip` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Tooltip` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::gui;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// let btn: gui::Button;
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// let tooltip = gui::Tooltip::new(&wnd, gui::TooltipOpts::default());
+	///
+	/// let btn2 = btn.clone(); // to pass into the closure
+	/// let tooltip2 = tooltip.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     btn2.set_tooltip_text(&tooltip2, "Click me")?;
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: TooltipOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts,
+					events: TooltipEvents::new(),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.create()?;
+			Ok(None) // not meaningful
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(co::WM::NOTIFY, move |p| {
+			let wm_nfy = wm::Notify::from_generic_wm(p);
+			if *self2.hwnd() != HWND::NULL && wm_nfy.nmhdr.hwndFrom == *self2.hwnd() {
+				self2.0.events.process(wm_nfy)?;
+			}
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn create(&self) -> SysResult<()> {
+		let opts = &self.0.opts;
+
+		self.0.base.create_window( // may panic
+			"tooltips_class32", None,
+			POINT::default(), SIZE::default(),
+			0,
+			opts.window_ex_style,
+			opts.window_style | opts.tooltip_style.into(),
+		)?;
+
+		if opts.max_tip_width.is_some() {
+			self.set_max_tip_width(opts.max_tip_width);
+		}
+		Ok(())
+	}
+
+	fn tool_info<'a>(&self, hctrl: &HWND) -> TTTOOLINFO<'a> {
+		let mut ti = TTTOOLINFO::default();
+		ti.uFlags = co::TTF::IDISHWND | co::TTF::SUBCLASS;
+		ti.hwnd = unsafe { self.0.base.parent().hwnd().raw_copy() };
+		ti.uId = hctrl.as_ptr() as _;
+		ti
+	}
+
+	/// Enables or disables the tooltip by sending a
+	/// [`ttm::Activate`](crate::msg::ttm::Activate) message.
+	pub fn activate(&self, activate: bool) {
+		self.hwnd().SendMessage(ttm::Activate { activate });
+	}
+
+	/// Adds the given control as a tool by sending a
+	/// [`ttm::AddTool`](crate::msg::ttm::AddTool) message.
+	///
+	/// If `text` is `None`, the text will be retrieved on demand through the
+	/// [`ttn_get_disp_info`](crate::gui::events::TooltipEvents::ttn_get_disp_info)
+	/// event.
+	pub fn add_tool(&self, ctrl: &impl GuiChild, text: Option<&str>) -> SysResult<()> {
+		self.add_tool_hwnd(ctrl.hwnd(), text)
+	}
+
+	fn add_tool_hwnd(&self, hctrl: &HWND, text: Option<&str>) -> SysResult<()> {
+		let mut text16 = WString::from_opt_str(text);
+		let mut ti = self.tool_info(hctrl);
+		match text {
+			Some(_) => ti.set_lpszText(Some(&mut text16)),
+			None => ti.set_lpszText_callback(),
+		}
+		self.hwnd().SendMessage(ttm::AddTool { info: &ti })
+	}
+
+	/// Removes the tool of the given control by sending a
+	/// [`ttm::DelTool`](crate::msg::ttm::DelTool) message.
+	pub fn del_tool(&self, ctrl: &impl GuiChild) {
+		let ti = self.tool_info(ctrl.hwnd());
+		self.hwnd().SendMessage(ttm::DelTool { info: &ti });
+	}
+
+	/// Tells whether the given control was added as a tool, by sending a
+	/// [`ttm::GetToolInfo`](crate::msg::ttm::GetToolInfo) message.
+	#[must_use]
+	pub fn has_tool(&self, ctrl: &impl GuiChild) -> bool {
+		self.has_tool_hwnd(ctrl.hwnd())
+	}
+
+	fn has_tool_hwnd(&self, hctrl: &HWND) -> bool {
+		let mut ti = self.tool_info(hctrl);
+		self.hwnd().SendMessage(ttm::GetToolInfo { info: &mut ti }).is_ok()
+	}
+
+	/// Hides the tooltip, if visible, by sending a
+	/// [`ttm::Pop`](crate::msg::ttm::Pop) message.
+	pub fn pop(&self) {
+		self.hwnd().SendMessage(ttm::Pop {});
+	}
+
+	/// Sets the delay times, in milliseconds, by sending a
+	/// [`ttm::SetDelayTime`](crate::msg::ttm::SetDelayTime) message.
+	///
+	/// If `time_ms` is `None`, the default value is restored.
+	pub fn set_delay_time(&self, which: co::TTDT, time_ms: Option<u16>) {
+		self.hwnd().SendMessage(ttm::SetDelayTime { which, time_ms });
+	}
+
+	/// Sets the maximum width of the tooltip window, in pixels, by sending a
+	/// [`ttm::SetMaxTipWidth`](crate::msg::ttm::SetMaxTipWidth) message.
+	/// Returns the previous width.
+	///
+	/// Setting a maximum width allows multi-line tooltips, which break lines at
+	/// `\r\n`.
+	pub fn set_max_tip_width(&self, width: Option<u32>) -> Option<u32> {
+		self.hwnd().SendMessage(ttm::SetMaxTipWidth { width })
+	}
+
+	/// Sets the title and the icon by sending a
+	/// [`ttm::SetTitle`](crate::msg::ttm::SetTitle) message.
+	pub fn set_title(&self, icon: co::TTI, title: &str) -> SysResult<()> {
+		self.hwnd().SendMessage(ttm::SetTitle {
+			icon,
+			title: &WString::from_str(title),
+		})
+	}
+
+	/// Sets the text of the given control, which is added as a tool if it
+	/// wasn't already.
+	///
+	/// This is the method called by
+	/// [`GuiChild::set_tooltip_text`](crate::prelude::GuiChild::set_tooltip_text).
+	pub fn set_tool_text(&self, ctrl: &impl GuiChild, text: &str) -> SysResult<()> {
+		self.set_tool_text_hwnd(ctrl.hwnd(), text)
+	}
+
+	pub(in crate::gui) fn set_tool_text_hwnd(&self,
+		hctrl: &HWND, text: &str) -> SysResult<()>
+	{
+		if self.has_tool_hwnd(hctrl) {
+			self.update_tip_text_hwnd(hctrl, text);
+			Ok(())
+		} else {
+			self.add_tool_hwnd(hctrl, Some(text))
+		}
+	}
+
+	/// Returns the number of tools by sending a
+	/// [`ttm::GetToolCount`](crate::msg::ttm::GetToolCount) message.
+	#[must_use]
+	pub fn tool_count(&self) -> u32 {
+		self.hwnd().SendMessage(ttm::GetToolCount {})
+	}
+
+	/// Updates the text of the tool of the given control by sending a
+	/// [`ttm::UpdateTipText`](crate::msg::ttm::UpdateTipText) message.
+	pub fn update_tip_text(&self, ctrl: &impl GuiChild, text: &str) {
+		self.update_tip_text_hwnd(ctrl.hwnd(), text)
+	}
+
+	fn update_tip_text_hwnd(&self, hctrl: &HWND, text: &str) {
+		let mut text16 = WString::from_str(text);
+		let mut ti = self.tool_info(hctrl);
+		ti.set_lpszText(Some(&mut text16));
+		self.hwnd().SendMessage(ttm::UpdateTipText { info: &ti });
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Tooltip`](crate::gui::Tooltip) programmatically with
+/// [`Tooltip::new`](crate::gui::Tooltip::new).
+pub struct TooltipOpts {
+	/// Tooltip styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `TTS::ALWAYSTIP | TTS::NOPREFIX`.
+	pub tooltip_style: co::TTS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::POPUP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::TOPMOST`.
+	pub window_ex_style: co::WS_EX,
+	/// Maximum width of the tooltip window, in pixels, to be
+	/// [set](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-setmaxtipwidth).
+	/// Allows multi-line tooltips.
+	///
+	/// Defaults to `None`.
+	pub max_tip_width: Option<u32>,
+}
+
+impl Default for TooltipOpts {
+	fn default() -> Self {
+		Self {
+			tooltip_style: co::TTS::ALWAYSTIP | co::TTS::NOPREFIX,
+			window_style: co::WS::POPUP,
+			window_ex_style: co::WS_EX::TOPMOST,
+			max_tip_width: None,
+		}
+	}
+}
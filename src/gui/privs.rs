@@ -7,11 +7,11 @@ use crate::gdi::decl::{HFONT, NONCLIENTMETRICS};
 use crate::gdi::guard::DeleteObjectGuard;
 use crate::gui::base::Base;
 use crate::gui::msg_error::MsgError;
-use crate::kernel::decl::{AnyResult, MulDiv, SysResult};
+use crate::kernel::decl::{AnyResult, HINSTANCE, MulDiv, SysResult};
 use crate::msg::{wm, WndMsg};
 use crate::prelude::{
-	gdi_Hdc, gdi_Hfont, Handle, NativeBitflag, user_Hwnd, uxtheme_Htheme,
-	uxtheme_Hwnd,
+	gdi_Hdc, gdi_Hfont, Handle, kernel_Hinstance, NativeBitflag, user_Hwnd,
+	uxtheme_Htheme, uxtheme_Hwnd,
 };
 use crate::user::decl::{
 	GetSystemMetrics, HWND, POINT, PostQuitMessage, RECT, SIZE,
@@ -81,6 +81,22 @@ pub(in crate::gui) fn auto_ctrl_id() -> u16 {
 
 //------------------------------------------------------------------------------
 
+static mut MSFTEDIT_LOADED: bool = false;
+
+/// Loads Msftedit.dll, which registers the rich edit window class, if not
+/// loaded yet. The library is kept loaded until the process ends.
+pub(in crate::gui) fn load_msftedit() -> SysResult<()> {
+	unsafe {
+		if !MSFTEDIT_LOADED {
+			let _ = HINSTANCE::LoadLibrary("Msftedit.dll")?.leak();
+			MSFTEDIT_LOADED = true;
+		}
+	}
+	Ok(())
+}
+
+//------------------------------------------------------------------------------
+
 static mut DPI: POINT = POINT::new(0, 0);
 
 /// Multiplies the given coordinates by current system DPI.
@@ -1,3 +1,5 @@
+use std::future::Future;
+
 use crate::co;
 use crate::gui::base::Base;
 use crate::gui::events::{ProcessResult, WindowEventsAll};
@@ -10,8 +12,8 @@ use crate::prelude::{
 	gdi_Hbrush, Handle, MsgSendRecv, user_Hinstance, user_Hwnd,
 };
 use crate::user::decl::{
-	ATOM, AtomStr, HBRUSH, HCURSOR, HICON, HWND, IdIdcStr, IdIdiStr, IdMenu,
-	POINT, RegisterClassEx, SIZE, WNDCLASSEX,
+	ATOM, AtomStr, HACCEL, HBRUSH, HCURSOR, HICON, HWND, IdIdcStr, IdIdiStr,
+	IdMenu, POINT, RegisterClassEx, SIZE, WNDCLASSEX,
 };
 
 /// The class background brush to be loaded for
@@ -139,6 +141,10 @@ impl RawBase {
 		self.base.parent()
 	}
 
+	pub(in crate::gui) fn menu_accel_table(&self) -> Option<&HACCEL> {
+		self.base.menu_accel_table()
+	}
+
 	pub(in crate::gui) fn parent_hinstance(&self) -> SysResult<HINSTANCE> {
 		self.base.parent_hinstance()
 	}
@@ -259,6 +265,12 @@ impl RawBase {
 		self.base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.base.spawn_local(future);
+	}
+
 	extern "system" fn window_proc(
 		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
 	{
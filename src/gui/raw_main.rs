@@ -1,4 +1,5 @@
 use std::cell::UnsafeCell;
+use std::future::Future;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -69,6 +70,12 @@ impl RawMain {
 		self.0.raw_base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.0.raw_base.spawn_local(future);
+	}
+
 	pub(in crate::gui) fn run_main(&self,
 		cmd_show: Option<co::SW>) -> AnyResult<i32>
 	{
@@ -123,7 +130,9 @@ impl RawMain {
 		self.hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 		self.hwnd().UpdateWindow().unwrap();
 
-		Base::run_main_loop(opts.accel_table.as_deref()) // blocks until window is closed
+		Base::run_main_loop(
+			opts.accel_table.as_deref().or(self.0.raw_base.menu_accel_table()),
+		) // blocks until window is closed
 	}
 
 	fn default_message_handlers(&self) {
@@ -222,6 +231,9 @@ pub struct WindowMainOpts {
 	/// This menu is **not** shared: the window will own it, and destroy it when
 	/// the window is destroyed.
 	///
+	/// A menu with automatically bound commands can be built with
+	/// [`MenuBuilder`](crate::gui::MenuBuilder).
+	///
 	/// Defaults to none.
 	pub menu: HMENU,
 	/// Main accelerator table of the window to be
@@ -230,6 +242,10 @@ pub struct WindowMainOpts {
 	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable)
 	/// to create one.
 	///
+	/// If `None`, the accelerators of a
+	/// [`MenuBuilder`](crate::gui::MenuBuilder) attached to the window are
+	/// used.
+	///
 	/// Defaults to `None`.
 	pub accel_table: Option<DestroyAcceleratorTableGuard>,
 }
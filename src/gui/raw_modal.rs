@@ -1,4 +1,5 @@
 use std::cell::UnsafeCell;
+use std::future::Future;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -68,6 +69,12 @@ impl RawModal {
 		self.0.raw_base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.0.raw_base.spawn_local(future);
+	}
+
 	pub(in crate::gui) fn show_modal(&self) -> SysResult<i32> {
 		let hparent = self.0.raw_base.parent().unwrap().hwnd();
 		let opts = &self.0.opts;
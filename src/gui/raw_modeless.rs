@@ -1,3 +1,4 @@
+use std::future::Future;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -62,6 +63,12 @@ impl RawModeless {
 		self.0.raw_base.run_ui_thread(func);
 	}
 
+	pub(in crate::gui) fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		self.0.raw_base.spawn_local(future);
+	}
+
 	fn default_message_handlers(&self, parent: &Base) {
 		let self2 = self.clone();
 		self.0.raw_base.parent().unwrap().privileged_on().wm(parent.creation_msg(), move |_| {
@@ -0,0 +1,300 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::TrayIconEvents;
+use crate::gui::privs::auto_ctrl_id;
+use crate::gui::raw_base::Icon;
+use crate::kernel::decl::{HIWORD, LOWORD, SysResult};
+use crate::msg::wm;
+use crate::prelude::{GuiEvents, GuiParent, Handle, user_Hmenu, user_Hwnd};
+use crate::shell::decl::{NOTIFYICONDATA, Shell_NotifyIcon};
+use crate::shell::privs::NOTIFYICON_VERSION_4;
+use crate::user::decl::{HICON, HMENU, HWND, POINT, RegisterWindowMessage};
+
+struct Obj { // actual fields of TrayIcon
+	parent_ptr: NonNull<Base>,
+	opts: TrayIconOpts,
+	nid: UnsafeCell<NOTIFYICONDATA>,
+	events: TrayIconEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// An icon in the
+/// [notification area](https://learn.microsoft.com/en-us/windows/win32/shell/notification-area)
+/// of the taskbar, also known as system tray, attached to a parent window.
+///
+/// The icon is added when the parent window is created, and removed when it is
+/// destroyed. If Windows Explorer restarts, the icon is automatically added
+/// again.
+///
+/// The parent should be a top-level window – a
+/// [`WindowMain`](crate::gui::WindowMain) or a
+/// [`WindowModeless`](crate::gui::WindowModeless) –, because only top-level
+/// windows are notified when Explorer restarts.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, AnyResult, POINT};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let tray = gui::TrayIcon::new(
+///     &wnd,
+///     gui::TrayIconOpts {
+///         tooltip: "My application".to_owned(),
+///         ..Default::default()
+///     },
+/// );
+///
+/// let tray2 = tray.clone(); // to pass into the closure
+///
+/// tray.on().nin_select(
+///     move |_: POINT| -> AnyResult<()> {
+///         tray2.show_balloon("Hello", "You clicked the icon.", winsafe::co::NIIF::INFO)?;
+///         Ok(())
+///     },
+/// );
+/// ```
+#[derive(Clone)]
+pub struct TrayIcon(Pin<Arc<Obj>>);
+
+unsafe impl Send for TrayIcon {}
+
+impl TrayIcon {
+	const WM_TRAY_ICON: co::WM = co::WM(co::WM::APP.0 + 0x3ffe);
+
+	/// Instantiates a new `TrayIcon` object, to be added to the notification
+	/// area with [`Shell_NotifyIcon`](crate::Shell_NotifyIcon) when the parent
+	/// window is created.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `TrayIcon` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: TrayIconOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		if *parent_ref.hwnd() != HWND::NULL {
+			panic!("Cannot create a tray icon after the parent window is created.");
+		}
+		let opts = TrayIconOpts::define_id(opts);
+
+		let mut nid = NOTIFYICONDATA::default();
+		nid.uID = opts.id as _;
+		nid.uFlags = co::NIF::MESSAGE | co::NIF::ICON | co::NIF::TIP | co::NIF::SHOWTIP;
+		nid.uCallbackMessage = Self::WM_TRAY_ICON;
+		nid.set_szTip(&opts.tooltip);
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					nid: UnsafeCell::new(nid),
+					events: TrayIconEvents::new(),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers(parent_ref);
+		new_self
+	}
+
+	fn default_message_handlers(&self, parent_ref: &Base) {
+		// An error here is returned when the parent window is created.
+		let wm_taskbar_created = RegisterWindowMessage("TaskbarCreated");
+
+		let self2 = self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			wm_taskbar_created?;
+			let nid = unsafe { &mut *self2.0.nid.get() };
+			nid.hWnd = unsafe { self2.0.parent_ptr.as_ref().hwnd().raw_copy() };
+			nid.hIcon = self2.0.opts.icon.as_hicon(&nid.hWnd.hinstance())?;
+			self2.add()?;
+			Ok(None) // not meaningful
+		});
+
+		let self2 = self.clone();
+		parent_ref.privileged_on().wm_destroy(move || {
+			let nid = unsafe { &mut *self2.0.nid.get() };
+			let _ = Shell_NotifyIcon(co::NIM::DELETE, nid); // icon may be already gone
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		parent_ref.privileged_on().wm(Self::WM_TRAY_ICON, move |p| {
+			if HIWORD(p.lparam as _) as u32 == self2.nid().uID { // other icons may share the same parent
+				let pos = POINT::new(
+					LOWORD(p.wparam as _) as i16 as _,
+					HIWORD(p.wparam as _) as i16 as _,
+				);
+				self2.0.events.process_event(LOWORD(p.lparam as _) as _, pos)?;
+			}
+			Ok(None) // not meaningful
+		});
+
+		if let Ok(wm_taskbar_created) = wm_taskbar_created {
+			let self2 = self.clone();
+			parent_ref.privileged_on().wm(co::WM(wm_taskbar_created), move |_| {
+				self2.add()?; // Explorer restarted, our icon is gone
+				Ok(None) // not meaningful
+			});
+		}
+	}
+
+	fn add(&self) -> SysResult<()> {
+		let nid = unsafe { &mut *self.0.nid.get() };
+		let prev_flags = nid.uFlags;
+		if nid.dwStateMask != co::NIS::NoValue {
+			nid.uFlags |= co::NIF::STATE; // set_visible() was called, keep the state
+		}
+		let res = Shell_NotifyIcon(co::NIM::ADD, nid);
+		nid.uFlags = prev_flags;
+		res?;
+
+		nid.uVersion = NOTIFYICON_VERSION_4;
+		Shell_NotifyIcon(co::NIM::SETVERSION, nid)
+	}
+
+	fn modify(&self, flags: co::NIF) -> SysResult<()> {
+		let nid = unsafe { &mut *self.0.nid.get() };
+		let prev_flags = nid.uFlags;
+		nid.uFlags = flags;
+		let res = Shell_NotifyIcon(co::NIM::MODIFY, nid);
+		nid.uFlags = prev_flags;
+		res
+	}
+
+	fn nid(&self) -> &NOTIFYICONDATA {
+		unsafe { &*self.0.nid.get() }
+	}
+
+	/// Exposes the tray icon events.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created. Events must be set
+	/// before window creation.
+	#[must_use]
+	pub fn on(&self) -> &TrayIconEvents {
+		if *unsafe { self.0.parent_ptr.as_ref() }.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+
+	/// Returns the identifier of the icon, unique among the icons of the
+	/// parent window.
+	#[must_use]
+	pub fn id(&self) -> u16 {
+		self.0.opts.id
+	}
+
+	/// Sets the icon.
+	///
+	/// **Note:** The icon handle is not copied, so it must remain valid while
+	/// it's being displayed.
+	pub fn set_icon(&self, hicon: &HICON) -> SysResult<()> {
+		unsafe { &mut *self.0.nid.get() }.hIcon = unsafe { hicon.raw_copy() };
+		self.modify(co::NIF::ICON)
+	}
+
+	/// Sets the tooltip text, which is truncated to 127 characters.
+	pub fn set_tooltip(&self, text: &str) -> SysResult<()> {
+		let nid = unsafe { &mut *self.0.nid.get() };
+		nid.set_szTip(text);
+		self.modify(co::NIF::TIP | co::NIF::SHOWTIP)
+	}
+
+	/// Shows or hides the icon. The state is kept if the icon is added again
+	/// after Explorer restarts.
+	pub fn set_visible(&self, visible: bool) -> SysResult<()> {
+		let nid = unsafe { &mut *self.0.nid.get() };
+		nid.dwState = if visible { co::NIS::NoValue } else { co::NIS::HIDDEN };
+		nid.dwStateMask = co::NIS::HIDDEN;
+		self.modify(co::NIF::STATE)
+	}
+
+	/// Shows a balloon notification – displayed as a toast on Windows 10 and
+	/// later.
+	///
+	/// The title is truncated to 63 characters, and the text to 255. Clicks on
+	/// the balloon are notified through
+	/// [`TrayIconEvents::nin_balloon_user_click`](crate::gui::events::TrayIconEvents::nin_balloon_user_click).
+	pub fn show_balloon(&self,
+		title: &str, text: &str, flags: co::NIIF) -> SysResult<()>
+	{
+		let nid = unsafe { &mut *self.0.nid.get() };
+		nid.set_szInfoTitle(title);
+		nid.set_szInfo(text);
+		nid.dwInfoFlags = flags;
+		self.modify(co::NIF::INFO)
+	}
+
+	/// Displays a popup menu at the given screen coordinates – usually those
+	/// received by
+	/// [`TrayIconEvents::wm_context_menu`](crate::gui::events::TrayIconEvents::wm_context_menu).
+	///
+	/// The chosen command is sent to the parent window as an ordinary
+	/// [`WM_COMMAND`](crate::prelude::GuiEventsAll::wm_command) message.
+	///
+	/// This method will block until the menu disappears.
+	pub fn show_menu(&self, hmenu: &HMENU, pos: POINT) -> SysResult<()> {
+		let hparent = &self.nid().hWnd;
+		hparent.SetForegroundWindow(); // so the menu is closed when clicking elsewhere
+		hmenu.TrackPopupMenu(
+			co::TPM::LEFTBUTTON | co::TPM::RIGHTBUTTON | co::TPM::BOTTOMALIGN,
+			pos, hparent)?;
+		hparent.PostMessage(wm::Null {})?; // necessary according to TrackPopupMenu docs
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`TrayIcon`](crate::gui::TrayIcon) with
+/// [`TrayIcon::new`](crate::gui::TrayIcon::new).
+pub struct TrayIconOpts {
+	/// The icon to be displayed.
+	///
+	/// Defaults to `gui::Icon::Idi(co::IDI::APPLICATION)`.
+	pub icon: Icon,
+	/// The tooltip text, truncated to 127 characters.
+	///
+	/// Defaults to empty string.
+	pub tooltip: String,
+	/// The identifier of the icon, which must be unique among the icons of the
+	/// parent window.
+	///
+	/// Defaults to an auto-generated ID.
+	pub id: u16,
+}
+
+impl Default for TrayIconOpts {
+	fn default() -> Self {
+		Self {
+			icon: Icon::Idi(co::IDI::APPLICATION),
+			tooltip: "".to_owned(),
+			id: 0,
+		}
+	}
+}
+
+impl TrayIconOpts {
+	fn define_id(mut self) -> Self {
+		if self.id == 0 {
+			self.id = auto_ctrl_id();
+		}
+		self
+	}
+}
@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::future::Future;
 
 use crate::gui::base::Base;
 use crate::gui::dlg_modeless::DlgModeless;
@@ -70,6 +71,15 @@ impl GuiThread for WindowModeless {
 			RawDlg::Dlg(d) => d.run_ui_thread(func),
 		}
 	}
+
+	fn spawn_local<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		match &self.0 {
+			RawDlg::Raw(r) => r.spawn_local(future),
+			RawDlg::Dlg(d) => d.spawn_local(future),
+		}
+	}
 }
 
 impl WindowModeless {
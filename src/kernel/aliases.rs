@@ -1,3 +1,5 @@
+#![allow(non_camel_case_types)]
+
 use crate::co;
 
 /// A [`Result` alias](crate#errors-and-result-aliases) which returns a `Box<dyn
@@ -8,6 +10,17 @@ use crate::co;
 /// any other `Result` can be converted into it.
 pub type AnyResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
+/// Type alias to
+/// [`LPHANDLER_FUNCTION_EX`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nc-winsvc-lphandler_function_ex)
+/// callback function.
+pub type LPHANDLER_FUNCTION_EX =
+	extern "system" fn(
+		dwControl: u32,
+		dwEventType: u32,
+		lpEventData: *mut std::ffi::c_void,
+		lpContext: *mut std::ffi::c_void,
+	) -> u32;
+
 /// A [`Result` alias](crate#errors-and-result-aliases) for native system error
 /// codes, which returns an [`ERROR`](crate::co::ERROR) on failure.
 ///
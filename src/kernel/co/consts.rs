@@ -15,6 +15,56 @@ const_bitflag! { ACCESS_RIGHTS: u32;
 	SYNCHRONIZE 0x0010_0000
 }
 
+const_bitflag! { ACE: u8;
+	/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+	/// `AceFlags` (`u8`).
+	///
+	/// Originally has `ACE` suffix, or `ACE_FLAG` suffix for
+	/// `SUCCESSFUL_ACCESS` and `FAILED_ACCESS`.
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	OBJECT_INHERIT 0x01
+	CONTAINER_INHERIT 0x02
+	NO_PROPAGATE_INHERIT 0x04
+	INHERIT_ONLY 0x08
+	INHERITED 0x10
+	SUCCESSFUL_ACCESS 0x40
+	FAILED_ACCESS 0x80
+}
+
+const_ordinary! { ACE_TYPE: u8;
+	/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+	/// `AceType` (`u8`).
+	///
+	/// Originally has `ACE_TYPE` suffix.
+	=>
+	=>
+	ACCESS_ALLOWED 0x00
+	ACCESS_DENIED 0x01
+	SYSTEM_AUDIT 0x02
+	SYSTEM_ALARM 0x03
+	ACCESS_ALLOWED_COMPOUND 0x04
+	ACCESS_ALLOWED_OBJECT 0x05
+	ACCESS_DENIED_OBJECT 0x06
+	SYSTEM_AUDIT_OBJECT 0x07
+	SYSTEM_ALARM_OBJECT 0x08
+	ACCESS_ALLOWED_CALLBACK 0x09
+	ACCESS_DENIED_CALLBACK 0x0a
+	ACCESS_ALLOWED_CALLBACK_OBJECT 0x0b
+	ACCESS_DENIED_CALLBACK_OBJECT 0x0c
+	SYSTEM_AUDIT_CALLBACK 0x0d
+	SYSTEM_ALARM_CALLBACK 0x0e
+	SYSTEM_AUDIT_CALLBACK_OBJECT 0x0f
+	SYSTEM_ALARM_CALLBACK_OBJECT 0x10
+	SYSTEM_MANDATORY_LABEL 0x11
+	SYSTEM_RESOURCE_ATTRIBUTE 0x12
+	SYSTEM_SCOPED_POLICY_ID 0x13
+	SYSTEM_PROCESS_TRUST_LABEL 0x14
+	SYSTEM_ACCESS_FILTER 0x15
+}
+
 const_bitflag! { CONSOLE: u32;
 	/// [`SetConsoleMode`](crate::prelude::kernel_Hstd::SetConsoleMode) `mode`
 	/// (`u32`).
@@ -137,6 +187,38 @@ const_bitflag! { CREATE: u32;
 	INHERIT_PARENT_AFFINITY 0x0001_0000
 }
 
+const_bitflag! { CREATE_EVENT: u32;
+	/// [`HEVENT::CreateEventEx`](crate::prelude::kernel_Hevent::CreateEventEx)
+	/// `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	INITIAL_SET 0x0000_0002
+	MANUAL_RESET 0x0000_0001
+}
+
+const_bitflag! { CREATE_MUTEX: u32;
+	/// [`HMUTEX::CreateMutexEx`](crate::prelude::kernel_Hmutex::CreateMutexEx)
+	/// `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	INITIAL_OWNER 0x0000_0001
+}
+
+const_bitflag! { CREATE_WAITABLE_TIMER: u32;
+	/// [`HWAITABLETIMER::CreateWaitableTimerEx`](crate::prelude::kernel_Hwaitabletimer::CreateWaitableTimerEx)
+	/// `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	HIGH_RESOLUTION 0x0000_0002
+	MANUAL_RESET 0x0000_0001
+}
+
 const_ordinary! { DISPOSITION: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `creation_disposition` (`u32`).
@@ -204,6 +286,51 @@ const_ordinary! { DRIVE: u32;
 	RAMDISK 6
 }
 
+const_bitflag! { DUPLICATE: u32;
+	/// [`HPROCESS::DuplicateHandle`](crate::prelude::kernel_Hprocess::DuplicateHandle)
+	/// `options` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	CLOSE_SOURCE 0x0000_0001
+	SAME_ACCESS 0x0000_0002
+}
+
+const_bitflag! { EVENT: u32;
+	/// Event
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/sync/synchronization-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x3
+	MODIFY_STATE 0x0002
+}
+
+const_ordinary! { FILE_ACTION: u32;
+	/// [`FILE_NOTIFY_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-file_notify_information)
+	/// `Action` (`u32`).
+	=>
+	=>
+	/// The file was added to the directory.
+	ADDED 0x0000_0001
+	/// The file was removed from the directory.
+	REMOVED 0x0000_0002
+	/// The file was modified. This can be a change in the time stamp or
+	/// attributes.
+	MODIFIED 0x0000_0003
+	/// The file was renamed and this is the old name.
+	RENAMED_OLD_NAME 0x0000_0004
+	/// The file was renamed and this is the new name.
+	RENAMED_NEW_NAME 0x0000_0005
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File attribute
 	/// [flags](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants),
@@ -282,6 +409,34 @@ const_bitflag! { FILE_MAP: u32;
 	TARGETS_INVALID 0x4000_0000
 }
 
+const_bitflag! { FILE_NOTIFY_CHANGE: u32;
+	/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges)
+	/// `notify_filter` (`u32`).
+	=>
+	=>
+	/// Any file name change in the watched directory or subtree: renaming,
+	/// creating, or deleting a file.
+	FILE_NAME 0x0000_0001
+	/// Any directory-name change in the watched directory or subtree: creating
+	/// or deleting a directory.
+	DIR_NAME 0x0000_0002
+	/// Any attribute change in the watched directory or subtree.
+	ATTRIBUTES 0x0000_0004
+	/// Any file-size change in the watched directory or subtree.
+	SIZE 0x0000_0008
+	/// Any change to the last write-time of files in the watched directory or
+	/// subtree.
+	LAST_WRITE 0x0000_0010
+	/// Any change to the last access time of files in the watched directory or
+	/// subtree.
+	LAST_ACCESS 0x0000_0020
+	/// Any change to the creation time of files in the watched directory or
+	/// subtree.
+	CREATION 0x0000_0040
+	/// Any security-descriptor change in the watched directory or subtree.
+	SECURITY 0x0000_0100
+}
+
 const_bitflag! { FILE_SHARE: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `share_mode` (`u32`).
@@ -424,6 +579,17 @@ const_bitflag! { GR: u32;
 	USEROBJECTS_PEAK 4
 }
 
+const_bitflag! { HANDLE_FLAG: u32;
+	/// [`SetHandleInformation`](crate::SetHandleInformation) `mask` and `flags`
+	/// (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	INHERIT 0x0000_0001
+	PROTECT_FROM_CLOSE 0x0000_0002
+}
+
 const_bitflag! { HEAP_ALLOC: u32;
 	/// [`HHEAPOBJ::HeapAlloc`](crate::prelude::kernel_Hheapobj::HeapAlloc)
 	/// `flags` (`u32`).
@@ -491,6 +657,86 @@ const_ordinary! { HF32: u32;
 	DEFAULT 1
 }
 
+const_bitflag! { JOB_OBJECT: u32;
+	/// Job object
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/procthread/job-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x3f
+	ASSIGN_PROCESS 0x0001
+	QUERY 0x0004
+	SET_ATTRIBUTES 0x0002
+	SET_SECURITY_ATTRIBUTES 0x0010
+	TERMINATE 0x0008
+}
+
+const_bitflag! { JOB_OBJECT_CPU_RATE_CONTROL: u32;
+	/// [`JOBOBJECT_CPU_RATE_CONTROL_INFORMATION`](crate::JOBOBJECT_CPU_RATE_CONTROL_INFORMATION)
+	/// `ControlFlags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ENABLE 0x1
+	WEIGHT_BASED 0x2
+	HARD_CAP 0x4
+	NOTIFY 0x8
+	MIN_MAX_RATE 0x10
+}
+
+const_bitflag! { JOB_OBJECT_LIMIT: u32;
+	/// [`JOBOBJECT_BASIC_LIMIT_INFORMATION`](crate::JOBOBJECT_BASIC_LIMIT_INFORMATION)
+	/// `LimitFlags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	WORKINGSET 0x0000_0001
+	PROCESS_TIME 0x0000_0002
+	JOB_TIME 0x0000_0004
+	ACTIVE_PROCESS 0x0000_0008
+	AFFINITY 0x0000_0010
+	PRIORITY_CLASS 0x0000_0020
+	PRESERVE_JOB_TIME 0x0000_0040
+	SCHEDULING_CLASS 0x0000_0080
+	PROCESS_MEMORY 0x0000_0100
+	JOB_MEMORY 0x0000_0200
+	DIE_ON_UNHANDLED_EXCEPTION 0x0000_0400
+	BREAKAWAY_OK 0x0000_0800
+	SILENT_BREAKAWAY_OK 0x0000_1000
+	KILL_ON_JOB_CLOSE 0x0000_2000
+	SUBSET_AFFINITY 0x0000_4000
+}
+
+const_ordinary! { JOB_OBJECT_MSG: u32;
+	/// Job object
+	/// [notification](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_associate_completion_port)
+	/// messages, retrieved with
+	/// [`HIOCP::GetQueuedCompletionStatus`](crate::prelude::kernel_Hiocp::GetQueuedCompletionStatus)
+	/// (`u32`).
+	=>
+	=>
+	END_OF_JOB_TIME 1
+	END_OF_PROCESS_TIME 2
+	ACTIVE_PROCESS_LIMIT 3
+	ACTIVE_PROCESS_ZERO 4
+	NEW_PROCESS 6
+	EXIT_PROCESS 7
+	ABNORMAL_EXIT_PROCESS 8
+	PROCESS_MEMORY_LIMIT 9
+	JOB_MEMORY_LIMIT 10
+	NOTIFICATION_LIMIT 11
+	JOB_CYCLE_TIME_LIMIT 12
+	SILO_TERMINATED 13
+}
+
 const_bitflag! { KEY: u32;
 	/// [Registry access rights](https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-key-security-and-access-rights)
 	/// (`u32`).
@@ -687,6 +933,22 @@ const_bitflag! { MBC: u32;
 	USEGLYPHCHARS 0x0000_0004
 }
 
+const_bitflag! { MUTEX: u32;
+	/// Mutex
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/sync/synchronization-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x1
+	MODIFY_STATE 0x0001
+}
+
 const_ordinary! { PAGE: u32;
 	/// [`HFILE::CreateFileMapping`](crate::prelude::kernel_Hfile::CreateFileMapping)
 	/// `protect` (`u32`).
@@ -1121,6 +1383,13 @@ const_ordinary! { RID: u32;
 	SECURITY_PROCESS_PROTECTION_LEVEL_ANTIMALWARE 0x0000_0600
 	SECURITY_PROCESS_PROTECTION_LEVEL_AUTHENTICODE 0x0000_0400
 	SECURITY_PROCESS_PROTECTION_LEVEL_NONE 0x0000_0000
+	SECURITY_MANDATORY_UNTRUSTED 0x0000_0000
+	SECURITY_MANDATORY_LOW 0x0000_1000
+	SECURITY_MANDATORY_MEDIUM 0x0000_2000
+	SECURITY_MANDATORY_MEDIUM_PLUS 0x0000_2100
+	SECURITY_MANDATORY_HIGH 0x0000_3000
+	SECURITY_MANDATORY_SYSTEM 0x0000_4000
+	SECURITY_MANDATORY_PROTECTED_PROCESS 0x0000_5000
 	SECURITY_TRUSTED_INSTALLER_1 9_5600_8885
 	SECURITY_TRUSTED_INSTALLER_2 34_1852_2649
 	SECURITY_TRUSTED_INSTALLER_3 18_3103_8044
@@ -1181,6 +1450,31 @@ const_ordinary! { RT: u16;
 	VXD 20
 }
 
+const_ordinary! { SC_ACTION: u32;
+	/// [`SC_ACTION`](crate::SC_ACTION) `Type` (`u32`).
+	=>
+	=>
+	NONE 0
+	RESTART 1
+	REBOOT 2
+	RUN_COMMAND 3
+}
+
+const_bitflag! { SC_MANAGER: u32;
+	/// Service Control Manager
+	/// [access rights](https://learn.microsoft.com/en-us/windows/win32/services/service-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	CONNECT 0x0001
+	CREATE_SERVICE 0x0002
+	ENUMERATE_SERVICE 0x0004
+	LOCK 0x0008
+	QUERY_LOCK_STATUS 0x0010
+	MODIFY_BOOT_CONFIG 0x0020
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | Self::CONNECT.0 | Self::CREATE_SERVICE.0 | Self::ENUMERATE_SERVICE.0 | Self::LOCK.0 | Self::QUERY_LOCK_STATUS.0 | Self::MODIFY_BOOT_CONFIG.0
+}
+
 const_ordinary! { SCS: u32;
 	/// [`GetBinaryType`](crate::GetBinaryType) return value (`u32`).
 	=>
@@ -1215,6 +1509,60 @@ const_bitflag! { SE: u16;
 	SELF_RELATIVE 0x8000
 }
 
+const_bitflag! { SE_GROUP: u32;
+	/// [`SID_AND_ATTRIBUTES`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-sid_and_attributes)
+	/// `Attributes` of a token group (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	MANDATORY 0x0000_0001
+	ENABLED_BY_DEFAULT 0x0000_0002
+	ENABLED 0x0000_0004
+	OWNER 0x0000_0008
+	USE_FOR_DENY_ONLY 0x0000_0010
+	INTEGRITY 0x0000_0020
+	INTEGRITY_ENABLED 0x0000_0040
+	RESOURCE 0x2000_0000
+	LOGON_ID 0xc000_0000
+}
+
+const_ordinary! { SE_OBJECT_TYPE: u32;
+	/// [`SE_OBJECT_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/accctrl/ne-accctrl-se_object_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `SE` prefix.
+	=>
+	=>
+	UNKNOWN_OBJECT_TYPE 0
+	FILE_OBJECT 1
+	SERVICE 2
+	PRINTER 3
+	REGISTRY_KEY 4
+	LMSHARE 5
+	KERNEL_OBJECT 6
+	WINDOW_OBJECT 7
+	DS_OBJECT 8
+	DS_OBJECT_ALL 9
+	PROVIDER_DEFINED_OBJECT 10
+	WMIGUID_OBJECT 11
+	REGISTRY_WOW64_32KEY 12
+	REGISTRY_WOW64_64KEY 13
+}
+
+const_bitflag! { SE_PRIVILEGE: u32;
+	/// [`LUID_AND_ATTRIBUTES`](crate::LUID_AND_ATTRIBUTES) `Attributes` of a
+	/// privilege (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ENABLED_BY_DEFAULT 0x0000_0001
+	ENABLED 0x0000_0002
+	REMOVED 0x0000_0004
+	USED_FOR_ACCESS 0x8000_0000
+}
+
 const_bitflag! { SECTION: u32;
 	/// Composes [`FILE_MAP`](crate::co::FILE_MAP) (`u32`).
 	=>
@@ -1262,6 +1610,146 @@ const_bitflag! { SECURITY_INFORMATION: u32;
 	UNPROTECTED_SACL 0x1000_0000
 }
 
+const_bitflag! { SEMAPHORE: u32;
+	/// Semaphore
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/sync/synchronization-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x3
+	MODIFY_STATE 0x0002
+}
+
+const_bitflag! { SERVICE: u32;
+	/// Service
+	/// [access rights](https://learn.microsoft.com/en-us/windows/win32/services/service-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	QUERY_CONFIG 0x0001
+	CHANGE_CONFIG 0x0002
+	QUERY_STATUS 0x0004
+	ENUMERATE_DEPENDENTS 0x0008
+	START 0x0010
+	STOP 0x0020
+	PAUSE_CONTINUE 0x0040
+	INTERROGATE 0x0080
+	USER_DEFINED_CONTROL 0x0100
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | Self::QUERY_CONFIG.0 | Self::CHANGE_CONFIG.0 | Self::QUERY_STATUS.0 | Self::ENUMERATE_DEPENDENTS.0 | Self::START.0 | Self::STOP.0 | Self::PAUSE_CONTINUE.0 | Self::INTERROGATE.0 | Self::USER_DEFINED_CONTROL.0
+}
+
+const_bitflag! { SERVICE_ACCEPT: u32;
+	/// [`SERVICE_STATUS`](crate::SERVICE_STATUS) `dwControlsAccepted` (`u32`).
+	=>
+	=>
+	STOP 0x0000_0001
+	PAUSE_CONTINUE 0x0000_0002
+	SHUTDOWN 0x0000_0004
+	PARAMCHANGE 0x0000_0008
+	NETBINDCHANGE 0x0000_0010
+	HARDWAREPROFILECHANGE 0x0000_0020
+	POWEREVENT 0x0000_0040
+	SESSIONCHANGE 0x0000_0080
+	PRESHUTDOWN 0x0000_0100
+	TIMECHANGE 0x0000_0200
+	TRIGGEREVENT 0x0000_0400
+	USERMODEREBOOT 0x0000_0800
+}
+
+const_ordinary! { SERVICE_CONTROL: u32;
+	/// [`HSERVICE::ControlService`](crate::prelude::kernel_Hservice::ControlService)
+	/// `control` (`u32`).
+	=>
+	=>
+	STOP 0x0000_0001
+	PAUSE 0x0000_0002
+	CONTINUE 0x0000_0003
+	INTERROGATE 0x0000_0004
+	SHUTDOWN 0x0000_0005
+	PARAMCHANGE 0x0000_0006
+	NETBINDADD 0x0000_0007
+	NETBINDREMOVE 0x0000_0008
+	NETBINDENABLE 0x0000_0009
+	NETBINDDISABLE 0x0000_000a
+	DEVICEEVENT 0x0000_000b
+	HARDWAREPROFILECHANGE 0x0000_000c
+	POWEREVENT 0x0000_000d
+	SESSIONCHANGE 0x0000_000e
+	PRESHUTDOWN 0x0000_000f
+	TIMECHANGE 0x0000_0010
+	TRIGGEREVENT 0x0000_0020
+	USERMODEREBOOT 0x0000_0040
+}
+
+const_ordinary! { SERVICE_ERROR: u32;
+	/// [`HSC::CreateService`](crate::prelude::kernel_Hsc::CreateService)
+	/// `error_control` (`u32`).
+	=>
+	=>
+	IGNORE 0x0000_0000
+	NORMAL 0x0000_0001
+	SEVERE 0x0000_0002
+	CRITICAL 0x0000_0003
+}
+
+const_ordinary! { SERVICE_START: u32;
+	/// [`HSC::CreateService`](crate::prelude::kernel_Hsc::CreateService)
+	/// `start_type` (`u32`).
+	///
+	/// Originally has `SERVICE` prefix and `START` suffix.
+	=>
+	=>
+	BOOT 0x0000_0000
+	SYSTEM 0x0000_0001
+	AUTO 0x0000_0002
+	DEMAND 0x0000_0003
+	DISABLED 0x0000_0004
+}
+
+const_ordinary! { SERVICE_STATE: u32;
+	/// [`SERVICE_STATUS`](crate::SERVICE_STATUS) `dwCurrentState` (`u32`).
+	///
+	/// Originally has `SERVICE` prefix.
+	=>
+	=>
+	STOPPED 0x0000_0001
+	START_PENDING 0x0000_0002
+	STOP_PENDING 0x0000_0003
+	RUNNING 0x0000_0004
+	CONTINUE_PENDING 0x0000_0005
+	PAUSE_PENDING 0x0000_0006
+	PAUSED 0x0000_0007
+}
+
+const_bitflag! { SERVICE_TYPE: u32;
+	/// [`HSC::CreateService`](crate::prelude::kernel_Hsc::CreateService)
+	/// `service_type` and [`SERVICE_STATUS`](crate::SERVICE_STATUS)
+	/// `dwServiceType` (`u32`).
+	///
+	/// Originally has `SERVICE` prefix.
+	=>
+	=>
+	KERNEL_DRIVER 0x0000_0001
+	FILE_SYSTEM_DRIVER 0x0000_0002
+	ADAPTER 0x0000_0004
+	RECOGNIZER_DRIVER 0x0000_0008
+	DRIVER Self::KERNEL_DRIVER.0 | Self::FILE_SYSTEM_DRIVER.0 | Self::RECOGNIZER_DRIVER.0
+	WIN32_OWN_PROCESS 0x0000_0010
+	WIN32_SHARE_PROCESS 0x0000_0020
+	WIN32 Self::WIN32_OWN_PROCESS.0 | Self::WIN32_SHARE_PROCESS.0
+	USER_SERVICE 0x0000_0040
+	USER_INSTANCE 0x0000_0080
+	USER_OWN_PROCESS Self::USER_SERVICE.0 | Self::WIN32_OWN_PROCESS.0
+	USER_SHARE_PROCESS Self::USER_SERVICE.0 | Self::WIN32_SHARE_PROCESS.0
+	INTERACTIVE_PROCESS 0x0000_0100
+}
+
 const_ordinary! { SID_NAME_USE: u32;
 	/// [`SID_NAME_USE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-sid_name_use)
 	/// enumeration (`u32`).
@@ -1687,6 +2175,23 @@ const_bitflag! { THREAD_CREATE: u32;
 	STACK_SIZE_PARAM_IS_A_RESERVATION 0x0001_0000
 }
 
+const_bitflag! { TIMER: u32;
+	/// Waitable timer
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/sync/synchronization-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x3
+	MODIFY_STATE 0x0002
+	QUERY_STATE 0x0001
+}
+
 const_bitflag! { TOKEN: u32;
 	/// [Token access rights](https://learn.microsoft.com/en-us/windows/win32/secauthz/access-rights-for-access-token-objects).
 	=>
@@ -1714,6 +2219,79 @@ const_bitflag! { TOKEN: u32;
 	ACCESS_PSEUDO_HANDLE Self::QUERY.0 | Self::QUERY_SOURCE.0
 }
 
+const_ordinary! { TOKEN_ELEVATION_TYPE: u32;
+	/// [`TOKEN_ELEVATION_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-token_elevation_type)
+	/// enumeration (`u32`).
+	/// 
+	/// Originally has `TokenElevationType` prefix.
+	=>
+	=>
+	/// The token is not split; UAC is disabled, or the user is a standard
+	/// user.
+	Default 1
+	/// The token is the elevated part of a split token.
+	Full 2
+	/// The token is the filtered part of a split token, so the process can be
+	/// elevated.
+	Limited 3
+}
+
+const_ordinary! { TOKEN_INFORMATION_CLASS: u32;
+	/// [`TOKEN_INFORMATION_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-token_information_class)
+	/// enumeration (`u32`).
+	/// 
+	/// Originally has `Token` prefix.
+	=>
+	=>
+	User 1
+	Groups 2
+	Privileges 3
+	Owner 4
+	PrimaryGroup 5
+	DefaultDacl 6
+	Source 7
+	Type 8
+	ImpersonationLevel 9
+	Statistics 10
+	RestrictedSids 11
+	SessionId 12
+	GroupsAndPrivileges 13
+	SessionReference 14
+	SandBoxInert 15
+	AuditPolicy 16
+	Origin 17
+	ElevationType 18
+	LinkedToken 19
+	Elevation 20
+	HasRestrictions 21
+	AccessInformation 22
+	VirtualizationAllowed 23
+	VirtualizationEnabled 24
+	IntegrityLevel 25
+	UIAccess 26
+	MandatoryPolicy 27
+	LogonSid 28
+	IsAppContainer 29
+	Capabilities 30
+	AppContainerSid 31
+	AppContainerNumber 32
+	UserClaimAttributes 33
+	DeviceClaimAttributes 34
+	RestrictedUserClaimAttributes 35
+	RestrictedDeviceClaimAttributes 36
+	DeviceGroups 37
+	RestrictedDeviceGroups 38
+	SecurityAttributes 39
+	IsRestricted 40
+	ProcessTrustLevel 41
+	PrivateNameSpace 42
+	SingletonAttributes 43
+	BnoIsolation 44
+	ChildProcessFlags 45
+	IsLessPrivilegedAppContainer 46
+	IsSandboxed 47
+}
+
 const_bitflag! { TRANSACTION: u32;
 	/// [`Transaction access masks`](https://learn.microsoft.com/en-us/windows/win32/ktm/transaction-access-masks)
 	/// (`u32`).
@@ -1816,8 +2394,9 @@ const_ordinary! { VER_PLATFORM: u32;
 }
 
 const_ordinary! { WAIT: u32;
-	/// [`HPROCESS::WaitForSingleObject`](crate::prelude::kernel_Hprocess::WaitForSingleObject)
-	/// return value (`u32`).
+	/// [`WaitForSingleObject`](crate::prelude::HandleWaitable::WaitForSingleObject)
+	/// and [`WaitForMultipleObjects`](crate::WaitForMultipleObjects) return
+	/// value (`u32`).
 	=>
 	=>
 	ABANDONED 0x0000_0080
@@ -1,7 +1,35 @@
 use crate::co;
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{
+	HACCESSTOKEN, JOBOBJECT_ASSOCIATE_COMPLETION_PORT,
+	JOBOBJECT_BASIC_ACCOUNTING_INFORMATION, JOBOBJECT_BASIC_LIMIT_INFORMATION,
+	JOBOBJECT_CPU_RATE_CONTROL_INFORMATION, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+	LUID_AND_ATTRIBUTES, SC_ACTION, WString,
+};
+use crate::kernel::guard::{CloseHandleGuard, SidGuard};
 use crate::kernel::privs::{IS_INTRESOURCE, MAKEINTRESOURCE, parse_multi_z_str};
 
+/// Error returned when parsing a [`GUID`](crate::GUID) from a string fails.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GuidParseError {
+	/// The string doesn't have 32, 36 or 38 chars; the actual length is given.
+	BadLength(usize),
+	/// An unexpected char was found at the given byte position.
+	BadChar(char, usize),
+}
+
+impl std::error::Error for GuidParseError {}
+
+impl std::fmt::Display for GuidParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::BadLength(len) => write!(f,
+				"Bad GUID length: {}, expected 32, 36 or 38 chars.", len),
+			Self::BadChar(ch, pos) => write!(f,
+				"Bad GUID char: {:?} at position {}.", ch, pos),
+		}
+	}
+}
+
 /// A resource identifier.
 ///
 /// Variable parameter for:
@@ -51,11 +79,80 @@ impl IdStr {
 	}
 }
 
+/// Job object information.
+///
+/// Variable parameter for:
+///
+/// * [`HJOB::QueryInformationJobObject`](crate::prelude::kernel_Hjob::QueryInformationJobObject);
+/// * [`HJOB::SetInformationJobObject`](crate::prelude::kernel_Hjob::SetInformationJobObject).
+pub enum JobObjectInfo {
+	/// `JobObjectAssociateCompletionPortInformation`, which can only be set.
+	AssociateCompletionPort(JOBOBJECT_ASSOCIATE_COMPLETION_PORT),
+	/// `JobObjectBasicAccountingInformation`, which can only be queried.
+	BasicAccounting(JOBOBJECT_BASIC_ACCOUNTING_INFORMATION),
+	/// `JobObjectBasicLimitInformation`.
+	BasicLimit(JOBOBJECT_BASIC_LIMIT_INFORMATION),
+	/// `JobObjectCpuRateControlInformation`.
+	CpuRateControl(JOBOBJECT_CPU_RATE_CONTROL_INFORMATION),
+	/// `JobObjectExtendedLimitInformation`.
+	ExtendedLimit(JOBOBJECT_EXTENDED_LIMIT_INFORMATION),
+}
+
+impl JobObjectInfo {
+	/// Returns the `JOBOBJECTINFOCLASS` value.
+	#[must_use]
+	pub(crate) const fn class(&self) -> u32 {
+		match self {
+			Self::AssociateCompletionPort(_) => 7,
+			Self::BasicAccounting(_) => 1,
+			Self::BasicLimit(_) => 2,
+			Self::CpuRateControl(_) => 15,
+			Self::ExtendedLimit(_) => 9,
+		}
+	}
+
+	/// Returns a pointer to the struct, and its size.
+	#[must_use]
+	pub(crate) fn as_ptr_size(&self) -> (*const std::ffi::c_void, u32) {
+		fn ptr_size<T>(v: &T) -> (*const std::ffi::c_void, u32) {
+			(v as *const _ as _, std::mem::size_of::<T>() as _)
+		}
+
+		match self {
+			Self::AssociateCompletionPort(v) => ptr_size(v),
+			Self::BasicAccounting(v) => ptr_size(v),
+			Self::BasicLimit(v) => ptr_size(v),
+			Self::CpuRateControl(v) => ptr_size(v),
+			Self::ExtendedLimit(v) => ptr_size(v),
+		}
+	}
+
+	/// Returns a mutable pointer to the struct, and its size.
+	#[must_use]
+	pub(crate) fn as_mut_ptr_size(&mut self) -> (*mut std::ffi::c_void, u32) {
+		fn ptr_size<T>(v: &mut T) -> (*mut std::ffi::c_void, u32) {
+			(v as *mut _ as _, std::mem::size_of::<T>() as _)
+		}
+
+		match self {
+			Self::AssociateCompletionPort(v) => ptr_size(v),
+			Self::BasicAccounting(v) => ptr_size(v),
+			Self::BasicLimit(v) => ptr_size(v),
+			Self::CpuRateControl(v) => ptr_size(v),
+			Self::ExtendedLimit(v) => ptr_size(v),
+		}
+	}
+}
+
 /// Registry value types.
 ///
 /// This is a high-level abstraction over the [`co::REG`](crate::co::REG)
 /// constants, plus the value they carry.
+///
+/// New variants may be added in the future, so a `match` must have a wildcard
+/// arm.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum RegistryValue {
 	/// Binary value, defined as [`REG::BINARY`](crate::co::REG::BINARY).
 	Binary(Vec<u8>),
@@ -72,9 +169,11 @@ pub enum RegistryValue {
 	ExpandSz(String),
 	/// Multiple strings, defined as [`REG::MULTI_SZ`](crate::co::REG::MULTI_SZ).
 	MultiSz(Vec<String>),
-	/// No value, defined as [`REG::NONE`](crate::co::REG::NONE). Also used for
-	/// non-implemented value types.
+	/// No value, defined as [`REG::NONE`](crate::co::REG::NONE).
 	None,
+	/// Any other value type, or a [`REG::NONE`](crate::co::REG::NONE) value
+	/// which carries data, kept as raw bytes.
+	Other(co::REG, Vec<u8>),
 }
 
 impl std::fmt::Display for RegistryValue {
@@ -101,6 +200,15 @@ impl std::fmt::Display for RegistryValue {
 					.join(", "),
 			),
 			Self::None => write!(f, "[REG_NONE]"),
+			Self::Other(reg_type, b) => write!(
+				f,
+				"[{}] {}",
+				reg_type,
+				b.iter()
+					.map(|n| format!("{:02}", *n))
+					.collect::<Vec<_>>()
+					.join(" "),
+			),
 		}
 	}
 }
@@ -118,7 +226,7 @@ impl RegistryValue {
 	#[must_use]
 	pub unsafe fn from_raw(buf: Vec<u8>, reg_type: co::REG) -> RegistryValue {
 		match reg_type {
-			co::REG::NONE => RegistryValue::None,
+			co::REG::NONE if buf.is_empty() => RegistryValue::None,
 			co::REG::DWORD => RegistryValue::Dword(
 				u32::from_ne_bytes(unsafe {
 					*std::mem::transmute::<_, *const [u8; 4]>(buf.as_ptr())
@@ -135,14 +243,14 @@ impl RegistryValue {
 			},
 			co::REG::EXPAND_SZ => {
 				let (_, vec16, _) = unsafe { buf.align_to::<u16>() };
-				RegistryValue::Sz(WString::from_wchars_slice(&vec16).to_string())
+				RegistryValue::ExpandSz(WString::from_wchars_slice(&vec16).to_string())
 			},
 			co::REG::MULTI_SZ => {
 				let (_, vec16, _) = unsafe { buf.align_to::<u16>() };
 				RegistryValue::MultiSz(parse_multi_z_str(vec16.as_ptr()))
 			},
 			co::REG::BINARY => RegistryValue::Binary(buf),
-			_ => RegistryValue::None, // other types not implemented yet
+			_ => RegistryValue::Other(reg_type, buf), // types without a specific variant
 		}
 	}
 
@@ -168,6 +276,7 @@ impl RegistryValue {
 				Self::as_ptr_with_len_str(&str_buf)
 			},
 			Self::None => (std::ptr::null(), 0),
+			Self::Other(_, b) => (b.as_ptr() as _, b.len() as _),
 		}
 	}
 
@@ -189,6 +298,7 @@ impl RegistryValue {
 			Self::ExpandSz(_) => co::REG::EXPAND_SZ,
 			Self::MultiSz(_) => co::REG::MULTI_SZ,
 			Self::None => co::REG::NONE,
+			Self::Other(reg_type, _) => *reg_type,
 		}
 	}
 }
@@ -237,3 +347,60 @@ impl RtStr {
 		}
 	}
 }
+
+/// Variant parameter for:
+///
+/// * [`HSERVICE::ChangeServiceConfig2`](crate::prelude::kernel_Hservice::ChangeServiceConfig2).
+pub enum ServiceConfig2 {
+	/// [`SERVICE_DESCRIPTION`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_descriptionw)
+	/// text. An empty string deletes the current description.
+	Description(String),
+	/// [`SERVICE_DELAYED_AUTO_START_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_delayed_auto_start_info)
+	/// flag.
+	DelayedAutoStart(bool),
+	/// [`SERVICE_FAILURE_ACTIONS`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_failure_actionsw)
+	/// data.
+	FailureActions {
+		/// Time after which the failure count is reset to zero, in seconds;
+		/// `None` means never.
+		reset_period: Option<u32>,
+		/// Message broadcasted before a reboot action, if any.
+		reboot_msg: Option<String>,
+		/// Command line run by a run command action, if any.
+		command: Option<String>,
+		/// The actions to be taken on each failure.
+		actions: Vec<SC_ACTION>,
+	},
+	/// [`SERVICE_FAILURE_ACTIONS_FLAG`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_failure_actions_flag)
+	/// flag.
+	FailureActionsFlag(bool),
+	/// [`SERVICE_PRESHUTDOWN_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_preshutdown_info)
+	/// timeout, in milliseconds.
+	PreshutdownTimeout(u32),
+}
+
+/// Access token information.
+///
+/// Returned by:
+///
+/// * [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::kernel_Haccesstoken::GetTokenInformation).
+pub enum TokenInfo {
+	/// `TokenElevation`: whether the token is elevated.
+	Elevation(bool),
+	/// `TokenElevationType`.
+	ElevationType(co::TOKEN_ELEVATION_TYPE),
+	/// `TokenGroups`: the group SIDs, with their attributes.
+	Groups(Vec<(SidGuard, co::SE_GROUP)>),
+	/// `TokenIntegrityLevel`: the mandatory integrity SID, whose last
+	/// sub-authority is one of the `co::RID::SECURITY_MANDATORY_*` values.
+	IntegrityLevel(SidGuard),
+	/// `TokenLinkedToken`: the other part of a split token; the elevated token
+	/// if this one is filtered, and vice-versa.
+	LinkedToken(CloseHandleGuard<HACCESSTOKEN>),
+	/// `TokenPrivileges`.
+	Privileges(Vec<LUID_AND_ATTRIBUTES>),
+	/// `TokenSessionId`: the Terminal Services session ID.
+	SessionId(u32),
+	/// `TokenUser`: the user SID.
+	User(SidGuard),
+}
@@ -1,28 +1,58 @@
 use crate::kernel::ffi_types::{BOOL, HANDLE, PCSTR, PCVOID, PFUNC, PSTR, PVOID};
 
 extern_sys! { "advapi32";
+	AddAce(PVOID, u32, u32, PCVOID, u32) -> BOOL
+	AddAccessAllowedAceEx(PVOID, u32, u32, u32, PCVOID) -> BOOL
+	AddAccessDeniedAceEx(PVOID, u32, u32, u32, PCVOID) -> BOOL
+	AddAuditAccessAceEx(PVOID, u32, u32, u32, PCVOID, BOOL, BOOL) -> BOOL
+	AddMandatoryAce(PVOID, u32, u32, u32, PCVOID) -> BOOL
+	AdjustTokenPrivileges(HANDLE, BOOL, PCVOID, u32, PVOID, *mut u32) -> BOOL
 	AllocateAndInitializeSid(PCVOID, u8, u32, u32, u32, u32, u32, u32, u32, u32, *mut u8) -> BOOL
+	ChangeServiceConfig2W(HANDLE, u32, PVOID) -> BOOL
+	CheckTokenMembership(HANDLE, PCVOID, *mut BOOL) -> BOOL
+	CloseServiceHandle(HANDLE) -> BOOL
+	ControlService(HANDLE, u32, PVOID) -> BOOL
+	ConvertSecurityDescriptorToStringSecurityDescriptorW(PCVOID, u32, u32, *mut PSTR, *mut u32) -> BOOL
 	ConvertSidToStringSidW(PCVOID, *mut PSTR) -> BOOL
+	ConvertStringSecurityDescriptorToSecurityDescriptorW(PCSTR, u32, *mut PVOID, *mut u32) -> BOOL
 	ConvertStringSidToSidW(PCSTR, *mut *mut u8) -> BOOL
 	CopySid(u32, *mut u8, PCVOID) -> BOOL
+	CreateServiceW(HANDLE, PCSTR, PCSTR, u32, u32, u32, u32, PCSTR, PCSTR, *mut u32, PCSTR, PCSTR, PCSTR) -> HANDLE
 	CreateWellKnownSid(u32, PCVOID, *mut u8, *mut u32) -> BOOL
 	DecryptFileW(PCSTR, u32) -> BOOL
+	DeleteAce(PVOID, u32) -> BOOL
+	DeleteService(HANDLE) -> BOOL
 	EncryptFileW(PCSTR) -> BOOL
 	EncryptionDisable(PCSTR, BOOL) -> BOOL
 	EqualDomainSid(PVOID, PVOID, *mut BOOL) -> BOOL
 	EqualPrefixSid(PVOID, PVOID) -> BOOL
 	EqualSid(PVOID, PVOID) -> BOOL
 	FreeSid(PVOID)
+	GetAce(PCVOID, u32, *mut PVOID) -> BOOL
 	GetLengthSid(PVOID) -> u32
+	GetNamedSecurityInfoW(PCSTR, u32, u32, *mut PVOID, *mut PVOID, *mut PVOID, *mut PVOID, *mut PVOID) -> u32
+	GetSecurityDescriptorControl(PCVOID, *mut u16, *mut u32) -> BOOL
+	GetSecurityDescriptorDacl(PCVOID, *mut BOOL, *mut PVOID, *mut BOOL) -> BOOL
+	GetSecurityDescriptorGroup(PCVOID, *mut PVOID, *mut BOOL) -> BOOL
+	GetSecurityDescriptorOwner(PCVOID, *mut PVOID, *mut BOOL) -> BOOL
+	GetSecurityDescriptorSacl(PCVOID, *mut BOOL, *mut PVOID, *mut BOOL) -> BOOL
+	GetSecurityInfo(HANDLE, u32, u32, *mut PVOID, *mut PVOID, *mut PVOID, *mut PVOID, *mut PVOID) -> u32
 	GetSidLengthRequired(u8) -> u32
+	GetTokenInformation(HANDLE, u32, PVOID, u32, *mut u32) -> BOOL
 	GetUserNameW(PSTR, *mut u32) -> BOOL
 	GetWindowsAccountDomainSid(PCVOID, *mut u8, *mut u32) -> BOOL
+	InitializeAcl(PVOID, u32, u32) -> BOOL
 	InitializeSecurityDescriptor(PVOID, u32) -> BOOL
 	IsValidSecurityDescriptor(PCVOID) -> BOOL
 	IsValidSid(PVOID) -> BOOL
 	IsWellKnownSid(PVOID, u32) -> BOOL
 	LookupAccountNameW(PCSTR, PCSTR, *mut u8, *mut u32, PSTR, *mut u32, *mut u32) -> BOOL
 	LookupAccountSidW(PCSTR, PCVOID, PSTR, *mut u32, PSTR, *mut u32, *mut u32) -> BOOL
+	LookupPrivilegeNameW(PCSTR, PCVOID, PSTR, *mut u32) -> BOOL
+	LookupPrivilegeValueW(PCSTR, PCSTR, PVOID) -> BOOL
+	OpenSCManagerW(PCSTR, PCSTR, u32) -> HANDLE
+	OpenServiceW(HANDLE, PCSTR, u32) -> HANDLE
+	QueryServiceStatusEx(HANDLE, u32, *mut u8, u32, *mut u32) -> BOOL
 	RegCloseKey(HANDLE) -> i32
 	RegConnectRegistryW(PCSTR, HANDLE, *mut HANDLE) -> i32
 	RegCopyTreeW(HANDLE, PCSTR, HANDLE) -> i32
@@ -41,6 +71,7 @@ extern_sys! { "advapi32";
 	RegEnumValueW(HANDLE, u32, PSTR, *mut u32, *mut u32, *mut u32, *mut u8, *mut u32) -> i32
 	RegFlushKey(HANDLE) -> i32
 	RegGetValueW(HANDLE, PCSTR, PCSTR, u32, *mut u32, PVOID, *mut u32) -> i32
+	RegisterServiceCtrlHandlerExW(PCSTR, PFUNC, PVOID) -> HANDLE
 	RegLoadKeyW(HANDLE, PCSTR, PCSTR) -> i32
 	RegOpenCurrentUser(u32, *mut HANDLE) -> i32
 	RegOpenKeyExW(HANDLE, PCSTR, u32, u32, *mut HANDLE) -> i32
@@ -57,20 +88,39 @@ extern_sys! { "advapi32";
 	RegSetKeyValueW(HANDLE, PCSTR, PCSTR, u32, PCVOID, u32) -> i32
 	RegSetValueExW(HANDLE, PCSTR, u32, u32, *const u8, u32) -> i32
 	RegUnLoadKeyW(HANDLE, PCSTR) -> i32
+	SetNamedSecurityInfoW(PCSTR, u32, u32, PCVOID, PCVOID, PCVOID, PCVOID) -> u32
+	SetSecurityInfo(HANDLE, u32, u32, PCVOID, PCVOID, PCVOID, PCVOID) -> u32
+	SetServiceStatus(HANDLE, PCVOID) -> BOOL
+	StartServiceCtrlDispatcherW(PCVOID) -> BOOL
+	StartServiceW(HANDLE, u32, *const PCSTR) -> BOOL
 }
 
 extern_sys! { "kernel32";
+	AssignProcessToJobObject(HANDLE, HANDLE) -> BOOL
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
+	CancelIoEx(HANDLE, PVOID) -> BOOL
+	CancelWaitableTimer(HANDLE) -> BOOL
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
+	CreateEventExW(PVOID, PCSTR, u32, u32) -> HANDLE
+	CreateEventW(PVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
+	CreateIoCompletionPort(HANDLE, HANDLE, usize, u32) -> HANDLE
+	CreateJobObjectW(PVOID, PCSTR) -> HANDLE
+	CreateMutexExW(PVOID, PCSTR, u32, u32) -> HANDLE
+	CreateMutexW(PVOID, BOOL, PCSTR) -> HANDLE
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
 	CreateProcessW(PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
+	CreateSemaphoreW(PVOID, i32, i32, PCSTR) -> HANDLE
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
+	CreateWaitableTimerExW(PVOID, PCSTR, u32, u32) -> HANDLE
+	CreateWaitableTimerW(PVOID, BOOL, PCSTR) -> HANDLE
 	DeleteFileW(PCSTR) -> BOOL
+	DeleteProcThreadAttributeList(PVOID)
+	DuplicateHandle(HANDLE, HANDLE, HANDLE, *mut HANDLE, u32, BOOL, u32) -> BOOL
 	DuplicateToken(HANDLE, u32, *mut HANDLE) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
@@ -120,6 +170,7 @@ extern_sys! { "kernel32";
 	GetModuleFileNameW(HANDLE, PSTR, u32) -> u32
 	GetModuleHandleW(PCSTR) -> HANDLE
 	GetNativeSystemInfo(PVOID)
+	GetOverlappedResult(HANDLE, PVOID, *mut u32, BOOL) -> BOOL
 	GetPriorityClass(HANDLE) -> u32
 	GetProcAddress(HANDLE, *const u8) -> PCVOID
 	GetProcessHandleCount(HANDLE, &mut u32) -> BOOL
@@ -128,6 +179,7 @@ extern_sys! { "kernel32";
 	GetProcessId(HANDLE) -> u32
 	GetProcessIdOfThread(HANDLE) -> u32
 	GetProcessTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
+	GetQueuedCompletionStatus(HANDLE, *mut u32, *mut usize, *mut PVOID, u32) -> BOOL
 	GetStartupInfoW(PVOID)
 	GetStdHandle(u32) -> HANDLE
 	GetSystemDirectoryW(PSTR, u32) -> u32
@@ -162,6 +214,7 @@ extern_sys! { "kernel32";
 	HeapSize(HANDLE, u32, PVOID) -> usize
 	HeapUnlock(HANDLE) -> BOOL
 	HeapWalk(HANDLE, PVOID) -> BOOL
+	InitializeProcThreadAttributeList(PVOID, u32, u32, *mut usize) -> BOOL
 	IsDebuggerPresent() -> BOOL
 	IsNativeVhdBoot(*mut BOOL) -> BOOL
 	IsProcessCritical(HANDLE, *mut BOOL) -> BOOL
@@ -183,24 +236,39 @@ extern_sys! { "kernel32";
 	MoveFileW(PCSTR, PCSTR) -> BOOL
 	MulDiv(i32, i32, i32) -> i32
 	MultiByteToWideChar(u32, u32, *const u8, i32, PSTR, i32) -> i32
+	OpenEventW(u32, BOOL, PCSTR) -> HANDLE
+	OpenJobObjectW(u32, BOOL, PCSTR) -> HANDLE
+	OpenMutexW(u32, BOOL, PCSTR) -> HANDLE
 	OpenProcess(u32, BOOL, u32) -> HANDLE
 	OpenProcessToken(HANDLE, u32, *mut HANDLE) -> BOOL
+	OpenSemaphoreW(u32, BOOL, PCSTR) -> HANDLE
 	OpenThreadToken(HANDLE, u32, BOOL, *mut HANDLE) -> BOOL
+	OpenWaitableTimerW(u32, BOOL, PCSTR) -> HANDLE
 	OutputDebugStringW(PCSTR)
+	PostQueuedCompletionStatus(HANDLE, u32, usize, PVOID) -> BOOL
 	Process32FirstW(HANDLE, PVOID) -> BOOL
 	Process32NextW(HANDLE, PVOID) -> BOOL
+	PulseEvent(HANDLE) -> BOOL
 	QueryFullProcessImageNameW(HANDLE, u32, PSTR, *mut u32) -> BOOL
+	QueryInformationJobObject(HANDLE, u32, PVOID, u32, *mut u32) -> BOOL
 	QueryPerformanceCounter(*mut i64) -> BOOL
 	QueryPerformanceFrequency(*mut i64) -> BOOL
 	QueryProcessAffinityUpdateMode(HANDLE, *mut u32) -> BOOL
 	ReadConsoleW(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReadDirectoryChangesW(HANDLE, PVOID, u32, BOOL, u32, *mut u32, PVOID, PVOID) -> BOOL
 	ReadFile(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReleaseMutex(HANDLE) -> BOOL
+	ReleaseSemaphore(HANDLE, i32, *mut i32) -> BOOL
 	ReplaceFileW(PCSTR, PCSTR, PCSTR, u32, PVOID, PVOID) -> BOOL
+	ResetEvent(HANDLE) -> BOOL
 	ResumeThread(HANDLE) -> u32
 	SetConsoleMode(HANDLE, u32) -> BOOL
 	SetCurrentDirectoryW(PCSTR) -> BOOL
 	SetEndOfFile(HANDLE) -> BOOL
+	SetEvent(HANDLE) -> BOOL
 	SetFilePointerEx(HANDLE, i64, *mut i64, u32) -> BOOL
+	SetHandleInformation(HANDLE, u32, u32) -> BOOL
+	SetInformationJobObject(HANDLE, u32, PVOID, u32) -> BOOL
 	SetLastError(u32)
 	SetPriorityClass(HANDLE, u32) -> BOOL
 	SetProcessAffinityUpdateMode(HANDLE, u32) -> BOOL
@@ -209,21 +277,25 @@ extern_sys! { "kernel32";
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
 	SetThreadStackGuarantee(*mut u32) -> BOOL
+	SetWaitableTimer(HANDLE, *const i64, i32, PFUNC, PVOID, BOOL) -> BOOL
 	SizeofResource(HANDLE, HANDLE) -> u32
 	Sleep(u32)
 	SuspendThread(HANDLE) -> u32
 	SwitchToThread() -> BOOL
 	SystemTimeToFileTime(PCVOID, PVOID) -> BOOL
 	SystemTimeToTzSpecificLocalTime(PCVOID, PCVOID, PVOID) -> BOOL
+	TerminateJobObject(HANDLE, u32) -> BOOL
 	TerminateProcess(HANDLE, u32) -> BOOL
 	TerminateThread(HANDLE, u32) -> BOOL
 	Thread32First(HANDLE, PVOID) -> BOOL
 	Thread32Next(HANDLE, PVOID) -> BOOL
 	UnlockFile(HANDLE, u32, u32, u32, u32) -> BOOL
 	UnmapViewOfFile(PCVOID) -> BOOL
+	UpdateProcThreadAttribute(PVOID, u32, usize, PVOID, usize, PVOID, *mut usize) -> BOOL
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
+	WaitForMultipleObjects(u32, *const HANDLE, BOOL, u32) -> u32
 	WaitForSingleObject(HANDLE, u32) -> u32
 	WideCharToMultiByte(u32, u32, PCSTR, i32, PSTR, i32, *const u8, *mut BOOL) -> i32
 	WriteConsoleW(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
@@ -4,19 +4,21 @@ use std::collections::HashMap;
 
 use crate::{co, kernel};
 use crate::kernel::decl::{
-	FILETIME, HLOCAL, LANGID, MEMORYSTATUSEX, OSVERSIONINFOEX,
+	ACL, FILETIME, HLOCAL, LANGID, LUID, MEMORYSTATUSEX, OSVERSIONINFOEX,
 	SECURITY_DESCRIPTOR, SID, SID_IDENTIFIER_AUTHORITY, STARTUPINFO, SysResult,
 	SYSTEM_INFO, SYSTEMTIME, TIME_ZONE_INFORMATION, WString,
 };
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::guard::{
-	FreeSidGuard, LocalFreeGuard, LocalFreeSidGuard, SidGuard,
+	AclGuard, FreeSidGuard, LocalFreeGuard, LocalFreeSecurityDescriptorGuard,
+	LocalFreeSidGuard, SidGuard,
 };
 use crate::kernel::privs::{
-	bool_to_sysresult, INVALID_FILE_ATTRIBUTES, MAX_COMPUTERNAME_LENGTH,
-	MAX_PATH, parse_multi_z_str, ptr_to_sysresult, SECURITY_DESCRIPTOR_REVISION,
+	ACL_REVISION, bool_to_sysresult, INFINITE, INVALID_FILE_ATTRIBUTES,
+	MAX_COMPUTERNAME_LENGTH, MAX_PATH, MAXIMUM_WAIT_OBJECTS, parse_multi_z_str,
+	ptr_to_sysresult, SDDL_REVISION_1, SECURITY_DESCRIPTOR_REVISION,
 };
-use crate::prelude::Handle;
+use crate::prelude::{Handle, HandleWaitable};
 
 /// [`AllocateAndInitializeSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-allocateandinitializesid)
 /// function.
@@ -88,6 +90,37 @@ pub fn AllocateAndInitializeSid(
 	}
 }
 
+/// [`ConvertSecurityDescriptorToStringSecurityDescriptor`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsecuritydescriptortostringsecuritydescriptorw)
+/// function.
+/// 
+/// Returns the SDDL string with the parts selected by `sec_info`.
+/// 
+/// Note that [`SECURITY_DESCRIPTOR`](crate::SECURITY_DESCRIPTOR) implements
+/// [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html), which
+/// calls this function with all the parts.
+#[must_use]
+pub fn ConvertSecurityDescriptorToStringSecurityDescriptor(
+	sd: &SECURITY_DESCRIPTOR,
+	sec_info: co::SECURITY_INFORMATION,
+) -> SysResult<String>
+{
+	let mut pstr = std::ptr::null_mut() as *mut u16;
+	bool_to_sysresult(
+		unsafe {
+			kernel::ffi::ConvertSecurityDescriptorToStringSecurityDescriptorW(
+				sd as *const _ as _,
+				SDDL_REVISION_1,
+				sec_info.0,
+				&mut pstr,
+				std::ptr::null_mut(),
+			)
+		},
+	)?;
+	let sddl = WString::from_wchars_nullt(pstr).to_string();
+	let _ = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(pstr as _)) }; // free returned pointer
+	Ok(sddl)
+}
+
 /// [`ConvertSidToStringSid`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsidtostringsidw)
 /// function.
 /// 
@@ -108,6 +141,54 @@ pub fn ConvertSidToStringSid(sid: &SID) -> SysResult<String> {
 	Ok(name)
 }
 
+/// [`ConvertStringSecurityDescriptorToSecurityDescriptor`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertstringsecuritydescriptortosecuritydescriptorw)
+/// function.
+/// 
+/// # Examples
+/// 
+/// Create a file which can be accessed only by SYSTEM and the Administrators
+/// group:
+/// 
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, ConvertStringSecurityDescriptorToSecurityDescriptor};
+/// use winsafe::{HFILE, SECURITY_ATTRIBUTES};
+/// 
+/// let mut sd = ConvertStringSecurityDescriptorToSecurityDescriptor(
+///     "D:P(A;;GA;;;SY)(A;;GA;;;BA)")?;
+/// 
+/// let mut sa = SECURITY_ATTRIBUTES::default();
+/// sa.set_lpSecurityDescriptor(Some(&mut sd));
+/// 
+/// let (hfile, _) = HFILE::CreateFile(
+///     "C:\\Temp\\secret.txt",
+///     co::GENERIC::READ | co::GENERIC::WRITE,
+///     co::FILE_SHARE::NoValue,
+///     Some(&mut sa),
+///     co::DISPOSITION::CREATE_NEW,
+///     co::FILE_ATTRIBUTE::NORMAL,
+///     None,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn ConvertStringSecurityDescriptorToSecurityDescriptor(
+	str_sd: &str,
+) -> SysResult<LocalFreeSecurityDescriptorGuard>
+{
+	let mut psd = std::ptr::null_mut();
+	unsafe {
+		bool_to_sysresult(
+			kernel::ffi::ConvertStringSecurityDescriptorToSecurityDescriptorW(
+				WString::from_str(str_sd).as_ptr(),
+				SDDL_REVISION_1,
+				&mut psd,
+				std::ptr::null_mut(),
+			),
+		).map(|_| LocalFreeSecurityDescriptorGuard::new(HLOCAL::from_ptr(psd)))
+	}
+}
+
 /// [`ConvertStringSidToSid`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertstringsidtosidw)
 /// function.
 #[must_use]
@@ -639,12 +720,96 @@ pub fn GetLocalTime(st: &mut SYSTEMTIME) {
 	unsafe { kernel::ffi::GetLocalTime(st as *mut _ as _) }
 }
 
+/// [`GetNamedSecurityInfo`](https://learn.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-getnamedsecurityinfow)
+/// function.
+/// 
+/// Returns the security descriptor with the parts selected by `sec_info`,
+/// which can be read with its methods.
+/// 
+/// # Examples
+/// 
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, GetNamedSecurityInfo};
+/// 
+/// let sd = GetNamedSecurityInfo(
+///     "C:\\Temp\\foo.txt",
+///     co::SE_OBJECT_TYPE::FILE_OBJECT,
+///     co::SECURITY_INFORMATION::OWNER | co::SECURITY_INFORMATION::DACL,
+/// )?;
+/// 
+/// println!("{}", sd); // SDDL string
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn GetNamedSecurityInfo(
+	object_name: &str,
+	object_type: co::SE_OBJECT_TYPE,
+	sec_info: co::SECURITY_INFORMATION,
+) -> SysResult<LocalFreeSecurityDescriptorGuard>
+{
+	let mut psd = std::ptr::null_mut();
+	match co::ERROR(
+		unsafe {
+			kernel::ffi::GetNamedSecurityInfoW(
+				WString::from_str(object_name).as_ptr(),
+				object_type.0,
+				sec_info.0,
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				&mut psd,
+			)
+		},
+	) {
+		co::ERROR::SUCCESS => Ok(unsafe {
+			LocalFreeSecurityDescriptorGuard::new(HLOCAL::from_ptr(psd))
+		}),
+		err => Err(err),
+	}
+}
+
 /// [`GetNativeSystemInfo`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getnativesysteminfo)
 /// function.
 pub fn GetNativeSystemInfo(si: &mut SYSTEM_INFO) {
 	unsafe { kernel::ffi::GetNativeSystemInfo(si as *mut _ as _) }
 }
 
+/// [`GetSecurityInfo`](https://learn.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-getsecurityinfo)
+/// function.
+/// 
+/// Returns the security descriptor with the parts selected by `sec_info`,
+/// which can be read with its methods.
+#[must_use]
+pub fn GetSecurityInfo(
+	handle: &impl Handle,
+	object_type: co::SE_OBJECT_TYPE,
+	sec_info: co::SECURITY_INFORMATION,
+) -> SysResult<LocalFreeSecurityDescriptorGuard>
+{
+	let mut psd = std::ptr::null_mut();
+	match co::ERROR(
+		unsafe {
+			kernel::ffi::GetSecurityInfo(
+				handle.as_ptr(),
+				object_type.0,
+				sec_info.0,
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				&mut psd,
+			)
+		},
+	) {
+		co::ERROR::SUCCESS => Ok(unsafe {
+			LocalFreeSecurityDescriptorGuard::new(HLOCAL::from_ptr(psd))
+		}),
+		err => Err(err),
+	}
+}
+
 /// [`GetSidLengthRequired`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsidlengthrequired)
 /// function.
 #[must_use]
@@ -933,6 +1098,26 @@ pub const fn HIWORD(v: u32) -> u16 {
 	(v >> 16 & 0xffff) as _
 }
 
+/// [`InitializeAcl`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializeacl)
+/// function.
+/// 
+/// Returns an empty [`ACL`](crate::ACL), which grows as access control
+/// entries are added with
+/// [`AclGuard::add_ace`](crate::guard::AclGuard::add_ace).
+#[must_use]
+pub fn InitializeAcl() -> SysResult<AclGuard> {
+	let mut raw = vec![0u32; std::mem::size_of::<ACL>() / std::mem::size_of::<u32>()];
+	unsafe {
+		bool_to_sysresult(
+			kernel::ffi::InitializeAcl(
+				raw.as_mut_ptr() as _,
+				std::mem::size_of::<ACL>() as _,
+				ACL_REVISION,
+			),
+		).map(|_| AclGuard::new(raw))
+	}
+}
+
 /// [`InitializeSecurityDescriptor`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializesecuritydescriptor)
 /// function.
 /// 
@@ -1217,6 +1402,65 @@ pub fn LookupAccountSid(
 	).map(|_| (account_buf.to_string(), domain_buf.to_string(), sid_name_use))
 }
 
+/// [`LookupPrivilegeName`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-lookupprivilegenamew)
+/// function.
+#[must_use]
+pub fn LookupPrivilegeName(system_name: Option<&str>, luid: LUID) -> SysResult<String> {
+	let mut name_sz = u32::default();
+
+	unsafe {
+		kernel::ffi::LookupPrivilegeNameW( // retrieve needed buffer size
+			WString::from_opt_str(system_name).as_ptr(),
+			&luid as *const _ as _,
+			std::ptr::null_mut(),
+			&mut name_sz,
+		);
+	}
+	let get_size_err = GetLastError();
+	if get_size_err != co::ERROR::INSUFFICIENT_BUFFER {
+		return Err(get_size_err);
+	}
+
+	let mut name_buf = WString::new_alloc_buf(name_sz as _);
+
+	bool_to_sysresult(
+		unsafe {
+			kernel::ffi::LookupPrivilegeNameW(
+				WString::from_opt_str(system_name).as_ptr(),
+				&luid as *const _ as _,
+				name_buf.as_mut_ptr(),
+				&mut name_sz,
+			)
+		},
+	).map(|_| name_buf.to_string())
+}
+
+/// [`LookupPrivilegeValue`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-lookupprivilegevaluew)
+/// function.
+/// 
+/// # Examples
+/// 
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, LookupPrivilegeValue};
+/// 
+/// let luid = LookupPrivilegeValue(None, "SeBackupPrivilege")?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn LookupPrivilegeValue(system_name: Option<&str>, name: &str) -> SysResult<LUID> {
+	let mut luid = LUID::new(0, 0);
+	bool_to_sysresult(
+		unsafe {
+			kernel::ffi::LookupPrivilegeValueW(
+				WString::from_opt_str(system_name).as_ptr(),
+				WString::from_str(name).as_ptr(),
+				&mut luid as *mut _ as _,
+			)
+		},
+	).map(|_| luid)
+}
+
 /// [`LOWORD`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/ms632659(v=vs.85))
 /// function. Originally a macro.
 #[must_use]
@@ -1391,12 +1635,137 @@ pub fn SetCurrentDirectory(path_name: &str) -> SysResult<()> {
 	)
 }
 
+/// [`SetHandleInformation`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-sethandleinformation)
+/// function.
+///
+/// # Examples
+///
+/// Making a handle inheritable:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HPIPE, SetHandleInformation};
+///
+/// let (hread, hwrite) = HPIPE::CreatePipe(None, 0)?;
+///
+/// SetHandleInformation(
+///     &*hread,
+///     co::HANDLE_FLAG::INHERIT,
+///     co::HANDLE_FLAG::INHERIT,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn SetHandleInformation(
+	handle: &impl Handle,
+	mask: co::HANDLE_FLAG,
+	flags: co::HANDLE_FLAG,
+) -> SysResult<()>
+{
+	bool_to_sysresult(
+		unsafe {
+			kernel::ffi::SetHandleInformation(handle.as_ptr(), mask.0, flags.0)
+		},
+	)
+}
+
 /// [`SetLastError`](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setlasterror)
 /// function.
 pub fn SetLastError(err_code: co::ERROR) {
 	unsafe { kernel::ffi::SetLastError(err_code.0) }
 }
 
+/// [`SetNamedSecurityInfo`](https://learn.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-setnamedsecurityinfow)
+/// function.
+/// 
+/// Only the parts selected by `sec_info` are set.
+/// 
+/// # Examples
+/// 
+/// Replace the DACL of a file, so only the Administrators group can access it:
+/// 
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, Ace, CreateWellKnownSid, InitializeAcl};
+/// use winsafe::SetNamedSecurityInfo;
+/// 
+/// let admins = CreateWellKnownSid(
+///     co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators, None)?;
+/// 
+/// let mut dacl = InitializeAcl()?;
+/// dacl.add_ace(&Ace::allow(co::ACE::NoValue, co::GENERIC::ALL.into(), &admins)?)?;
+/// 
+/// SetNamedSecurityInfo(
+///     "C:\\Temp\\foo.txt",
+///     co::SE_OBJECT_TYPE::FILE_OBJECT,
+///     co::SECURITY_INFORMATION::DACL
+///         | co::SECURITY_INFORMATION::PROTECTED_DACL, // don't inherit
+///     None,
+///     None,
+///     Some(&dacl),
+///     None,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn SetNamedSecurityInfo(
+	object_name: &str,
+	object_type: co::SE_OBJECT_TYPE,
+	sec_info: co::SECURITY_INFORMATION,
+	owner: Option<&SID>,
+	group: Option<&SID>,
+	dacl: Option<&ACL>,
+	sacl: Option<&ACL>,
+) -> SysResult<()>
+{
+	match co::ERROR(
+		unsafe {
+			kernel::ffi::SetNamedSecurityInfoW(
+				WString::from_str(object_name).as_ptr(),
+				object_type.0,
+				sec_info.0,
+				owner.map_or(std::ptr::null(), |p| p as *const _ as _),
+				group.map_or(std::ptr::null(), |p| p as *const _ as _),
+				dacl.map_or(std::ptr::null(), |p| p as *const _ as _),
+				sacl.map_or(std::ptr::null(), |p| p as *const _ as _),
+			)
+		},
+	) {
+		co::ERROR::SUCCESS => Ok(()),
+		err => Err(err),
+	}
+}
+
+/// [`SetSecurityInfo`](https://learn.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-setsecurityinfo)
+/// function.
+/// 
+/// Only the parts selected by `sec_info` are set.
+pub fn SetSecurityInfo(
+	handle: &impl Handle,
+	object_type: co::SE_OBJECT_TYPE,
+	sec_info: co::SECURITY_INFORMATION,
+	owner: Option<&SID>,
+	group: Option<&SID>,
+	dacl: Option<&ACL>,
+	sacl: Option<&ACL>,
+) -> SysResult<()>
+{
+	match co::ERROR(
+		unsafe {
+			kernel::ffi::SetSecurityInfo(
+				handle.as_ptr(),
+				object_type.0,
+				sec_info.0,
+				owner.map_or(std::ptr::null(), |p| p as *const _ as _),
+				group.map_or(std::ptr::null(), |p| p as *const _ as _),
+				dacl.map_or(std::ptr::null(), |p| p as *const _ as _),
+				sacl.map_or(std::ptr::null(), |p| p as *const _ as _),
+			)
+		},
+	) {
+		co::ERROR::SUCCESS => Ok(()),
+		err => Err(err),
+	}
+}
+
 /// [`SetThreadStackGuarantee`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadstackguarantee)
 /// function.
 /// 
@@ -1488,6 +1857,73 @@ pub fn VerSetConditionMask(
 	}
 }
 
+/// [`WaitForMultipleObjects`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitformultipleobjects)
+/// function.
+///
+/// If `milliseconds` is `None`, waits indefinitely.
+///
+/// Returns the wait result and the zero-based index of the object which
+/// satisfied the wait; the index is meaningful only when the result is
+/// [`WAIT::OBJECT_0`](crate::co::WAIT::OBJECT_0) or
+/// [`WAIT::ABANDONED`](crate::co::WAIT::ABANDONED).
+///
+/// # Panics
+///
+/// Panics if `handles` has more than 64 elements.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HEVENT, ProcessBuilder, WaitForMultipleObjects};
+///
+/// let child = ProcessBuilder::new("C:\\Windows\\notepad.exe").spawn()?;
+/// let hev_quit = HEVENT::CreateEvent(None, false, false, None)?;
+///
+/// let (res, idx) = WaitForMultipleObjects(
+///     &[child.hprocess(), &*hev_quit], false, None)?;
+///
+/// match (res, idx) {
+///     (co::WAIT::OBJECT_0, 0) => println!("Notepad closed."),
+///     (co::WAIT::OBJECT_0, 1) => println!("Quit signaled."),
+///     _ => {},
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn WaitForMultipleObjects(
+	handles: &[&dyn HandleWaitable],
+	wait_all: bool,
+	milliseconds: Option<u32>,
+) -> SysResult<(co::WAIT, u32)>
+{
+	if handles.len() > MAXIMUM_WAIT_OBJECTS {
+		panic!("You can wait for up to {} objects.", MAXIMUM_WAIT_OBJECTS);
+	}
+
+	let raw_handles = handles.iter()
+		.map(|h| h.waitable_ptr())
+		.collect::<Vec<_>>();
+
+	let ret = unsafe {
+		kernel::ffi::WaitForMultipleObjects(
+			raw_handles.len() as _,
+			raw_handles.as_ptr(),
+			wait_all as _,
+			milliseconds.unwrap_or(INFINITE),
+		)
+	};
+
+	let num = handles.len() as u32;
+	match co::WAIT(ret) {
+		co::WAIT::FAILED => Err(GetLastError()),
+		co::WAIT::TIMEOUT => Ok((co::WAIT::TIMEOUT, 0)),
+		_ if ret < co::WAIT::OBJECT_0.0 + num => Ok((co::WAIT::OBJECT_0, ret - co::WAIT::OBJECT_0.0)),
+		_ if ret >= co::WAIT::ABANDONED.0 && ret < co::WAIT::ABANDONED.0 + num =>
+			Ok((co::WAIT::ABANDONED, ret - co::WAIT::ABANDONED.0)),
+		wait => Ok((wait, 0)),
+	}
+}
+
 /// [`WideCharToMultiByte`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-widechartomultibyte)
 /// function.
 ///
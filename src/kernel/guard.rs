@@ -2,10 +2,225 @@ use std::ops::{Deref, DerefMut};
 
 use crate::{co, kernel};
 use crate::kernel::decl::{
-	HFILEMAPVIEW, HFINDFILE, HGLOBAL, HHEAPMEM, HHEAPOBJ, HIDWORD, HINSTANCE,
-	HKEY, HLOCAL, HUPDATERSRC, LODWORD, PROCESS_INFORMATION, SID,
+	Ace, ACL, GetLengthSid, HACCESSTOKEN, HFILEMAPVIEW, HFINDFILE, HGLOBAL,
+	HHEAPMEM, HHEAPOBJ, HIDWORD, HINSTANCE, HKEY, HLOCAL, HUPDATERSRC, LODWORD,
+	LookupPrivilegeValue, LUID_AND_ATTRIBUTES, PROCESS_INFORMATION,
+	SECURITY_DESCRIPTOR, SID, SysResult,
 };
-use crate::prelude::{Handle, kernel_Hfile, kernel_Hglobal, kernel_Hheapobj};
+use crate::kernel::privs::bool_to_sysresult;
+use crate::prelude::{
+	Handle, kernel_Haccesstoken, kernel_Hfile, kernel_Hglobal, kernel_Hheapobj,
+	NativeBitflag,
+};
+
+/// Owns an [`ACL`](crate::ACL), whose memory is kept in a buffer which grows
+/// as access control entries are added.
+///
+/// Created with [`InitializeAcl`](crate::InitializeAcl), or copied from an
+/// existing `ACL` with
+/// [`From`](https://doc.rust-lang.org/std/convert/trait.From.html).
+///
+/// # Examples
+///
+/// Copy the DACL of a file and insert an entry denying write access to
+/// Everyone, before the allowing ones:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, Ace, CreateWellKnownSid, GetNamedSecurityInfo};
+/// use winsafe::{SetNamedSecurityInfo};
+/// use winsafe::guard::AclGuard;
+///
+/// let file = "C:\\Temp\\foo.txt";
+///
+/// let sd = GetNamedSecurityInfo(
+///     file,
+///     co::SE_OBJECT_TYPE::FILE_OBJECT,
+///     co::SECURITY_INFORMATION::DACL,
+/// )?;
+///
+/// let mut dacl = match sd.Dacl()? {
+///     Some(Some(dacl)) => AclGuard::from(dacl),
+///     _ => return Ok(()), // no DACL or NULL DACL, not handled here
+/// };
+///
+/// let everyone = CreateWellKnownSid(co::WELL_KNOWN_SID_TYPE::World, None)?;
+/// dacl.insert_ace(0, &Ace::deny(co::ACE::NoValue, co::GENERIC::WRITE.into(), &everyone)?)?;
+///
+/// SetNamedSecurityInfo(
+///     file,
+///     co::SE_OBJECT_TYPE::FILE_OBJECT,
+///     co::SECURITY_INFORMATION::DACL,
+///     None,
+///     None,
+///     Some(&dacl),
+///     None,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct AclGuard {
+	raw: Vec<u32>, // ACLs must be DWORD aligned
+}
+
+impl Deref for AclGuard {
+	type Target = ACL;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*(self.raw.as_ptr() as *const _) }
+	}
+}
+
+impl DerefMut for AclGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { &mut *(self.raw.as_mut_ptr() as *mut _) }
+	}
+}
+
+impl Clone for AclGuard {
+	fn clone(&self) -> Self {
+		Self { raw: self.raw.clone() }
+	}
+}
+
+impl From<&ACL> for AclGuard {
+	fn from(acl: &ACL) -> Self {
+		let num_dwords = (acl.AclSize as usize).div_ceil(std::mem::size_of::<u32>());
+		let mut raw = vec![0u32; num_dwords];
+		unsafe {
+			std::ptr::copy_nonoverlapping(
+				acl as *const _ as *const u8,
+				raw.as_mut_ptr() as *mut u8,
+				acl.AclSize as _,
+			);
+		}
+		Self { raw }
+	}
+}
+
+impl AclGuard {
+	/// Constructs a new guard by taking ownership of the data.
+	///
+	/// # Safety
+	///
+	/// Be sure the data is an initialized [`ACL`](crate::ACL) structure, whose
+	/// `AclSize` matches the buffer size.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(raw: Vec<u32>) -> Self {
+		Self { raw }
+	}
+
+	/// Appends an access control entry, growing the buffer as needed.
+	///
+	/// Depending on the entry type, calls
+	/// [`AddAccessAllowedAceEx`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessallowedaceex),
+	/// [`AddAccessDeniedAceEx`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessdeniedaceex),
+	/// [`AddAuditAccessAceEx`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addauditaccessaceex)
+	/// or
+	/// [`AddMandatoryAce`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addmandatoryace).
+	/// Any other type returns an
+	/// [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) error.
+	///
+	/// Note that the system expects the denying entries to come before the
+	/// allowing ones, so you may want to add them first, or use
+	/// [`insert_ace`](crate::guard::AclGuard::insert_ace).
+	pub fn add_ace(&mut self, ace: &Ace) -> SysResult<()> {
+		if !Ace::is_basic(ace.ace_type) || ace.ace_type == co::ACE_TYPE::SYSTEM_ALARM {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		}
+
+		let old_sz = self.AclSize;
+		let new_sz = old_sz as usize + 8 + GetLengthSid(&ace.sid) as usize; // header + mask + SID
+		if new_sz > 0xfffc { // max DWORD-aligned u16
+			return Err(co::ERROR::ALLOTTED_SPACE_EXCEEDED);
+		}
+		self.raw.resize(new_sz / std::mem::size_of::<u32>(), 0); // SIDs are DWORD-sized
+		self.AclSize = new_sz as _;
+
+		let pacl = self.raw.as_mut_ptr() as _;
+		let rev = self.AclRevision as u32;
+		let psid = &*ace.sid as *const _ as _;
+
+		let res = bool_to_sysresult(
+			unsafe {
+				match ace.ace_type {
+					co::ACE_TYPE::ACCESS_ALLOWED => kernel::ffi::AddAccessAllowedAceEx(
+						pacl, rev, ace.flags.0 as _, ace.mask, psid),
+					co::ACE_TYPE::ACCESS_DENIED => kernel::ffi::AddAccessDeniedAceEx(
+						pacl, rev, ace.flags.0 as _, ace.mask, psid),
+					co::ACE_TYPE::SYSTEM_AUDIT => kernel::ffi::AddAuditAccessAceEx(
+						pacl, rev,
+						(ace.flags & !(co::ACE::SUCCESSFUL_ACCESS | co::ACE::FAILED_ACCESS)).0 as _,
+						ace.mask, psid,
+						ace.flags.has(co::ACE::SUCCESSFUL_ACCESS) as _,
+						ace.flags.has(co::ACE::FAILED_ACCESS) as _),
+					_ => kernel::ffi::AddMandatoryAce(
+						pacl, rev, ace.flags.0 as _, ace.mask, psid),
+				}
+			},
+		);
+
+		if res.is_err() {
+			self.AclSize = old_sz; // restore the original size
+			self.raw.truncate(old_sz as usize / std::mem::size_of::<u32>());
+		}
+		res
+	}
+
+	/// Inserts an access control entry at the given index, growing the buffer
+	/// as needed. An index past the last entry appends it.
+	///
+	/// The entry is appended with
+	/// [`add_ace`](crate::guard::AclGuard::add_ace), then moved to the index
+	/// with
+	/// [`AddAce`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addace).
+	pub fn insert_ace(&mut self, index: u32, ace: &Ace) -> SysResult<()> {
+		self.add_ace(ace)?;
+		let last = self.AceCount as u32 - 1;
+		if index >= last {
+			return Ok(());
+		}
+
+		let mut pace = std::ptr::null_mut();
+		if let Err(e) = bool_to_sysresult(
+			unsafe { kernel::ffi::GetAce(self.raw.as_ptr() as _, last, &mut pace) },
+		) {
+			self.remove_ace(last)?;
+			return Err(e);
+		}
+		let ace_sz = unsafe { ((pace as *const u8).add(2) as *const u16).read_unaligned() }; // AceSize in the header
+		let ace_buf = unsafe {
+			std::slice::from_raw_parts(pace as *const u8, ace_sz as _).to_vec()
+		};
+
+		self.remove_ace(last)?; // the ACL keeps its size, so there's room for AddAce
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::AddAce(
+					self.raw.as_mut_ptr() as _,
+					self.AclRevision as _,
+					index,
+					ace_buf.as_ptr() as _,
+					ace_sz as _,
+				)
+			},
+		)
+	}
+
+	/// Removes the access control entry at the given index with
+	/// [`DeleteAce`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-deleteace).
+	///
+	/// The buffer is not shrunk.
+	pub fn remove_ace(&mut self, index: u32) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { kernel::ffi::DeleteAce(self.raw.as_mut_ptr() as _, index) },
+		)
+	}
+}
+
+//------------------------------------------------------------------------------
 
 /// RAII implementation for a [`Handle`](crate::prelude::Handle) which
 /// automatically calls
@@ -141,6 +356,76 @@ impl CloseHandlePiGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for [`HSC`](crate::HSC) and
+/// [`HSERVICE`](crate::HSERVICE) which automatically calls
+/// [`CloseServiceHandle`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-closeservicehandle)
+/// when the object goes out of scope.
+pub struct CloseServiceHandleGuard<T>
+	where T: Handle,
+{
+	handle: T,
+}
+
+impl<T> Drop for CloseServiceHandleGuard<T>
+	where T: Handle,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.handle.as_opt() {
+			unsafe { kernel::ffi::CloseServiceHandle(h.as_ptr()); } // ignore errors
+		}
+	}
+}
+
+impl<T> Deref for CloseServiceHandleGuard<T>
+	where T: Handle,
+{
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.handle
+	}
+}
+
+impl<T> DerefMut for CloseServiceHandleGuard<T>
+	where T: Handle,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.handle
+	}
+}
+
+impl<T> CloseServiceHandleGuard<T>
+	where T: Handle,
+{
+	/// Constructs the guard by taking ownership of the handle.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the handle must be freed with
+	/// [`CloseServiceHandle`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-closeservicehandle)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(handle: T) -> Self {
+		Self { handle }
+	}
+
+	/// Ejects the underlying handle, leaving a
+	/// [`Handle::INVALID`](crate::prelude::Handle::INVALID) in its place.
+	///
+	/// Since the internal handle will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> T {
+		std::mem::replace(&mut self.handle, T::INVALID)
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation [`HUPDATERSRC`](crate::HUPDATERSRC) which automatically
 /// calls
 /// [`EndUpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-endupdateresourcew)
@@ -466,6 +751,52 @@ handle_guard! { LocalFreeGuard: HLOCAL;
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for [`SECURITY_DESCRIPTOR`](crate::SECURITY_DESCRIPTOR)
+/// which automatically calls
+/// [`LocalFree`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree)
+/// when the object goes out of scope.
+pub struct LocalFreeSecurityDescriptorGuard {
+	pmem: LocalFreeGuard,
+}
+
+impl Deref for LocalFreeSecurityDescriptorGuard {
+	type Target = SECURITY_DESCRIPTOR;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*(self.pmem.as_ptr() as *mut _) }
+	}
+}
+
+impl DerefMut for LocalFreeSecurityDescriptorGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { &mut *(self.pmem.as_ptr() as *mut _) }
+	}
+}
+
+impl std::fmt::Display for LocalFreeSecurityDescriptorGuard {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		self.deref().fmt(f) // delegate the underlying SECURITY_DESCRIPTOR
+	}
+}
+
+impl LocalFreeSecurityDescriptorGuard {
+	/// Constructs the guard by taking ownership of the handle.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the pointer is an [`HLOCAL`](crate::HLOCAL) handle pointing to a
+	/// [`SECURITY_DESCRIPTOR`](crate::SECURITY_DESCRIPTOR) memory block.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(pmem: HLOCAL) -> Self {
+		Self { pmem: LocalFreeGuard::new(pmem) }
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`SID`](crate::SID) which automatically calls
 /// [`LocalFree`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree)
 /// when the object goes out of scope.
@@ -505,6 +836,75 @@ impl LocalFreeSidGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for the privileges of an
+/// [`HACCESSTOKEN`](crate::HACCESSTOKEN), which enables them upon creation, and
+/// automatically restores their previous state with
+/// [`AdjustTokenPrivileges`](crate::prelude::kernel_Haccesstoken::AdjustTokenPrivileges)
+/// when the object goes out of scope.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HPROCESS};
+/// use winsafe::guard::PrivilegeGuard;
+///
+/// let htoken = HPROCESS::GetCurrentProcess().OpenProcessToken(
+///     co::TOKEN::ADJUST_PRIVILEGES | co::TOKEN::QUERY)?;
+///
+/// {
+///     let _backup = PrivilegeGuard::enable(
+///         &htoken, &["SeBackupPrivilege", "SeRestorePrivilege"])?;
+///
+///     // read and write files regardless of their DACLs...
+/// } // privileges restored here
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct PrivilegeGuard<'a> {
+	htoken: &'a HACCESSTOKEN,
+	prev_state: Vec<LUID_AND_ATTRIBUTES>,
+}
+
+impl<'a> Drop for PrivilegeGuard<'a> {
+	fn drop(&mut self) {
+		if !self.prev_state.is_empty() { // privileges already enabled are left untouched
+			let _ = self.htoken.AdjustTokenPrivileges(Some(&self.prev_state));
+		}
+	}
+}
+
+impl<'a> PrivilegeGuard<'a> {
+	/// Enables the given privileges, whose names are resolved with
+	/// [`LookupPrivilegeValue`](crate::LookupPrivilegeValue).
+	///
+	/// The token must have been opened with
+	/// [`TOKEN::ADJUST_PRIVILEGES`](crate::co::TOKEN::ADJUST_PRIVILEGES) and
+	/// [`TOKEN::QUERY`](crate::co::TOKEN::QUERY) access rights. If the token
+	/// doesn't hold all the privileges, none is enabled, and
+	/// [`ERROR::NOT_ALL_ASSIGNED`](crate::co::ERROR::NOT_ALL_ASSIGNED) is
+	/// returned.
+	#[must_use]
+	pub fn enable(
+		htoken: &'a HACCESSTOKEN,
+		privilege_names: &[&str],
+	) -> SysResult<Self>
+	{
+		let new_state = privilege_names.iter()
+			.map(|name| Ok(LUID_AND_ATTRIBUTES::new(
+				LookupPrivilegeValue(None, name)?,
+				co::SE_PRIVILEGE::ENABLED,
+			)))
+			.collect::<SysResult<Vec<_>>>()?;
+
+		Ok(Self {
+			htoken,
+			prev_state: htoken.AdjustTokenPrivileges(Some(&new_state))?,
+		})
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`HKEY`](crate::HKEY) which automatically calls
 /// [`RegCloseKey`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regclosekey)
 /// when the object goes out of scope.
@@ -573,6 +973,12 @@ pub struct SidGuard {
 	raw: Vec<u8>,
 }
 
+impl Clone for SidGuard {
+	fn clone(&self) -> Self {
+		Self { raw: self.raw.clone() }
+	}
+}
+
 impl Deref for SidGuard {
 	type Target = SID;
 
@@ -676,3 +1082,74 @@ handle_guard! { UnmapViewOfFileGuard: HFILEMAPVIEW;
 	/// [`UnmapViewOfFile`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-unmapviewoffile)
 	/// when the object goes out of scope.
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::kernel::decl::{CreateWellKnownSid, InitializeAcl};
+
+	fn sid(ty: co::WELL_KNOWN_SID_TYPE) -> SidGuard {
+		CreateWellKnownSid(ty, None).unwrap()
+	}
+
+	fn summary(acl: &ACL) -> Vec<(co::ACE_TYPE, co::ACE, u32, String)> {
+		acl.aces()
+			.map(|ace| ace.unwrap())
+			.map(|ace| (ace.ace_type, ace.flags, ace.mask, ace.sid.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn acl_insert_and_read_back() {
+		let mut acl = InitializeAcl().unwrap();
+		let world = sid(co::WELL_KNOWN_SID_TYPE::World);
+		let system = sid(co::WELL_KNOWN_SID_TYPE::LocalSystem);
+		let admins = sid(co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators);
+
+		acl.add_ace(&Ace::allow(co::ACE::OBJECT_INHERIT, co::GENERIC::READ.0, &world).unwrap()).unwrap();
+		acl.add_ace(&Ace::allow(co::ACE::NoValue, co::GENERIC::ALL.0, &admins).unwrap()).unwrap();
+		acl.insert_ace(0, &Ace::deny(co::ACE::NoValue, co::GENERIC::WRITE.0, &world).unwrap()).unwrap(); // front
+		acl.insert_ace(2, // middle
+			&Ace::allow(co::ACE::OBJECT_INHERIT | co::ACE::CONTAINER_INHERIT, co::GENERIC::ALL.0, &system).unwrap()).unwrap();
+		acl.insert_ace(99, &Ace::allow(co::ACE::INHERIT_ONLY, 0x1234, &system).unwrap()).unwrap(); // past the end, appended
+
+		assert_eq!(acl.AceCount, 5);
+		assert_eq!(summary(&acl), [
+			(co::ACE_TYPE::ACCESS_DENIED, co::ACE::NoValue, co::GENERIC::WRITE.0, "S-1-1-0".to_owned()),
+			(co::ACE_TYPE::ACCESS_ALLOWED, co::ACE::OBJECT_INHERIT, co::GENERIC::READ.0, "S-1-1-0".to_owned()),
+			(co::ACE_TYPE::ACCESS_ALLOWED, co::ACE::OBJECT_INHERIT | co::ACE::CONTAINER_INHERIT,
+				co::GENERIC::ALL.0, "S-1-5-18".to_owned()),
+			(co::ACE_TYPE::ACCESS_ALLOWED, co::ACE::NoValue, co::GENERIC::ALL.0, "S-1-5-32-544".to_owned()),
+			(co::ACE_TYPE::ACCESS_ALLOWED, co::ACE::INHERIT_ONLY, 0x1234, "S-1-5-18".to_owned()),
+		]);
+
+		acl.remove_ace(1).unwrap();
+		let copy = AclGuard::from(&*acl);
+		assert_eq!(summary(&copy).len(), 4);
+		assert_eq!(summary(&copy)[1].3, "S-1-5-18");
+	}
+
+	#[test]
+	fn acl_audit_and_unsupported() {
+		let mut acl = InitializeAcl().unwrap();
+		let world = sid(co::WELL_KNOWN_SID_TYPE::World);
+
+		let audit = Ace::audit(co::ACE::FAILED_ACCESS | co::ACE::OBJECT_INHERIT, 0x10, &world).unwrap();
+		acl.add_ace(&audit).unwrap();
+		let alarm = Ace::with_type(co::ACE_TYPE::SYSTEM_ALARM, co::ACE::NoValue, 0, &world).unwrap();
+		assert_eq!(acl.insert_ace(0, &alarm).err(), Some(co::ERROR::NOT_SUPPORTED));
+
+		assert_eq!(summary(&acl), [(co::ACE_TYPE::SYSTEM_AUDIT,
+			co::ACE::FAILED_ACCESS | co::ACE::OBJECT_INHERIT, 0x10, "S-1-1-0".to_owned())]);
+	}
+
+	#[test]
+	fn ace_clone() {
+		let ace = Ace::deny(co::ACE::CONTAINER_INHERIT, 0x42,
+			&sid(co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators)).unwrap();
+		let copy = ace.clone();
+		drop(ace);
+		assert_eq!((copy.ace_type, copy.flags, copy.mask), (co::ACE_TYPE::ACCESS_DENIED, co::ACE::CONTAINER_INHERIT, 0x42));
+		assert_eq!(copy.sid.to_string(), "S-1-5-32-544");
+	}
+}
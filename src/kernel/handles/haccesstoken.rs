@@ -1,7 +1,9 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::{co, kernel};
-use crate::kernel::decl::{GetLastError, SysResult};
+use crate::kernel::decl::{
+	CopySid, GetLastError, LUID_AND_ATTRIBUTES, SID, SysResult, TokenInfo,
+};
 use crate::kernel::guard::CloseHandleGuard;
 use crate::kernel::privs::bool_to_sysresult;
 use crate::prelude::Handle;
@@ -23,6 +25,123 @@ impl kernel_Haccesstoken for HACCESSTOKEN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Haccesstoken: Handle {
+	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
+	/// method.
+	///
+	/// If `new_state` is `None`, all privileges are disabled. Returns the
+	/// previous state of the changed privileges, which can be passed back to
+	/// this method to restore them.
+	///
+	/// If not all the privileges could be assigned, the partial changes are
+	/// reverted, and
+	/// [`ERROR::NOT_ALL_ASSIGNED`](crate::co::ERROR::NOT_ALL_ASSIGNED) is
+	/// returned.
+	///
+	/// The token must have been opened with
+	/// [`TOKEN::ADJUST_PRIVILEGES`](crate::co::TOKEN::ADJUST_PRIVILEGES) and
+	/// [`TOKEN::QUERY`](crate::co::TOKEN::QUERY) access rights. To enable a
+	/// privilege only for a scope, consider using a
+	/// [`PrivilegeGuard`](crate::guard::PrivilegeGuard).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HPROCESS, LookupPrivilegeValue, LUID_AND_ATTRIBUTES};
+	///
+	/// let htoken = HPROCESS::GetCurrentProcess().OpenProcessToken(
+	///     co::TOKEN::ADJUST_PRIVILEGES | co::TOKEN::QUERY)?;
+	///
+	/// let prev_state = htoken.AdjustTokenPrivileges(Some(&[
+	///     LUID_AND_ATTRIBUTES::new(
+	///         LookupPrivilegeValue(None, "SeShutdownPrivilege")?,
+	///         co::SE_PRIVILEGE::ENABLED,
+	///     ),
+	/// ]))?;
+	///
+	/// // do the work...
+	///
+	/// htoken.AdjustTokenPrivileges(Some(&prev_state))?; // restore
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn AdjustTokenPrivileges(&self,
+		new_state: Option<&[LUID_AND_ATTRIBUTES]>,
+	) -> SysResult<Vec<LUID_AND_ATTRIBUTES>>
+	{
+		let new_buf = new_state.map(token_privileges_to_buf);
+		let mut prev_buf = vec![0u32; 1 + new_state.map_or(0, |privs| privs.len()) * 3];
+
+		loop {
+			let mut ret_len = u32::default();
+			let ok = unsafe {
+				kernel::ffi::AdjustTokenPrivileges(
+					self.as_ptr(),
+					new_state.is_none() as _,
+					new_buf.as_ref().map_or(std::ptr::null(), |buf| buf.as_ptr() as _),
+					(prev_buf.len() * std::mem::size_of::<u32>()) as _,
+					prev_buf.as_mut_ptr() as _,
+					&mut ret_len,
+				)
+			};
+
+			if ok == 0 {
+				match GetLastError() {
+					co::ERROR::INSUFFICIENT_BUFFER => { // no privileges were changed
+						prev_buf.resize((ret_len as usize).div_ceil(std::mem::size_of::<u32>()), 0);
+						continue;
+					},
+					err => return Err(err),
+				}
+			}
+
+			let prev_state = unsafe { token_privileges_from_ptr(prev_buf.as_ptr() as _) };
+			return match GetLastError() { // set even on success
+				co::ERROR::NOT_ALL_ASSIGNED => {
+					if !prev_state.is_empty() {
+						self.AdjustTokenPrivileges(Some(&prev_state))?; // revert partial changes
+					}
+					Err(co::ERROR::NOT_ALL_ASSIGNED)
+				},
+				_ => Ok(prev_state),
+			};
+		}
+	}
+
+	/// [`CheckTokenMembership`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-checktokenmembership)
+	/// method.
+	///
+	/// The token must be an impersonation token. To check the token of the
+	/// calling thread, call this method on
+	/// [`HACCESSTOKEN::NULL`](crate::HACCESSTOKEN::NULL).
+	///
+	/// # Examples
+	///
+	/// Check whether the current user is an administrator:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CreateWellKnownSid, HACCESSTOKEN};
+	///
+	/// let admins = CreateWellKnownSid(
+	///     co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators, None)?;
+	///
+	/// let is_admin = HACCESSTOKEN::NULL.CheckTokenMembership(&admins)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CheckTokenMembership(&self, sid_to_check: &SID) -> SysResult<bool> {
+		let mut is_member = 0;
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::CheckTokenMembership(
+					self.as_ptr(),
+					sid_to_check as *const _ as _,
+					&mut is_member,
+				)
+			},
+		).map(|_| is_member != 0)
+	}
+
 	/// [`DuplicateToken`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-duplicatetoken)
 	/// method.
 	#[must_use]
@@ -56,6 +175,134 @@ pub trait kernel_Haccesstoken: Handle {
 		HACCESSTOKEN(unsafe { kernel::ffi::GetCurrentThreadEffectiveToken() })
 	}
 
+	/// [`GetTokenInformation`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-gettokeninformation)
+	/// method.
+	///
+	/// The returned [`TokenInfo`](crate::TokenInfo) variant matches the given
+	/// class. Only the classes with a `TokenInfo` variant are supported; any
+	/// other returns an
+	/// [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) error.
+	///
+	/// # Examples
+	///
+	/// Check whether the current process is elevated:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HACCESSTOKEN, TokenInfo};
+	///
+	/// let htoken = HACCESSTOKEN::GetCurrentProcessToken();
+	///
+	/// if let TokenInfo::ElevationType(ty) = htoken.GetTokenInformation(
+	///     co::TOKEN_INFORMATION_CLASS::ElevationType)?
+	/// {
+	///     match ty {
+	///         co::TOKEN_ELEVATION_TYPE::Full => println!("Elevated."),
+	///         co::TOKEN_ELEVATION_TYPE::Limited => println!("Can be elevated."),
+	///         _ => println!("Not split."),
+	///     }
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetTokenInformation(&self,
+		information_class: co::TOKEN_INFORMATION_CLASS,
+	) -> SysResult<TokenInfo>
+	{
+		#[repr(C)]
+		struct SID_AND_ATTRIBUTES {
+			Sid: *const SID,
+			Attributes: u32,
+		}
+
+		#[repr(C)]
+		struct TOKEN_GROUPS {
+			GroupCount: u32,
+			Groups: [SID_AND_ATTRIBUTES; 1],
+		}
+
+		match information_class {
+			co::TOKEN_INFORMATION_CLASS::Elevation
+			| co::TOKEN_INFORMATION_CLASS::ElevationType
+			| co::TOKEN_INFORMATION_CLASS::Groups
+			| co::TOKEN_INFORMATION_CLASS::IntegrityLevel
+			| co::TOKEN_INFORMATION_CLASS::LinkedToken
+			| co::TOKEN_INFORMATION_CLASS::Privileges
+			| co::TOKEN_INFORMATION_CLASS::SessionId
+			| co::TOKEN_INFORMATION_CLASS::User => {},
+			_ => return Err(co::ERROR::NOT_SUPPORTED),
+		}
+
+		let mut buf_sz = u32::default();
+		unsafe {
+			kernel::ffi::GetTokenInformation( // retrieve needed buffer size
+				self.as_ptr(),
+				information_class.0,
+				std::ptr::null_mut(),
+				0,
+				&mut buf_sz,
+			);
+		}
+		match GetLastError() {
+			co::ERROR::INSUFFICIENT_BUFFER | co::ERROR::BAD_LENGTH => {},
+			err => return Err(err),
+		}
+
+		let mut buf = vec![0u64; (buf_sz as usize).div_ceil(std::mem::size_of::<u64>())]; // pointer-aligned
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetTokenInformation(
+					self.as_ptr(),
+					information_class.0,
+					buf.as_mut_ptr() as _,
+					buf_sz,
+					&mut buf_sz,
+				)
+			},
+		)?;
+
+		let pbuf = buf.as_ptr() as *const u8;
+		let read_u32 = || unsafe { *(pbuf as *const u32) };
+
+		Ok(match information_class {
+			co::TOKEN_INFORMATION_CLASS::Elevation => TokenInfo::Elevation(read_u32() != 0),
+			co::TOKEN_INFORMATION_CLASS::ElevationType => TokenInfo::ElevationType(
+				co::TOKEN_ELEVATION_TYPE(read_u32())),
+			co::TOKEN_INFORMATION_CLASS::Groups => {
+				let tg = unsafe { &*(pbuf as *const TOKEN_GROUPS) };
+				let groups = unsafe {
+					std::slice::from_raw_parts(tg.Groups.as_ptr(), tg.GroupCount as _)
+				};
+				TokenInfo::Groups(
+					groups.iter()
+						.map(|sa| Ok((
+							CopySid(unsafe { &*sa.Sid })?,
+							co::SE_GROUP(sa.Attributes),
+						)))
+						.collect::<SysResult<Vec<_>>>()?,
+				)
+			},
+			co::TOKEN_INFORMATION_CLASS::IntegrityLevel => { // TOKEN_MANDATORY_LABEL
+				let sa = unsafe { &*(pbuf as *const SID_AND_ATTRIBUTES) };
+				TokenInfo::IntegrityLevel(CopySid(unsafe { &*sa.Sid })?)
+			},
+			co::TOKEN_INFORMATION_CLASS::LinkedToken => TokenInfo::LinkedToken(
+				unsafe {
+					CloseHandleGuard::new(
+						HACCESSTOKEN::from_ptr(*(pbuf as *const *mut std::ffi::c_void)),
+					)
+				},
+			),
+			co::TOKEN_INFORMATION_CLASS::Privileges => TokenInfo::Privileges(
+				unsafe { token_privileges_from_ptr(pbuf) }),
+			co::TOKEN_INFORMATION_CLASS::SessionId => TokenInfo::SessionId(read_u32()),
+			_ => { // TOKEN_USER
+				let sa = unsafe { &*(pbuf as *const SID_AND_ATTRIBUTES) };
+				TokenInfo::User(CopySid(unsafe { &*sa.Sid })?)
+			},
+		})
+	}
+
 	/// [`IsTokenRestricted`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-istokenrestricted)
 	/// method.
 	#[must_use]
@@ -69,3 +316,23 @@ pub trait kernel_Haccesstoken: Handle {
 		}
 	}
 }
+
+/// Parses a `TOKEN_PRIVILEGES` struct.
+unsafe fn token_privileges_from_ptr(ptp: *const u8) -> Vec<LUID_AND_ATTRIBUTES> {
+	let count = *(ptp as *const u32);
+	std::slice::from_raw_parts(
+		ptp.add(std::mem::size_of::<u32>()) as *const LUID_AND_ATTRIBUTES, // after PrivilegeCount
+		count as _,
+	).to_vec()
+}
+
+/// Serializes a `TOKEN_PRIVILEGES` struct.
+fn token_privileges_to_buf(privs: &[LUID_AND_ATTRIBUTES]) -> Vec<u32> {
+	let mut buf = vec![0u32; 1 + privs.len() * 3]; // PrivilegeCount + 3 u32 for each entry
+	buf[0] = privs.len() as _;
+	unsafe {
+		std::ptr::copy_nonoverlapping(
+			privs.as_ptr(), buf.as_mut_ptr().add(1) as *mut LUID_AND_ATTRIBUTES, privs.len());
+	}
+	buf
+}
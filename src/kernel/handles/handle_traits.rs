@@ -2,6 +2,10 @@
 
 use std::{fmt, hash};
 
+use crate::{co, kernel};
+use crate::kernel::decl::{GetLastError, SysResult};
+use crate::kernel::privs::INFINITE;
+
 /// A native
 /// [handle](https://learn.microsoft.com/en-us/windows/win32/sysinfo/handles-and-objects),
 /// implemented by all handle types.
@@ -102,3 +106,44 @@ pub trait Handle: Sized
 		}
 	}
 }
+
+/// A [`Handle`](crate::prelude::Handle) to a kernel object which can be waited
+/// upon, like events, mutexes, processes and threads.
+///
+/// To wait for more than one object at once, see
+/// [`WaitForMultipleObjects`](crate::WaitForMultipleObjects), which accepts
+/// handles of different types as `&dyn HandleWaitable`.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait HandleWaitable {
+	/// Returns the underlying raw pointer of the waitable object.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void;
+
+	/// [`WaitForSingleObject`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+	/// method.
+	///
+	/// If `milliseconds` is `None`, waits indefinitely.
+	fn WaitForSingleObject(&self,
+		milliseconds: Option<u32>) -> SysResult<co::WAIT>
+	{
+		match unsafe {
+			co::WAIT(
+				kernel::ffi::WaitForSingleObject(
+					self.waitable_ptr(),
+					milliseconds.unwrap_or(INFINITE),
+				),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
+}
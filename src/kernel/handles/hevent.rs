@@ -0,0 +1,134 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{SECURITY_ATTRIBUTES, SysResult, WString};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::{Handle, HandleWaitable};
+
+impl_handle! { HEVENT;
+	/// Handle to an
+	/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
+	/// Originally just a `HANDLE`.
+}
+
+// Event objects can be signaled and waited upon from any thread.
+unsafe impl Sync for HEVENT {}
+
+impl kernel_Hevent for HEVENT {}
+impl HandleWaitable for HEVENT {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HEVENT`](crate::HEVENT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hevent: Handle {
+	/// [`CreateEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw)
+	/// static method.
+	///
+	/// If a named event already exists, it is opened, and
+	/// [`GetLastError`](crate::GetLastError) will return
+	/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+	///
+	/// # Examples
+	///
+	/// Creating a named event, shared with other processes:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HEVENT;
+	///
+	/// let hevent = HEVENT::CreateEvent(
+	///     None, true, false, Some("MyAppShutdownEvent"))?;
+	///
+	/// hevent.WaitForSingleObject(None)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateEvent(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		manual_reset: bool,
+		initial_state: bool,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HEVENT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateEventW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					manual_reset as _,
+					initial_state as _,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`CreateEventEx`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventexw)
+	/// static method.
+	#[must_use]
+	fn CreateEventEx(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		name: Option<&str>,
+		flags: co::CREATE_EVENT,
+		desired_access: co::EVENT,
+	) -> SysResult<CloseHandleGuard<HEVENT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateEventExW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					WString::from_opt_str(name).as_ptr(),
+					flags.0,
+					desired_access.0,
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-openeventw)
+	/// static method.
+	#[must_use]
+	fn OpenEvent(
+		desired_access: co::EVENT,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HEVENT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenEventW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`PulseEvent`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-pulseevent)
+	/// method.
+	fn PulseEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::PulseEvent(self.as_ptr()) })
+	}
+
+	/// [`ResetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-resetevent)
+	/// method.
+	fn ResetEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::ResetEvent(self.as_ptr()) })
+	}
+
+	/// [`SetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-setevent)
+	/// method.
+	fn SetEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::SetEvent(self.as_ptr()) })
+	}
+}
@@ -29,6 +29,22 @@ impl kernel_Hfile for HFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfile: Handle {
+	/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func)
+	/// method.
+	///
+	/// If `overlapped` is `None`, all pending I/O operations issued by the
+	/// calling process for the handle are cancelled.
+	fn CancelIoEx(&self, overlapped: Option<&OVERLAPPED>) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::CancelIoEx(
+					self.as_ptr(),
+					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *const _ as _),
+				)
+			},
+		)
+	}
+
 	/// [`CreateFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createfilew)
 	/// static method.
 	///
@@ -161,6 +177,26 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`GetOverlappedResult`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresult)
+	/// method.
+	///
+	/// Returns the number of bytes transferred.
+	fn GetOverlappedResult(&self,
+		overlapped: &OVERLAPPED, wait: bool) -> SysResult<u32>
+	{
+		let mut bytes_transferred = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetOverlappedResult(
+					self.as_ptr(),
+					overlapped as *const _ as _,
+					&mut bytes_transferred,
+					wait as _,
+				)
+			},
+		).map(|_| bytes_transferred)
+	}
+
 	/// [`LockFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-lockfile)
 	/// method.
 	///
@@ -209,6 +245,52 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`ReadDirectoryChanges`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-readdirectorychangesw)
+	/// method.
+	///
+	/// The directory must be opened with
+	/// [`FILE_ATTRIBUTE::FLAG_BACKUP_SEMANTICS`](crate::co::FILE_ATTRIBUTE::FLAG_BACKUP_SEMANTICS).
+	/// The buffer must be aligned on a `u32` boundary, and it will be filled
+	/// with `FILE_NOTIFY_INFORMATION` entries, which can be parsed with
+	/// [`DirChange::parse_buffer`](crate::DirChange::parse_buffer).
+	///
+	/// Returns the number of bytes written to the buffer. For a synchronous
+	/// call, zero means the buffer overflowed, and the changes were lost.
+	///
+	/// Unless you need something specific, consider using the
+	/// [`DirWatcher`](crate::DirWatcher) high-level abstraction.
+	///
+	/// # Panics
+	///
+	/// Panics if `buffer` is not aligned on a `u32` boundary.
+	fn ReadDirectoryChanges(&self,
+		buffer: &mut [u8],
+		watch_subtree: bool,
+		notify_filter: co::FILE_NOTIFY_CHANGE,
+		overlapped: Option<&mut OVERLAPPED>,
+	) -> SysResult<u32>
+	{
+		if buffer.as_ptr() as usize % std::mem::align_of::<u32>() != 0 {
+			panic!("ReadDirectoryChanges buffer must be aligned on a u32 boundary.");
+		}
+
+		let mut bytes_returned = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::ReadDirectoryChangesW(
+					self.as_ptr(),
+					buffer.as_mut_ptr() as _,
+					buffer.len() as _,
+					watch_subtree as _,
+					notify_filter.0,
+					&mut bytes_returned,
+					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| bytes_returned)
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// method.
 	///
@@ -0,0 +1,144 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel;
+use crate::kernel::decl::{HFILE, SysResult};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{bool_to_sysresult, INFINITE, ptr_to_sysresult_handle};
+use crate::prelude::Handle;
+
+impl_handle! { HIOCP;
+	/// Handle to an
+	/// [I/O completion port](https://learn.microsoft.com/en-us/windows/win32/fileio/i-o-completion-ports).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hiocp for HIOCP {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HIOCP`](crate::HIOCP).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hiocp: Handle {
+	/// [`CreateIoCompletionPort`](https://learn.microsoft.com/en-us/windows/win32/fileio/createiocompletionport)
+	/// static method.
+	///
+	/// Creates a new completion port, not associated with any file. If
+	/// `number_of_concurrent_threads` is zero, the number of processors is
+	/// used.
+	#[must_use]
+	fn CreateIoCompletionPort(
+		number_of_concurrent_threads: u32,
+	) -> SysResult<CloseHandleGuard<HIOCP>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateIoCompletionPort(
+					HFILE::INVALID.as_ptr(),
+					std::ptr::null_mut(),
+					0,
+					number_of_concurrent_threads,
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`CreateIoCompletionPort`](https://learn.microsoft.com/en-us/windows/win32/fileio/createiocompletionport)
+	/// method.
+	///
+	/// Associates the file with this existing completion port.
+	fn CreateIoCompletionPortAssociate(&self,
+		hfile: &HFILE, completion_key: usize) -> SysResult<()>
+	{
+		ptr_to_sysresult_handle::<HIOCP>(
+			unsafe {
+				kernel::ffi::CreateIoCompletionPort(
+					hfile.as_ptr(),
+					self.as_ptr(),
+					completion_key,
+					0,
+				)
+			},
+		).map(|_| ())
+	}
+
+	/// [`GetQueuedCompletionStatus`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getqueuedcompletionstatus)
+	/// method.
+	///
+	/// Returns the number of bytes transferred, the completion key, and the
+	/// `OVERLAPPED` pointer, in this order. If `timeout_ms` is `None`, waits
+	/// indefinitely.
+	///
+	/// For job object notifications, the number of bytes is the
+	/// [`co::JOB_OBJECT_MSG`](crate::co::JOB_OBJECT_MSG), and the pointer is
+	/// the process ID.
+	///
+	/// # Examples
+	///
+	/// Waiting until all processes of a job have exited:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HIOCP, HJOB, JobObjectInfo};
+	/// use winsafe::JOBOBJECT_ASSOCIATE_COMPLETION_PORT;
+	///
+	/// let hjob = HJOB::CreateJobObject(None, None)?;
+	/// let hiocp = HIOCP::CreateIoCompletionPort(1)?;
+	///
+	/// let mut assoc = JOBOBJECT_ASSOCIATE_COMPLETION_PORT::default();
+	/// assoc.CompletionPort = unsafe { hiocp.raw_copy() };
+	/// hjob.SetInformationJobObject(&JobObjectInfo::AssociateCompletionPort(assoc))?;
+	///
+	/// // ... assign processes to the job ...
+	///
+	/// loop {
+	///     let (msg, _, _) = hiocp.GetQueuedCompletionStatus(None)?;
+	///     if co::JOB_OBJECT_MSG::from(msg) == co::JOB_OBJECT_MSG::ACTIVE_PROCESS_ZERO {
+	///         break;
+	///     }
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn GetQueuedCompletionStatus(&self,
+		timeout_ms: Option<u32>) -> SysResult<(u32, usize, usize)>
+	{
+		let mut bytes = u32::default();
+		let mut key = usize::default();
+		let mut overlapped = std::ptr::null_mut();
+
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetQueuedCompletionStatus(
+					self.as_ptr(),
+					&mut bytes,
+					&mut key,
+					&mut overlapped,
+					timeout_ms.unwrap_or(INFINITE),
+				)
+			},
+		).map(|_| (bytes, key, overlapped as _))
+	}
+
+	/// [`PostQueuedCompletionStatus`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-postqueuedcompletionstatus)
+	/// method.
+	fn PostQueuedCompletionStatus(&self,
+		bytes_transferred: u32,
+		completion_key: usize,
+		overlapped: usize,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::PostQueuedCompletionStatus(
+					self.as_ptr(),
+					bytes_transferred,
+					completion_key,
+					overlapped as _,
+				)
+			},
+		)
+	}
+}
@@ -0,0 +1,177 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	HPROCESS, JobObjectInfo, SECURITY_ATTRIBUTES, SysResult, WString,
+};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::{Handle, HandleWaitable};
+
+impl_handle! { HJOB;
+	/// Handle to a
+	/// [job object](https://learn.microsoft.com/en-us/windows/win32/procthread/job-objects).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hjob for HJOB {}
+impl HandleWaitable for HJOB {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HJOB`](crate::HJOB).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hjob: Handle {
+	/// [`AssignProcessToJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-assignprocesstojobobject)
+	/// method.
+	///
+	/// To make sure the process doesn't spawn children before being assigned,
+	/// create it with
+	/// [`CREATE::SUSPENDED`](crate::co::CREATE::SUSPENDED), then resume its
+	/// main thread after the assignment.
+	fn AssignProcessToJobObject(&self, hprocess: &HPROCESS) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::AssignProcessToJobObject(
+					self.as_ptr(),
+					hprocess.as_ptr(),
+				)
+			},
+		)
+	}
+
+	/// [`CreateJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-createjobobjectw)
+	/// static method.
+	///
+	/// # Examples
+	///
+	/// Creating a job which terminates all its processes when the handle is
+	/// closed:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HJOB, HPROCESS, JobObjectInfo};
+	/// use winsafe::JOBOBJECT_EXTENDED_LIMIT_INFORMATION;
+	///
+	/// let hjob = HJOB::CreateJobObject(None, None)?;
+	///
+	/// let mut limits = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+	/// limits.BasicLimitInformation.LimitFlags =
+	///     co::JOB_OBJECT_LIMIT::KILL_ON_JOB_CLOSE | co::JOB_OBJECT_LIMIT::JOB_MEMORY;
+	/// limits.JobMemoryLimit = 512 * 1024 * 1024;
+	/// hjob.SetInformationJobObject(&JobObjectInfo::ExtendedLimit(limits))?;
+	///
+	/// let hprocess: HPROCESS; // initialized somewhere
+	/// # let hprocess = HPROCESS::NULL;
+	/// hjob.AssignProcessToJobObject(&hprocess)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateJobObject(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HJOB>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateJobObjectW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-openjobobjectw)
+	/// static method.
+	#[must_use]
+	fn OpenJobObject(
+		desired_access: co::JOB_OBJECT,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HJOB>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenJobObjectW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`QueryInformationJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-queryinformationjobobject)
+	/// method.
+	///
+	/// The information class is determined by the variant of `info`, whose
+	/// struct will be filled.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{HJOB, JobObjectInfo};
+	///
+	/// let hjob: HJOB; // initialized somewhere
+	/// # let hjob = HJOB::NULL;
+	///
+	/// let mut info = JobObjectInfo::BasicAccounting(Default::default());
+	/// hjob.QueryInformationJobObject(&mut info)?;
+	///
+	/// if let JobObjectInfo::BasicAccounting(acc) = info {
+	///     println!("Active processes: {}", acc.ActiveProcesses);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn QueryInformationJobObject(&self, info: &mut JobObjectInfo) -> SysResult<()> {
+		let class = info.class();
+		let (ptr, sz) = info.as_mut_ptr_size();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::QueryInformationJobObject(
+					self.as_ptr(),
+					class,
+					ptr,
+					sz,
+					std::ptr::null_mut(),
+				)
+			},
+		)
+	}
+
+	/// [`SetInformationJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-setinformationjobobject)
+	/// method.
+	///
+	/// The information class is determined by the variant of `info`.
+	fn SetInformationJobObject(&self, info: &JobObjectInfo) -> SysResult<()> {
+		let (ptr, sz) = info.as_ptr_size();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::SetInformationJobObject(
+					self.as_ptr(),
+					info.class(),
+					ptr as _,
+					sz,
+				)
+			},
+		)
+	}
+
+	/// [`TerminateJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-terminatejobobject)
+	/// method.
+	fn TerminateJobObject(&self, exit_code: u32) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { kernel::ffi::TerminateJobObject(self.as_ptr(), exit_code) },
+		)
+	}
+}
@@ -2,12 +2,13 @@
 
 use crate::{co, kernel};
 use crate::kernel::decl::{
-	FILETIME, HTRANSACTION, RegistryValue, SECURITY_ATTRIBUTES, SysResult,
-	VALENT, WString,
+	FILETIME, HTRANSACTION, RegFile, RegFileKey, RegFileValue, RegistryValue,
+	SECURITY_ATTRIBUTES, SysResult, VALENT, WString,
 };
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::guard::RegCloseKeyGuard;
 use crate::kernel::privs::error_to_sysresult;
+use crate::kernel::utilities::split_root_key;
 use crate::prelude::Handle;
 
 impl_handle! { HKEY;
@@ -49,6 +50,106 @@ pub trait kernel_Hkey: Handle {
 	predef_key!(PERFORMANCE_TEXT, 0x8000_0050);
 	predef_key!(PERFORMANCE_NLSTEXT, 0x8000_0060);
 
+	/// Reads the whole tree of the given registry key, returning it as a
+	/// [`RegFile`](crate::RegFile), which can then be serialized as a `.reg`
+	/// file.
+	///
+	/// `key_path` must start with the name of a predefined root key, like
+	/// `HKEY_CURRENT_USER\Software\Foo`. The short forms, like `HKCU`, are
+	/// also accepted, and written with the full name, because the Registry
+	/// Editor doesn't accept them.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HKEY;
+	///
+	/// let reg = HKEY::export_reg_tree("HKEY_CURRENT_USER\\Control Panel\\Mouse")?;
+	/// reg.serialize_to_file("C:\\Temp\\mouse.reg")?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn export_reg_tree(key_path: &str) -> SysResult<RegFile> {
+		let (hroot, root_name, sub_key) = split_root_key(key_path)?;
+		let sub_key = sub_key.trim_end_matches('\\');
+		let hkey = hroot.RegOpenKeyEx(
+			if sub_key.is_empty() { None } else { Some(sub_key) },
+			co::REG_OPTION::default(),
+			co::KEY::READ,
+		)?;
+
+		let full_path = if sub_key.is_empty() {
+			root_name.to_owned()
+		} else {
+			format!("{}\\{}", root_name, sub_key)
+		};
+
+		let mut keys = Vec::<RegFileKey>::default();
+		export_key_tree(&hkey, &full_path, &mut keys)?;
+		Ok(RegFile { keys })
+	}
+
+	/// Applies all the keys and values of the given
+	/// [`RegFile`](crate::RegFile) to the registry, the same way the Registry
+	/// Editor imports a `.reg` file:
+	///
+	/// * keys are created if they don't exist;
+	/// * keys marked for deletion are deleted along with all their subkeys;
+	/// * values without data are deleted.
+	///
+	/// Deleting keys or values which don't exist is not an error.
+	///
+	/// All key paths are validated before the registry is touched. A root key
+	/// marked for deletion, like `[-HKEY_CURRENT_USER]`, is rejected with
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER), since
+	/// it would wipe the whole hive.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{HKEY, RegFile};
+	///
+	/// let reg = RegFile::parse_from_file("C:\\Temp\\mouse.reg")?;
+	/// HKEY::import_reg_file(&reg)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn import_reg_file(reg_file: &RegFile) -> SysResult<()> {
+		for key in reg_file.keys.iter() {
+			let (_, _, sub_key) = split_root_key(&key.path)?;
+			if key.delete && sub_key.trim_matches('\\').is_empty() {
+				return Err(co::ERROR::INVALID_PARAMETER); // would delete the whole hive
+			}
+		}
+
+		for key in reg_file.keys.iter() {
+			let (hroot, _, sub_key) = split_root_key(&key.path)?;
+
+			if key.delete {
+				match hroot.RegDeleteTree(Some(sub_key)) {
+					Ok(_) | Err(co::ERROR::FILE_NOT_FOUND) => {},
+					Err(e) => return Err(e),
+				}
+				continue;
+			}
+
+			let (hkey, _) = hroot.RegCreateKeyEx(
+				sub_key, None, co::REG_OPTION::default(), co::KEY::WRITE, None)?;
+
+			for val in key.values.iter() {
+				match &val.data {
+					Some(data) => hkey.RegSetValueEx(Some(&val.name), data.clone())?,
+					None => match hkey.RegDeleteValue(Some(&val.name)) {
+						Ok(_) | Err(co::ERROR::FILE_NOT_FOUND) => {},
+						Err(e) => return Err(e),
+					},
+				}
+			}
+		}
+		Ok(())
+	}
+
 	/// [`RegConnectRegistry`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regconnectregistryw)
 	/// static method.
 	///
@@ -390,6 +491,7 @@ pub trait kernel_Hkey: Handle {
 	///         println!("");
 	///     },
 	///     RegistryValue::None => println!("No value"),
+	///     other => println!("Other: {}", other),
 	/// }
 	/// # Ok::<_, winsafe::co::ERROR>(())
 	/// ```
@@ -650,6 +752,7 @@ pub trait kernel_Hkey: Handle {
 	///             println!("");
 	///         },
 	///         RegistryValue::None => println!("No value"),
+	///         other => println!("Other: {}", other),
 	///     }
 	/// }
 	///
@@ -786,6 +889,7 @@ pub trait kernel_Hkey: Handle {
 	///         println!("");
 	///     },
 	///     RegistryValue::None => println!("No value"),
+	///     other => println!("Other: {}", other),
 	/// }
 	/// # Ok::<_, co::ERROR>(())
 	/// ```
@@ -1036,6 +1140,29 @@ impl HKEY {
 
 //------------------------------------------------------------------------------
 
+fn export_key_tree(
+	hkey: &HKEY,
+	key_path: &str,
+	keys: &mut Vec<RegFileKey>,
+) -> SysResult<()>
+{
+	let mut values = Vec::<RegFileValue>::default();
+	for val in hkey.RegEnumValue()? {
+		let (name, _) = val?;
+		let data = hkey.RegQueryValueEx(Some(&name))?;
+		values.push(RegFileValue { name, data: Some(data) });
+	}
+	keys.push(RegFileKey { path: key_path.to_owned(), delete: false, values });
+
+	let sub_keys = hkey.RegEnumKeyEx()?.collect::<SysResult<Vec<_>>>()?;
+	for sub_key in sub_keys.iter() {
+		let hsub = hkey.RegOpenKeyEx(
+			Some(sub_key), co::REG_OPTION::default(), co::KEY::READ)?;
+		export_key_tree(&hsub, &format!("{}\\{}", key_path, sub_key), keys)?;
+	}
+	Ok(())
+}
+
 fn validate_retrieved_reg_val(
 	data_type1: co::REG,
 	data_len1: u32,
@@ -0,0 +1,117 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{SECURITY_ATTRIBUTES, SysResult, WString};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::{Handle, HandleWaitable};
+
+impl_handle! { HMUTEX;
+	/// Handle to a
+	/// [mutex](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createmutexw).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hmutex for HMUTEX {}
+impl HandleWaitable for HMUTEX {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HMUTEX`](crate::HMUTEX).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hmutex: Handle {
+	/// [`CreateMutex`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createmutexw)
+	/// static method.
+	///
+	/// If a named mutex already exists, it is opened, and
+	/// [`GetLastError`](crate::GetLastError) will return
+	/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+	///
+	/// # Examples
+	///
+	/// Ensuring a single instance of the application:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, GetLastError, HMUTEX};
+	///
+	/// let hmutex = HMUTEX::CreateMutex(None, true, Some("MyAppSingleInstance"))?;
+	/// if GetLastError() == co::ERROR::ALREADY_EXISTS {
+	///     println!("Another instance is already running.");
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateMutex(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		initial_owner: bool,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HMUTEX>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateMutexW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					initial_owner as _,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`CreateMutexEx`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createmutexexw)
+	/// static method.
+	#[must_use]
+	fn CreateMutexEx(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		name: Option<&str>,
+		flags: co::CREATE_MUTEX,
+		desired_access: co::MUTEX,
+	) -> SysResult<CloseHandleGuard<HMUTEX>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateMutexExW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					WString::from_opt_str(name).as_ptr(),
+					flags.0,
+					desired_access.0,
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenMutex`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-openmutexw)
+	/// static method.
+	#[must_use]
+	fn OpenMutex(
+		desired_access: co::MUTEX,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HMUTEX>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenMutexW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`ReleaseMutex`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-releasemutex)
+	/// method.
+	fn ReleaseMutex(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::ReleaseMutex(self.as_ptr()) })
+	}
+}
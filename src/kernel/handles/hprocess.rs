@@ -8,9 +8,9 @@ use crate::kernel::decl::{
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::guard::{CloseHandleGuard, CloseHandlePiGuard};
 use crate::kernel::privs::{
-	bool_to_sysresult, INFINITE, MAX_PATH, ptr_to_sysresult_handle,
+	bool_to_sysresult, MAX_PATH, ptr_to_sysresult_handle,
 };
-use crate::prelude::Handle;
+use crate::prelude::{Handle, HandleWaitable};
 
 impl_handle! { HPROCESS;
 	/// Handle to a
@@ -19,6 +19,11 @@ impl_handle! { HPROCESS;
 }
 
 impl kernel_Hprocess for HPROCESS {}
+impl HandleWaitable for HPROCESS {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HPROCESS`](crate::HPROCESS).
@@ -83,6 +88,60 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`DuplicateHandle`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle)
+	/// method.
+	///
+	/// This method is called on the source process. If `target_process` is
+	/// not the current process, the returned handle is not valid in the
+	/// current process, so you must
+	/// [`leak`](crate::guard::CloseHandleGuard::leak) it.
+	///
+	/// # Examples
+	///
+	/// Duplicating a handle as inheritable:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HEVENT, HPROCESS};
+	///
+	/// let hevent = HEVENT::CreateEvent(None, true, false, None)?;
+	///
+	/// let hcurproc = HPROCESS::GetCurrentProcess();
+	/// let hevent_inheritable = hcurproc.DuplicateHandle(
+	///     &*hevent,
+	///     &hcurproc,
+	///     0,
+	///     true,
+	///     co::DUPLICATE::SAME_ACCESS,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn DuplicateHandle<H>(&self,
+		source_handle: &H,
+		target_process: &HPROCESS,
+		desired_access: u32,
+		inherit_handle: bool,
+		options: co::DUPLICATE,
+	) -> SysResult<CloseHandleGuard<H>>
+		where H: Handle,
+	{
+		let mut htarget = H::NULL;
+		unsafe {
+			bool_to_sysresult(
+				kernel::ffi::DuplicateHandle(
+					self.as_ptr(),
+					source_handle.as_ptr(),
+					target_process.as_ptr(),
+					htarget.as_mut(),
+					desired_access,
+					inherit_handle as _,
+					options.0,
+				),
+			).map(|_| CloseHandleGuard::new(htarget))
+		}
+	}
+
 	/// [`FlushInstructionCache`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-flushinstructioncache)
 	/// method.
 	fn FlushInstructionCache(&self,
@@ -327,22 +386,4 @@ pub trait kernel_Hprocess: Handle {
 			unsafe { kernel::ffi::TerminateProcess(self.as_ptr(), exit_code) },
 		)
 	}
-
-	/// [`WaitForSingleObject`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
-	/// method.
-	fn WaitForSingleObject(&self,
-		milliseconds: Option<u32>) -> SysResult<co::WAIT>
-	{
-		match unsafe {
-			co::WAIT(
-				kernel::ffi::WaitForSingleObject(
-					self.as_ptr(),
-					milliseconds.unwrap_or(INFINITE),
-				),
-			)
-		} {
-			co::WAIT::FAILED => Err(GetLastError()),
-			wait => Ok(wait),
-		}
-	}
 }
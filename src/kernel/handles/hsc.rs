@@ -0,0 +1,148 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{HSERVICE, SysResult, WString};
+use crate::kernel::guard::CloseServiceHandleGuard;
+use crate::kernel::privs::ptr_to_sysresult_handle;
+use crate::prelude::Handle;
+
+impl_handle! { HSC;
+	/// Handle to a
+	/// [Service Control Manager](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-openscmanagerw)
+	/// database. Originally `SC_HANDLE`.
+}
+
+impl kernel_Hsc for HSC {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HSC`](crate::HSC).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hsc: Handle {
+	/// [`CreateService`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-createservicew)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HSC};
+	///
+	/// let hsc = HSC::OpenSCManager(None, None, co::SC_MANAGER::CREATE_SERVICE)?;
+	///
+	/// let hservice = hsc.CreateService(
+	///     "MyService",
+	///     Some("My Service"),
+	///     co::SERVICE::ALL_ACCESS,
+	///     co::SERVICE_TYPE::WIN32_OWN_PROCESS,
+	///     co::SERVICE_START::AUTO,
+	///     co::SERVICE_ERROR::NORMAL,
+	///     "C:\\Program Files\\MyService\\my_service.exe",
+	///     None,
+	///     None,
+	///     &[] as &[&str],
+	///     None,
+	///     None,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateService(&self,
+		service_name: &str,
+		display_name: Option<&str>,
+		desired_access: co::SERVICE,
+		service_type: co::SERVICE_TYPE,
+		start_type: co::SERVICE_START,
+		error_control: co::SERVICE_ERROR,
+		binary_path_name: &str,
+		load_order_group: Option<&str>,
+		tag_id: Option<&mut u32>,
+		dependencies: &[impl AsRef<str>],
+		service_start_name: Option<&str>,
+		password: Option<&str>,
+	) -> SysResult<CloseServiceHandleGuard<HSERVICE>>
+	{
+		let dependencies_buf = if dependencies.is_empty() {
+			WString::default()
+		} else {
+			WString::from_str_vec(dependencies)
+		};
+
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateServiceW(
+					self.as_ptr(),
+					WString::from_str(service_name).as_ptr(),
+					WString::from_opt_str(display_name).as_ptr(),
+					desired_access.0,
+					service_type.0,
+					start_type.0,
+					error_control.0,
+					WString::from_str(binary_path_name).as_ptr(),
+					WString::from_opt_str(load_order_group).as_ptr(),
+					tag_id.map_or(std::ptr::null_mut(), |n| n),
+					dependencies_buf.as_ptr(),
+					WString::from_opt_str(service_start_name).as_ptr(),
+					WString::from_opt_str(password).as_ptr(),
+				),
+			).map(|h| CloseServiceHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenSCManager`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-openscmanagerw)
+	/// static method.
+	#[must_use]
+	fn OpenSCManager(
+		machine_name: Option<&str>,
+		database_name: Option<&str>,
+		desired_access: co::SC_MANAGER,
+	) -> SysResult<CloseServiceHandleGuard<HSC>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenSCManagerW(
+					WString::from_opt_str(machine_name).as_ptr(),
+					WString::from_opt_str(database_name).as_ptr(),
+					desired_access.0,
+				),
+			).map(|h| CloseServiceHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenService`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-openservicew)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HSC};
+	///
+	/// let hsc = HSC::OpenSCManager(None, None, co::SC_MANAGER::CONNECT)?;
+	/// let hservice = hsc.OpenService("Spooler", co::SERVICE::QUERY_STATUS)?;
+	///
+	/// let status = hservice.QueryServiceStatusEx()?;
+	/// println!("PID: {}", status.dwProcessId);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn OpenService(&self,
+		service_name: &str,
+		desired_access: co::SERVICE,
+	) -> SysResult<CloseServiceHandleGuard<HSERVICE>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenServiceW(
+					self.as_ptr(),
+					WString::from_str(service_name).as_ptr(),
+					desired_access.0,
+				),
+			).map(|h| CloseServiceHandleGuard::new(h))
+		}
+	}
+}
@@ -0,0 +1,93 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{SECURITY_ATTRIBUTES, SysResult, WString};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::{Handle, HandleWaitable};
+
+impl_handle! { HSEMAPHORE;
+	/// Handle to a
+	/// [semaphore](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createsemaphorew).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hsemaphore for HSEMAPHORE {}
+impl HandleWaitable for HSEMAPHORE {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HSEMAPHORE`](crate::HSEMAPHORE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hsemaphore: Handle {
+	/// [`CreateSemaphore`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createsemaphorew)
+	/// static method.
+	///
+	/// If a named semaphore already exists, it is opened, and
+	/// [`GetLastError`](crate::GetLastError) will return
+	/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+	#[must_use]
+	fn CreateSemaphore(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		initial_count: i32,
+		maximum_count: i32,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HSEMAPHORE>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateSemaphoreW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					initial_count,
+					maximum_count,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenSemaphore`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-opensemaphorew)
+	/// static method.
+	#[must_use]
+	fn OpenSemaphore(
+		desired_access: co::SEMAPHORE,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HSEMAPHORE>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenSemaphoreW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`ReleaseSemaphore`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-releasesemaphore)
+	/// method.
+	///
+	/// Returns the previous count.
+	fn ReleaseSemaphore(&self, release_count: i32) -> SysResult<i32> {
+		let mut prev_count = i32::default();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::ReleaseSemaphore(
+					self.as_ptr(),
+					release_count,
+					&mut prev_count,
+				)
+			},
+		).map(|_| prev_count)
+	}
+}
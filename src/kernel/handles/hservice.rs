@@ -0,0 +1,214 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	SC_ACTION, SERVICE_STATUS, SERVICE_STATUS_PROCESS, ServiceConfig2, SysResult,
+	WString,
+};
+use crate::kernel::privs::{bool_to_sysresult, INFINITE};
+use crate::prelude::Handle;
+
+impl_handle! { HSERVICE;
+	/// Handle to a
+	/// [service](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-openservicew).
+	/// Originally `SC_HANDLE`.
+}
+
+impl kernel_Hservice for HSERVICE {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HSERVICE`](crate::HSERVICE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hservice: Handle {
+	/// [`ChangeServiceConfig2`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-changeserviceconfig2w)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HSC, SC_ACTION, ServiceConfig2};
+	///
+	/// let hsc = HSC::OpenSCManager(None, None, co::SC_MANAGER::CONNECT)?;
+	/// let hservice = hsc.OpenService("MyService", co::SERVICE::CHANGE_CONFIG)?;
+	///
+	/// hservice.ChangeServiceConfig2(
+	///     &ServiceConfig2::Description("Does important things.".to_owned()),
+	/// )?;
+	///
+	/// hservice.ChangeServiceConfig2(
+	///     &ServiceConfig2::FailureActions {
+	///         reset_period: Some(86_400),
+	///         reboot_msg: None,
+	///         command: None,
+	///         actions: vec![
+	///             SC_ACTION { Type: co::SC_ACTION::RESTART, Delay: 5_000 },
+	///         ],
+	///     },
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn ChangeServiceConfig2(&self, info: &ServiceConfig2) -> SysResult<()> {
+		let change = |level: u32, data: *const std::ffi::c_void| {
+			bool_to_sysresult(
+				unsafe {
+					kernel::ffi::ChangeServiceConfig2W(self.as_ptr(), level, data as _)
+				},
+			)
+		};
+
+		match info {
+			ServiceConfig2::Description(text) => {
+				let text16 = WString::from_str(text);
+				let data = SERVICE_DESCRIPTION { lpDescription: text16.as_ptr() };
+				change(1, &data as *const _ as _)
+			},
+			ServiceConfig2::FailureActions {
+				reset_period, reboot_msg, command, actions,
+			} => {
+				let reboot_msg16 = WString::from_opt_str(reboot_msg.as_deref());
+				let command16 = WString::from_opt_str(command.as_deref());
+				let data = SERVICE_FAILURE_ACTIONS {
+					dwResetPeriod: reset_period.unwrap_or(INFINITE),
+					lpRebootMsg: reboot_msg16.as_ptr(),
+					lpCommand: command16.as_ptr(),
+					cActions: actions.len() as _,
+					lpsaActions: if actions.is_empty() {
+						std::ptr::null()
+					} else {
+						actions.as_ptr()
+					},
+				};
+				change(2, &data as *const _ as _)
+			},
+			ServiceConfig2::DelayedAutoStart(flag) => {
+				let data = SERVICE_FLAG { flag: *flag as _ };
+				change(3, &data as *const _ as _)
+			},
+			ServiceConfig2::FailureActionsFlag(flag) => {
+				let data = SERVICE_FLAG { flag: *flag as _ };
+				change(4, &data as *const _ as _)
+			},
+			ServiceConfig2::PreshutdownTimeout(timeout_ms) => {
+				let data = SERVICE_FLAG { flag: *timeout_ms as _ };
+				change(7, &data as *const _ as _)
+			},
+		}
+	}
+
+	/// [`ControlService`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-controlservice)
+	/// method.
+	///
+	/// Returns the latest status reported by the service.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HSC};
+	///
+	/// let hsc = HSC::OpenSCManager(None, None, co::SC_MANAGER::CONNECT)?;
+	/// let hservice = hsc.OpenService("MyService", co::SERVICE::STOP)?;
+	///
+	/// let status = hservice.ControlService(co::SERVICE_CONTROL::STOP)?;
+	/// println!("{}", status.dwCurrentState);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn ControlService(&self,
+		control: co::SERVICE_CONTROL) -> SysResult<SERVICE_STATUS>
+	{
+		let mut status = SERVICE_STATUS::default();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::ControlService(
+					self.as_ptr(),
+					control.0,
+					&mut status as *mut _ as _,
+				)
+			},
+		).map(|_| status)
+	}
+
+	/// [`DeleteService`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-deleteservice)
+	/// method.
+	///
+	/// The service is actually removed only after all its handles are closed.
+	fn DeleteService(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::DeleteService(self.as_ptr()) })
+	}
+
+	/// [`QueryServiceStatusEx`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-queryservicestatusex)
+	/// method.
+	#[must_use]
+	fn QueryServiceStatusEx(&self) -> SysResult<SERVICE_STATUS_PROCESS> {
+		let mut status = SERVICE_STATUS_PROCESS::default();
+		let mut needed = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::QueryServiceStatusEx(
+					self.as_ptr(),
+					0, // SC_STATUS_PROCESS_INFO
+					&mut status as *mut _ as _,
+					std::mem::size_of::<SERVICE_STATUS_PROCESS>() as _,
+					&mut needed,
+				)
+			},
+		).map(|_| status)
+	}
+
+	/// [`StartService`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-startservicew)
+	/// method.
+	///
+	/// The `args` are passed to the service main function, after the service
+	/// name, which is always the first argument.
+	fn StartService(&self, args: &[impl AsRef<str>]) -> SysResult<()> {
+		let args16 = args.iter()
+			.map(|a| WString::from_str(a.as_ref()))
+			.collect::<Vec<_>>();
+		let args_ptrs = args16.iter()
+			.map(|a| a.as_ptr())
+			.collect::<Vec<_>>();
+
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::StartServiceW(
+					self.as_ptr(),
+					args_ptrs.len() as _,
+					if args_ptrs.is_empty() {
+						std::ptr::null()
+					} else {
+						args_ptrs.as_ptr()
+					},
+				)
+			},
+		)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+#[repr(C)]
+struct SERVICE_DESCRIPTION {
+	lpDescription: *const u16,
+}
+
+#[repr(C)]
+struct SERVICE_FAILURE_ACTIONS {
+	dwResetPeriod: u32,
+	lpRebootMsg: *const u16,
+	lpCommand: *const u16,
+	cActions: u32,
+	lpsaActions: *const SC_ACTION,
+}
+
+/// Used for `SERVICE_DELAYED_AUTO_START_INFO`, `SERVICE_FAILURE_ACTIONS_FLAG`
+/// and `SERVICE_PRESHUTDOWN_INFO`, which have a single 32-bit member.
+#[repr(C)]
+struct SERVICE_FLAG {
+	flag: u32,
+}
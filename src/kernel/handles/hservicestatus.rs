@@ -0,0 +1,64 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel;
+use crate::kernel::decl::{
+	LPHANDLER_FUNCTION_EX, SERVICE_STATUS, SysResult, WString,
+};
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::Handle;
+
+impl_handle! { HSERVICESTATUS;
+	/// Handle to a
+	/// [service status](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-registerservicectrlhandlerexw).
+	/// Originally `SERVICE_STATUS_HANDLE`.
+	///
+	/// This handle doesn't need to be closed.
+}
+
+impl kernel_Hservicestatus for HSERVICESTATUS {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HSERVICESTATUS`](crate::HSERVICESTATUS).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hservicestatus: Handle {
+	/// [`RegisterServiceCtrlHandlerEx`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-registerservicectrlhandlerexw)
+	/// static method.
+	///
+	/// Prefer using the high-level [`ServiceRuntime`](crate::ServiceRuntime),
+	/// which registers the handler automatically.
+	///
+	/// # Safety
+	///
+	/// The `context` value is passed as-is to `handler_proc`, which must be
+	/// valid for the whole lifetime of the service.
+	#[must_use]
+	unsafe fn RegisterServiceCtrlHandlerEx(
+		service_name: &str,
+		handler_proc: LPHANDLER_FUNCTION_EX,
+		context: Option<usize>,
+	) -> SysResult<HSERVICESTATUS>
+	{
+		ptr_to_sysresult_handle(
+			kernel::ffi::RegisterServiceCtrlHandlerExW(
+				WString::from_str(service_name).as_ptr(),
+				handler_proc as _,
+				context.unwrap_or_default() as _,
+			),
+		)
+	}
+
+	/// [`SetServiceStatus`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-setservicestatus)
+	/// method.
+	fn SetServiceStatus(&self, status: &SERVICE_STATUS) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::SetServiceStatus(self.as_ptr(), status as *const _ as _)
+			},
+		)
+	}
+}
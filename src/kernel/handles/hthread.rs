@@ -9,7 +9,7 @@ use crate::kernel::guard::CloseHandleGuard;
 use crate::kernel::privs::{
 	bool_to_sysresult, minus1_as_error, ptr_to_sysresult_handle,
 };
-use crate::prelude::Handle;
+use crate::prelude::{Handle, HandleWaitable};
 
 impl_handle! { HTHREAD;
 	/// Handle to a
@@ -18,6 +18,11 @@ impl_handle! { HTHREAD;
 }
 
 impl kernel_Hthread for HTHREAD {}
+impl HandleWaitable for HTHREAD {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HTHREAD`](crate::HTHREAD).
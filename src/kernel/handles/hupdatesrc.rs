@@ -41,6 +41,37 @@ pub trait kernel_Hupdatersrc: Handle {
 		}
 	}
 
+	/// [`EndUpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-endupdateresourcew)
+	/// method.
+	///
+	/// Commits or discards the changes. Since
+	/// [`EndUpdateResourceGuard`](crate::guard::EndUpdateResourceGuard) already
+	/// calls this method when dropped, ignoring any error, use it only on a
+	/// handle ejected with
+	/// [`EndUpdateResourceGuard::leak`](crate::guard::EndUpdateResourceGuard::leak).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HUPDATERSRC, IdStr, LANGID, RtStr};
+	///
+	/// let mut hupsrc = HUPDATERSRC::BeginUpdateResource("C:\\Temp\\foo.exe", false)?;
+	/// hupsrc.UpdateResource(
+	///     RtStr::Rt(co::RT::RCDATA),
+	///     IdStr::Id(1),
+	///     LANGID::USER_DEFAULT,
+	///     &[0x01, 0x02, 0x03],
+	/// )?;
+	/// hupsrc.leak().EndUpdateResource(false)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn EndUpdateResource(&self, discard: bool) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { kernel::ffi::EndUpdateResourceW(self.as_ptr(), discard as _) },
+		)
+	}
+
 	/// [`UpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-updateresourcew)
 	/// method.
 	fn UpdateResource(&self,
@@ -0,0 +1,143 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{SECURITY_ATTRIBUTES, SysResult, WString};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::{Handle, HandleWaitable};
+
+impl_handle! { HWAITABLETIMER;
+	/// Handle to a
+	/// [waitable timer](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createwaitabletimerw).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hwaitabletimer for HWAITABLETIMER {}
+impl HandleWaitable for HWAITABLETIMER {
+	fn waitable_ptr(&self) -> *mut std::ffi::c_void {
+		self.as_ptr()
+	}
+}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HWAITABLETIMER`](crate::HWAITABLETIMER).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hwaitabletimer: Handle {
+	/// [`CancelWaitableTimer`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-cancelwaitabletimer)
+	/// method.
+	fn CancelWaitableTimer(&self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { kernel::ffi::CancelWaitableTimer(self.as_ptr()) },
+		)
+	}
+
+	/// [`CreateWaitableTimer`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createwaitabletimerw)
+	/// static method.
+	///
+	/// If a named timer already exists, it is opened, and
+	/// [`GetLastError`](crate::GetLastError) will return
+	/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+	#[must_use]
+	fn CreateWaitableTimer(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		manual_reset: bool,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HWAITABLETIMER>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateWaitableTimerW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					manual_reset as _,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`CreateWaitableTimerEx`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createwaitabletimerexw)
+	/// static method.
+	#[must_use]
+	fn CreateWaitableTimerEx(
+		security_attributes: Option<&SECURITY_ATTRIBUTES>,
+		name: Option<&str>,
+		flags: co::CREATE_WAITABLE_TIMER,
+		desired_access: co::TIMER,
+	) -> SysResult<CloseHandleGuard<HWAITABLETIMER>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateWaitableTimerExW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					WString::from_opt_str(name).as_ptr(),
+					flags.0,
+					desired_access.0,
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenWaitableTimer`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-openwaitabletimerw)
+	/// static method.
+	#[must_use]
+	fn OpenWaitableTimer(
+		desired_access: co::TIMER,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HWAITABLETIMER>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenWaitableTimerW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`SetWaitableTimer`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-setwaitabletimer)
+	/// method.
+	///
+	/// `due_time` is given in 100-nanosecond intervals: positive values are
+	/// absolute [`FILETIME`](crate::FILETIME) values, negative values are
+	/// relative to the current time. `period` is given in milliseconds; if
+	/// zero, the timer is signaled only once.
+	///
+	/// # Examples
+	///
+	/// Signal the timer after 2 seconds, then every 500 milliseconds:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWAITABLETIMER;
+	///
+	/// let htimer = HWAITABLETIMER::CreateWaitableTimer(None, false, None)?;
+	/// htimer.SetWaitableTimer(-2 * 10_000_000, 500, false)?;
+	///
+	/// htimer.WaitForSingleObject(None)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn SetWaitableTimer(&self,
+		due_time: i64, period: u32, resume: bool) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::SetWaitableTimer(
+					self.as_ptr(),
+					&due_time,
+					period as _,
+					std::ptr::null(),
+					std::ptr::null_mut(),
+					resume as _,
+				)
+			},
+		)
+	}
+}
@@ -1,5 +1,6 @@
 mod haccesstoken;
 mod handle_traits;
+mod hevent;
 mod hfile;
 mod hfilemap;
 mod hfilemapview;
@@ -7,18 +8,27 @@ mod hfindfile;
 mod hglobal;
 mod hheapobj;
 mod hinstance;
+mod hiocp;
+mod hjob;
 mod hkey;
 mod hlocal;
+mod hmutex;
 mod hpipe;
 mod hprocess;
 mod hprocesslist;
+mod hsc;
+mod hsemaphore;
+mod hservice;
+mod hservicestatus;
 mod hstd;
 mod hthread;
 mod htransaction;
 mod hupdatesrc;
+mod hwaitabletimer;
 
 pub mod decl {
 	pub use super::haccesstoken::HACCESSTOKEN;
+	pub use super::hevent::HEVENT;
 	pub use super::hfile::HFILE;
 	pub use super::hfilemap::HFILEMAP;
 	pub use super::hfilemapview::HFILEMAPVIEW;
@@ -26,21 +36,23 @@ pub mod decl {
 	pub use super::hglobal::HGLOBAL;
 	pub use super::hheapobj::HHEAPOBJ;
 	pub use super::hinstance::HINSTANCE;
+	pub use super::hiocp::HIOCP;
+	pub use super::hjob::HJOB;
 	pub use super::hkey::HKEY;
 	pub use super::hlocal::HLOCAL;
+	pub use super::hmutex::HMUTEX;
 	pub use super::hpipe::HPIPE;
 	pub use super::hprocess::HPROCESS;
 	pub use super::hprocesslist::HPROCESSLIST;
+	pub use super::hsc::HSC;
+	pub use super::hsemaphore::HSEMAPHORE;
+	pub use super::hservice::HSERVICE;
+	pub use super::hservicestatus::HSERVICESTATUS;
 	pub use super::hstd::HSTD;
 	pub use super::hthread::HTHREAD;
 	pub use super::htransaction::HTRANSACTION;
 	pub use super::hupdatesrc::HUPDATERSRC;
-
-	impl_handle! { HEVENT;
-		/// Handle to an
-		/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
-		/// Originally just a `HANDLE`.
-	}
+	pub use super::hwaitabletimer::HWAITABLETIMER;
 
 	impl_handle! { HHEAPMEM;
 		/// Handle to a
@@ -72,6 +84,7 @@ pub mod decl {
 pub mod traits {
 	pub use super::haccesstoken::kernel_Haccesstoken;
 	pub use super::handle_traits::*;
+	pub use super::hevent::kernel_Hevent;
 	pub use super::hfile::kernel_Hfile;
 	pub use super::hfilemap::kernel_Hfilemap;
 	pub use super::hfilemapview::kernel_Hfilemapview;
@@ -79,13 +92,21 @@ pub mod traits {
 	pub use super::hglobal::kernel_Hglobal;
 	pub use super::hheapobj::kernel_Hheapobj;
 	pub use super::hinstance::kernel_Hinstance;
+	pub use super::hiocp::kernel_Hiocp;
+	pub use super::hjob::kernel_Hjob;
 	pub use super::hkey::kernel_Hkey;
 	pub use super::hlocal::kernel_Hlocal;
+	pub use super::hmutex::kernel_Hmutex;
 	pub use super::hpipe::kernel_Hpipe;
 	pub use super::hprocess::kernel_Hprocess;
 	pub use super::hprocesslist::kernel_Hprocesslist;
+	pub use super::hsc::kernel_Hsc;
+	pub use super::hsemaphore::kernel_Hsemaphore;
+	pub use super::hservice::kernel_Hservice;
+	pub use super::hservicestatus::kernel_Hservicestatus;
 	pub use super::hstd::kernel_Hstd;
 	pub use super::hthread::kernel_Hthread;
 	pub use super::htransaction::kernel_Htransaction;
 	pub use super::hupdatesrc::kernel_Hupdatersrc;
+	pub use super::hwaitabletimer::kernel_Hwaitabletimer;
 }
@@ -26,4 +26,7 @@ pub mod decl {
 pub mod traits {
 	pub use super::co_traits::*;
 	pub use super::handles::traits::*;
+	pub use super::utilities::settings::{
+		Settings, SettingsStore, SettingsValue,
+	};
 }
@@ -5,6 +5,7 @@ use crate::kernel::decl::{GetLastError, SysResult, WString};
 use crate::kernel::ffi_types::{BOOL, HANDLE};
 use crate::prelude::Handle;
 
+pub(crate) const ACL_REVISION: u32 = 2;
 pub(crate) const GMEM_INVALID_HANDLE: u32 = 0x8000;
 pub(crate) const INFINITE: u32 = 0xffff_ffff;
 pub(crate) const INVALID_FILE_ATTRIBUTES: i32 = -1;
@@ -12,6 +13,8 @@ pub(crate) const LMEM_INVALID_HANDLE: u32 = 0x8000;
 pub(crate) const MAX_COMPUTERNAME_LENGTH: usize = 15;
 pub(crate) const MAX_MODULE_NAME32: usize = 255;
 pub(crate) const MAX_PATH: usize = 260;
+pub(crate) const MAXIMUM_WAIT_OBJECTS: usize = 64;
+pub(crate) const SDDL_REVISION_1: u32 = 1;
 pub(crate) const SECURITY_DESCRIPTOR_REVISION: u32 = 1;
 
 /// [`IS_INTRESOURCE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-is_intresource)
@@ -106,3 +109,178 @@ pub(crate) fn parse_multi_z_str(src: *const u16) -> Vec<String> {
 	}
 	strings
 }
+
+/// Writes the symbolic representation of a constant value, used by the
+/// `Debug` and `Display` implementations of the constant types. Values without
+/// a name are written in hexadecimal.
+///
+/// Bitflags are decomposed into `A | B | 0x10`, where the last part is the
+/// remainder which has no name.
+pub(crate) fn format_const(
+	f: &mut std::fmt::Formatter,
+	prefix: Option<&str>,
+	names: &[(&str, u64)],
+	bits: usize,
+	is_bitflag: bool,
+	val: u64,
+) -> std::fmt::Result
+{
+	let mask = u64::MAX >> (64 - bits);
+	let val = val & mask;
+	let write_name = |f: &mut std::fmt::Formatter, name: &str| match prefix {
+		Some(prefix) => write!(f, "{}::{}", prefix, name),
+		None => write!(f, "{}", name),
+	};
+
+	if let Some((name, _)) = names.iter().find(|(_, v)| *v & mask == val) {
+		return write_name(f, name); // exact match
+	} else if !is_bitflag || val == 0 {
+		return write!(f, "{:#x}", val);
+	}
+
+	let mut remaining = val;
+	let mut first = true;
+	for (name, v) in names.iter().map(|(name, v)| (name, *v & mask)) {
+		if v != 0 && (val & v) == v && (remaining & v) != 0 {
+			if !first {
+				write!(f, " | ")?;
+			}
+			write_name(f, name)?;
+			remaining &= !v;
+			first = false;
+		}
+	}
+	if remaining != 0 {
+		if !first {
+			write!(f, " | ")?;
+		}
+		write!(f, "{:#x}", remaining)?;
+	}
+	Ok(())
+}
+
+/// Parses the symbolic representation of a constant value, as written by
+/// [`format_const`]. Names may be prefixed with the type name, and numbers may
+/// be decimal or hexadecimal.
+pub(crate) fn parse_const(
+	type_name: &str,
+	names: &[(&str, u64)],
+	bits: usize,
+	is_bitflag: bool,
+	s: &str,
+) -> SysResult<u64>
+{
+	let mask = u64::MAX >> (64 - bits);
+	let parts = s.split('|').map(|part| part.trim()).collect::<Vec<_>>();
+	if parts.is_empty() || (!is_bitflag && parts.len() > 1) {
+		return Err(ERROR::INVALID_PARAMETER);
+	}
+
+	let mut val = 0u64;
+	for part in parts.iter() {
+		let name = part.strip_prefix(type_name)
+			.and_then(|rest| rest.strip_prefix("::"))
+			.unwrap_or(part);
+
+		if let Some((_, v)) = names.iter().find(|(n, _)| *n == name) {
+			val |= *v & mask;
+		} else if let Some(hex) = name.strip_prefix("0x").or_else(|| name.strip_prefix("0X")) {
+			let num = u64::from_str_radix(&hex.replace('_', ""), 16)
+				.map_err(|_| ERROR::INVALID_PARAMETER)?;
+			if num & !mask != 0 {
+				return Err(ERROR::INVALID_PARAMETER); // doesn't fit in the type
+			}
+			val |= num;
+		} else {
+			let num = name.replace('_', "").parse::<i128>()
+				.map_err(|_| ERROR::INVALID_PARAMETER)?;
+			if num < -(1i128 << (bits - 1)) || num > mask as i128 {
+				return Err(ERROR::INVALID_PARAMETER); // doesn't fit in the type
+			}
+			val |= num as u64 & mask;
+		}
+	}
+	Ok(val)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const NAMES: &[(&str, u64)] = &[
+		("NONE", 0x00),
+		("READ", 0x01),
+		("WRITE", 0x02),
+		("READ_WRITE", 0x03),
+		("EXEC", 0x10),
+	];
+
+	struct Fmt(bool, u64);
+
+	impl std::fmt::Display for Fmt {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			format_const(f, Some("FLAG"), NAMES, 8, self.0, self.1)
+		}
+	}
+
+	fn round_trip(is_bitflag: bool, val: u64) -> u64 {
+		parse_const("FLAG", NAMES, 8, is_bitflag, &Fmt(is_bitflag, val).to_string()).unwrap()
+	}
+
+	#[test]
+	fn format_ordinary() {
+		assert_eq!(Fmt(false, 0x01).to_string(), "FLAG::READ");
+		assert_eq!(Fmt(false, 0x03).to_string(), "FLAG::READ_WRITE");
+		assert_eq!(Fmt(false, 0x00).to_string(), "FLAG::NONE");
+		assert_eq!(Fmt(false, 0x11).to_string(), "0x11"); // not combined
+		assert_eq!(Fmt(false, 0x1ff).to_string(), "0xff"); // masked to 8 bits
+	}
+
+	#[test]
+	fn format_bitflag() {
+		assert_eq!(Fmt(true, 0x13).to_string(), "FLAG::READ | FLAG::WRITE | FLAG::EXEC");
+		assert_eq!(Fmt(true, 0x12).to_string(), "FLAG::WRITE | FLAG::EXEC");
+		assert_eq!(Fmt(true, 0x84).to_string(), "0x84"); // no known bits
+		assert_eq!(Fmt(true, 0x91).to_string(), "FLAG::READ | FLAG::EXEC | 0x80"); // unknown bits last
+	}
+
+	#[test]
+	fn parse_names_and_numbers() {
+		assert_eq!(parse_const("FLAG", NAMES, 8, false, "READ"), Ok(0x01));
+		assert_eq!(parse_const("FLAG", NAMES, 8, false, " FLAG::EXEC "), Ok(0x10));
+		assert_eq!(parse_const("FLAG", NAMES, 8, false, "0x1F"), Ok(0x1f));
+		assert_eq!(parse_const("FLAG", NAMES, 8, false, "0Xf_f"), Ok(0xff));
+		assert_eq!(parse_const("FLAG", NAMES, 8, false, "200"), Ok(200));
+		assert_eq!(parse_const("FLAG", NAMES, 8, false, "-1"), Ok(0xff));
+		assert_eq!(parse_const("FLAG", NAMES, 8, true, "READ | FLAG::EXEC | 0x80"), Ok(0x91));
+		assert_eq!(parse_const("FLAG", NAMES, 8, true, "WRITE|2"), Ok(0x02));
+	}
+
+	#[test]
+	fn parse_invalid() {
+		for s in ["", "FOO", "OTHER::READ", "READ | WRITE", "0x100", "256", "-129", "0xg", "1.0"] {
+			assert_eq!(parse_const("FLAG", NAMES, 8, false, s), Err(ERROR::INVALID_PARAMETER), "{:?}", s);
+		}
+		assert_eq!(parse_const("FLAG", NAMES, 8, true, "READ | "), Err(ERROR::INVALID_PARAMETER));
+	}
+
+	#[test]
+	fn round_trips() {
+		for val in [0x00, 0x01, 0x03, 0x10, 0x7f, 0xff] {
+			assert_eq!(round_trip(false, val), val);
+		}
+		for val in [0x00, 0x01, 0x13, 0x12, 0x80, 0x91, 0xff] {
+			assert_eq!(round_trip(true, val), val);
+		}
+	}
+
+	#[test]
+	fn real_types() {
+		let attrs = crate::co::FILE_ATTRIBUTE::HIDDEN | crate::co::FILE_ATTRIBUTE::SYSTEM;
+		assert_eq!(format!("{:?}", attrs), "FILE_ATTRIBUTE::HIDDEN | FILE_ATTRIBUTE::SYSTEM");
+		assert_eq!(attrs.to_string(), "HIDDEN | SYSTEM");
+		assert_eq!("SYSTEM | FILE_ATTRIBUTE::HIDDEN".parse(), Ok(attrs));
+		assert_eq!(crate::co::REG::DWORD.to_string(), "DWORD");
+		assert_eq!("QWORD".parse(), Ok(crate::co::REG::QWORD));
+	}
+}
@@ -2,16 +2,22 @@
 
 use std::marker::PhantomData;
 
-use crate::co;
+use crate::{co, kernel};
 use crate::kernel::decl::{
-	ConvertSidToStringSid, HEVENT, HINSTANCE, HPIPE, HPROCESS, HTHREAD,
-	InitializeSecurityDescriptor, MAKEQWORD, WString,
+	Ace, ConvertSecurityDescriptorToStringSecurityDescriptor,
+	ConvertSidToStringSid, GuidParseError, HEVENT, HINSTANCE, HIOCP, HIWORD,
+	HPIPE, HPROCESS, HTHREAD, InitializeSecurityDescriptor, LOWORD, MAKEDWORD,
+	MAKEQWORD, SysResult, WString,
 };
-use crate::kernel::privs::{MAX_MODULE_NAME32, MAX_PATH};
+use crate::kernel::privs::{bool_to_sysresult, MAX_MODULE_NAME32, MAX_PATH};
 use crate::prelude::NativeBitflag;
 
 /// [`ACL`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-acl)
 /// struct.
+///
+/// This is the header of a variable-size structure, which is followed by the
+/// access control entries. An owned `ACL`, which can be edited, is kept by an
+/// [`AclGuard`](crate::guard::AclGuard).
 #[repr(C)]
 #[derive(Default)]
 pub struct ACL {
@@ -22,6 +28,49 @@ pub struct ACL {
 	pub Sbz2: u16,
 }
 
+impl ACL {
+	/// Returns an iterator over the access control entries, which are read
+	/// with
+	/// [`GetAce`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getace).
+	///
+	/// Only the entries with the basic layout can be read; see
+	/// [`Ace`](crate::Ace). Any other entry yields an
+	/// [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) error.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, GetNamedSecurityInfo};
+	///
+	/// let sd = GetNamedSecurityInfo(
+	///     "C:\\Temp\\foo.txt",
+	///     co::SE_OBJECT_TYPE::FILE_OBJECT,
+	///     co::SECURITY_INFORMATION::DACL,
+	/// )?;
+	///
+	/// if let Some(Some(dacl)) = sd.Dacl()? {
+	///     for ace in dacl.aces() {
+	///         let ace = ace?;
+	///         println!("{} {} {:#010x}", ace.ace_type, ace.sid, ace.mask);
+	///     }
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn aces(&self) -> impl Iterator<Item = SysResult<Ace>> + '_ {
+		(0..self.AceCount as u32).map(move |index| {
+			let mut pace = std::ptr::null_mut();
+			bool_to_sysresult(
+				unsafe {
+					kernel::ffi::GetAce(self as *const _ as _, index, &mut pace)
+				},
+			)?;
+			unsafe { Ace::from_ptr(pace as _) }
+		})
+	}
+}
+
 /// [`BY_HANDLE_FILE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/ns-fileapi-by_handle_file_information)
 /// struct.
 #[repr(C)]
@@ -61,6 +110,33 @@ pub struct FILETIME {
 
 /// [`GUID`](https://learn.microsoft.com/en-us/windows/win32/api/guiddef/ns-guiddef-guid)
 /// struct.
+///
+/// Can be parsed from strings in any of the formats below, with hex digits in
+/// any case:
+///
+/// * `00000000-0000-0000-c000-000000000046`;
+/// * `{00000000-0000-0000-c000-000000000046}`;
+/// * `(00000000-0000-0000-c000-000000000046)`;
+/// * `0000000000000000c000000000000046`.
+///
+/// When formatted, the alternate flag adds the braces, and the `X` format
+/// uses uppercase hex digits.
+///
+/// To generate a new random `GUID`, use
+/// [`CoCreateGuid`](crate::CoCreateGuid).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{GUID, GuidParseError};
+///
+/// let g: GUID = "{00000000-0000-0000-C000-000000000046}".parse()?;
+///
+/// assert_eq!(format!("{}", g), "00000000-0000-0000-c000-000000000046");
+/// assert_eq!(format!("{:#X}", g), "{00000000-0000-0000-C000-000000000046}");
+/// # Ok::<_, GuidParseError>(())
+/// ```
 #[repr(C)]
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 pub struct GUID {
@@ -72,17 +148,96 @@ pub struct GUID {
 
 impl std::fmt::Display for GUID {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		write!(f, "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+		std::fmt::LowerHex::fmt(self, f)
+	}
+}
+
+impl Default for GUID {
+	fn default() -> Self {
+		Self::new("00000000-0000-0000-c000-000000000046") // IUnknown GUID
+	}
+}
+
+impl std::fmt::LowerHex for GUID {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		let (open, close) = if f.alternate() { ("{", "}") } else { ("", "") };
+		write!(f, "{}{:08x}-{:04x}-{:04x}-{:04x}-{:012x}{}",
+			open,
 			self.data1, self.data2, self.data3,
 			self.data4.swap_bytes() >> 48,
 			self.data4.swap_bytes() & 0x0000_ffff_ffff_ffff,
+			close,
 		)
 	}
 }
 
-impl Default for GUID {
-	fn default() -> Self {
-		Self::new("00000000-0000-0000-c000-000000000046") // IUnknown GUID
+impl std::fmt::UpperHex for GUID {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		let (open, close) = if f.alternate() { ("{", "}") } else { ("", "") };
+		write!(f, "{}{:08X}-{:04X}-{:04X}-{:04X}-{:012X}{}",
+			open,
+			self.data1, self.data2, self.data3,
+			self.data4.swap_bytes() >> 48,
+			self.data4.swap_bytes() & 0x0000_ffff_ffff_ffff,
+			close,
+		)
+	}
+}
+
+impl std::str::FromStr for GUID {
+	type Err = GuidParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let bad_char = |pos: usize| { // char which contains the given byte
+			let (idx, ch) = s.char_indices()
+				.take_while(|(idx, _)| *idx <= pos)
+				.last()
+				.unwrap();
+			GuidParseError::BadChar(ch, idx)
+		};
+
+		let bytes = s.as_bytes();
+		let (digits, offset) = match bytes.len() {
+			32 | 36 => (bytes, 0),
+			38 => {
+				let close = match bytes[0] {
+					b'{' => b'}',
+					b'(' => b')',
+					_ => return Err(bad_char(0)),
+				};
+				if bytes[37] != close {
+					return Err(bad_char(37));
+				}
+				(&bytes[1..37], 1)
+			},
+			len => return Err(GuidParseError::BadLength(len)),
+		};
+
+		let hyphenated = digits.len() == 36;
+		let mut val: u128 = 0;
+		for (idx, ch) in digits.iter().enumerate() {
+			if hyphenated && [8, 13, 18, 23].contains(&idx) {
+				if *ch != b'-' {
+					return Err(bad_char(idx + offset));
+				}
+				continue;
+			}
+			let digit = match (*ch as char).to_digit(16) {
+				Some(digit) => digit,
+				None => return Err(bad_char(idx + offset)),
+			};
+			val = (val << 4) | digit as u128;
+		}
+
+		Ok(Self::from_u128(val))
+	}
+}
+
+impl TryFrom<&str> for GUID {
+	type Error = GuidParseError;
+
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		s.parse()
 	}
 }
 
@@ -92,7 +247,9 @@ impl GUID {
 	///
 	/// # Panics
 	///
-	/// Panics if the string has an invalid format.
+	/// Panics if the string has an invalid format. To parse a string which may
+	/// be invalid, or which is in another format, use
+	/// [`str::parse`](https://doc.rust-lang.org/std/primitive.str.html#method.parse).
 	///
 	/// # Examples
 	///
@@ -125,6 +282,58 @@ impl GUID {
 		}
 	}
 
+	/// Creates a new `GUID` from a 16-byte array in the
+	/// [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122) layout, where all
+	/// fields are big-endian. This is the order of the hex digits in the
+	/// string representation.
+	#[must_use]
+	pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+		Self::from_u128(u128::from_be_bytes(bytes))
+	}
+
+	/// Creates a new `GUID` from a 16-byte array in the Microsoft mixed-endian
+	/// layout, where the first three fields are little-endian. This is the
+	/// layout of the `GUID` struct in memory.
+	#[must_use]
+	pub const fn from_bytes_le(bytes: [u8; 16]) -> Self {
+		let b = bytes;
+		Self::from_bytes([b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6],
+			b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]])
+	}
+
+	/// Returns the `GUID` as a 16-byte array in the
+	/// [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122) layout, where all
+	/// fields are big-endian.
+	#[must_use]
+	pub const fn to_bytes(&self) -> [u8; 16] {
+		self.to_u128().to_be_bytes()
+	}
+
+	/// Returns the `GUID` as a 16-byte array in the Microsoft mixed-endian
+	/// layout, where the first three fields are little-endian.
+	#[must_use]
+	pub const fn to_bytes_le(&self) -> [u8; 16] {
+		let b = self.to_bytes();
+		[b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6],
+			b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]]
+	}
+
+	const fn from_u128(val: u128) -> Self {
+		Self {
+			data1: (val >> 96) as _,
+			data2: (val >> 80) as _,
+			data3: (val >> 64) as _,
+			data4: (val as u64).swap_bytes(),
+		}
+	}
+
+	const fn to_u128(self) -> u128 {
+		((self.data1 as u128) << 96)
+			| ((self.data2 as u128) << 80)
+			| ((self.data3 as u128) << 64)
+			| self.data4.swap_bytes() as u128
+	}
+
 	const fn parse_block<const N: usize>(chars: [u8; N]) -> u64 {
 		let mut res: u64 = 0;
 		let mut idx: usize = 0;
@@ -170,6 +379,131 @@ pub struct HEAPLIST32 {
 
 impl_default_with_size!(HEAPLIST32, dwSize);
 
+/// [`IO_COUNTERS`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-io_counters)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct IO_COUNTERS {
+	pub ReadOperationCount: u64,
+	pub WriteOperationCount: u64,
+	pub OtherOperationCount: u64,
+	pub ReadTransferCount: u64,
+	pub WriteTransferCount: u64,
+	pub OtherTransferCount: u64,
+}
+
+/// [`JOBOBJECT_ASSOCIATE_COMPLETION_PORT`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_associate_completion_port)
+/// struct.
+#[repr(C)]
+pub struct JOBOBJECT_ASSOCIATE_COMPLETION_PORT {
+	pub CompletionKey: usize,
+	pub CompletionPort: HIOCP,
+}
+
+impl_default!(JOBOBJECT_ASSOCIATE_COMPLETION_PORT);
+
+/// [`JOBOBJECT_BASIC_ACCOUNTING_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_basic_accounting_information)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct JOBOBJECT_BASIC_ACCOUNTING_INFORMATION {
+	pub TotalUserTime: i64,
+	pub TotalKernelTime: i64,
+	pub ThisPeriodTotalUserTime: i64,
+	pub ThisPeriodTotalKernelTime: i64,
+	pub TotalPageFaultCount: u32,
+	pub TotalProcesses: u32,
+	pub ActiveProcesses: u32,
+	pub TotalTerminatedProcesses: u32,
+}
+
+/// [`JOBOBJECT_BASIC_LIMIT_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_basic_limit_information)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct JOBOBJECT_BASIC_LIMIT_INFORMATION {
+	pub PerProcessUserTimeLimit: i64,
+	pub PerJobUserTimeLimit: i64,
+	pub LimitFlags: co::JOB_OBJECT_LIMIT,
+	pub MinimumWorkingSetSize: usize,
+	pub MaximumWorkingSetSize: usize,
+	pub ActiveProcessLimit: u32,
+	pub Affinity: usize,
+	pub PriorityClass: co::PRIORITY_CLASS,
+	pub SchedulingClass: u32,
+}
+
+/// [`JOBOBJECT_CPU_RATE_CONTROL_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_cpu_rate_control_information)
+/// struct.
+///
+/// The meaning of the union field depends on `ControlFlags`: it's either the
+/// `CpuRate`, the `Weight`, or the `MinRate`/`MaxRate` pair.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct JOBOBJECT_CPU_RATE_CONTROL_INFORMATION {
+	pub ControlFlags: co::JOB_OBJECT_CPU_RATE_CONTROL,
+	union0: u32,
+}
+
+impl JOBOBJECT_CPU_RATE_CONTROL_INFORMATION {
+	/// Returns the `CpuRate` union field, in hundredths of a percent.
+	#[must_use]
+	pub const fn CpuRate(&self) -> u32 {
+		self.union0
+	}
+
+	/// Sets the `CpuRate` union field, in hundredths of a percent.
+	pub fn set_CpuRate(&mut self, val: u32) {
+		self.union0 = val;
+	}
+
+	/// Returns the `Weight` union field.
+	#[must_use]
+	pub const fn Weight(&self) -> u32 {
+		self.union0
+	}
+
+	/// Sets the `Weight` union field, from 1 to 9.
+	pub fn set_Weight(&mut self, val: u32) {
+		self.union0 = val;
+	}
+
+	/// Returns the `MinRate` union field, in hundredths of a percent.
+	#[must_use]
+	pub const fn MinRate(&self) -> u16 {
+		LOWORD(self.union0)
+	}
+
+	/// Sets the `MinRate` union field, in hundredths of a percent.
+	pub fn set_MinRate(&mut self, val: u16) {
+		self.union0 = MAKEDWORD(val, HIWORD(self.union0));
+	}
+
+	/// Returns the `MaxRate` union field, in hundredths of a percent.
+	#[must_use]
+	pub const fn MaxRate(&self) -> u16 {
+		HIWORD(self.union0)
+	}
+
+	/// Sets the `MaxRate` union field, in hundredths of a percent.
+	pub fn set_MaxRate(&mut self, val: u16) {
+		self.union0 = MAKEDWORD(LOWORD(self.union0), val);
+	}
+}
+
+/// [`JOBOBJECT_EXTENDED_LIMIT_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_extended_limit_information)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct JOBOBJECT_EXTENDED_LIMIT_INFORMATION {
+	pub BasicLimitInformation: JOBOBJECT_BASIC_LIMIT_INFORMATION,
+	pub IoInfo: IO_COUNTERS,
+	pub ProcessMemoryLimit: usize,
+	pub JobMemoryLimit: usize,
+	pub PeakProcessMemoryUsed: usize,
+	pub PeakJobMemoryUsed: usize,
+}
+
 /// [`LANGID`](https://learn.microsoft.com/en-us/windows/win32/intl/language-identifiers)
 /// language identifier.
 #[repr(transparent)]
@@ -296,6 +630,23 @@ impl LUID {
 	}
 }
 
+/// [`LUID_AND_ATTRIBUTES`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-luid_and_attributes)
+/// struct.
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct LUID_AND_ATTRIBUTES {
+	pub Luid: LUID,
+	pub Attributes: co::SE_PRIVILEGE,
+}
+
+impl LUID_AND_ATTRIBUTES {
+	/// Creates a new `LUID_AND_ATTRIBUTES`.
+	#[must_use]
+	pub const fn new(luid: LUID, attrs: co::SE_PRIVILEGE) -> Self {
+		Self { Luid: luid, Attributes: attrs }
+	}
+}
+
 /// [`MODULEENTRY32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-moduleentry32w)
 /// struct.
 #[repr(C)]
@@ -475,6 +826,15 @@ pub struct PROCESSOR_NUMBER {
 	Reserved: u8,
 }
 
+/// [`SC_ACTION`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-sc_action)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct SC_ACTION {
+	pub Type: co::SC_ACTION,
+	pub Delay: u32,
+}
+
 /// [`SECURITY_ATTRIBUTES`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/aa379560(v=vs.85))
 /// struct.
 #[repr(C)]
@@ -495,15 +855,32 @@ impl<'a> SECURITY_ATTRIBUTES<'a> {
 
 /// [`SECURITY_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-security_descriptor)
 /// struct.
+///
+/// A security descriptor returned by the system is a variable-size
+/// structure in the self-relative format, which can be:
+///
+/// * parsed from an SDDL string with
+///   [`ConvertStringSecurityDescriptorToSecurityDescriptor`](crate::ConvertStringSecurityDescriptorToSecurityDescriptor);
+/// * retrieved from an object with
+///   [`GetNamedSecurityInfo`](crate::GetNamedSecurityInfo) or
+///   [`GetSecurityInfo`](crate::GetSecurityInfo).
+///
+/// All of them yield a
+/// [`LocalFreeSecurityDescriptorGuard`](crate::guard::LocalFreeSecurityDescriptorGuard).
+/// Its fields must be read through the methods, which work with both the
+/// absolute and self-relative formats.
+///
+/// Implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html),
+/// which yields the SDDL string.
 #[repr(C)]
 pub struct SECURITY_DESCRIPTOR {
-	pub Revision: u8,
-	pub Sbz1: u8,
-	pub Control: co::SE,
-	pub Owner: *mut std::ffi::c_void,
-	pub Group: *mut std::ffi::c_void,
-	pub Sacl: *mut ACL,
-	pub Dacl: *mut ACL,
+	Revision: u8,
+	Sbz1: u8,
+	Control: co::SE,
+	Owner: *mut std::ffi::c_void,
+	Group: *mut std::ffi::c_void,
+	Sacl: *mut ACL,
+	Dacl: *mut ACL,
 }
 
 impl Default for SECURITY_DESCRIPTOR {
@@ -512,6 +889,126 @@ impl Default for SECURITY_DESCRIPTOR {
 	}
 }
 
+impl std::fmt::Display for SECURITY_DESCRIPTOR {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match ConvertSecurityDescriptorToStringSecurityDescriptor(
+			self,
+			co::SECURITY_INFORMATION::OWNER
+				| co::SECURITY_INFORMATION::GROUP
+				| co::SECURITY_INFORMATION::DACL
+				| co::SECURITY_INFORMATION::SACL
+				| co::SECURITY_INFORMATION::LABEL,
+		) {
+			Ok(sddl) => write!(f, "{}", sddl),
+			Err(err) => write!(f, "{}", err),
+		}
+	}
+}
+
+impl SECURITY_DESCRIPTOR {
+	/// Returns the control flags with
+	/// [`GetSecurityDescriptorControl`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorcontrol).
+	#[must_use]
+	pub fn Control(&self) -> SysResult<co::SE> {
+		let mut control = co::SE::default();
+		let mut revision = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetSecurityDescriptorControl(
+					self as *const _ as _, &mut control.0, &mut revision)
+			},
+		).map(|_| control)
+	}
+
+	/// Returns the discretionary ACL with
+	/// [`GetSecurityDescriptorDacl`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptordacl).
+	///
+	/// Returns `None` if the DACL is not present, and `Some(None)` if it's a
+	/// NULL DACL, which grants full access to everyone.
+	#[must_use]
+	pub fn Dacl(&self) -> SysResult<Option<Option<&ACL>>> {
+		let (mut present, mut pacl, mut defaulted) = (0, std::ptr::null_mut(), 0);
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetSecurityDescriptorDacl(
+					self as *const _ as _, &mut present, &mut pacl, &mut defaulted)
+			},
+		).map(|_| (present != 0).then_some(unsafe { (pacl as *const ACL).as_ref() }))
+	}
+
+	/// Returns the primary group with
+	/// [`GetSecurityDescriptorGroup`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorgroup),
+	/// if any.
+	#[must_use]
+	pub fn Group(&self) -> SysResult<Option<&SID>> {
+		let (mut psid, mut defaulted) = (std::ptr::null_mut(), 0);
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetSecurityDescriptorGroup(
+					self as *const _ as _, &mut psid, &mut defaulted)
+			},
+		).map(|_| unsafe { (psid as *const SID).as_ref() })
+	}
+
+	/// Returns the owner with
+	/// [`GetSecurityDescriptorOwner`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorowner),
+	/// if any.
+	#[must_use]
+	pub fn Owner(&self) -> SysResult<Option<&SID>> {
+		let (mut psid, mut defaulted) = (std::ptr::null_mut(), 0);
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetSecurityDescriptorOwner(
+					self as *const _ as _, &mut psid, &mut defaulted)
+			},
+		).map(|_| unsafe { (psid as *const SID).as_ref() })
+	}
+
+	/// Returns the system ACL with
+	/// [`GetSecurityDescriptorSacl`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorsacl),
+	/// if any.
+	#[must_use]
+	pub fn Sacl(&self) -> SysResult<Option<&ACL>> {
+		let (mut present, mut pacl, mut defaulted) = (0, std::ptr::null_mut(), 0);
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetSecurityDescriptorSacl(
+					self as *const _ as _, &mut present, &mut pacl, &mut defaulted)
+			},
+		).map(|_| unsafe { (pacl as *const ACL).as_ref() })
+	}
+}
+
+/// [`SERVICE_STATUS`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_status)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct SERVICE_STATUS {
+	pub dwServiceType: co::SERVICE_TYPE,
+	pub dwCurrentState: co::SERVICE_STATE,
+	pub dwControlsAccepted: co::SERVICE_ACCEPT,
+	pub dwWin32ExitCode: co::ERROR,
+	pub dwServiceSpecificExitCode: u32,
+	pub dwCheckPoint: u32,
+	pub dwWaitHint: u32,
+}
+
+/// [`SERVICE_STATUS_PROCESS`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_status_process)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct SERVICE_STATUS_PROCESS {
+	pub dwServiceType: co::SERVICE_TYPE,
+	pub dwCurrentState: co::SERVICE_STATE,
+	pub dwControlsAccepted: co::SERVICE_ACCEPT,
+	pub dwWin32ExitCode: co::ERROR,
+	pub dwServiceSpecificExitCode: u32,
+	pub dwCheckPoint: u32,
+	pub dwWaitHint: u32,
+	pub dwProcessId: u32,
+	pub dwServiceFlags: u32,
+}
+
 /// [`SID`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-sid)
 /// struct.
 /// 
@@ -638,6 +1135,25 @@ impl<'a, 'b> STARTUPINFO<'a, 'b> {
 	}
 }
 
+/// [`STARTUPINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-startupinfoexw)
+/// struct.
+///
+/// Used when the process is created with
+/// [`CREATE::EXTENDED_STARTUPINFO_PRESENT`](crate::co::CREATE::EXTENDED_STARTUPINFO_PRESENT).
+#[repr(C)]
+pub struct STARTUPINFOEX<'a, 'b> {
+	pub StartupInfo: STARTUPINFO<'a, 'b>,
+	pub lpAttributeList: *mut std::ffi::c_void,
+}
+
+impl<'a, 'b> Default for STARTUPINFOEX<'a, 'b> {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.StartupInfo.cb = std::mem::size_of::<Self>() as _;
+		obj
+	}
+}
+
 /// [`SYSTEM_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info)
 /// struct.
 #[repr(C)]
@@ -757,3 +1273,78 @@ impl WIN32_FIND_DATA {
 		MAKEQWORD(self.nFileSizeLow, self.nFileSizeHigh)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const BYTES: [u8; 16] = [0x6b, 0x29, 0xfc, 0x40, 0xca, 0x47, 0x10, 0x67,
+		0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda];
+	const BYTES_LE: [u8; 16] = [0x40, 0xfc, 0x29, 0x6b, 0x47, 0xca, 0x67, 0x10,
+		0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda];
+
+	fn parse(s: &str) -> Result<[u8; 16], GuidParseError> {
+		s.parse::<GUID>().map(|g| g.to_bytes())
+	}
+
+	#[test]
+	fn guid_byte_layouts() {
+		let g = GUID::new("6b29fc40-ca47-1067-b31d-00dd010662da");
+		assert_eq!(g.to_bytes(), BYTES);
+		assert_eq!(g.to_bytes_le(), BYTES_LE);
+		assert!(GUID::from_bytes(BYTES) == g);
+		assert!(GUID::from_bytes_le(BYTES_LE) == g);
+
+		#[cfg(target_endian = "little")]
+		assert_eq!(unsafe { std::mem::transmute::<GUID, [u8; 16]>(g) }, BYTES_LE); // struct memory layout
+	}
+
+	#[test]
+	fn guid_string_round_trip() {
+		for s in [
+			"6b29fc40-ca47-1067-b31d-00dd010662da",
+			"6B29FC40-CA47-1067-B31D-00DD010662DA",
+			"{6b29fc40-ca47-1067-b31d-00dd010662da}",
+			"{6B29FC40-CA47-1067-B31D-00DD010662DA}",
+			"(6b29fc40-CA47-1067-b31d-00DD010662da)",
+			"6b29fc40ca471067b31d00dd010662da",
+			"6B29FC40CA471067B31D00DD010662DA",
+		] {
+			assert_eq!(parse(s), Ok(BYTES), "{}", s);
+		}
+
+		let g = GUID::from_bytes(BYTES);
+		assert_eq!(format!("{}", g), "6b29fc40-ca47-1067-b31d-00dd010662da");
+		assert_eq!(format!("{:x}", g), "6b29fc40-ca47-1067-b31d-00dd010662da");
+		assert_eq!(format!("{:#x}", g), "{6b29fc40-ca47-1067-b31d-00dd010662da}");
+		assert_eq!(format!("{:X}", g), "6B29FC40-CA47-1067-B31D-00DD010662DA");
+		assert_eq!(format!("{:#X}", g), "{6B29FC40-CA47-1067-B31D-00DD010662DA}");
+
+		for text in [format!("{}", g), format!("{:#x}", g), format!("{:X}", g), format!("{:#X}", g)] {
+			assert!(text.parse::<GUID>().unwrap() == g, "{}", text);
+		}
+		assert!(GUID::try_from("6b29fc40-ca47-1067-b31d-00dd010662da").unwrap() == g);
+		assert_eq!(format!("{}", GUID::default()), "00000000-0000-0000-c000-000000000046");
+	}
+
+	#[test]
+	fn guid_parse_errors() {
+		assert_eq!(parse(""), Err(GuidParseError::BadLength(0)));
+		assert_eq!(parse("6b29fc40-ca47-1067-b31d-00dd010662d"), Err(GuidParseError::BadLength(35)));
+		assert_eq!(parse("6b29fc40-ca47-1067-b31d-00dd010662da0"), Err(GuidParseError::BadLength(37)));
+		assert_eq!(parse("{6b29fc40-ca47-1067-b31d-00dd010662da}0"), Err(GuidParseError::BadLength(39)));
+
+		assert_eq!(parse("6b29fc40-ca47-1067-b31d-00dd010662dg"), Err(GuidParseError::BadChar('g', 35)));
+		assert_eq!(parse("6b29fc40ca471067b31d00dd01066 da"), Err(GuidParseError::BadChar(' ', 29)));
+		assert_eq!(parse("{6b29fc40-ca47-1067-b31d-00dd010662xa}"), Err(GuidParseError::BadChar('x', 35)));
+		assert_eq!(parse("+6b29fc40ca471067b31d00dd010662d"), Err(GuidParseError::BadChar('+', 0)));
+
+		assert_eq!(parse("6b29fc4-0ca47-1067-b31d-00dd010662da"), Err(GuidParseError::BadChar('-', 7)));
+		assert_eq!(parse("6b29fc40ca47-1067-b31d-00dd010662da-"), Err(GuidParseError::BadChar('c', 8)));
+		assert_eq!(parse("6b29fc40-ca47-1067-b31d00-dd010662da"), Err(GuidParseError::BadChar('0', 23)));
+
+		assert_eq!(parse("[6b29fc40-ca47-1067-b31d-00dd010662da]"), Err(GuidParseError::BadChar('[', 0)));
+		assert_eq!(parse("{6b29fc40-ca47-1067-b31d-00dd010662da)"), Err(GuidParseError::BadChar(')', 37)));
+		assert_eq!(parse("6b29fc40-ca47-1067-b31d-00dd010662é"), Err(GuidParseError::BadChar('é', 34))); // 2-byte char
+	}
+}
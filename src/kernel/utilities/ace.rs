@@ -0,0 +1,116 @@
+use crate::co;
+use crate::kernel::decl::{CopySid, SID, SysResult};
+use crate::kernel::guard::SidGuard;
+
+/// An access control entry, which can be read from an [`ACL`](crate::ACL)
+/// with [`ACL::aces`](crate::ACL::aces), and added to an
+/// [`AclGuard`](crate::guard::AclGuard) with
+/// [`AclGuard::add_ace`](crate::guard::AclGuard::add_ace).
+///
+/// Only the entries with the basic layout – a header, an access mask and a
+/// [`SID`](crate::SID) – are supported, which are:
+///
+/// * [`ACE_TYPE::ACCESS_ALLOWED`](crate::co::ACE_TYPE::ACCESS_ALLOWED);
+/// * [`ACE_TYPE::ACCESS_DENIED`](crate::co::ACE_TYPE::ACCESS_DENIED);
+/// * [`ACE_TYPE::SYSTEM_AUDIT`](crate::co::ACE_TYPE::SYSTEM_AUDIT);
+/// * [`ACE_TYPE::SYSTEM_ALARM`](crate::co::ACE_TYPE::SYSTEM_ALARM), which can
+///   only be read;
+/// * [`ACE_TYPE::SYSTEM_MANDATORY_LABEL`](crate::co::ACE_TYPE::SYSTEM_MANDATORY_LABEL).
+///
+/// # Examples
+///
+/// Grant read access to Everyone, inherited by files and subdirectories:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, Ace, CreateWellKnownSid};
+///
+/// let sid = CreateWellKnownSid(co::WELL_KNOWN_SID_TYPE::World, None)?;
+///
+/// let ace = Ace::allow(
+///     co::ACE::OBJECT_INHERIT | co::ACE::CONTAINER_INHERIT,
+///     co::GENERIC::READ.into(),
+///     &sid,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone)]
+pub struct Ace {
+	/// The type of the entry.
+	pub ace_type: co::ACE_TYPE,
+	/// The inheritance and audit flags.
+	pub flags: co::ACE,
+	/// The access mask.
+	pub mask: u32,
+	/// The trustee.
+	pub sid: SidGuard,
+}
+
+impl Ace {
+	/// Creates an [`ACE_TYPE::ACCESS_ALLOWED`](crate::co::ACE_TYPE::ACCESS_ALLOWED)
+	/// entry, copying the [`SID`](crate::SID).
+	#[must_use]
+	pub fn allow(flags: co::ACE, mask: u32, sid: &SID) -> SysResult<Self> {
+		Self::with_type(co::ACE_TYPE::ACCESS_ALLOWED, flags, mask, sid)
+	}
+
+	/// Creates a [`ACE_TYPE::SYSTEM_AUDIT`](crate::co::ACE_TYPE::SYSTEM_AUDIT)
+	/// entry, copying the [`SID`](crate::SID).
+	///
+	/// The `flags` should contain
+	/// [`ACE::SUCCESSFUL_ACCESS`](crate::co::ACE::SUCCESSFUL_ACCESS),
+	/// [`ACE::FAILED_ACCESS`](crate::co::ACE::FAILED_ACCESS), or both.
+	#[must_use]
+	pub fn audit(flags: co::ACE, mask: u32, sid: &SID) -> SysResult<Self> {
+		Self::with_type(co::ACE_TYPE::SYSTEM_AUDIT, flags, mask, sid)
+	}
+
+	/// Creates an [`ACE_TYPE::ACCESS_DENIED`](crate::co::ACE_TYPE::ACCESS_DENIED)
+	/// entry, copying the [`SID`](crate::SID).
+	#[must_use]
+	pub fn deny(flags: co::ACE, mask: u32, sid: &SID) -> SysResult<Self> {
+		Self::with_type(co::ACE_TYPE::ACCESS_DENIED, flags, mask, sid)
+	}
+
+	/// Creates an entry of any type, copying the [`SID`](crate::SID).
+	#[must_use]
+	pub fn with_type(
+		ace_type: co::ACE_TYPE,
+		flags: co::ACE,
+		mask: u32,
+		sid: &SID,
+	) -> SysResult<Self>
+	{
+		Ok(Self { ace_type, flags, mask, sid: CopySid(sid)? })
+	}
+
+	/// Tells whether the entry type has the basic layout.
+	pub(in crate::kernel) fn is_basic(ace_type: co::ACE_TYPE) -> bool {
+		matches!(ace_type,
+			co::ACE_TYPE::ACCESS_ALLOWED
+				| co::ACE_TYPE::ACCESS_DENIED
+				| co::ACE_TYPE::SYSTEM_AUDIT
+				| co::ACE_TYPE::SYSTEM_ALARM
+				| co::ACE_TYPE::SYSTEM_MANDATORY_LABEL)
+	}
+
+	/// Parses an entry returned by
+	/// [`GetAce`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getace).
+	///
+	/// # Safety
+	///
+	/// The pointer must point to a valid `ACE_HEADER`.
+	pub(in crate::kernel) unsafe fn from_ptr(pace: *const u8) -> SysResult<Self> {
+		let ace_type = co::ACE_TYPE(*pace);
+		if !Self::is_basic(ace_type) {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		}
+
+		Ok(Self {
+			ace_type,
+			flags: co::ACE(*pace.add(1)),
+			mask: (pace.add(4) as *const u32).read_unaligned(), // after the header
+			sid: CopySid(&*(pace.add(8) as *const SID))?, // after the mask
+		})
+	}
+}
@@ -0,0 +1,387 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::co;
+use crate::kernel::decl::{
+	HEVENT, HFILE, OVERLAPPED, SysResult, WaitForMultipleObjects,
+};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::prelude::{Handle, kernel_Hevent, kernel_Hfile};
+
+/// A change reported by a [`DirWatcher`](crate::DirWatcher), parsed from a
+/// [`FILE_NOTIFY_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-file_notify_information)
+/// entry.
+///
+/// The paths are relative to the watched directory.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DirChange {
+	/// The file was added to the directory.
+	Added(String),
+	/// The file was removed from the directory.
+	Removed(String),
+	/// The file was modified, including its time stamp or attributes.
+	Modified(String),
+	/// The file was renamed, and this is the old name. It's usually followed
+	/// by a [`RenamedNewName`](crate::DirChange::RenamedNewName).
+	RenamedOldName(String),
+	/// The file was renamed, and this is the new name.
+	RenamedNewName(String),
+}
+
+impl DirChange {
+	/// Parses a buffer filled by
+	/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges),
+	/// which contains a sequence of `FILE_NOTIFY_INFORMATION` entries.
+	///
+	/// The buffer must be truncated to the number of bytes actually returned.
+	/// Entries with an unknown action are skipped, and parsing stops at the
+	/// first malformed entry.
+	#[must_use]
+	pub fn parse_buffer(buf: &[u8]) -> Vec<DirChange> {
+		let read_u32 = |off: usize| -> Option<u32> {
+			buf.get(off..off + 4)
+				.map(|b| u32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+		};
+
+		let mut changes = Vec::new();
+		let mut off = 0;
+
+		while let (Some(next_off), Some(action), Some(name_len)) = ( // stop at truncated header
+			read_u32(off), read_u32(off + 4), read_u32(off + 8),
+		) {
+			let name_bytes = match buf.get(off + 12..off + 12 + name_len as usize) {
+				Some(name_bytes) => name_bytes,
+				None => break, // truncated name
+			};
+			let name = String::from_utf16_lossy(
+				&name_bytes.chunks_exact(2)
+					.map(|ch| u16::from_ne_bytes([ch[0], ch[1]]))
+					.collect::<Vec<_>>(),
+			);
+
+			match co::FILE_ACTION(action) {
+				co::FILE_ACTION::ADDED => changes.push(Self::Added(name)),
+				co::FILE_ACTION::REMOVED => changes.push(Self::Removed(name)),
+				co::FILE_ACTION::MODIFIED => changes.push(Self::Modified(name)),
+				co::FILE_ACTION::RENAMED_OLD_NAME => changes.push(Self::RenamedOldName(name)),
+				co::FILE_ACTION::RENAMED_NEW_NAME => changes.push(Self::RenamedNewName(name)),
+				_ => {}, // unknown action
+			}
+
+			if next_off == 0 {
+				break; // last entry
+			}
+			off += next_off as usize;
+		}
+
+		changes
+	}
+
+	/// Returns the path of the changed file, relative to the watched
+	/// directory.
+	#[must_use]
+	pub fn path(&self) -> &str {
+		match self {
+			Self::Added(path)
+				| Self::Removed(path)
+				| Self::Modified(path)
+				| Self::RenamedOldName(path)
+				| Self::RenamedNewName(path) => path,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Stops a [`DirWatcher`](crate::DirWatcher), possibly from another thread.
+///
+/// Created with [`DirWatcher::stopper`](crate::DirWatcher::stopper).
+#[derive(Clone)]
+pub struct DirWatcherStopper {
+	hev_stop: Arc<CloseHandleGuard<HEVENT>>,
+}
+
+impl DirWatcherStopper {
+	/// Stops the watcher: a blocked
+	/// [`DirWatcher::read`](crate::DirWatcher::read) returns `None`, and so
+	/// will any further reads.
+	pub fn stop(&self) -> SysResult<()> {
+		self.hev_stop.SetEvent()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Watches the changes in a directory with
+/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges).
+///
+/// The reading methods block the current thread until changes happen, so you
+/// may want to run the watcher in another thread, stopping it with a
+/// [`DirWatcherStopper`](crate::DirWatcherStopper). To receive the changes in
+/// the GUI thread, see [`gui::DirMonitor`](crate::gui::DirMonitor).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, DirChange, DirWatcher};
+///
+/// let mut watcher = DirWatcher::open(
+///     "C:\\Temp",
+///     true,
+///     co::FILE_NOTIFY_CHANGE::FILE_NAME | co::FILE_NOTIFY_CHANGE::LAST_WRITE,
+/// )?;
+///
+/// let stopper = watcher.stopper(); // can be sent to another thread
+///
+/// for change in watcher.iter() {
+///     match change? {
+///         DirChange::Added(path) => println!("Added: {}", path),
+///         DirChange::Removed(path) => println!("Removed: {}", path),
+///         change => println!("Other: {}", change.path()),
+///     }
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct DirWatcher {
+	hdir: CloseHandleGuard<HFILE>,
+	hev_io: CloseHandleGuard<HEVENT>,
+	stopper: DirWatcherStopper,
+	recursive: bool,
+	filter: co::FILE_NOTIFY_CHANGE,
+	buf: Vec<u32>, // ReadDirectoryChanges requires u32 alignment
+}
+
+impl DirWatcher {
+	const BUF_SZ: usize = 64 * 1024; // bytes; larger buffers fail on network drives
+
+	/// Opens the directory to be watched.
+	///
+	/// If `recursive` is true, the changes in all subdirectories are also
+	/// reported.
+	#[must_use]
+	pub fn open(
+		dir_path: &str,
+		recursive: bool,
+		filter: co::FILE_NOTIFY_CHANGE,
+	) -> SysResult<Self>
+	{
+		let (hdir, _) = HFILE::CreateFile(
+			dir_path,
+			co::GENERIC::READ,
+			co::FILE_SHARE::READ | co::FILE_SHARE::WRITE | co::FILE_SHARE::DELETE,
+			None,
+			co::DISPOSITION::OPEN_EXISTING,
+			co::FILE_ATTRIBUTE::FLAG_BACKUP_SEMANTICS | co::FILE_ATTRIBUTE::FLAG_OVERLAPPED,
+			None,
+		)?;
+
+		Ok(Self {
+			hdir,
+			hev_io: HEVENT::CreateEvent(None, true, false, None)?,
+			stopper: DirWatcherStopper {
+				hev_stop: Arc::new(HEVENT::CreateEvent(None, true, false, None)?),
+			},
+			recursive,
+			filter,
+			buf: vec![0; Self::BUF_SZ / std::mem::size_of::<u32>()],
+		})
+	}
+
+	/// Returns the underlying directory handle.
+	#[must_use]
+	pub fn hdir(&self) -> &HFILE {
+		&self.hdir
+	}
+
+	/// Returns an iterator over the changes, which blocks until they happen.
+	///
+	/// If the internal buffer overflows, an
+	/// [`ERROR::NOTIFY_ENUM_DIR`](crate::co::ERROR::NOTIFY_ENUM_DIR) error is
+	/// yielded, and the iteration continues. Any other error ends the
+	/// iteration, as well as stopping the watcher.
+	#[must_use]
+	pub fn iter(&mut self) -> impl Iterator<Item = SysResult<DirChange>> + '_ {
+		DirWatcherIter {
+			watcher: self,
+			pending: VecDeque::new(),
+			no_more: false,
+		}
+	}
+
+	/// Blocks until changes happen, returning them in the order they were
+	/// reported.
+	///
+	/// Returns `None` if the watcher was stopped by a
+	/// [`DirWatcherStopper`](crate::DirWatcherStopper). If the internal buffer
+	/// overflows, the changes are lost and an
+	/// [`ERROR::NOTIFY_ENUM_DIR`](crate::co::ERROR::NOTIFY_ENUM_DIR) error is
+	/// returned; you may then enumerate the directory again.
+	pub fn read(&mut self) -> SysResult<Option<Vec<DirChange>>> {
+		let buf = unsafe {
+			std::slice::from_raw_parts_mut(
+				self.buf.as_mut_ptr() as *mut u8,
+				self.buf.len() * std::mem::size_of::<u32>(),
+			)
+		};
+
+		let mut ov = OVERLAPPED {
+			hEvent: unsafe { self.hev_io.raw_copy() },
+			..Default::default()
+		};
+		self.hev_io.ResetEvent()?;
+		self.hdir.ReadDirectoryChanges(
+			buf, self.recursive, self.filter, Some(&mut ov))?;
+
+		let (_, idx) = WaitForMultipleObjects(
+			&[&*self.hev_io, &**self.stopper.hev_stop], false, None)?;
+
+		if idx == 1 { // stop signaled
+			let _ = self.hdir.CancelIoEx(Some(&ov)); // operation may be already complete
+			let _ = self.hdir.GetOverlappedResult(&ov, true); // wait until buffer is released
+			return Ok(None);
+		}
+
+		match self.hdir.GetOverlappedResult(&ov, true)? {
+			0 => Err(co::ERROR::NOTIFY_ENUM_DIR), // buffer overflow
+			num_bytes => Ok(Some(DirChange::parse_buffer(&buf[..num_bytes as usize]))),
+		}
+	}
+
+	/// Returns a [`DirWatcherStopper`](crate::DirWatcherStopper), which can be
+	/// used to stop this watcher from another thread.
+	#[must_use]
+	pub fn stopper(&self) -> DirWatcherStopper {
+		self.stopper.clone()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct DirWatcherIter<'a> {
+	watcher: &'a mut DirWatcher,
+	pending: VecDeque<DirChange>,
+	no_more: bool,
+}
+
+impl<'a> Iterator for DirWatcherIter<'a> {
+	type Item = SysResult<DirChange>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(change) = self.pending.pop_front() {
+				return Some(Ok(change));
+			} else if self.no_more {
+				return None;
+			}
+
+			match self.watcher.read() {
+				Ok(Some(changes)) => self.pending.extend(changes),
+				Ok(None) => {
+					self.no_more = true; // stopped
+				},
+				Err(co::ERROR::NOTIFY_ENUM_DIR) => {
+					return Some(Err(co::ERROR::NOTIFY_ENUM_DIR)); // changes lost, but we can go on
+				},
+				Err(e) => {
+					self.no_more = true; // prevent further iterations
+					let _ = self.watcher.stopper.stop();
+					return Some(Err(e));
+				},
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn stopper_is_send_sync() {
+		fn assert_send_sync<T: Send + Sync>() {}
+		assert_send_sync::<DirWatcherStopper>(); // meant to be sent to another thread
+	}
+
+	/// Builds a buffer of FILE_NOTIFY_INFORMATION entries, each one aligned to
+	/// 32 bits.
+	fn buffer(entries: &[(u32, &str)]) -> Vec<u8> {
+		let mut buf = Vec::<u8>::default();
+		for (idx, (action, name)) in entries.iter().enumerate() {
+			let name_bytes = name.encode_utf16()
+				.flat_map(|ch| ch.to_ne_bytes())
+				.collect::<Vec<_>>();
+			let entry_len = (12 + name_bytes.len() + 3) & !3;
+			let next_off = if idx == entries.len() - 1 { 0 } else { entry_len as u32 };
+
+			let start = buf.len();
+			buf.extend(next_off.to_ne_bytes());
+			buf.extend(action.to_ne_bytes());
+			buf.extend((name_bytes.len() as u32).to_ne_bytes());
+			buf.extend(name_bytes);
+			buf.resize(start + entry_len, 0x00);
+		}
+		buf
+	}
+
+	#[test]
+	fn single_entry() {
+		assert_eq!(
+			DirChange::parse_buffer(&buffer(&[(1, "foo.txt")])),
+			[DirChange::Added("foo.txt".to_owned())],
+		);
+		assert_eq!(
+			DirChange::parse_buffer(&buffer(&[(3, "sub\\ação.rs")])),
+			[DirChange::Modified("sub\\ação.rs".to_owned())],
+		);
+		assert!(DirChange::parse_buffer(&[]).is_empty());
+	}
+
+	#[test]
+	fn multiple_entries() {
+		let changes = DirChange::parse_buffer(&buffer(&[
+			(1, "a"), (2, "bb"), (3, "ccc"), (1, "dddd"),
+		]));
+		assert_eq!(changes, [
+			DirChange::Added("a".to_owned()),
+			DirChange::Removed("bb".to_owned()),
+			DirChange::Modified("ccc".to_owned()),
+			DirChange::Added("dddd".to_owned()),
+		]);
+		assert_eq!(changes[2].path(), "ccc");
+	}
+
+	#[test]
+	fn rename_pairs() {
+		assert_eq!(
+			DirChange::parse_buffer(&buffer(&[(4, "old.txt"), (5, "new.txt")])),
+			[
+				DirChange::RenamedOldName("old.txt".to_owned()),
+				DirChange::RenamedNewName("new.txt".to_owned()),
+			],
+		);
+	}
+
+	#[test]
+	fn unknown_actions() {
+		assert_eq!(
+			DirChange::parse_buffer(&buffer(&[(0, "x"), (2, "y"), (99, "z"), (1, "w")])),
+			[DirChange::Removed("y".to_owned()), DirChange::Added("w".to_owned())],
+		);
+	}
+
+	#[test]
+	fn truncated_buffers() {
+		let buf = buffer(&[(1, "first"), (2, "second")]);
+		let second_off = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+
+		let first = [DirChange::Added("first".to_owned())];
+		assert_eq!(DirChange::parse_buffer(&buf[..second_off + 8]), first); // truncated header
+		assert_eq!(DirChange::parse_buffer(&buf[..second_off + 14]), first); // truncated name
+		assert!(DirChange::parse_buffer(&buf[..11]).is_empty());
+
+		let mut bad_len = buf.clone();
+		bad_len[8..12].copy_from_slice(&1000u32.to_ne_bytes()); // name past the end
+		assert!(DirChange::parse_buffer(&bad_len).is_empty());
+	}
+}
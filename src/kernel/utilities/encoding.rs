@@ -43,6 +43,130 @@ impl std::fmt::Display for Encoding {
 }
 
 impl Encoding {
+	/// Returns the [BOM](https://en.wikipedia.org/wiki/Byte_order_mark) bytes
+	/// of the encoding; empty for [`Ansi`](crate::Encoding::Ansi),
+	/// [`Win1252`](crate::Encoding::Win1252) and
+	/// [`Unknown`](crate::Encoding::Unknown), which have no BOM.
+	#[must_use]
+	pub const fn bom(&self) -> &'static [u8] {
+		match self {
+			Self::Unknown | Self::Ansi | Self::Win1252 => &[],
+			Self::Utf8 => &[0xef, 0xbb, 0xbf],
+			Self::Utf16be => &[0xfe, 0xff],
+			Self::Utf16le => &[0xff, 0xfe],
+			Self::Utf32be => &[0x00, 0x00, 0xfe, 0xff],
+			Self::Utf32le => &[0xff, 0xfe, 0x00, 0x00],
+			Self::Scsu => &[0x0e, 0xfe, 0xff],
+			Self::Bocu1 => &[0xfb, 0xee, 0x28],
+		}
+	}
+
+	/// Decodes the raw data as a string in this encoding, skipping the BOM, if
+	/// any. Invalid sequences are replaced by
+	/// [`U+FFFD`](https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html).
+	///
+	/// To decode data which arrives in chunks, use a
+	/// [`TextDecoder`](crate::TextDecoder).
+	///
+	/// # Panics
+	///
+	/// Panics if the encoding is [`Unknown`](crate::Encoding::Unknown).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{Encoding, FileAccess, FileMapped};
+	///
+	/// let file_in = FileMapped::open("C:\\Temp\\foo.txt", FileAccess::ExistingReadOnly)?;
+	/// let (encoding, _) = Encoding::guess(file_in.as_slice());
+	/// let contents = encoding.decode(file_in.as_slice());
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn decode(&self, data: &[u8]) -> String {
+		let mut decoder = TextDecoder::new(*self);
+		let mut text = decoder.feed(data);
+		text.push_str(&decoder.finish());
+		text
+	}
+
+	/// Encodes the string into raw data in this encoding, optionally
+	/// prepended by its [BOM](crate::Encoding::bom).
+	///
+	/// Characters which cannot be represented in
+	/// [`Ansi`](crate::Encoding::Ansi) or
+	/// [`Win1252`](crate::Encoding::Win1252) are replaced by `?`.
+	///
+	/// # Panics
+	///
+	/// Panics if the encoding is [`Unknown`](crate::Encoding::Unknown).
+	///
+	/// # Examples
+	///
+	/// Saving a text file which can be read back with
+	/// [`WString::parse`](crate::WString::parse):
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{Encoding, File, FileAccess};
+	///
+	/// let data = Encoding::Utf16le.encode("Hello, world", true);
+	///
+	/// let file_out = File::open("C:\\Temp\\foo.txt", FileAccess::OpenOrCreateRW)?;
+	/// file_out.erase_and_write(&data)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn encode(&self, text: &str, with_bom: bool) -> Vec<u8> {
+		let bom = if with_bom && !self.bom().is_empty() { Some('\u{feff}') } else { None };
+		let chars = bom.into_iter().chain(text.chars()); // BOM is just an U+FEFF char
+
+		match self {
+			Self::Unknown => panic!("Cannot encode with Unknown encoding."),
+			Self::Ansi => text.chars()
+				.map(|ch| if (ch as u32) < 0x80 { ch as u8 } else { b'?' })
+				.collect(),
+			Self::Win1252 => text.chars()
+				.map(|ch| Self::win1252_byte(ch).unwrap_or(b'?'))
+				.collect(),
+			Self::Utf8 => {
+				let mut data = Vec::with_capacity(text.len() + 3);
+				chars.for_each(|ch| {
+					data.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+				});
+				data
+			},
+			Self::Utf16be | Self::Utf16le => {
+				let mut data = Vec::with_capacity(text.len() * 2 + 2);
+				chars.for_each(|ch| {
+					for unit in ch.encode_utf16(&mut [0; 2]) {
+						data.extend_from_slice(&if *self == Self::Utf16be {
+							unit.to_be_bytes()
+						} else {
+							unit.to_le_bytes()
+						});
+					}
+				});
+				data
+			},
+			Self::Utf32be | Self::Utf32le => chars
+				.flat_map(|ch| if *self == Self::Utf32be {
+					(ch as u32).to_be_bytes()
+				} else {
+					(ch as u32).to_le_bytes()
+				})
+				.collect(),
+			Self::Scsu => ScsuEncoder::default().encode(chars),
+			Self::Bocu1 => {
+				let mut data = Vec::with_capacity(text.len() + 3);
+				let mut prev = BOCU1_ASCII_PREV;
+				chars.for_each(|ch| bocu1_encode(&mut prev, ch, &mut data));
+				data
+			},
+		}
+	}
+
 	/// Guesses the encoding of the given raw data, also returning the size of
 	/// its [BOM](https://en.wikipedia.org/wiki/Byte_order_mark), if any.
 	#[must_use]
@@ -79,6 +203,11 @@ impl Encoding {
 			return Some((Self::Utf16be, UTF16BE.len()));
 		}
 
+		const UTF32LE: [u8; 4] = [0xff, 0xfe, 0x00, 0x00];
+		if has_bom(&UTF32LE) { // must come before UTF-16 LE, which is a prefix
+			return Some((Self::Utf32le, UTF32LE.len()));
+		}
+
 		const UTF16LE: [u8; 2] = [0xff, 0xfe];
 		if has_bom(&UTF16LE) {
 			return Some((Self::Utf16le, UTF16LE.len()));
@@ -89,11 +218,6 @@ impl Encoding {
 			return Some((Self::Utf32be, UTF32BE.len()));
 		}
 
-		const UTF32LE: [u8; 4] = [0xff, 0xfe, 0x00, 0x00];
-		if has_bom(&UTF32LE) {
-			return Some((Self::Utf32le, UTF32LE.len()));
-		}
-
 		const SCSU: [u8; 3] = [0x0e, 0xfe, 0xff];
 		if has_bom(&SCSU) {
 			return Some((Self::Scsu, SCSU.len()));
@@ -192,4 +316,862 @@ impl Encoding {
 		}
 		true // all the conditions accepted through the whole string
 	}
+
+	fn win1252_byte(ch: char) -> Option<u8> {
+		match ch as u32 {
+			0x00..=0x7f | 0xa0..=0xff => Some(ch as u8),
+			c => WIN1252_80_9F.iter()
+				.position(|unit| *unit as u32 == c)
+				.map(|idx| 0x80 + idx as u8),
+		}
+	}
+}
+
+/// Characters of the 0x80-0x9f range of Windows-1252. The 5 undefined bytes
+/// are mapped to the C1 controls with the same value, like
+/// [`MultiByteToWideChar`](crate::MultiByteToWideChar) does.
+const WIN1252_80_9F: [u16; 32] = [
+	0x20ac, 0x0081, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+	0x02c6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008d, 0x017d, 0x008f,
+	0x0090, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+	0x02dc, 0x2122, 0x0161, 0x203a, 0x0153, 0x009d, 0x017e, 0x0178,
+];
+
+//------------------------------------------------------------------------------
+
+// SCSU, as specified in https://www.unicode.org/reports/tr6/
+
+const SCSU_STATIC_WINDOWS: [u32; 8] = [
+	0x0000, 0x0080, 0x0100, 0x0300, 0x2000, 0x2080, 0x2100, 0x3000,
+];
+const SCSU_DYNAMIC_WINDOWS: [u32; 8] = [
+	0x0080, 0x00c0, 0x0400, 0x0600, 0x0900, 0x3040, 0x30a0, 0xff00,
+];
+
+const SCSU_SQ0: u8 = 0x01; // quote from window 0-7, single-byte mode
+const SCSU_SDX: u8 = 0x0b; // define extended window
+const SCSU_SQU: u8 = 0x0e; // quote Unicode
+const SCSU_SCU: u8 = 0x0f; // change to Unicode mode
+const SCSU_SC0: u8 = 0x10; // change to window 0-7
+const SCSU_SD0: u8 = 0x18; // define window 0-7
+const SCSU_UC0: u8 = 0xe0; // change to window 0-7, Unicode mode
+const SCSU_UD0: u8 = 0xe8; // define window 0-7, Unicode mode
+const SCSU_UQU: u8 = 0xf0; // quote Unicode, Unicode mode
+const SCSU_UDX: u8 = 0xf1; // define extended window, Unicode mode
+
+/// Returns the dynamic window offset of a define-window byte.
+const fn scsu_window_offset(b: u8) -> Option<u32> {
+	match b {
+		0x01..=0x67 => Some(b as u32 * 0x80),
+		0x68..=0xa7 => Some(b as u32 * 0x80 + 0xac00),
+		0xf9 => Some(0x00c0),
+		0xfa => Some(0x0250),
+		0xfb => Some(0x0370),
+		0xfc => Some(0x0530),
+		0xfd => Some(0x3040),
+		0xfe => Some(0x30a0),
+		0xff => Some(0xff60),
+		_ => None, // reserved
+	}
+}
+
+/// Returns the dynamic window offset of an extended define-window pair of
+/// bytes, along with the window index.
+const fn scsu_ext_window_offset(hi: u8, lo: u8) -> (usize, u32) {
+	((hi >> 5) as usize, 0x10000 + (((((hi & 0x1f) as u32) << 8) | lo as u32) << 7))
+}
+
+struct ScsuEncoder {
+	unicode_mode: bool,
+	active: usize,
+	windows: [u32; 8],
+	next_window: usize, // round-robin for new window definitions
+}
+
+impl Default for ScsuEncoder {
+	fn default() -> Self {
+		Self {
+			unicode_mode: false,
+			active: 0,
+			windows: SCSU_DYNAMIC_WINDOWS,
+			next_window: 0,
+		}
+	}
+}
+
+impl ScsuEncoder {
+	fn encode(&mut self, chars: impl Iterator<Item = char>) -> Vec<u8> {
+		let mut data = Vec::new();
+		for ch in chars {
+			if self.unicode_mode {
+				self.encode_unicode_mode(ch as u32, &mut data);
+			} else {
+				self.encode_single_byte_mode(ch as u32, &mut data);
+			}
+		}
+		data
+	}
+
+	fn encode_single_byte_mode(&mut self, c: u32, data: &mut Vec<u8>) {
+		match c {
+			0x00 | 0x09 | 0x0a | 0x0d | 0x20..=0x7f => data.push(c as u8),
+			0x01..=0x1f => data.extend_from_slice(&[SCSU_SQ0, c as u8]), // quote from static window 0
+			0xfeff => data.extend_from_slice(&[SCSU_SQU, 0xfe, 0xff]), // BOM, don't waste a window
+			_ => if let Some(n) = self.find_window(c) {
+				if n != self.active {
+					data.push(SCSU_SC0 + n as u8);
+					self.active = n;
+				}
+				data.push(0x80 + (c - self.windows[n]) as u8);
+			} else if self.define_window(c, false, data) {
+				data.push(0x80 + (c - self.windows[self.active]) as u8);
+			} else { // not windowable, like CJK
+				data.push(SCSU_SCU);
+				self.unicode_mode = true;
+				self.encode_unicode_mode(c, data);
+			},
+		}
+	}
+
+	fn encode_unicode_mode(&mut self, c: u32, data: &mut Vec<u8>) {
+		if c < 0x80 {
+			data.push(SCSU_UC0 + self.active as u8);
+			self.unicode_mode = false;
+			self.encode_single_byte_mode(c, data);
+		} else if let Some(n) = self.find_window(c) {
+			data.push(SCSU_UC0 + n as u8);
+			self.active = n;
+			self.unicode_mode = false;
+			self.encode_single_byte_mode(c, data);
+		} else if c >= 0x10000 {
+			for unit in char::from_u32(c).unwrap().encode_utf16(&mut [0; 2]) {
+				data.extend_from_slice(&unit.to_be_bytes()); // surrogates don't clash with tags
+			}
+		} else if c < 0x3400 && self.define_window(c, true, data) {
+			self.unicode_mode = false;
+			self.encode_single_byte_mode(c, data);
+		} else {
+			if (SCSU_UC0 as u32..=0xf2).contains(&(c >> 8)) {
+				data.push(SCSU_UQU); // high byte would be taken as a tag
+			}
+			data.extend_from_slice(&(c as u16).to_be_bytes());
+		}
+	}
+
+	fn find_window(&self, c: u32) -> Option<usize> {
+		let in_window = |n: usize| c >= self.windows[n] && c < self.windows[n] + 0x80;
+		if in_window(self.active) {
+			Some(self.active)
+		} else {
+			(0..self.windows.len()).find(|n| in_window(*n))
+		}
+	}
+
+	/// Defines and selects a new window for the char, if possible.
+	fn define_window(&mut self, c: u32, unicode_mode: bool, data: &mut Vec<u8>) -> bool {
+		let n = self.next_window;
+		if c >= 0x10000 {
+			let k = (c - 0x10000) >> 7;
+			data.extend_from_slice(&[
+				if unicode_mode { SCSU_UDX } else { SCSU_SDX },
+				((n as u32) << 5 | k >> 8) as u8,
+				k as u8,
+			]);
+			self.windows[n] = 0x10000 + (k << 7);
+		} else {
+			let x = match c {
+				0x0080..=0x33ff => c >> 7,
+				0xe000..=0xffff => (c - 0xac00) >> 7,
+				_ => return false, // can only be encoded in Unicode mode
+			};
+			data.extend_from_slice(&[
+				if unicode_mode { SCSU_UD0 } else { SCSU_SD0 } + n as u8,
+				x as u8,
+			]);
+			self.windows[n] = scsu_window_offset(x as u8).unwrap();
+		}
+		self.active = n;
+		self.next_window = (n + 1) % self.windows.len();
+		true
+	}
+}
+
+#[derive(Clone)]
+struct ScsuDecoder {
+	unicode_mode: bool,
+	active: usize,
+	windows: [u32; 8],
+}
+
+impl Default for ScsuDecoder {
+	fn default() -> Self {
+		Self {
+			unicode_mode: false,
+			active: 0,
+			windows: SCSU_DYNAMIC_WINDOWS,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+// BOCU-1, as specified in https://www.unicode.org/notes/tn6/
+
+const BOCU1_ASCII_PREV: i32 = 0x40;
+const BOCU1_MIN: i32 = 0x21;
+const BOCU1_MIDDLE: i32 = 0x90;
+const BOCU1_RESET: u8 = 0xff;
+const BOCU1_TRAIL_CONTROLS_COUNT: i32 = 20;
+const BOCU1_TRAIL_BYTE_OFFSET: i32 = BOCU1_MIN - BOCU1_TRAIL_CONTROLS_COUNT;
+const BOCU1_TRAIL_COUNT: i32 = (0xff - BOCU1_MIN + 1) + BOCU1_TRAIL_CONTROLS_COUNT;
+const BOCU1_SINGLE: i32 = 64;
+const BOCU1_LEAD_2: i32 = 43;
+const BOCU1_LEAD_3: i32 = 3;
+const BOCU1_REACH_POS_1: i32 = BOCU1_SINGLE - 1;
+const BOCU1_REACH_NEG_1: i32 = -BOCU1_SINGLE;
+const BOCU1_REACH_POS_2: i32 = BOCU1_REACH_POS_1 + BOCU1_LEAD_2 * BOCU1_TRAIL_COUNT;
+const BOCU1_REACH_NEG_2: i32 = BOCU1_REACH_NEG_1 - BOCU1_LEAD_2 * BOCU1_TRAIL_COUNT;
+const BOCU1_REACH_POS_3: i32 = BOCU1_REACH_POS_2 + BOCU1_LEAD_3 * BOCU1_TRAIL_COUNT * BOCU1_TRAIL_COUNT;
+const BOCU1_REACH_NEG_3: i32 = BOCU1_REACH_NEG_2 - BOCU1_LEAD_3 * BOCU1_TRAIL_COUNT * BOCU1_TRAIL_COUNT;
+const BOCU1_START_POS_2: i32 = BOCU1_MIDDLE + BOCU1_REACH_POS_1 + 1;
+const BOCU1_START_POS_3: i32 = BOCU1_START_POS_2 + BOCU1_LEAD_2;
+const BOCU1_START_POS_4: i32 = BOCU1_START_POS_3 + BOCU1_LEAD_3;
+const BOCU1_START_NEG_2: i32 = BOCU1_MIDDLE + BOCU1_REACH_NEG_1;
+const BOCU1_START_NEG_3: i32 = BOCU1_START_NEG_2 - BOCU1_LEAD_2;
+const BOCU1_START_NEG_4: i32 = BOCU1_START_NEG_3 - BOCU1_LEAD_3;
+
+/// Trail bytes which are C0 controls, skipping the ones which are actually
+/// used as controls.
+const BOCU1_TRAIL_TO_BYTE: [u8; BOCU1_TRAIL_CONTROLS_COUNT as usize] = [
+	0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x10, 0x11,
+	0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+	0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// Returns the "previous" value for the next code point, which is the middle
+/// of the script block of the current one.
+const fn bocu1_prev(c: i32) -> i32 {
+	match c {
+		0x3040..=0x309f => 0x3070, // Hiragana
+		0x4e00..=0x9fa5 => 0x4e00 - BOCU1_REACH_NEG_2, // CJK Unihan
+		0xac00..=0xd7a3 => (0xd7a3 + 0xac00) / 2, // Korean Hangul
+		_ => (c & !0x7f) + BOCU1_ASCII_PREV, // small scripts
+	}
+}
+
+const fn bocu1_trail_to_byte(t: i32) -> u8 {
+	if t < BOCU1_TRAIL_CONTROLS_COUNT {
+		BOCU1_TRAIL_TO_BYTE[t as usize]
+	} else {
+		(t + BOCU1_TRAIL_BYTE_OFFSET) as u8
+	}
+}
+
+const fn bocu1_byte_to_trail(b: u8) -> Option<i32> {
+	match b {
+		0x01..=0x06 => Some(b as i32 - 0x01),
+		0x10..=0x19 => Some(b as i32 - 0x10 + 6),
+		0x1c..=0x1f => Some(b as i32 - 0x1c + 16),
+		0x21..=0xff => Some(b as i32 - BOCU1_TRAIL_BYTE_OFFSET),
+		_ => None, // not a trail byte
+	}
+}
+
+fn bocu1_encode(prev: &mut i32, ch: char, data: &mut Vec<u8>) {
+	let c = ch as i32;
+	if c <= 0x20 {
+		if c != 0x20 {
+			*prev = BOCU1_ASCII_PREV; // C0 controls reset the state, but space doesn't
+		}
+		data.push(c as u8);
+		return;
+	}
+
+	let mut diff = c - *prev;
+	*prev = bocu1_prev(c);
+
+	let (lead, count) = if diff >= BOCU1_REACH_NEG_1 {
+		if diff <= BOCU1_REACH_POS_1 {
+			data.push((BOCU1_MIDDLE + diff) as u8); // single byte
+			return;
+		} else if diff <= BOCU1_REACH_POS_2 {
+			diff -= BOCU1_REACH_POS_1 + 1;
+			(BOCU1_START_POS_2, 1)
+		} else if diff <= BOCU1_REACH_POS_3 {
+			diff -= BOCU1_REACH_POS_2 + 1;
+			(BOCU1_START_POS_3, 2)
+		} else {
+			diff -= BOCU1_REACH_POS_3 + 1;
+			(BOCU1_START_POS_4, 3)
+		}
+	} else if diff >= BOCU1_REACH_NEG_2 {
+		diff -= BOCU1_REACH_NEG_1;
+		(BOCU1_START_NEG_2, 1)
+	} else if diff >= BOCU1_REACH_NEG_3 {
+		diff -= BOCU1_REACH_NEG_2;
+		(BOCU1_START_NEG_3, 2)
+	} else {
+		diff -= BOCU1_REACH_NEG_3;
+		(BOCU1_START_NEG_4, 3)
+	};
+
+	let mut trail = [0u8; 3];
+	for t in trail[..count].iter_mut().rev() { // like itoa() digits
+		*t = bocu1_trail_to_byte(diff.rem_euclid(BOCU1_TRAIL_COUNT));
+		diff = diff.div_euclid(BOCU1_TRAIL_COUNT);
+	}
+	data.push((lead + diff) as u8);
+	data.extend_from_slice(&trail[..count]);
+}
+
+#[derive(Clone)]
+struct Bocu1Decoder {
+	prev: i32,
+	count: usize, // remaining trail bytes
+	diff: i32,
+}
+
+impl Default for Bocu1Decoder {
+	fn default() -> Self {
+		Self { prev: BOCU1_ASCII_PREV, count: 0, diff: 0 }
+	}
+}
+
+impl Bocu1Decoder {
+	/// Processes one byte, eventually returning a decoded char, or `Err` for
+	/// an invalid sequence.
+	fn decode(&mut self, b: u8) -> Result<Option<char>, ()> {
+		if self.count == 0 {
+			self.decode_lead_byte(b)
+		} else {
+			self.decode_trail_byte(b)
+		}
+	}
+
+	fn decode_lead_byte(&mut self, b: u8) -> Result<Option<char>, ()> {
+		let b32 = b as i32;
+		if b <= 0x20 {
+			if b != 0x20 {
+				self.prev = BOCU1_ASCII_PREV;
+			}
+			return Ok(Some(b as char));
+		} else if (BOCU1_START_NEG_2..BOCU1_START_POS_2).contains(&b32) {
+			return self.deliver(self.prev + (b32 - BOCU1_MIDDLE)); // single byte
+		} else if b == BOCU1_RESET {
+			self.prev = BOCU1_ASCII_PREV;
+			return Ok(None);
+		}
+
+		let tc = BOCU1_TRAIL_COUNT;
+		(self.diff, self.count) = if b32 >= BOCU1_START_NEG_2 { // positive difference
+			if b32 < BOCU1_START_POS_3 {
+				((b32 - BOCU1_START_POS_2) * tc + BOCU1_REACH_POS_1 + 1, 1)
+			} else if b32 < BOCU1_START_POS_4 {
+				((b32 - BOCU1_START_POS_3) * tc * tc + BOCU1_REACH_POS_2 + 1, 2)
+			} else {
+				(BOCU1_REACH_POS_3 + 1, 3)
+			}
+		} else if b32 >= BOCU1_START_NEG_3 { // negative difference
+			((b32 - BOCU1_START_NEG_2) * tc + BOCU1_REACH_NEG_1, 1)
+		} else if b32 > BOCU1_MIN {
+			((b32 - BOCU1_START_NEG_3) * tc * tc + BOCU1_REACH_NEG_2, 2)
+		} else {
+			(-tc * tc * tc + BOCU1_REACH_NEG_3, 3)
+		};
+		Ok(None)
+	}
+
+	fn decode_trail_byte(&mut self, b: u8) -> Result<Option<char>, ()> {
+		let t = match bocu1_byte_to_trail(b) {
+			Some(t) => t,
+			None => {
+				self.reset();
+				return Err(());
+			},
+		};
+
+		match self.count {
+			1 => {
+				self.count = 0;
+				self.deliver(self.prev + self.diff + t)
+			},
+			2 => {
+				self.diff += t * BOCU1_TRAIL_COUNT;
+				self.count = 1;
+				Ok(None)
+			},
+			_ => {
+				self.diff += t * BOCU1_TRAIL_COUNT * BOCU1_TRAIL_COUNT;
+				self.count = 2;
+				Ok(None)
+			},
+		}
+	}
+
+	fn deliver(&mut self, c: i32) -> Result<Option<char>, ()> {
+		match char::from_u32(c as _) {
+			Some(ch) if c >= 0 => {
+				self.prev = bocu1_prev(c);
+				Ok(Some(ch))
+			},
+			_ => {
+				self.reset();
+				Err(())
+			},
+		}
+	}
+
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Incrementally decodes raw data in a given [`Encoding`](crate::Encoding),
+/// which arrives in chunks – for example, read with
+/// [`File::read`](crate::File::read).
+///
+/// Multi-byte sequences split between chunks are kept until the next chunk
+/// arrives. A leading BOM, if any, is skipped. Invalid sequences are replaced
+/// by
+/// [`U+FFFD`](https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{Encoding, File, FileAccess, TextDecoder};
+///
+/// let file_in = File::open("C:\\Temp\\foo.txt", FileAccess::ExistingReadOnly)?;
+/// let mut buf = [0u8; 4096];
+///
+/// let num_bytes = file_in.read(&mut buf)? as usize;
+/// let (encoding, _) = Encoding::guess(&buf[..num_bytes]);
+/// let mut decoder = TextDecoder::new(encoding);
+/// let mut contents = decoder.feed(&buf[..num_bytes]);
+///
+/// loop {
+///     let num_bytes = file_in.read(&mut buf)? as usize;
+///     if num_bytes == 0 {
+///         break;
+///     }
+///     contents.push_str(&decoder.feed(&buf[..num_bytes]));
+/// }
+/// contents.push_str(&decoder.finish());
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone)]
+pub struct TextDecoder {
+	encoding: Encoding,
+	pending: Vec<u8>, // incomplete sequence at the end of last chunk
+	high_surrogate: Option<u16>, // UTF-16 and SCSU may split surrogate pairs
+	at_start: bool, // BOM not checked yet
+	scsu: ScsuDecoder,
+	bocu1: Bocu1Decoder,
+}
+
+impl TextDecoder {
+	/// Creates a new decoder.
+	///
+	/// # Panics
+	///
+	/// Panics if the encoding is [`Unknown`](crate::Encoding::Unknown).
+	#[must_use]
+	pub fn new(encoding: Encoding) -> Self {
+		if encoding == Encoding::Unknown {
+			panic!("Cannot decode Unknown encoding.");
+		}
+
+		Self {
+			encoding,
+			pending: Vec::new(),
+			high_surrogate: None,
+			at_start: true,
+			scsu: ScsuDecoder::default(),
+			bocu1: Bocu1Decoder::default(),
+		}
+	}
+
+	/// Returns the encoding being decoded.
+	#[must_use]
+	pub const fn encoding(&self) -> Encoding {
+		self.encoding
+	}
+
+	/// Decodes a chunk of raw data, returning the text decoded so far.
+	#[must_use]
+	pub fn feed(&mut self, chunk: &[u8]) -> String {
+		let mut data = std::mem::take(&mut self.pending);
+		data.extend_from_slice(chunk);
+
+		let mut text = String::with_capacity(data.len());
+		let num_consumed = match self.encoding {
+			Encoding::Unknown => unreachable!(),
+			Encoding::Ansi => {
+				data.iter().for_each(|b| self.push_char(&mut text, *b as char)); // raw u8 to char conversion
+				data.len()
+			},
+			Encoding::Win1252 => {
+				data.iter().for_each(|b| {
+					let ch = match *b {
+						0x80..=0x9f => char::from_u32(WIN1252_80_9F[(*b - 0x80) as usize] as _).unwrap(),
+						b => b as char,
+					};
+					self.push_char(&mut text, ch);
+				});
+				data.len()
+			},
+			Encoding::Utf8 => self.feed_utf8(&data, &mut text),
+			Encoding::Utf16be | Encoding::Utf16le => {
+				let is_be = self.encoding == Encoding::Utf16be;
+				data.chunks_exact(2).for_each(|b| {
+					let unit = if is_be {
+						u16::from_be_bytes([b[0], b[1]])
+					} else {
+						u16::from_le_bytes([b[0], b[1]])
+					};
+					self.push_unit(&mut text, unit);
+				});
+				data.len() & !1
+			},
+			Encoding::Utf32be | Encoding::Utf32le => {
+				let is_be = self.encoding == Encoding::Utf32be;
+				data.chunks_exact(4).for_each(|b| {
+					let c = if is_be {
+						u32::from_be_bytes([b[0], b[1], b[2], b[3]])
+					} else {
+						u32::from_le_bytes([b[0], b[1], b[2], b[3]])
+					};
+					self.push_code_point(&mut text, c);
+				});
+				data.len() & !3
+			},
+			Encoding::Scsu => self.feed_scsu(&data, &mut text),
+			Encoding::Bocu1 => {
+				data.iter().for_each(|b| match self.bocu1.decode(*b) {
+					Ok(Some(ch)) => self.push_char(&mut text, ch),
+					Ok(None) => {},
+					Err(_) => self.push_char(&mut text, char::REPLACEMENT_CHARACTER),
+				});
+				data.len()
+			},
+		};
+
+		self.pending = data[num_consumed..].to_vec();
+		text
+	}
+
+	/// Finishes the decoding, returning any remaining text. Incomplete
+	/// sequences are replaced by
+	/// [`U+FFFD`](https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html).
+	#[must_use]
+	pub fn finish(mut self) -> String {
+		let mut text = String::new();
+		if !self.pending.is_empty() || self.bocu1.count > 0 {
+			self.push_char(&mut text, char::REPLACEMENT_CHARACTER);
+		}
+		if self.high_surrogate.take().is_some() {
+			text.push(char::REPLACEMENT_CHARACTER);
+		}
+		text
+	}
+
+	fn feed_utf8(&mut self, data: &[u8], text: &mut String) -> usize {
+		let mut pos = 0;
+		while pos < data.len() {
+			match std::str::from_utf8(&data[pos..]) {
+				Ok(s) => {
+					self.push_str(text, s);
+					pos = data.len();
+				},
+				Err(e) => {
+					let valid_len = e.valid_up_to();
+					self.push_str(text, std::str::from_utf8(&data[pos..pos + valid_len]).unwrap());
+					pos += valid_len;
+					match e.error_len() {
+						Some(invalid_len) => {
+							self.push_char(text, char::REPLACEMENT_CHARACTER);
+							pos += invalid_len;
+						},
+						None => break, // incomplete sequence at the end, wait for next chunk
+					}
+				},
+			}
+		}
+		pos
+	}
+
+	fn feed_scsu(&mut self, data: &[u8], text: &mut String) -> usize {
+		let mut i = 0;
+		while i < data.len() {
+			let b = data[i];
+			let num_bytes = if self.scsu.unicode_mode {
+				match b {
+					SCSU_UC0..=0xe7 | 0xf2 => 1,
+					SCSU_UD0..=0xef => 2,
+					SCSU_UQU | SCSU_UDX => 3,
+					_ => 2,
+				}
+			} else {
+				match b {
+					SCSU_SQ0..=0x08 | SCSU_SD0..=0x1f => 2,
+					SCSU_SDX | SCSU_SQU => 3,
+					_ => 1,
+				}
+			};
+			if i + num_bytes > data.len() {
+				break; // incomplete command, wait for next chunk
+			}
+			let args = &data[i + 1..i + num_bytes];
+			i += num_bytes;
+
+			let scsu = &mut self.scsu;
+			if scsu.unicode_mode {
+				match b {
+					SCSU_UC0..=0xe7 => {
+						scsu.active = (b - SCSU_UC0) as usize;
+						scsu.unicode_mode = false;
+					},
+					SCSU_UD0..=0xef => match scsu_window_offset(args[0]) {
+						Some(offset) => {
+							scsu.active = (b - SCSU_UD0) as usize;
+							scsu.windows[scsu.active] = offset;
+							scsu.unicode_mode = false;
+						},
+						None => self.push_char(text, char::REPLACEMENT_CHARACTER),
+					},
+					SCSU_UQU => self.push_unit(text, u16::from_be_bytes([args[0], args[1]])),
+					SCSU_UDX => {
+						let (n, offset) = scsu_ext_window_offset(args[0], args[1]);
+						scsu.active = n;
+						scsu.windows[n] = offset;
+						scsu.unicode_mode = false;
+					},
+					0xf2 => self.push_char(text, char::REPLACEMENT_CHARACTER), // reserved
+					_ => self.push_unit(text, u16::from_be_bytes([b, args[0]])),
+				}
+			} else {
+				match b {
+					0x00 | 0x09 | 0x0a | 0x0d | 0x20..=0x7f => self.push_char(text, b as char),
+					0x80..=0xff => {
+						let c = scsu.windows[scsu.active] + (b - 0x80) as u32;
+						self.push_code_point(text, c);
+					},
+					SCSU_SQ0..=0x08 => {
+						let n = (b - SCSU_SQ0) as usize;
+						let c = if args[0] < 0x80 {
+							SCSU_STATIC_WINDOWS[n] + args[0] as u32
+						} else {
+							scsu.windows[n] + (args[0] - 0x80) as u32
+						};
+						self.push_code_point(text, c);
+					},
+					SCSU_SDX => {
+						let (n, offset) = scsu_ext_window_offset(args[0], args[1]);
+						scsu.active = n;
+						scsu.windows[n] = offset;
+					},
+					SCSU_SQU => self.push_unit(text, u16::from_be_bytes([args[0], args[1]])),
+					SCSU_SCU => scsu.unicode_mode = true,
+					SCSU_SC0..=0x17 => scsu.active = (b - SCSU_SC0) as usize,
+					SCSU_SD0..=0x1f => match scsu_window_offset(args[0]) {
+						Some(offset) => {
+							scsu.active = (b - SCSU_SD0) as usize;
+							scsu.windows[scsu.active] = offset;
+						},
+						None => self.push_char(text, char::REPLACEMENT_CHARACTER),
+					},
+					_ => self.push_char(text, char::REPLACEMENT_CHARACTER), // reserved
+				}
+			}
+		}
+		i
+	}
+
+	fn push_str(&mut self, text: &mut String, s: &str) {
+		if let Some(ch) = s.chars().next() {
+			self.push_char(text, ch); // checks the BOM
+			text.push_str(&s[ch.len_utf8()..]);
+		}
+	}
+
+	fn push_char(&mut self, text: &mut String, ch: char) {
+		if self.high_surrogate.take().is_some() {
+			text.push(char::REPLACEMENT_CHARACTER); // unpaired high surrogate
+		}
+		if self.at_start {
+			self.at_start = false;
+			if ch == '\u{feff}' {
+				return; // skip BOM
+			}
+		}
+		text.push(ch);
+	}
+
+	fn push_code_point(&mut self, text: &mut String, c: u32) {
+		self.push_char(text, char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER));
+	}
+
+	fn push_unit(&mut self, text: &mut String, unit: u16) {
+		match unit {
+			0xd800..=0xdbff => {
+				if self.high_surrogate.replace(unit).is_some() {
+					text.push(char::REPLACEMENT_CHARACTER); // previous one was unpaired
+				}
+			},
+			0xdc00..=0xdfff => match self.high_surrogate.take() {
+				Some(high) => {
+					let c = 0x10000 + (((high as u32 - 0xd800) << 10) | (unit as u32 - 0xdc00));
+					self.push_code_point(text, c);
+				},
+				None => self.push_char(text, char::REPLACEMENT_CHARACTER), // unpaired low surrogate
+			},
+			_ => self.push_code_point(text, unit as _),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const UNICODE_ENCODINGS: [Encoding; 7] = [
+		Encoding::Utf8, Encoding::Utf16be, Encoding::Utf16le,
+		Encoding::Utf32be, Encoding::Utf32le, Encoding::Scsu, Encoding::Bocu1,
+	];
+
+	const TEXTS: [&str; 9] = [
+		"",
+		"Hello, world",
+		"Ação é ñ – “quotes” €",
+		"Москва и Ελλάδα, then back to ASCII",
+		"日本語のテキスト, 한국어, 中文",
+		"emoji 😀 and music 𝄞 outside the BMP 𐐷",
+		"\t\r\n\0\u{1}\u{1f} controls \u{7f}\u{80}\u{9f}", // leading NUL would make the UTF-16 LE BOM look like UTF-32 LE
+		"private \u{e000}\u{f8ff} and specials \u{fffd}\u{ffff} \u{feff}",
+		"mixed: a日b😀cМ\u{e0}\u{3042}\u{ff21}\u{10ffff}z",
+	];
+
+	#[test]
+	fn round_trip_unicode() {
+		for enc in UNICODE_ENCODINGS {
+			for text in TEXTS {
+				let data = enc.encode(text, false);
+				assert_eq!(enc.decode(&data), text, "{} without BOM: {:?}", enc, text);
+
+				let data = enc.encode(text, true);
+				assert!(data.starts_with(enc.bom()), "{}: {:?}", enc, text);
+				assert_eq!(enc.decode(&data), text, "{} with BOM: {:?}", enc, text);
+				assert!(Encoding::guess(&data) == (enc, enc.bom().len()), "{}: {:?}", enc, text);
+			}
+		}
+	}
+
+	#[test]
+	fn round_trip_single_byte() {
+		let text = "Hello\r\n\t~";
+		assert_eq!(Encoding::Ansi.decode(&Encoding::Ansi.encode(text, true)), text);
+		assert_eq!(Encoding::Ansi.encode("Ação", false), b"A??o");
+
+		let text = "Ação é ñ – “quotes” € ‰ ÿ \u{81}";
+		for with_bom in [false, true] {
+			let data = Encoding::Win1252.encode(text, with_bom);
+			assert_eq!(data.len(), text.chars().count()); // BOM ignored
+			assert_eq!(Encoding::Win1252.decode(&data), text);
+		}
+		assert_eq!(Encoding::Win1252.encode("€日", false), [0x80, b'?']);
+	}
+
+	#[test]
+	fn utf_layouts() {
+		assert_eq!(Encoding::Utf16be.encode("a😀", true), [0xfe, 0xff, 0x00, 0x61, 0xd8, 0x3d, 0xde, 0x00]);
+		assert_eq!(Encoding::Utf16le.encode("a😀", false), [0x61, 0x00, 0x3d, 0xd8, 0x00, 0xde]);
+		assert_eq!(Encoding::Utf32le.encode("😀", true), [0xff, 0xfe, 0x00, 0x00, 0x00, 0xf6, 0x01, 0x00]);
+		assert_eq!(Encoding::Utf8.encode("€", true), [0xef, 0xbb, 0xbf, 0xe2, 0x82, 0xac]);
+	}
+
+	#[test]
+	fn scsu_reference_vectors() {
+		// Examples from Unicode Technical Standard #6, section 9.
+		assert_eq!(Encoding::Scsu.encode("Öl fließt", false),
+			[0xd6, 0x6c, 0x20, 0x66, 0x6c, 0x69, 0x65, 0xdf, 0x74]);
+		assert_eq!(Encoding::Scsu.encode("Москва", false),
+			[0x12, 0x9c, 0xbe, 0xc1, 0xba, 0xb2, 0xb0]);
+		assert_eq!(Encoding::Scsu.encode("", true), [0x0e, 0xfe, 0xff]);
+
+		assert_eq!(Encoding::Scsu.decode(&[0xd6, 0x6c, 0x20, 0x66, 0x6c, 0x69, 0x65, 0xdf, 0x74]), "Öl fließt");
+		assert_eq!(Encoding::Scsu.decode(&[0x12, 0x9c, 0xbe, 0xc1, 0xba, 0xb2, 0xb0]), "Москва");
+		assert_eq!(Encoding::Scsu.decode(&[0x0f, 0x30, 0x42, 0x4e, 0x00, 0xe0, 0x41]), "\u{3042}\u{4e00}A"); // SCU ... UC0
+	}
+
+	#[test]
+	fn bocu1_reference_vectors() {
+		assert_eq!(Encoding::Bocu1.encode("", true), [0xfb, 0xee, 0x28]);
+		assert_eq!(Encoding::Bocu1.encode("A a", false), [0x91, 0x20, 0xb1]);
+		assert_eq!(Encoding::Bocu1.encode("\u{feff}", false), Encoding::Bocu1.bom());
+		assert_eq!(Encoding::Bocu1.decode(&[0x91, 0x20, 0xb1]), "A a");
+		assert_eq!(Encoding::Bocu1.decode(&Encoding::Bocu1.encode("A a", true)), "A a"); // BOM is part of the state
+
+		// BOCU-1 keeps the binary order of code points.
+		let mut texts = ["a", "ab", "b", "é", "Ж", "あ", "日", "😀", "\u{ffff}", "a😀"];
+		texts.sort();
+		let encoded = texts.iter()
+			.map(|text| Encoding::Bocu1.encode(text, false))
+			.collect::<Vec<_>>();
+		assert!(encoded.windows(2).all(|pair| pair[0] < pair[1]));
+	}
+
+	#[test]
+	fn invalid_sequences() {
+		assert_eq!(Encoding::Utf8.decode(&[b'a', 0xff, b'b']), "a\u{fffd}b");
+		assert_eq!(Encoding::Utf8.decode(&[b'a', 0xe2, 0x82]), "a\u{fffd}"); // truncated
+		assert_eq!(Encoding::Utf16le.decode(&[0x3d, 0xd8, 0x61, 0x00]), "\u{fffd}a"); // lone surrogate
+		assert_eq!(Encoding::Utf16le.decode(&[0x61, 0x00, 0x62]), "a\u{fffd}"); // odd length
+		assert_eq!(Encoding::Utf32le.decode(&[0x00, 0x00, 0x11, 0x00]), "\u{fffd}"); // out of range
+	}
+
+	#[test]
+	fn text_decoder_split_chunks() {
+		for enc in UNICODE_ENCODINGS {
+			for text in TEXTS {
+				let data = enc.encode(text, true);
+
+				for split in 0..=data.len() { // every possible split point
+					let mut decoder = TextDecoder::new(enc);
+					let mut decoded = decoder.feed(&data[..split]);
+					decoded.push_str(&decoder.feed(&data[split..]));
+					decoded.push_str(&decoder.finish());
+					assert_eq!(decoded, text, "{} split at {}: {:?}", enc, split, text);
+				}
+
+				let mut decoder = TextDecoder::new(enc); // one byte at a time
+				let mut decoded = String::default();
+				for b in data.iter() {
+					decoded.push_str(&decoder.feed(&[*b]));
+				}
+				decoded.push_str(&decoder.finish());
+				assert_eq!(decoded, text, "{} byte by byte: {:?}", enc, text);
+			}
+		}
+	}
+
+	#[test]
+	fn text_decoder_pending_sequences() {
+		let mut decoder = TextDecoder::new(Encoding::Utf8);
+		assert_eq!(decoder.feed(&[0xf0, 0x9f]), ""); // half of 😀
+		assert_eq!(decoder.feed(&[0x98, 0x80, b'!']), "😀!");
+
+		let mut decoder = TextDecoder::new(Encoding::Utf16be);
+		assert_eq!(decoder.feed(&[0xd8, 0x3d, 0xde]), ""); // high surrogate and half of the low one
+		assert_eq!(decoder.feed(&[0x00]), "😀");
+
+		let mut decoder = TextDecoder::new(Encoding::Utf8);
+		assert_eq!(decoder.feed(&[b'a', 0xe2, 0x82]), "a");
+		assert_eq!(decoder.finish(), "\u{fffd}"); // incomplete at the end
+
+		let mut decoder = TextDecoder::new(Encoding::Utf16le);
+		assert_eq!(decoder.feed(&[0x3d, 0xd8]), "");
+		assert_eq!(decoder.finish(), "\u{fffd}"); // lone high surrogate
+	}
 }
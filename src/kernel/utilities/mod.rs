@@ -1,13 +1,29 @@
+mod ace;
+mod dir_watcher;
 mod encoding;
 mod file_mapped;
 mod file;
 mod ini;
+mod process_builder;
+mod reg_file;
+mod service_runtime;
 mod w_string;
 
+pub(in crate::kernel) mod settings;
 pub mod path;
 
-pub use encoding::Encoding;
+pub use ace::Ace;
+pub use dir_watcher::{DirChange, DirWatcher, DirWatcherStopper};
+pub use encoding::{Encoding, TextDecoder};
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
 pub use ini::{Ini, IniEntry, IniSection};
+pub use process_builder::{
+	ProcessBuilder, ProcessChild, ProcessOutput, ProcessStdio,
+};
+pub use reg_file::{RegFile, RegFileKey, RegFileValue};
+pub use service_runtime::{ServiceContext, ServiceRuntime};
+pub use settings::SettingsMapper;
 pub use w_string::WString;
+
+pub(in crate::kernel) use reg_file::split_root_key;
@@ -0,0 +1,747 @@
+use std::collections::BTreeMap;
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	HFILE, HPIPE, HPROCESS, HSTD, PROCESS_INFORMATION, SetHandleInformation,
+	STARTUPINFO, STARTUPINFOEX, SysResult, WString,
+};
+use crate::kernel::ffi_types::HANDLE;
+use crate::kernel::guard::{CloseHandleGuard, CloseHandlePiGuard};
+use crate::kernel::privs::bool_to_sysresult;
+use crate::prelude::{
+	Handle, HandleWaitable, kernel_Hfile, kernel_Hpipe, kernel_Hprocess,
+	kernel_Hstd,
+};
+
+const PROC_THREAD_ATTRIBUTE_HANDLE_LIST: usize = 0x0002_0002;
+
+type ChildEnd = Option<CloseHandleGuard<HPIPE>>; // inheritable, passed to the child
+type ParentEnd = Option<CloseHandleGuard<HPIPE>>; // kept by the current process
+
+/// How a standard stream of a child process is set up by a
+/// [`ProcessBuilder`](crate::ProcessBuilder).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProcessStdio {
+	/// The child inherits the stream from the current process.
+	Inherit,
+	/// The stream is redirected to the `NUL` device.
+	Null,
+	/// A pipe is created, and the current process holds its other end, which
+	/// is available in the [`ProcessChild`](crate::ProcessChild).
+	Piped,
+}
+
+//------------------------------------------------------------------------------
+
+/// The result of a finished child process, returned by
+/// [`ProcessBuilder::output`](crate::ProcessBuilder::output) and
+/// [`ProcessChild::wait_with_output`](crate::ProcessChild::wait_with_output).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProcessOutput {
+	/// The exit code, retrieved with
+	/// [`HPROCESS::GetExitCodeProcess`](crate::prelude::kernel_Hprocess::GetExitCodeProcess).
+	pub exit_code: u32,
+	/// The data written to the standard output, if piped.
+	pub stdout: Vec<u8>,
+	/// The data written to the standard error, if piped.
+	pub stderr: Vec<u8>,
+}
+
+//------------------------------------------------------------------------------
+
+/// A process launched by
+/// [`ProcessBuilder::spawn`](crate::ProcessBuilder::spawn).
+///
+/// Dropping this object closes the handles, but doesn't terminate the process.
+pub struct ProcessChild {
+	pi: CloseHandlePiGuard,
+	stdin: Option<CloseHandleGuard<HPIPE>>,
+	stdout: Option<CloseHandleGuard<HPIPE>>,
+	stderr: Option<CloseHandleGuard<HPIPE>>,
+}
+
+impl ProcessChild {
+	/// Returns the process handle.
+	#[must_use]
+	pub fn hprocess(&self) -> &HPROCESS {
+		&self.pi.hProcess
+	}
+
+	/// Returns the process ID.
+	#[must_use]
+	pub fn id(&self) -> u32 {
+		self.pi.dwProcessId
+	}
+
+	/// Returns the write end of the standard input pipe, if piped.
+	#[must_use]
+	pub fn stdin(&self) -> Option<&HPIPE> {
+		self.stdin.as_deref()
+	}
+
+	/// Returns the read end of the standard output pipe, if piped.
+	#[must_use]
+	pub fn stdout(&self) -> Option<&HPIPE> {
+		self.stdout.as_deref()
+	}
+
+	/// Returns the read end of the standard error pipe, if piped.
+	#[must_use]
+	pub fn stderr(&self) -> Option<&HPIPE> {
+		self.stderr.as_deref()
+	}
+
+	/// Takes ownership of the standard input pipe, if piped. Closing it
+	/// signals the end of the input to the child.
+	#[must_use]
+	pub fn take_stdin(&mut self) -> Option<CloseHandleGuard<HPIPE>> {
+		self.stdin.take()
+	}
+
+	/// Takes ownership of the standard output pipe, if piped.
+	#[must_use]
+	pub fn take_stdout(&mut self) -> Option<CloseHandleGuard<HPIPE>> {
+		self.stdout.take()
+	}
+
+	/// Takes ownership of the standard error pipe, if piped.
+	#[must_use]
+	pub fn take_stderr(&mut self) -> Option<CloseHandleGuard<HPIPE>> {
+		self.stderr.take()
+	}
+
+	/// Blocks until the process exits, returning its exit code.
+	///
+	/// If the process writes to a piped stream which is not being read, it may
+	/// block forever; see
+	/// [`wait_with_output`](crate::ProcessChild::wait_with_output).
+	pub fn wait(&self) -> SysResult<u32> {
+		self.pi.hProcess.WaitForSingleObject(None)?;
+		self.pi.hProcess.GetExitCodeProcess()
+	}
+
+	/// Blocks until the process exits or the timeout elapses. Returns the exit
+	/// code, or `None` if the process is still running.
+	pub fn wait_timeout(&self, milliseconds: u32) -> SysResult<Option<u32>> {
+		match self.pi.hProcess.WaitForSingleObject(Some(milliseconds))? {
+			co::WAIT::TIMEOUT => Ok(None),
+			_ => self.pi.hProcess.GetExitCodeProcess().map(Some),
+		}
+	}
+
+	/// Closes the standard input pipe, reads the standard output and error
+	/// pipes until the end, then waits for the process to exit.
+	///
+	/// The pipes are read concurrently, so the child won't block on a full
+	/// pipe. Streams which are not piped are returned empty.
+	pub fn wait_with_output(mut self) -> SysResult<ProcessOutput> {
+		drop(self.stdin.take());
+
+		let stderr_thread = self.stderr.take()
+			.map(|hpipe| std::thread::spawn(move || read_to_end(&hpipe)));
+		let stdout = match self.stdout.take() {
+			Some(hpipe) => read_to_end(&hpipe)?,
+			None => Vec::new(),
+		};
+		let stderr = match stderr_thread {
+			Some(thread) => thread.join().unwrap()?,
+			None => Vec::new(),
+		};
+
+		Ok(ProcessOutput { exit_code: self.wait()?, stdout, stderr })
+	}
+}
+
+fn read_to_end(hpipe: &HPIPE) -> SysResult<Vec<u8>> {
+	let mut data = Vec::new();
+	let mut buf = [0u8; 4096];
+	loop {
+		match hpipe.ReadFile(&mut buf, None) {
+			Ok(0) | Err(co::ERROR::BROKEN_PIPE) => break, // write end closed
+			Ok(num_read) => data.extend_from_slice(&buf[..num_read as usize]),
+			Err(e) => return Err(e),
+		}
+	}
+	Ok(data)
+}
+
+//------------------------------------------------------------------------------
+
+/// Builds and launches a child process with
+/// [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess),
+/// in the fashion of
+/// [`std::process::Command`](https://doc.rust-lang.org/std/process/struct.Command.html).
+///
+/// The arguments are quoted so they're parsed back by
+/// [`CommandLineToArgv`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw)
+/// exactly as given.
+///
+/// When a standard stream is redirected, the child handles are passed with
+/// `PROC_THREAD_ATTRIBUTE_HANDLE_LIST`, so no other inheritable handle of the
+/// current process leaks into the child.
+///
+/// # Examples
+///
+/// Capturing the output of a command:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, ProcessBuilder};
+///
+/// let output = ProcessBuilder::new("cmd.exe")
+///     .args(&["/c", "dir", "C:\\Program Files"])
+///     .env("DIRCMD", "/b")
+///     .creation_flags(co::CREATE::NO_WINDOW)
+///     .output()?;
+///
+/// println!("Exit code: {}", output.exit_code);
+/// println!("{}", String::from_utf8_lossy(&output.stdout));
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Writing to the standard input, and waiting with a timeout:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, ProcessBuilder, ProcessStdio};
+///
+/// let mut child = ProcessBuilder::new("sort.exe")
+///     .stdin(ProcessStdio::Piped)
+///     .stdout(ProcessStdio::Null)
+///     .spawn()?;
+///
+/// child.stdin().unwrap().WriteFile(b"banana\r\napple\r\n", None)?;
+/// drop(child.take_stdin()); // signal end of input
+///
+/// match child.wait_timeout(5000)? {
+///     Some(exit_code) => println!("Exited with {}", exit_code),
+///     None => println!("Still running"),
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProcessBuilder {
+	program: String,
+	args: Vec<String>,
+	env_clear: bool,
+	env_vars: Vec<(String, Option<String>)>, // None means removed
+	current_dir: Option<String>,
+	creation_flags: co::CREATE,
+	stdin: Option<ProcessStdio>,
+	stdout: Option<ProcessStdio>,
+	stderr: Option<ProcessStdio>,
+}
+
+impl ProcessBuilder {
+	/// Creates a new builder for the given program, which is searched as
+	/// documented in
+	/// [`CreateProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessw)
+	/// `lpCommandLine`.
+	///
+	/// By default, the environment and the current directory are inherited,
+	/// and no creation flags are set.
+	#[must_use]
+	pub fn new(program: &str) -> Self {
+		Self {
+			program: program.to_owned(),
+			args: Vec::new(),
+			env_clear: false,
+			env_vars: Vec::new(),
+			current_dir: None,
+			creation_flags: co::CREATE::NoValue,
+			stdin: None,
+			stdout: None,
+			stderr: None,
+		}
+	}
+
+	/// Appends an argument.
+	pub fn arg(&mut self, arg: &str) -> &mut Self {
+		self.args.push(arg.to_owned());
+		self
+	}
+
+	/// Appends many arguments.
+	pub fn args(&mut self, args: &[impl AsRef<str>]) -> &mut Self {
+		self.args.extend(args.iter().map(|arg| arg.as_ref().to_owned()));
+		self
+	}
+
+	/// Sets the
+	/// [`CREATE`](crate::co::CREATE) flags, like
+	/// [`CREATE::NO_WINDOW`](crate::co::CREATE::NO_WINDOW).
+	///
+	/// The `UNICODE_ENVIRONMENT` and `EXTENDED_STARTUPINFO_PRESENT` flags are
+	/// added automatically, when needed.
+	pub fn creation_flags(&mut self, flags: co::CREATE) -> &mut Self {
+		self.creation_flags = flags;
+		self
+	}
+
+	/// Sets the working directory of the child.
+	pub fn current_dir(&mut self, dir: &str) -> &mut Self {
+		self.current_dir = Some(dir.to_owned());
+		self
+	}
+
+	/// Sets an environment variable, overriding the inherited one. Names are
+	/// case-insensitive.
+	pub fn env(&mut self, name: &str, val: &str) -> &mut Self {
+		self.env_vars.push((name.to_owned(), Some(val.to_owned())));
+		self
+	}
+
+	/// Clears all environment variables, including the inherited ones.
+	pub fn env_clear(&mut self) -> &mut Self {
+		self.env_clear = true;
+		self.env_vars.clear();
+		self
+	}
+
+	/// Removes an environment variable, including an inherited one. Names are
+	/// case-insensitive.
+	pub fn env_remove(&mut self, name: &str) -> &mut Self {
+		self.env_vars.push((name.to_owned(), None));
+		self
+	}
+
+	/// Sets the standard input of the child. Defaults to
+	/// [`ProcessStdio::Inherit`](crate::ProcessStdio::Inherit) in
+	/// [`spawn`](crate::ProcessBuilder::spawn), and
+	/// [`ProcessStdio::Null`](crate::ProcessStdio::Null) in
+	/// [`output`](crate::ProcessBuilder::output).
+	pub fn stdin(&mut self, stdio: ProcessStdio) -> &mut Self {
+		self.stdin = Some(stdio);
+		self
+	}
+
+	/// Sets the standard output of the child. Defaults to
+	/// [`ProcessStdio::Inherit`](crate::ProcessStdio::Inherit) in
+	/// [`spawn`](crate::ProcessBuilder::spawn), and
+	/// [`ProcessStdio::Piped`](crate::ProcessStdio::Piped) in
+	/// [`output`](crate::ProcessBuilder::output).
+	pub fn stdout(&mut self, stdio: ProcessStdio) -> &mut Self {
+		self.stdout = Some(stdio);
+		self
+	}
+
+	/// Sets the standard error of the child. Defaults to
+	/// [`ProcessStdio::Inherit`](crate::ProcessStdio::Inherit) in
+	/// [`spawn`](crate::ProcessBuilder::spawn), and
+	/// [`ProcessStdio::Piped`](crate::ProcessStdio::Piped) in
+	/// [`output`](crate::ProcessBuilder::output).
+	pub fn stderr(&mut self, stdio: ProcessStdio) -> &mut Self {
+		self.stderr = Some(stdio);
+		self
+	}
+
+	/// Returns the command line which will be passed to the child, with the
+	/// program and the arguments quoted as needed.
+	///
+	/// Returns [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the program name contains a quote, or if any of the strings
+	/// contains a null char; these can't be represented.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, ProcessBuilder};
+	///
+	/// let cmd_line = ProcessBuilder::new("C:\\Temp\\foo.exe")
+	///     .args(&["a b", "say \"hi\"", "C:\\my dir\\", ""])
+	///     .command_line()?;
+	///
+	/// assert_eq!(cmd_line,
+	///     r#""C:\Temp\foo.exe" "a b" "say \"hi\"" "C:\my dir\\" """#);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn command_line(&self) -> SysResult<String> {
+		if self.program.contains('"')
+			|| self.program.contains('\0')
+			|| self.args.iter().any(|arg| arg.contains('\0'))
+		{
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		// The program name is parsed until the closing quote, without escapes.
+		let mut cmd_line = format!("\"{}\"", self.program);
+		for arg in self.args.iter() {
+			cmd_line.push(' ');
+			Self::push_quoted_arg(&mut cmd_line, arg);
+		}
+		Ok(cmd_line)
+	}
+
+	fn push_quoted_arg(cmd_line: &mut String, arg: &str) {
+		let quote = arg.is_empty() || arg.contains(' ') || arg.contains('\t');
+		if quote {
+			cmd_line.push('"');
+		}
+
+		let mut backslashes = 0;
+		for ch in arg.chars() {
+			if ch == '\\' {
+				backslashes += 1;
+			} else {
+				if ch == '"' { // backslashes before a quote must be escaped, then the quote itself
+					cmd_line.push_str(&"\\".repeat(backslashes + 1));
+				}
+				backslashes = 0;
+			}
+			cmd_line.push(ch);
+		}
+
+		if quote { // trailing backslashes would escape the closing quote
+			cmd_line.push_str(&"\\".repeat(backslashes));
+			cmd_line.push('"');
+		}
+	}
+
+	/// Builds the environment block, or `None` if the environment is simply
+	/// inherited.
+	fn env_block(&self) -> Option<Vec<u16>> {
+		if !self.env_clear && self.env_vars.is_empty() {
+			return None;
+		}
+
+		let mut vars = BTreeMap::new(); // must be sorted case-insensitively
+		if !self.env_clear {
+			for (name, val) in std::env::vars_os() {
+				let name = name.to_string_lossy().into_owned();
+				vars.insert(name.to_uppercase(), (name, val.to_string_lossy().into_owned()));
+			}
+		}
+		for (name, val) in self.env_vars.iter() {
+			match val {
+				Some(val) => vars.insert(name.to_uppercase(), (name.clone(), val.clone())),
+				None => vars.remove(&name.to_uppercase()),
+			};
+		}
+
+		let mut block = Vec::new();
+		for (name, val) in vars.values() {
+			block.extend(name.encode_utf16());
+			block.push('=' as u16);
+			block.extend(val.encode_utf16());
+			block.push(0);
+		}
+		if block.is_empty() {
+			block.push(0); // an empty block still needs two terminating nulls
+		}
+		block.push(0);
+		Some(block)
+	}
+
+	/// Launches the child process.
+	///
+	/// Unless set otherwise, the standard streams are inherited.
+	pub fn spawn(&self) -> SysResult<ProcessChild> {
+		self.spawn_with_defaults(ProcessStdio::Inherit, ProcessStdio::Inherit)
+	}
+
+	/// Launches the child process, reads its output and waits for it to exit.
+	///
+	/// Unless set otherwise, the standard input is
+	/// [`Null`](crate::ProcessStdio::Null), and the standard output and error
+	/// are [`Piped`](crate::ProcessStdio::Piped).
+	pub fn output(&self) -> SysResult<ProcessOutput> {
+		self.spawn_with_defaults(ProcessStdio::Null, ProcessStdio::Piped)?
+			.wait_with_output()
+	}
+
+	fn spawn_with_defaults(&self,
+		default_in: ProcessStdio, default_out: ProcessStdio) -> SysResult<ProcessChild>
+	{
+		let stdio = [
+			(self.stdin.unwrap_or(default_in), co::STD_HANDLE::INPUT),
+			(self.stdout.unwrap_or(default_out), co::STD_HANDLE::OUTPUT),
+			(self.stderr.unwrap_or(default_out), co::STD_HANDLE::ERROR),
+		];
+
+		let mut cmd_line = WString::from_str(&self.command_line()?);
+		let mut env_block = self.env_block();
+		let mut flags = self.creation_flags;
+		if env_block.is_some() {
+			flags |= co::CREATE::UNICODE_ENVIRONMENT;
+		}
+
+		if stdio.iter().all(|(stdio, _)| *stdio == ProcessStdio::Inherit) {
+			let mut si = STARTUPINFO::default();
+			let pi = self.create_process(&mut cmd_line, env_block.as_mut(),
+				false, flags, &mut si as *mut _ as _)?;
+			return Ok(ProcessChild { pi, stdin: None, stdout: None, stderr: None });
+		}
+
+		let mut child_ends = Vec::with_capacity(3); // kept alive until the child is created
+		let mut parent_ends = Vec::with_capacity(3);
+		for (stdio, std_handle) in stdio {
+			let (child_end, parent_end) = Self::prepare_stdio(stdio, std_handle)?;
+			child_ends.push(child_end);
+			parent_ends.push(parent_end);
+		}
+
+		let mut si = STARTUPINFOEX::default();
+		si.StartupInfo.dwFlags = co::STARTF::USESTDHANDLES;
+		si.StartupInfo.hStdInput = Self::raw_or_null(&child_ends[0]);
+		si.StartupInfo.hStdOutput = Self::raw_or_null(&child_ends[1]);
+		si.StartupInfo.hStdError = Self::raw_or_null(&child_ends[2]);
+
+		let attr_list = ProcThreadAttrList::with_handle_list(
+			child_ends.iter()
+				.filter_map(|child_end| child_end.as_ref().map(|h| h.as_ptr()))
+				.collect(),
+		)?;
+		si.lpAttributeList = attr_list.as_ptr();
+
+		let pi = self.create_process(&mut cmd_line, env_block.as_mut(), true,
+			flags | co::CREATE::EXTENDED_STARTUPINFO_PRESENT, &mut si as *mut _ as _)?;
+
+		let mut parent_ends = parent_ends.into_iter();
+		Ok(ProcessChild {
+			pi,
+			stdin: parent_ends.next().unwrap(),
+			stdout: parent_ends.next().unwrap(),
+			stderr: parent_ends.next().unwrap(),
+		})
+	}
+
+	fn create_process(&self,
+		cmd_line: &mut WString,
+		env_block: Option<&mut Vec<u16>>,
+		inherit_handles: bool,
+		flags: co::CREATE,
+		si: *mut std::ffi::c_void,
+	) -> SysResult<CloseHandlePiGuard>
+	{
+		let mut pi = PROCESS_INFORMATION::default();
+		unsafe {
+			bool_to_sysresult(
+				kernel::ffi::CreateProcessW(
+					std::ptr::null(),
+					cmd_line.as_mut_ptr(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+					inherit_handles as _,
+					flags.0,
+					env_block.map_or(std::ptr::null_mut(), |block| block.as_mut_ptr() as _),
+					WString::from_opt_str(self.current_dir.as_deref()).as_ptr(),
+					si,
+					&mut pi as *mut _ as _,
+				),
+			).map(|_| CloseHandlePiGuard::new(pi))
+		}
+	}
+
+	/// Returns the inheritable handle to be passed to the child, and the handle
+	/// to be kept by the current process, if any.
+	fn prepare_stdio(
+		stdio: ProcessStdio,
+		std_handle: co::STD_HANDLE,
+	) -> SysResult<(ChildEnd, ParentEnd)>
+	{
+		match stdio {
+			ProcessStdio::Inherit => {
+				let hstd = HSTD::GetStdHandle(std_handle)?.leak(); // not owned by us
+				if hstd == HSTD::NULL {
+					return Ok((None, None)); // no console, nothing to inherit
+				}
+				let hcurproc = HPROCESS::GetCurrentProcess();
+				let hdup = hcurproc.DuplicateHandle(
+					&hstd, &hcurproc, 0, true, co::DUPLICATE::SAME_ACCESS)?;
+				Ok((Some(Self::into_hpipe(hdup)), None))
+			},
+			ProcessStdio::Null => {
+				let (hnul, _) = HFILE::CreateFile(
+					"NUL",
+					co::GENERIC::READ | co::GENERIC::WRITE,
+					co::FILE_SHARE::READ | co::FILE_SHARE::WRITE,
+					None,
+					co::DISPOSITION::OPEN_EXISTING,
+					co::FILE_ATTRIBUTE::NORMAL,
+					None,
+				)?;
+				SetHandleInformation(&*hnul,
+					co::HANDLE_FLAG::INHERIT, co::HANDLE_FLAG::INHERIT)?;
+				Ok((Some(Self::into_hpipe(hnul)), None))
+			},
+			ProcessStdio::Piped => {
+				let (hread, hwrite) = HPIPE::CreatePipe(None, 0)?;
+				let (child_end, parent_end) = if std_handle == co::STD_HANDLE::INPUT {
+					(hread, hwrite)
+				} else {
+					(hwrite, hread)
+				};
+				SetHandleInformation(&*child_end,
+					co::HANDLE_FLAG::INHERIT, co::HANDLE_FLAG::INHERIT)?;
+				Ok((Some(child_end), Some(parent_end)))
+			},
+		}
+	}
+
+	fn into_hpipe<H: Handle>(mut guard: CloseHandleGuard<H>) -> CloseHandleGuard<HPIPE> {
+		unsafe { CloseHandleGuard::new(HPIPE::from_ptr(guard.leak().as_ptr())) }
+	}
+
+	fn raw_or_null(child_end: &ChildEnd) -> HPIPE {
+		child_end.as_ref()
+			.map_or(HPIPE::NULL, |hpipe| unsafe { hpipe.raw_copy() })
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Attribute list with a `PROC_THREAD_ATTRIBUTE_HANDLE_LIST`, which must live
+/// until the process is created.
+struct ProcThreadAttrList {
+	buf: Vec<usize>, // opaque, pointer-aligned
+	handles: Vec<HANDLE>, // referenced by the list
+}
+
+impl Drop for ProcThreadAttrList {
+	fn drop(&mut self) {
+		unsafe { kernel::ffi::DeleteProcThreadAttributeList(self.as_ptr()); }
+	}
+}
+
+impl ProcThreadAttrList {
+	fn with_handle_list(handles: Vec<HANDLE>) -> SysResult<Self> {
+		let mut sz = usize::default();
+		unsafe { // first call retrieves the size, and fails with INSUFFICIENT_BUFFER
+			kernel::ffi::InitializeProcThreadAttributeList(
+				std::ptr::null_mut(), 1, 0, &mut sz);
+		}
+
+		let ptr_sz = std::mem::size_of::<usize>();
+		let mut buf = vec![0usize; sz.div_ceil(ptr_sz)];
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::InitializeProcThreadAttributeList(
+					buf.as_mut_ptr() as _, 1, 0, &mut sz)
+			},
+		)?;
+		let new_self = Self { buf, handles }; // from now on, the list is deleted on drop
+
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::UpdateProcThreadAttribute(
+					new_self.as_ptr(),
+					0,
+					PROC_THREAD_ATTRIBUTE_HANDLE_LIST,
+					new_self.handles.as_ptr() as _,
+					new_self.handles.len() * std::mem::size_of::<HANDLE>(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| new_self)
+	}
+
+	fn as_ptr(&self) -> *mut std::ffi::c_void {
+		self.buf.as_ptr() as _
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Splits the arguments the way the C runtime does, skipping the program
+	/// name.
+	fn parse_args(cmd_line: &str) -> Vec<String> {
+		let mut chars = cmd_line.chars().peekable();
+		assert_eq!(chars.next(), Some('"'));
+		while chars.next() != Some('"') { } // program name has no escapes
+		let mut args = Vec::new();
+
+		loop {
+			while chars.next_if(|ch| *ch == ' ' || *ch == '\t').is_some() { }
+			if chars.peek().is_none() {
+				return args;
+			}
+
+			let mut arg = String::new();
+			let mut in_quotes = false;
+			while let Some(ch) = chars.next() {
+				match ch {
+					'\\' => {
+						let mut backslashes = 1;
+						while chars.next_if_eq(&'\\').is_some() {
+							backslashes += 1;
+						}
+						if chars.peek() == Some(&'"') {
+							arg.push_str(&"\\".repeat(backslashes / 2));
+							if backslashes % 2 == 1 {
+								arg.push(chars.next().unwrap()); // escaped quote
+							}
+						} else {
+							arg.push_str(&"\\".repeat(backslashes));
+						}
+					},
+					'"' => in_quotes = !in_quotes,
+					' ' | '\t' if !in_quotes => break,
+					ch => arg.push(ch),
+				}
+			}
+			args.push(arg);
+		}
+	}
+
+	fn cmd_line(args: &[&str]) -> String {
+		ProcessBuilder::new("C:\\Temp\\foo.exe").args(args).command_line().unwrap()
+	}
+
+	#[test]
+	fn quoting() {
+		let cases: [(&str, &str); 12] = [
+			("plain", r#"plain"#),
+			("", r#""""#),
+			("a b", r#""a b""#),
+			("a\tb", "\"a\tb\""),
+			(r#"say"hi"#, r#"say\"hi"#),
+			(r#"say "hi""#, r#""say \"hi\"""#),
+			(r#"C:\dir\"#, r#"C:\dir\"#), // no quotes, backslashes kept as they are
+			(r#"C:\my dir\"#, r#""C:\my dir\\""#),
+			(r#"C:\my dir\\"#, r#""C:\my dir\\\\""#),
+			(r#"a\"b"#, r#"a\\\"b"#),
+			(r#"a\\"b c"#, r#""a\\\\\"b c""#),
+			(r#"\\server\share"#, r#"\\server\share"#),
+		];
+
+		for (arg, quoted) in cases {
+			let mut cmd_line = String::new();
+			ProcessBuilder::push_quoted_arg(&mut cmd_line, arg);
+			assert_eq!(cmd_line, quoted, "{:?}", arg);
+		}
+	}
+
+	#[test]
+	fn command_line() {
+		assert_eq!(ProcessBuilder::new("foo.exe").command_line().unwrap(), r#""foo.exe""#);
+		assert_eq!(cmd_line(&["a b", "say \"hi\"", "C:\\my dir\\", ""]),
+			r#""C:\Temp\foo.exe" "a b" "say \"hi\"" "C:\my dir\\" """#);
+		assert_eq!(cmd_line(&["", ""]), r#""C:\Temp\foo.exe" "" """#);
+	}
+
+	#[test]
+	fn command_line_invalid() {
+		assert_eq!(ProcessBuilder::new("foo\".exe").command_line(), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(ProcessBuilder::new("foo\0.exe").command_line(), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(ProcessBuilder::new("foo.exe").arg("a\0b").command_line(), Err(co::ERROR::INVALID_PARAMETER));
+	}
+
+	#[test]
+	fn command_line_round_trip() {
+		let args = [
+			"", "plain", "a b", "a\tb", "\t", " ", "\"", "\\", "\\\\", "\\\"",
+			"trailing\\", "trailing space\\", "trailing quote\"", "\"quoted\"",
+			"a\\\\\"b", "\"\\\"\\\\\"", "multi  space", "ünïcödé 日本",
+		];
+		assert_eq!(parse_args(&cmd_line(&args)), args);
+
+		for arg in args { // each one alone, since the neighbors might mask mistakes
+			assert_eq!(parse_args(&cmd_line(&[arg])), [arg]);
+		}
+	}
+}
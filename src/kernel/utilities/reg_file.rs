@@ -0,0 +1,595 @@
+use crate::co;
+use crate::kernel::decl::{
+	File, FileAccess, FileMapped, HKEY, RegistryValue, SysResult, WString,
+};
+use crate::prelude::kernel_Hkey;
+
+const HEADER: &str = "Windows Registry Editor Version 5.00";
+
+/// High-level abstraction to load, manage and serialize the keys and values of
+/// a `.reg` file, as produced by the Registry Editor.
+///
+/// Parsing and serializing are performed entirely in memory, without touching
+/// the registry. To export and import actual registry trees, see
+/// [`HKEY::export_reg_tree`](crate::prelude::kernel_Hkey::export_reg_tree) and
+/// [`HKEY::import_reg_file`](crate::prelude::kernel_Hkey::import_reg_file).
+///
+/// # Examples
+///
+/// Printing all keys and values:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::RegFile;
+///
+/// let reg = RegFile::parse_from_file("C:\\Temp\\foo.reg")?;
+///
+/// for key in reg.keys.iter() {
+///     println!("Key: {}", key.path);
+///     for val in key.values.iter() {
+///         println!("Value: {}; Data: {:?}", val.name, val.data);
+///     }
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct RegFile {
+	/// All the keys of the file, in order. They can be modified at will.
+	pub keys: Vec<RegFileKey>,
+}
+
+impl RegFile {
+	/// Parses a `RegFile` from a string.
+	///
+	/// Only the `Windows Registry Editor Version 5.00` format is accepted. If
+	/// the contents are malformed, returns
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	#[must_use]
+	pub fn parse_str(contents: &str) -> SysResult<Self> {
+		let mut lines = logical_lines(contents).into_iter();
+
+		match lines.next() {
+			Some(header) if header == HEADER => {},
+			_ => return Err(co::ERROR::INVALID_DATA), // missing or unsupported header
+		}
+
+		let mut keys = Vec::<RegFileKey>::default();
+		for line in lines {
+			if line.starts_with('[') {
+				if !line.ends_with(']') {
+					return Err(co::ERROR::INVALID_DATA);
+				}
+				let path = &line[1..line.len() - 1];
+				let (path, delete) = match path.strip_prefix('-') {
+					Some(path) => (path, true),
+					None => (path, false),
+				};
+				keys.push(RegFileKey {
+					path: path.to_owned(),
+					delete,
+					values: Vec::<RegFileValue>::default(),
+				});
+			} else {
+				let cur_key = keys.last_mut()
+					.ok_or(co::ERROR::INVALID_DATA)?; // value outside a key
+				cur_key.values.push(parse_value_line(&line)?);
+			}
+		}
+
+		Ok(Self { keys })
+	}
+
+	/// Parses a `RegFile` from raw bytes with
+	/// [`WString::parse`](crate::WString::parse).
+	#[must_use]
+	pub fn parse_bytes(bytes: &[u8]) -> SysResult<Self> {
+		Self::parse_str(&WString::parse(bytes)?.to_string())
+	}
+
+	/// Parses a `RegFile` directly from a file with
+	/// [`WString::parse`](crate::WString::parse). The file will be
+	/// [mapped in memory](crate::FileMapped) during reading for maximum
+	/// performance.
+	#[must_use]
+	pub fn parse_from_file(reg_path: &str) -> SysResult<Self> {
+		let fin = FileMapped::open(reg_path, FileAccess::ExistingReadOnly)?;
+		Self::parse_bytes(fin.as_slice())
+	}
+
+	/// Returns a reference to the [`RegFileKey`](crate::RegFileKey) with the
+	/// given path, if any.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn find_key(&self, path: &str) -> Option<&RegFileKey> {
+		let path_uc = path.to_uppercase();
+		self.keys.iter()
+			.find(|key| key.path.to_uppercase() == path_uc)
+	}
+
+	/// Serializes the keys and values to a string, in the
+	/// `Windows Registry Editor Version 5.00` format.
+	#[must_use]
+	pub fn serialize_to_str(&self) -> String {
+		let mut buf = String::new();
+		buf.push_str(HEADER);
+		buf.push_str("\r\n\r\n");
+
+		for key in self.keys.iter() {
+			buf.push('[');
+			if key.delete {
+				buf.push('-');
+			}
+			buf.push_str(&key.path);
+			buf.push_str("]\r\n");
+
+			for val in key.values.iter() {
+				serialize_value(&mut buf, val);
+			}
+			buf.push_str("\r\n");
+		}
+		buf
+	}
+
+	/// Serializes the keys and values to raw bytes, encoded as UTF-16 little
+	/// endian with a BOM, which is the encoding used by the Registry Editor.
+	#[must_use]
+	pub fn serialize_to_bytes(&self) -> Vec<u8> {
+		let mut bytes = vec![0xff, 0xfe]; // UTF-16 LE BOM
+		bytes.extend(
+			self.serialize_to_str()
+				.encode_utf16()
+				.flat_map(|ch| ch.to_le_bytes()),
+		);
+		bytes
+	}
+
+	/// Serializes the data directly to a file, encoded as UTF-16 little endian
+	/// with a BOM.
+	pub fn serialize_to_file(&self, reg_path: &str) -> SysResult<()> {
+		let fout = File::open(reg_path, FileAccess::OpenOrCreateRW)?;
+		fout.erase_and_write(&self.serialize_to_bytes())?;
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A single key of a [`RegFile`](crate::RegFile).
+pub struct RegFileKey {
+	/// Full path of the key, starting with the root key name, like
+	/// `HKEY_CURRENT_USER\Software\Foo`.
+	pub path: String,
+	/// If `true`, the key is written as `[-path]`, meaning the whole key tree
+	/// will be deleted when imported. In this case, the values are ignored.
+	pub delete: bool,
+	/// The values of this key.
+	pub values: Vec<RegFileValue>,
+}
+
+impl RegFileKey {
+	/// Returns a reference to the [`RegFileValue`](crate::RegFileValue) with
+	/// the given name, if any.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn find_value(&self, name: &str) -> Option<&RegFileValue> {
+		let name_uc = name.to_uppercase();
+		self.values.iter()
+			.find(|val| val.name.to_uppercase() == name_uc)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A single value of a [`RegFileKey`](crate::RegFileKey).
+pub struct RegFileValue {
+	/// Name of the value. An empty string means the default value, written as
+	/// `@`.
+	pub name: String,
+	/// Data of the value. If `None`, the value is written as `"name"=-`,
+	/// meaning it will be deleted when imported.
+	pub data: Option<RegistryValue>,
+}
+
+//------------------------------------------------------------------------------
+
+/// Splits a full key path, like `HKEY_CURRENT_USER\Software\Foo`, into its
+/// predefined root key, the full name of the root key, and the remaining sub
+/// key path.
+pub(in crate::kernel) fn split_root_key(
+	path: &str) -> SysResult<(HKEY, &'static str, &str)>
+{
+	let (root, sub_key) = match path.find('\\') {
+		Some(idx) => (&path[..idx], &path[idx + 1..]),
+		None => (path, ""),
+	};
+
+	let (hroot, root_name) = match root.to_uppercase().as_str() {
+		"HKEY_CLASSES_ROOT" | "HKCR" => (HKEY::CLASSES_ROOT, "HKEY_CLASSES_ROOT"),
+		"HKEY_CURRENT_USER" | "HKCU" => (HKEY::CURRENT_USER, "HKEY_CURRENT_USER"),
+		"HKEY_LOCAL_MACHINE" | "HKLM" => (HKEY::LOCAL_MACHINE, "HKEY_LOCAL_MACHINE"),
+		"HKEY_USERS" | "HKU" => (HKEY::USERS, "HKEY_USERS"),
+		"HKEY_CURRENT_CONFIG" | "HKCC" => (HKEY::CURRENT_CONFIG, "HKEY_CURRENT_CONFIG"),
+		_ => return Err(co::ERROR::INVALID_DATA),
+	};
+	Ok((hroot, root_name, sub_key))
+}
+
+/// Removes comments and blank lines, and joins the hex lines continued with a
+/// trailing backslash.
+fn logical_lines(contents: &str) -> Vec<String> {
+	let mut lines = Vec::<String>::default();
+	let mut cur = String::new();
+
+	for line in contents.lines() {
+		let line = if cur.is_empty() { line.trim() } else { line.trim_start() };
+		if cur.is_empty() && (line.is_empty() || line.starts_with(';')) {
+			continue;
+		}
+
+		let line = line.trim_end();
+		if !line.starts_with('[') && line.ends_with('\\') {
+			cur.push_str(&line[..line.len() - 1]);
+		} else {
+			cur.push_str(line);
+			lines.push(std::mem::take(&mut cur));
+		}
+	}
+	if !cur.is_empty() {
+		lines.push(cur);
+	}
+	lines
+}
+
+fn parse_value_line(line: &str) -> SysResult<RegFileValue> {
+	let (name, rest) = if let Some(rest) = line.strip_prefix('@') {
+		("".to_owned(), rest)
+	} else {
+		parse_quoted(line)?
+	};
+
+	let rest = rest.trim_start()
+		.strip_prefix('=')
+		.ok_or(co::ERROR::INVALID_DATA)?
+		.trim();
+
+	let data = if rest == "-" {
+		None
+	} else if rest.starts_with('"') {
+		let (s, tail) = parse_quoted(rest)?;
+		if !tail.trim().is_empty() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		Some(RegistryValue::Sz(s))
+	} else if let Some(hex) = rest.strip_prefix("dword:") {
+		Some(RegistryValue::Dword(
+			u32::from_str_radix(hex.trim(), 16)
+				.map_err(|_| co::ERROR::INVALID_DATA)?,
+		))
+	} else if let Some(hex) = rest.strip_prefix("hex:") {
+		Some(RegistryValue::Binary(parse_hex_bytes(hex)?))
+	} else if let Some(typed) = rest.strip_prefix("hex(") {
+		let close_idx = typed.find("):").ok_or(co::ERROR::INVALID_DATA)?;
+		let reg_type = u32::from_str_radix(&typed[..close_idx], 16)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		let bytes = parse_hex_bytes(&typed[close_idx + 2..])?;
+		Some(typed_value(co::REG(reg_type), bytes)?)
+	} else {
+		return Err(co::ERROR::INVALID_DATA);
+	};
+
+	Ok(RegFileValue { name, data })
+}
+
+/// Parses a quoted and escaped string at the beginning of `s`, returning the
+/// unescaped string and the remaining text after the closing quote.
+fn parse_quoted(s: &str) -> SysResult<(String, &str)> {
+	let s = s.strip_prefix('"').ok_or(co::ERROR::INVALID_DATA)?;
+	let mut out = String::new();
+	let mut chars = s.char_indices();
+
+	while let Some((idx, ch)) = chars.next() {
+		match ch {
+			'"' => return Ok((out, &s[idx + 1..])),
+			'\\' => match chars.next() {
+				Some((_, esc)) => out.push(esc),
+				None => break,
+			},
+			ch => out.push(ch),
+		}
+	}
+	Err(co::ERROR::INVALID_DATA) // no closing quote
+}
+
+fn parse_hex_bytes(hex: &str) -> SysResult<Vec<u8>> {
+	hex.split(',')
+		.map(|b| b.trim())
+		.filter(|b| !b.is_empty())
+		.map(|b| u8::from_str_radix(b, 16).map_err(|_| co::ERROR::INVALID_DATA))
+		.collect()
+}
+
+fn typed_value(reg_type: co::REG, bytes: Vec<u8>) -> SysResult<RegistryValue> {
+	Ok(match reg_type {
+		co::REG::NONE if bytes.is_empty() => RegistryValue::None,
+		co::REG::SZ => RegistryValue::Sz(utf16_to_strs(&bytes)?.swap_remove(0)),
+		co::REG::EXPAND_SZ => RegistryValue::ExpandSz(utf16_to_strs(&bytes)?.swap_remove(0)),
+		co::REG::BINARY => RegistryValue::Binary(bytes),
+		co::REG::DWORD => RegistryValue::Dword(
+			u32::from_le_bytes(bytes.try_into().map_err(|_| co::ERROR::INVALID_DATA)?),
+		),
+		co::REG::MULTI_SZ => {
+			let mut strs = utf16_to_strs(&bytes)?;
+			while strs.last().is_some_and(|s| s.is_empty()) {
+				strs.pop(); // terminating nulls
+			}
+			RegistryValue::MultiSz(strs)
+		},
+		co::REG::QWORD => RegistryValue::Qword(
+			u64::from_le_bytes(bytes.try_into().map_err(|_| co::ERROR::INVALID_DATA)?),
+		),
+		_ => RegistryValue::Other(reg_type, bytes), // kept as raw bytes
+	})
+}
+
+/// Decodes UTF-16 LE bytes into the null-separated strings they contain. At
+/// least one string is always returned.
+fn utf16_to_strs(bytes: &[u8]) -> SysResult<Vec<String>> {
+	if bytes.len() % 2 != 0 {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+	let wchars = bytes.chunks(2)
+		.map(|ch2| u16::from_le_bytes([ch2[0], ch2[1]]))
+		.collect::<Vec<_>>();
+
+	let mut strs = wchars.split(|ch| *ch == 0x0000)
+		.map(String::from_utf16_lossy)
+		.collect::<Vec<_>>();
+	if strs.len() > 1 {
+		strs.pop(); // what comes after the last null
+	}
+	Ok(strs)
+}
+
+fn serialize_value(buf: &mut String, val: &RegFileValue) {
+	let line_start = buf.len();
+	if val.name.is_empty() {
+		buf.push('@');
+	} else {
+		push_quoted(buf, &val.name);
+	}
+	buf.push('=');
+
+	match &val.data {
+		None => buf.push('-'),
+		Some(data) => match data {
+			RegistryValue::Sz(s) if !s.contains(['\r', '\n', '\0']) => push_quoted(buf, s),
+			RegistryValue::Sz(s) => push_hex(buf, line_start, "hex(1):", &strs_to_utf16(&[s], false)),
+			RegistryValue::Dword(n) => buf.push_str(&format!("dword:{:08x}", n)),
+			RegistryValue::Qword(n) => push_hex(buf, line_start, "hex(b):", &n.to_le_bytes()),
+			RegistryValue::ExpandSz(s) => push_hex(buf, line_start, "hex(2):", &strs_to_utf16(&[s], false)),
+			RegistryValue::MultiSz(strs) => push_hex(buf, line_start, "hex(7):", &strs_to_utf16(strs, true)),
+			RegistryValue::Binary(bytes) => push_hex(buf, line_start, "hex:", bytes),
+			RegistryValue::None => push_hex(buf, line_start, "hex(0):", &[]),
+			RegistryValue::Other(reg_type, bytes) => push_hex(
+				buf, line_start, &format!("hex({:x}):", reg_type.0), bytes),
+		},
+	}
+	buf.push_str("\r\n");
+}
+
+fn push_quoted(buf: &mut String, s: &str) {
+	buf.push('"');
+	for ch in s.chars() {
+		if ch == '\\' || ch == '"' {
+			buf.push('\\');
+		}
+		buf.push(ch);
+	}
+	buf.push('"');
+}
+
+/// Writes the comma-separated bytes, breaking the lines at 80 columns, like the
+/// Registry Editor does.
+fn push_hex(buf: &mut String, line_start: usize, prefix: &str, bytes: &[u8]) {
+	buf.push_str(prefix);
+	let mut col = buf.len() - line_start;
+
+	for (idx, b) in bytes.iter().enumerate() {
+		buf.push_str(&format!("{:02x}", b));
+		col += 2;
+		if idx < bytes.len() - 1 {
+			buf.push(',');
+			col += 1;
+			if col > 76 {
+				buf.push_str("\\\r\n  ");
+				col = 2;
+			}
+		}
+	}
+}
+
+/// Encodes the strings as UTF-16 LE, each one followed by a terminating null.
+/// If `multi`, an additional null terminates the whole block.
+fn strs_to_utf16<S: AsRef<str>>(strs: &[S], multi: bool) -> Vec<u8> {
+	let mut bytes = Vec::<u8>::default();
+	for s in strs.iter() {
+		bytes.extend(
+			s.as_ref()
+				.encode_utf16()
+				.chain(std::iter::once(0x0000))
+				.flat_map(|ch| ch.to_le_bytes()),
+		);
+	}
+	if multi {
+		bytes.extend([0x00, 0x00]);
+	}
+	bytes
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn data_of<'a>(reg: &'a RegFile, key: &str, name: &str) -> &'a Option<RegistryValue> {
+		&reg.find_key(key).unwrap().find_value(name).unwrap().data
+	}
+
+	#[test]
+	fn parse_all_types() {
+		let reg = RegFile::parse_str("\
+Windows Registry Editor Version 5.00
+
+; comment
+[HKEY_CURRENT_USER\\Software\\Foo]
+@=\"default\"
+\"Str\"=\"a \\\"quoted\\\" C:\\\\path\"
+\"Num\"=dword:0000002a
+\"Bin\"=hex:01,02,\\
+  ff
+\"Q\"=hex(b):01,00,00,00,00,00,00,00
+\"Exp\"=hex(2):25,00,41,00,25,00,00,00
+\"Multi\"=hex(7):61,00,00,00,62,00,00,00,00,00
+\"Gone\"=-
+
+[-HKEY_CURRENT_USER\\Software\\Bar]
+").unwrap();
+
+		assert_eq!(reg.keys.len(), 2);
+		let foo = &reg.keys[0];
+		assert_eq!(foo.path, "HKEY_CURRENT_USER\\Software\\Foo");
+		assert!(!foo.delete);
+		assert_eq!(foo.values.len(), 8);
+		assert!(reg.keys[1].delete);
+
+		assert!(matches!(data_of(&reg, "hkey_current_user\\software\\foo", ""),
+			Some(RegistryValue::Sz(s)) if s == "default"));
+		assert!(matches!(data_of(&reg, foo.path.as_str(), "str"),
+			Some(RegistryValue::Sz(s)) if s == "a \"quoted\" C:\\path"));
+		assert!(matches!(data_of(&reg, &foo.path, "Num"), Some(RegistryValue::Dword(42))));
+		assert!(matches!(data_of(&reg, &foo.path, "Bin"),
+			Some(RegistryValue::Binary(b)) if b == &[1, 2, 0xff]));
+		assert!(matches!(data_of(&reg, &foo.path, "Q"), Some(RegistryValue::Qword(1))));
+		assert!(matches!(data_of(&reg, &foo.path, "Exp"),
+			Some(RegistryValue::ExpandSz(s)) if s == "%A%"));
+		assert!(matches!(data_of(&reg, &foo.path, "Multi"),
+			Some(RegistryValue::MultiSz(strs)) if strs == &["a", "b"]));
+		assert!(data_of(&reg, &foo.path, "Gone").is_none());
+	}
+
+	#[test]
+	fn parse_malformed() {
+		assert_eq!(RegFile::parse_str("REGEDIT4\r\n").err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str(&format!("{}\r\n\"a\"=\"b\"", HEADER)).err(),
+			Some(co::ERROR::INVALID_DATA)); // value outside a key
+		assert_eq!(RegFile::parse_str(&format!("{}\r\n[HKCU\\Foo", HEADER)).err(),
+			Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str(&format!("{}\r\n[HKCU]\r\n\"a\"=dword:xyz", HEADER)).err(),
+			Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str(&format!("{}\r\n[HKCU]\r\n\"a=\"b\"", HEADER)).err(),
+			Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn unknown_types_are_kept() {
+		let reg = RegFile::parse_str(&format!(
+			"{}\r\n[HKCU\\Foo]\r\n\"Link\"=hex(6):41,00\r\n\"NoneData\"=hex(0):07\r\n\"Empty\"=hex(0):\r\n",
+			HEADER,
+		)).unwrap();
+
+		assert!(matches!(data_of(&reg, "HKCU\\Foo", "Link"),
+			Some(RegistryValue::Other(co::REG::LINK, b)) if b == &[0x41, 0x00]));
+		assert!(matches!(data_of(&reg, "HKCU\\Foo", "NoneData"),
+			Some(RegistryValue::Other(co::REG::NONE, b)) if b == &[0x07]));
+		assert!(matches!(data_of(&reg, "HKCU\\Foo", "Empty"), Some(RegistryValue::None)));
+
+		let text = reg.serialize_to_str();
+		assert!(text.contains("\"Link\"=hex(6):41,00\r\n"));
+		assert!(text.contains("\"NoneData\"=hex(0):07\r\n"));
+		assert!(text.contains("\"Empty\"=hex(0):\r\n"));
+	}
+
+	#[test]
+	fn serialize_round_trip() {
+		let reg = RegFile {
+			keys: vec![
+				RegFileKey {
+					path: "HKEY_LOCAL_MACHINE\\Software\\Foo".to_owned(),
+					delete: false,
+					values: vec![
+						RegFileValue { name: "".to_owned(), data: Some(RegistryValue::Sz("x\"y\\z".to_owned())) },
+						RegFileValue { name: "Lines".to_owned(), data: Some(RegistryValue::Sz("a\r\nb".to_owned())) },
+						RegFileValue { name: "Num".to_owned(), data: Some(RegistryValue::Dword(0xdead_beef)) },
+						RegFileValue { name: "Big".to_owned(), data: Some(RegistryValue::Qword(u64::MAX - 1)) },
+						RegFileValue { name: "Exp".to_owned(), data: Some(RegistryValue::ExpandSz("%TEMP%".to_owned())) },
+						RegFileValue { name: "Multi".to_owned(), data: Some(RegistryValue::MultiSz(vec!["a".to_owned(), "".to_owned(), "c".to_owned()])) },
+						RegFileValue { name: "Long".to_owned(), data: Some(RegistryValue::Binary((0..=255).collect())) },
+						RegFileValue { name: "Raw".to_owned(), data: Some(RegistryValue::Other(co::REG::RESOURCE_LIST, vec![9, 8, 7])) },
+						RegFileValue { name: "Del".to_owned(), data: None },
+					],
+				},
+				RegFileKey {
+					path: "HKEY_LOCAL_MACHINE\\Software\\Bar".to_owned(),
+					delete: true,
+					values: Vec::default(),
+				},
+			],
+		};
+
+		let text = reg.serialize_to_str();
+		assert!(text.starts_with("Windows Registry Editor Version 5.00\r\n\r\n"));
+		assert!(text.lines().all(|line| line.len() <= 80));
+		assert!(text.contains("[-HKEY_LOCAL_MACHINE\\Software\\Bar]\r\n"));
+		assert!(text.contains("\"Num\"=dword:deadbeef\r\n"));
+
+		let reg2 = RegFile::parse_str(&text).unwrap();
+		assert_eq!(reg2.keys.len(), reg.keys.len());
+		for (key, key2) in reg.keys.iter().zip(reg2.keys.iter()) {
+			assert_eq!(key.path, key2.path);
+			assert_eq!(key.delete, key2.delete);
+			assert_eq!(key.values.len(), key2.values.len());
+			for (val, val2) in key.values.iter().zip(key2.values.iter()) {
+				assert_eq!(val.name, val2.name);
+				assert_eq!(format!("{:?}", val.data), format!("{:?}", val2.data));
+			}
+		}
+		assert_eq!(reg2.serialize_to_str(), text);
+	}
+
+	#[test]
+	fn serialize_bytes_utf16() {
+		let reg = RegFile { keys: Vec::default() };
+		let bytes = reg.serialize_to_bytes();
+		assert_eq!(&bytes[..4], &[0xff, 0xfe, b'W', 0x00]);
+		assert_eq!(bytes.len(), 2 + reg.serialize_to_str().len() * 2);
+	}
+
+	#[test]
+	fn root_keys() {
+		let (hroot, name, sub) = split_root_key("hkcu\\Software\\Foo").unwrap();
+		assert!(hroot == HKEY::CURRENT_USER);
+		assert_eq!(name, "HKEY_CURRENT_USER");
+		assert_eq!(sub, "Software\\Foo");
+
+		let (hroot, name, sub) = split_root_key("HKEY_LOCAL_MACHINE").unwrap();
+		assert!(hroot == HKEY::LOCAL_MACHINE);
+		assert_eq!(name, "HKEY_LOCAL_MACHINE");
+		assert_eq!(sub, "");
+
+		assert_eq!(split_root_key("HKEY_FOO\\Bar").err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn import_rejects_hive_deletion() {
+		for path in ["HKEY_CURRENT_USER", "HKCU\\", "HKLM\\\\"] {
+			let reg = RegFile {
+				keys: vec![
+					RegFileKey { path: "HKCU\\Software\\Foo".to_owned(), delete: true, values: Vec::default() },
+					RegFileKey { path: path.to_owned(), delete: true, values: Vec::default() },
+				],
+			};
+			// Validation fails before the registry is touched, so Foo is not deleted.
+			assert_eq!(HKEY::import_reg_file(&reg).err(), Some(co::ERROR::INVALID_PARAMETER));
+		}
+	}
+}
@@ -0,0 +1,320 @@
+use std::cell::Cell;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	AnyResult, HSERVICESTATUS, SERVICE_STATUS, SysResult, WString,
+};
+use crate::kernel::privs::bool_to_sysresult;
+use crate::prelude::{kernel_Hservicestatus, NativeBitflag};
+
+/// Service data stored between `StartServiceCtrlDispatcher` and `ServiceMain`.
+struct PendingService {
+	name: String,
+	accepted: co::SERVICE_ACCEPT,
+	main: Box<dyn FnOnce(&ServiceContext) -> AnyResult<()> + Send>,
+}
+
+static PENDING_SERVICE: Mutex<Option<PendingService>> = Mutex::new(None);
+static CONTROL_SENDER: Mutex<Option<(co::SERVICE_ACCEPT, Sender<co::SERVICE_CONTROL>)>> = Mutex::new(None);
+
+/// High-level runtime for a Windows
+/// [service](https://learn.microsoft.com/en-us/windows/win32/services/services)
+/// running in its own process.
+///
+/// Wraps
+/// [`StartServiceCtrlDispatcher`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-startservicectrldispatcherw)
+/// and
+/// [`RegisterServiceCtrlHandlerEx`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/nf-winsvc-registerservicectrlhandlerexw),
+/// reporting the start, running and stopped states automatically, so the
+/// service main function can be written in safe Rust.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, ServiceRuntime};
+///
+/// ServiceRuntime::run(
+///     "MyService",
+///     co::SERVICE_ACCEPT::STOP | co::SERVICE_ACCEPT::SHUTDOWN,
+///     |ctx| {
+///         loop {
+///             match ctx.wait_control(Some(1_000)) {
+///                 Some(co::SERVICE_CONTROL::STOP)
+///                     | Some(co::SERVICE_CONTROL::SHUTDOWN) => break,
+///                 _ => {}, // do some periodic work
+///             }
+///         }
+///         Ok(())
+///     },
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct ServiceRuntime {}
+
+impl ServiceRuntime {
+	/// Connects the current process to the Service Control Manager and blocks
+	/// until the service stops.
+	///
+	/// The `main` function runs in a thread created by the system. The
+	/// `accepted` controls are reported once `main` starts; the
+	/// [`INTERROGATE`](crate::co::SERVICE_CONTROL::INTERROGATE) control is
+	/// always handled internally. When `main` returns an error, the service is
+	/// stopped with it – if the error is a [`co::ERROR`](crate::co::ERROR), it
+	/// is used as the exit code, otherwise
+	/// [`SERVICE_SPECIFIC_ERROR`](crate::co::ERROR::SERVICE_SPECIFIC_ERROR) is
+	/// reported.
+	///
+	/// If the process was not started by the Service Control Manager – e.g.,
+	/// it was started from a console – this method fails with
+	/// [`FAILED_SERVICE_CONTROLLER_CONNECT`](crate::co::ERROR::FAILED_SERVICE_CONTROLLER_CONNECT).
+	pub fn run<F>(
+		service_name: &str,
+		accepted: co::SERVICE_ACCEPT,
+		main: F,
+	) -> SysResult<()>
+		where F: FnOnce(&ServiceContext) -> AnyResult<()> + Send + 'static,
+	{
+		*PENDING_SERVICE.lock().unwrap() = Some(PendingService {
+			name: service_name.to_owned(),
+			accepted,
+			main: Box::new(main),
+		});
+
+		let name16 = WString::from_str(service_name);
+		let table = [
+			SERVICE_TABLE_ENTRY {
+				lpServiceName: name16.as_ptr(),
+				lpServiceProc: Some(service_main),
+			},
+			SERVICE_TABLE_ENTRY { // the table is terminated by a null entry
+				lpServiceName: std::ptr::null(),
+				lpServiceProc: None,
+			},
+		];
+
+		let ret = bool_to_sysresult(
+			unsafe {
+				kernel::ffi::StartServiceCtrlDispatcherW(table.as_ptr() as _)
+			},
+		);
+		PENDING_SERVICE.lock().unwrap().take(); // if never started
+		ret
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Context of a service run by [`ServiceRuntime`](crate::ServiceRuntime),
+/// passed to the service main function.
+pub struct ServiceContext {
+	args: Vec<String>,
+	accepted: co::SERVICE_ACCEPT,
+	hstatus: HSERVICESTATUS,
+	status: Cell<SERVICE_STATUS>,
+	receiver: Receiver<co::SERVICE_CONTROL>,
+}
+
+impl ServiceContext {
+	/// Returns the arguments passed to the service by
+	/// [`HSERVICE::StartService`](crate::prelude::kernel_Hservice::StartService).
+	/// The first argument is always the service name.
+	#[must_use]
+	pub fn args(&self) -> &[String] {
+		&self.args
+	}
+
+	/// Returns the underlying status handle, which can be used for low-level
+	/// operations.
+	#[must_use]
+	pub const fn hstatus(&self) -> &HSERVICESTATUS {
+		&self.hstatus
+	}
+
+	/// Reports the current state of the service to the Service Control Manager.
+	///
+	/// For the pending states, the check point is incremented on each call,
+	/// and `wait_hint_ms` is the estimated time until the next report.
+	pub fn set_state(&self,
+		state: co::SERVICE_STATE, wait_hint_ms: u32) -> SysResult<()>
+	{
+		let mut status = self.status.get();
+		let is_pending = state == co::SERVICE_STATE::START_PENDING
+			|| state == co::SERVICE_STATE::STOP_PENDING;
+
+		status.dwCurrentState = state;
+		status.dwWaitHint = wait_hint_ms;
+		status.dwCheckPoint = if state == co::SERVICE_STATE::RUNNING
+			|| state == co::SERVICE_STATE::STOPPED
+		{
+			0
+		} else {
+			status.dwCheckPoint + 1
+		};
+		status.dwControlsAccepted = if is_pending {
+			co::SERVICE_ACCEPT::default() // no controls while starting or stopping
+		} else {
+			self.accepted
+		};
+
+		self.status.set(status);
+		self.hstatus.SetServiceStatus(&status)
+	}
+
+	/// Blocks until a control is sent to the service, or until the timeout
+	/// expires, in which case `None` is returned. If `timeout_ms` is `None`,
+	/// waits indefinitely.
+	///
+	/// Only the accepted controls, and the user-defined ones (128 to 255), are
+	/// delivered. When a stop control is received, the
+	/// [`STOP_PENDING`](crate::co::SERVICE_STATE::STOP_PENDING) state is
+	/// reported automatically.
+	#[must_use]
+	pub fn wait_control(&self,
+		timeout_ms: Option<u32>) -> Option<co::SERVICE_CONTROL>
+	{
+		let control = match timeout_ms {
+			Some(ms) => match self.receiver.recv_timeout(
+				std::time::Duration::from_millis(ms as _))
+			{
+				Ok(control) => Some(control),
+				Err(RecvTimeoutError::Timeout)
+					| Err(RecvTimeoutError::Disconnected) => None,
+			},
+			None => self.receiver.recv().ok(),
+		};
+
+		if let Some(control) = control {
+			if control == co::SERVICE_CONTROL::STOP
+				|| control == co::SERVICE_CONTROL::SHUTDOWN
+				|| control == co::SERVICE_CONTROL::PRESHUTDOWN
+			{
+				self.set_state(co::SERVICE_STATE::STOP_PENDING, 3_000).ok(); // ignore errors
+			}
+		}
+		control
+	}
+}
+
+//------------------------------------------------------------------------------
+
+#[allow(non_snake_case)]
+#[repr(C)]
+struct SERVICE_TABLE_ENTRY {
+	lpServiceName: *const u16,
+	lpServiceProc: Option<extern "system" fn(u32, *mut *mut u16)>,
+}
+
+extern "system" fn service_main(argc: u32, argv: *mut *mut u16) {
+	let pending = match PENDING_SERVICE.lock().unwrap().take() {
+		Some(pending) => pending,
+		None => return, // shouldn't happen
+	};
+
+	let args = (0..argc as usize)
+		.map(|i| WString::from_wchars_nullt(unsafe { *argv.add(i) }).to_string())
+		.collect::<Vec<_>>();
+
+	let (sender, receiver) = channel();
+	*CONTROL_SENDER.lock().unwrap() = Some((pending.accepted, sender));
+
+	let hstatus = match unsafe {
+		HSERVICESTATUS::RegisterServiceCtrlHandlerEx(
+			&pending.name, handler_proc, None)
+	} {
+		Ok(hstatus) => hstatus,
+		Err(_) => return, // nothing can be reported without the handle
+	};
+
+	let ctx = ServiceContext {
+		args,
+		accepted: pending.accepted,
+		hstatus,
+		status: Cell::new(SERVICE_STATUS {
+			dwServiceType: co::SERVICE_TYPE::WIN32_OWN_PROCESS,
+			..Default::default()
+		}),
+		receiver,
+	};
+
+	let ret = ctx.set_state(co::SERVICE_STATE::START_PENDING, 3_000)
+		.and_then(|_| ctx.set_state(co::SERVICE_STATE::RUNNING, 0))
+		.map_err(|e| e.into())
+		.and_then(|_| {
+			let main = pending.main;
+			std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| main(&ctx)))
+				.unwrap_or_else(|_| Err("Service main function panicked.".into()))
+		});
+
+	CONTROL_SENDER.lock().unwrap().take(); // further controls are refused
+
+	let mut status = ctx.status.get();
+	match ret {
+		Ok(_) => {
+			status.dwWin32ExitCode = co::ERROR::SUCCESS;
+			status.dwServiceSpecificExitCode = 0;
+		},
+		Err(e) => match e.downcast_ref::<co::ERROR>() {
+			Some(err) => {
+				status.dwWin32ExitCode = *err;
+				status.dwServiceSpecificExitCode = 0;
+			},
+			None => {
+				status.dwWin32ExitCode = co::ERROR::SERVICE_SPECIFIC_ERROR;
+				status.dwServiceSpecificExitCode = 1;
+			},
+		},
+	}
+	ctx.status.set(status);
+	ctx.set_state(co::SERVICE_STATE::STOPPED, 0).ok(); // ignore errors
+}
+
+extern "system" fn handler_proc(
+	control: u32,
+	_event_type: u32,
+	_event_data: *mut std::ffi::c_void,
+	_context: *mut std::ffi::c_void,
+) -> u32
+{
+	let control = co::SERVICE_CONTROL::from(control);
+	if control == co::SERVICE_CONTROL::INTERROGATE {
+		return co::ERROR::SUCCESS.0;
+	}
+
+	let sender_guard = CONTROL_SENDER.lock().unwrap();
+	let (accepted, sender) = match sender_guard.as_ref() {
+		Some(sender) => sender,
+		None => return co::ERROR::CALL_NOT_IMPLEMENTED.0,
+	};
+
+	let needed = match control {
+		co::SERVICE_CONTROL::STOP => co::SERVICE_ACCEPT::STOP,
+		co::SERVICE_CONTROL::PAUSE
+			| co::SERVICE_CONTROL::CONTINUE => co::SERVICE_ACCEPT::PAUSE_CONTINUE,
+		co::SERVICE_CONTROL::SHUTDOWN => co::SERVICE_ACCEPT::SHUTDOWN,
+		co::SERVICE_CONTROL::PARAMCHANGE => co::SERVICE_ACCEPT::PARAMCHANGE,
+		co::SERVICE_CONTROL::NETBINDADD
+			| co::SERVICE_CONTROL::NETBINDREMOVE
+			| co::SERVICE_CONTROL::NETBINDENABLE
+			| co::SERVICE_CONTROL::NETBINDDISABLE => co::SERVICE_ACCEPT::NETBINDCHANGE,
+		co::SERVICE_CONTROL::HARDWAREPROFILECHANGE => co::SERVICE_ACCEPT::HARDWAREPROFILECHANGE,
+		co::SERVICE_CONTROL::POWEREVENT => co::SERVICE_ACCEPT::POWEREVENT,
+		co::SERVICE_CONTROL::SESSIONCHANGE => co::SERVICE_ACCEPT::SESSIONCHANGE,
+		co::SERVICE_CONTROL::PRESHUTDOWN => co::SERVICE_ACCEPT::PRESHUTDOWN,
+		co::SERVICE_CONTROL::TIMECHANGE => co::SERVICE_ACCEPT::TIMECHANGE,
+		co::SERVICE_CONTROL::TRIGGEREVENT => co::SERVICE_ACCEPT::TRIGGEREVENT,
+		co::SERVICE_CONTROL::USERMODEREBOOT => co::SERVICE_ACCEPT::USERMODEREBOOT,
+		_ => co::SERVICE_ACCEPT::default(), // user-defined controls are always accepted
+	};
+
+	if (control.0 >= 128 && control.0 <= 255)
+		|| (needed != co::SERVICE_ACCEPT::default() && accepted.has(needed))
+	{
+		sender.send(control).ok(); // main function may have already returned
+		co::ERROR::SUCCESS.0
+	} else {
+		co::ERROR::CALL_NOT_IMPLEMENTED.0
+	}
+}
@@ -0,0 +1,420 @@
+use crate::co;
+use crate::kernel::decl::{HKEY, IniEntry, IniSection, RegistryValue, SysResult};
+use crate::prelude::kernel_Hkey;
+
+/// A type which can be stored as a single value by a
+/// [`SettingsStore`](crate::prelude::SettingsStore).
+///
+/// The following types are implemented:
+///
+/// | Rust type | Registry type |
+/// | - | - |
+/// | `u32` | [`co::REG::DWORD`](crate::co::REG::DWORD) |
+/// | `u64` | [`co::REG::QWORD`](crate::co::REG::QWORD) |
+/// | `String` | [`co::REG::SZ`](crate::co::REG::SZ) |
+/// | `Vec<String>` | [`co::REG::MULTI_SZ`](crate::co::REG::MULTI_SZ) |
+/// | `Vec<u8>` | [`co::REG::BINARY`](crate::co::REG::BINARY) |
+///
+/// Since text-based backends, like [`IniSection`](crate::IniSection), always
+/// return [`RegistryValue::Sz`](crate::RegistryValue::Sz), each type also
+/// accepts its textual representation when reading.
+///
+/// This trait is enabled with the `kernel` feature.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait SettingsValue: Sized {
+	/// Converts the value to a [`RegistryValue`](crate::RegistryValue).
+	#[must_use]
+	fn to_registry_value(&self) -> RegistryValue;
+
+	/// Converts a [`RegistryValue`](crate::RegistryValue) to the value,
+	/// returning `None` if the type doesn't match.
+	#[must_use]
+	fn from_registry_value(val: RegistryValue) -> Option<Self>;
+}
+
+impl SettingsValue for u32 {
+	fn to_registry_value(&self) -> RegistryValue {
+		RegistryValue::Dword(*self)
+	}
+
+	fn from_registry_value(val: RegistryValue) -> Option<Self> {
+		match val {
+			RegistryValue::Dword(n) => Some(n),
+			RegistryValue::Sz(s) => s.trim().parse().ok(),
+			_ => None,
+		}
+	}
+}
+
+impl SettingsValue for u64 {
+	fn to_registry_value(&self) -> RegistryValue {
+		RegistryValue::Qword(*self)
+	}
+
+	fn from_registry_value(val: RegistryValue) -> Option<Self> {
+		match val {
+			RegistryValue::Qword(n) => Some(n),
+			RegistryValue::Dword(n) => Some(n as _),
+			RegistryValue::Sz(s) => s.trim().parse().ok(),
+			_ => None,
+		}
+	}
+}
+
+impl SettingsValue for String {
+	fn to_registry_value(&self) -> RegistryValue {
+		RegistryValue::Sz(self.clone())
+	}
+
+	fn from_registry_value(val: RegistryValue) -> Option<Self> {
+		match val {
+			RegistryValue::Sz(s) | RegistryValue::ExpandSz(s) => Some(s),
+			_ => None,
+		}
+	}
+}
+
+impl SettingsValue for Vec<String> {
+	fn to_registry_value(&self) -> RegistryValue {
+		RegistryValue::MultiSz(self.clone())
+	}
+
+	fn from_registry_value(val: RegistryValue) -> Option<Self> {
+		match val {
+			RegistryValue::MultiSz(strs) => Some(strs),
+			RegistryValue::Sz(s) => Some(split_escaped(&s)),
+			_ => None,
+		}
+	}
+}
+
+impl SettingsValue for Vec<u8> {
+	fn to_registry_value(&self) -> RegistryValue {
+		RegistryValue::Binary(self.clone())
+	}
+
+	fn from_registry_value(val: RegistryValue) -> Option<Self> {
+		match val {
+			RegistryValue::Binary(bytes) => Some(bytes),
+			RegistryValue::Sz(s) => parse_hex(s.trim()),
+			_ => None,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A storage backend for [`Settings`](crate::prelude::Settings), which loads
+/// and saves named values.
+///
+/// It's implemented for:
+///
+/// * [`HKEY`](crate::HKEY), where each value is a registry value of the key;
+/// * [`IniSection`](crate::IniSection), where each value is an entry of the
+///   section.
+///
+/// This trait is enabled with the `kernel` feature.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait SettingsStore {
+	/// Loads the value with the given name, returning `None` if it doesn't
+	/// exist.
+	#[must_use]
+	fn load_value(&self, name: &str) -> SysResult<Option<RegistryValue>>;
+
+	/// Saves the value with the given name, overwriting it if it already
+	/// exists.
+	fn save_value(&mut self, name: &str, val: RegistryValue) -> SysResult<()>;
+}
+
+impl SettingsStore for HKEY {
+	fn load_value(&self, name: &str) -> SysResult<Option<RegistryValue>> {
+		match self.RegQueryValueEx(Some(name)) {
+			Ok(val) => Ok(Some(val)),
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+
+	fn save_value(&mut self, name: &str, val: RegistryValue) -> SysResult<()> {
+		self.RegSetValueEx(Some(name), val)
+	}
+}
+
+/// Values are stored as text: numbers in decimal, binary data in hexadecimal,
+/// and multiple strings separated by `|`, with `|` and `\` escaped by a
+/// backslash. An empty list is stored as an empty string, and a list with a
+/// single empty string is stored as a lone `\`, so both survive a round-trip.
+impl SettingsStore for IniSection {
+	fn load_value(&self, name: &str) -> SysResult<Option<RegistryValue>> {
+		Ok(self.find_entry(name).map(|ent| RegistryValue::Sz(ent.val.clone())))
+	}
+
+	fn save_value(&mut self, name: &str, val: RegistryValue) -> SysResult<()> {
+		let text = match val {
+			RegistryValue::Dword(n) => n.to_string(),
+			RegistryValue::Qword(n) => n.to_string(),
+			RegistryValue::Sz(s) | RegistryValue::ExpandSz(s) => s,
+			RegistryValue::MultiSz(strs) => join_escaped(&strs),
+			RegistryValue::Binary(bytes) | RegistryValue::Other(_, bytes) => bytes.iter()
+				.map(|b| format!("{:02x}", b))
+				.collect(),
+			RegistryValue::None => String::default(),
+		};
+
+		match self.find_entry_mut(name) {
+			Some(ent) => ent.val = text,
+			None => self.entries.push(IniEntry { key: name.to_owned(), val: text }),
+		}
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A struct whose fields are mapped to named values of a
+/// [`SettingsStore`](crate::prelude::SettingsStore), like a registry key or an
+/// `.ini` section.
+///
+/// The fields are declared only once, in
+/// [`map_fields`](crate::prelude::Settings::map_fields), which is then used
+/// both to load and to save them. Each field is declared with a pair of
+/// accessors: a shared one, used to save straight from `&self`, and a mutable
+/// one, used to load. Values missing from the store keep their
+/// [`Default`](std::default::Default) value when loading.
+///
+/// This trait is enabled with the `kernel` feature.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HKEY, Ini, SettingsMapper, SysResult};
+///
+/// #[derive(Default)]
+/// struct AppSettings {
+///     width: u32,
+///     height: u32,
+///     recent_files: Vec<String>,
+/// }
+///
+/// impl Settings for AppSettings {
+///     fn map_fields(m: &mut SettingsMapper<Self>) -> SysResult<()> {
+///         m.field("Width", |s| &s.width, |s| &mut s.width)?;
+///         m.field("Height", |s| &s.height, |s| &mut s.height)?;
+///         m.field("RecentFiles", |s| &s.recent_files, |s| &mut s.recent_files)?;
+///         Ok(())
+///     }
+/// }
+///
+/// let (mut hkey, _) = HKEY::CURRENT_USER.RegCreateKeyEx(
+///     "Software\\MyApp",
+///     None,
+///     co::REG_OPTION::NON_VOLATILE,
+///     co::KEY::READ | co::KEY::WRITE,
+///     None,
+/// )?;
+///
+/// let mut settings = AppSettings::load(&*hkey)?;
+/// settings.width = 800;
+/// settings.save(&mut *hkey)?;
+///
+/// // The same settings can be saved to an .ini file
+/// let mut ini = Ini::parse_from_file("C:\\Temp\\my_app.ini")?;
+/// if let Some(section) = ini.find_section_mut("Settings") {
+///     settings.save(section)?;
+/// }
+/// ini.serialize_to_file("C:\\Temp\\my_app.ini")?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub trait Settings: Default {
+	/// Declares each field, by calling
+	/// [`SettingsMapper::field`](crate::SettingsMapper::field) once for each
+	/// one of them.
+	fn map_fields(mapper: &mut SettingsMapper<Self>) -> SysResult<()>;
+
+	/// Creates a new object with [`Default`](std::default::Default), then
+	/// loads the fields from the store.
+	#[must_use]
+	fn load(store: &impl SettingsStore) -> SysResult<Self> {
+		let mut obj = Self::default();
+		Self::map_fields(&mut SettingsMapper { mode: MapperMode::Load(store, &mut obj) })?;
+		Ok(obj)
+	}
+
+	/// Saves all the fields to the store.
+	fn save(&self, store: &mut impl SettingsStore) -> SysResult<()> {
+		Self::map_fields(&mut SettingsMapper { mode: MapperMode::Save(store, self) })
+	}
+}
+
+/// Loads or saves the fields of a [`Settings`](crate::prelude::Settings)
+/// object, as declared in
+/// [`map_fields`](crate::prelude::Settings::map_fields).
+pub struct SettingsMapper<'a, S> {
+	mode: MapperMode<'a, S>,
+}
+
+enum MapperMode<'a, S> {
+	Load(&'a dyn SettingsStore, &'a mut S),
+	Save(&'a mut dyn SettingsStore, &'a S),
+}
+
+impl<'a, S> SettingsMapper<'a, S> {
+	/// Maps a field to the value with the given name. The `get` accessor is
+	/// used when saving, and `get_mut` when loading.
+	///
+	/// When loading, if the value doesn't exist or has a different type, the
+	/// field is left untouched.
+	pub fn field<T>(&mut self,
+		name: &str,
+		get: impl FnOnce(&S) -> &T,
+		get_mut: impl FnOnce(&mut S) -> &mut T,
+	) -> SysResult<()>
+		where T: SettingsValue,
+	{
+		match &mut self.mode {
+			MapperMode::Load(store, obj) => {
+				if let Some(val) = store.load_value(name)? {
+					if let Some(val) = T::from_registry_value(val) {
+						*get_mut(obj) = val;
+					}
+				}
+				Ok(())
+			},
+			MapperMode::Save(store, obj) => store.save_value(name, get(obj).to_registry_value()),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+fn join_escaped(strs: &[String]) -> String {
+	if strs.len() == 1 && strs[0].is_empty() {
+		return "\\".to_owned(); // otherwise it would be read as an empty list
+	}
+	strs.iter()
+		.map(|s| s.replace('\\', "\\\\").replace('|', "\\|"))
+		.collect::<Vec<_>>()
+		.join("|")
+}
+
+fn split_escaped(s: &str) -> Vec<String> {
+	if s.is_empty() {
+		return Vec::default();
+	}
+
+	let mut strs = Vec::<String>::default();
+	let mut cur = String::default();
+	let mut chars = s.chars();
+	while let Some(ch) = chars.next() {
+		match ch {
+			'\\' => cur.extend(chars.next()),
+			'|' => strs.push(std::mem::take(&mut cur)),
+			ch => cur.push(ch),
+		}
+	}
+	strs.push(cur);
+	strs
+}
+
+fn parse_hex(s: &str) -> Option<Vec<u8>> {
+	if s.len() % 2 != 0 {
+		return None;
+	}
+	(0..s.len()).step_by(2)
+		.map(|idx| s.get(idx..idx + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Default)]
+	struct TestSettings {
+		num: u32,
+		big: u64,
+		name: String,
+		strs: Vec<String>,
+		bytes: Vec<u8>,
+	}
+
+	impl Settings for TestSettings {
+		fn map_fields(m: &mut SettingsMapper<Self>) -> SysResult<()> {
+			m.field("Num", |s| &s.num, |s| &mut s.num)?;
+			m.field("Big", |s| &s.big, |s| &mut s.big)?;
+			m.field("Name", |s| &s.name, |s| &mut s.name)?;
+			m.field("Strs", |s| &s.strs, |s| &mut s.strs)?;
+			m.field("Bytes", |s| &s.bytes, |s| &mut s.bytes)?;
+			Ok(())
+		}
+	}
+
+	fn round_trip(strs: &[&str]) -> Vec<String> {
+		let strs = strs.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+		split_escaped(&join_escaped(&strs))
+	}
+
+	#[test]
+	fn escaped_lists() {
+		assert_eq!(join_escaped(&["a|b".to_owned(), "c\\".to_owned()]), "a\\|b|c\\\\");
+		assert_eq!(round_trip(&[]), Vec::<String>::default());
+		assert_eq!(round_trip(&[""]), vec![""]);
+		assert_eq!(round_trip(&["", ""]), vec!["", ""]);
+		assert_eq!(round_trip(&["\\"]), vec!["\\"]);
+		assert_eq!(round_trip(&["a|b", "", "c\\", "|"]), vec!["a|b", "", "c\\", "|"]);
+	}
+
+	#[test]
+	fn ini_round_trip() {
+		let settings = TestSettings {
+			num: 42,
+			big: u64::MAX,
+			name: "a=b".to_owned(),
+			strs: vec!["".to_owned()],
+			bytes: vec![0x00, 0xab, 0xff],
+		};
+
+		let mut section = IniSection { name: "S".to_owned(), entries: Vec::default() };
+		settings.save(&mut section).unwrap();
+		assert_eq!(section.find_entry("Num").unwrap().val, "42");
+		assert_eq!(section.find_entry("Bytes").unwrap().val, "00abff");
+
+		let loaded = TestSettings::load(&section).unwrap();
+		assert_eq!(loaded.num, 42);
+		assert_eq!(loaded.big, u64::MAX);
+		assert_eq!(loaded.name, "a=b");
+		assert_eq!(loaded.strs, vec![""]);
+		assert_eq!(loaded.bytes, vec![0x00, 0xab, 0xff]);
+	}
+
+	#[test]
+	fn ini_bad_values_keep_default() {
+		let mut section = IniSection { name: "S".to_owned(), entries: Vec::default() };
+		for (key, val) in [("Num", "x"), ("Bytes", "abc"), ("Name", "")] {
+			section.entries.push(IniEntry { key: key.to_owned(), val: val.to_owned() });
+		}
+
+		let loaded = TestSettings::load(&section).unwrap();
+		assert_eq!(loaded.num, 0);
+		assert!(loaded.bytes.is_empty());
+		assert_eq!(loaded.name, "");
+		assert!(loaded.strs.is_empty());
+	}
+}
@@ -1,5 +1,5 @@
 use crate::{co, kernel};
-use crate::kernel::decl::{Encoding, HLOCAL, MultiByteToWideChar, SysResult};
+use crate::kernel::decl::{Encoding, HLOCAL, SysResult};
 use crate::kernel::guard::LocalFreeGuard;
 use crate::prelude::{Handle, kernel_Hlocal};
 
@@ -201,8 +201,8 @@ impl WString {
 	/// built-in
 	/// [`String::from_utf8`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8).
 	///
-	/// To serialize the string back into UTF-8 bytes, use the built-in
-	/// [`String::into_bytes`](https://doc.rust-lang.org/std/string/struct.String.html#method.into_bytes).
+	/// To serialize the string back into bytes, use
+	/// [`Encoding::encode`](crate::Encoding::encode).
 	///
 	/// # Examples
 	///
@@ -220,54 +220,12 @@ impl WString {
 	/// ```
 	#[must_use]
 	pub fn parse(data: &[u8]) -> SysResult<Self> {
-		let mut data = data;
 		if data.is_empty() { // nothing to parse
 			return Ok(Self::default());
 		}
 
-		let (encoding, sz_bom) = Encoding::guess(data);
-		data = &data[sz_bom..]; // skip BOM, if any
-
-		Ok(Self::from_wchars_slice(
-			&match encoding {
-				Encoding::Ansi => Self::parse_ansi(data),
-				Encoding::Win1252 => MultiByteToWideChar(co::CP::WINDOWS_1252, co::MBC::NoValue, data)?,
-				Encoding::Utf8 => MultiByteToWideChar(co::CP::UTF8, co::MBC::NoValue, data)?,
-				Encoding::Utf16be => Self::parse_utf16(data, true),
-				Encoding::Utf16le => Self::parse_utf16(data, false),
-				Encoding::Utf32be
-				| Encoding::Utf32le
-				| Encoding::Scsu
-				| Encoding::Bocu1
-				| Encoding::Unknown => panic!("Encoding {} not implemented.", encoding),
-			}
-		))
-	}
-
-	fn parse_ansi(data: &[u8]) -> Vec<u16> {
-		data.iter()
-			.take_while(|ch| **ch != 0x0000) // ignore terminating null, if any
-			.map(|ch| *ch as u16) // raw u8 to u16 conversion
-			.collect()
-	}
-	
-	fn parse_utf16(data: &[u8], is_big_endian: bool) -> Vec<u16> {
-		let data = if data.len() % 2 == 1 {
-			&data[..data.len() - 1] // if odd number of bytes, discard last one
-		} else {
-			data
-		};
-
-		data.chunks(2)
-			.take_while(|ch2| **ch2 != [0x00, 0x00]) // ignore terminating null, if any
-			.map(|ch2| {
-				if is_big_endian {
-					u16::from_be_bytes(ch2.try_into().unwrap())
-				} else {
-					u16::from_le_bytes(ch2.try_into().unwrap())
-				}
-			})
-			.collect()
+		let (encoding, _) = Encoding::guess(data);
+		Ok(Self::from_str(&encoding.decode(data))) // BOM is skipped by the decoder
 	}
 }
 
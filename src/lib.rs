@@ -162,6 +162,22 @@
 //! [`SysResult`](crate::SysResult), which can contain an
 //! [`ERROR`](crate::co::ERROR) constant.
 //!
+//! Constant values are printed with their symbolic names, and bitflags are
+//! decomposed into the individual flags. The same textual form can be parsed
+//! back with [`FromStr`](std::str::FromStr):
+//!
+//! ```rust,no_run
+//! use winsafe::co;
+//!
+//! let ws = co::WS::CHILD | co::WS::VISIBLE;
+//! println!("{:?}", ws); // WS::CHILD | WS::VISIBLE
+//! println!("{}", ws); // CHILD | VISIBLE
+//!
+//! let parsed: co::WS = "CHILD | VISIBLE".parse()?;
+//! assert_eq!(parsed, ws);
+//! # Ok::<_, winsafe::co::ERROR>(())
+//! ```
+//!
 //! # Native structs
 //!
 //! WinSafe implements native Win32 structs in a very restricted way. First off,
@@ -241,12 +257,17 @@
 //!
 //! | Utility | Used for |
 //! | - | - |
+//! | [`Clipboard`](crate::Clipboard) | Typed clipboard read/write. |
 //! | [`Encoding`](crate::Encoding) | String encodings. |
 //! | [`File`](crate::File) | File read/write and other operations. |
 //! | [`FileMapped`](crate::FileMapped) | Memory-mapped file operations. |
 //! | [`Ini`](crate::Ini) | Managing key/value pairs of a `.ini` file. |
 //! | [`path`](crate::path) | File path operations. |
+//! | [`RegFile`](crate::RegFile) | Parsing and writing `.reg` files. |
 //! | [`ResourceInfo`](crate::ResourceInfo) | Retrieve embedded data from executables or DLLs. |
+//! | [`ServiceRuntime`](crate::ServiceRuntime) | Running the process as a Windows service. |
+//! | [`StreamIo`](crate::StreamIo) | Using an [`IStream`](crate::IStream) with the standard Rust I/O traits. |
+//! | [`Settings`](crate::prelude::Settings) | Mapping application settings to the registry or `.ini` files. |
 //! | [`task_dlg`](crate::task_dlg) | Various dialog prompts. |
 //! | [`WString`](crate::WString) | Managing native wide strings. |
 
@@ -503,6 +524,14 @@ pub mod msg {
 		pub use super::super::comctl::messages::trbm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod ttm {
+		//! Tooltip control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages),
+		//! whose constants have [`TTM`](crate::co::TTM) prefix.
+		pub use super::super::comctl::messages::ttm::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod tvm {
 		//! Tree view control
@@ -96,7 +96,35 @@ macro_rules! const_guid {
 
 		impl std::fmt::Display for $name {
 			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-				self.0.fmt(f)
+				std::fmt::Display::fmt(&self.0, f)
+			}
+		}
+
+		impl std::fmt::LowerHex for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+				std::fmt::LowerHex::fmt(&self.0, f)
+			}
+		}
+
+		impl std::fmt::UpperHex for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+				std::fmt::UpperHex::fmt(&self.0, f)
+			}
+		}
+
+		impl std::str::FromStr for $name {
+			type Err = crate::kernel::decl::GuidParseError;
+
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				s.parse().map(Self)
+			}
+		}
+
+		impl TryFrom<&str> for $name {
+			type Error = crate::kernel::decl::GuidParseError;
+
+			fn try_from(s: &str) -> Result<Self, Self::Error> {
+				s.parse()
 			}
 		}
 
@@ -122,6 +150,37 @@ macro_rules! const_guid {
 			pub const fn new(guid_str: &str) -> Self {
 				Self(crate::kernel::decl::GUID::new(guid_str))
 			}
+
+			/// Creates a new `GUID` from a 16-byte array in the RFC 4122
+			/// layout; see
+			/// [`GUID::from_bytes`](crate::GUID::from_bytes).
+			#[must_use]
+			pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+				Self(crate::kernel::decl::GUID::from_bytes(bytes))
+			}
+
+			/// Creates a new `GUID` from a 16-byte array in the Microsoft
+			/// mixed-endian layout; see
+			/// [`GUID::from_bytes_le`](crate::GUID::from_bytes_le).
+			#[must_use]
+			pub const fn from_bytes_le(bytes: [u8; 16]) -> Self {
+				Self(crate::kernel::decl::GUID::from_bytes_le(bytes))
+			}
+
+			/// Returns the `GUID` as a 16-byte array in the RFC 4122 layout;
+			/// see [`GUID::to_bytes`](crate::GUID::to_bytes).
+			#[must_use]
+			pub const fn to_bytes(&self) -> [u8; 16] {
+				self.0.to_bytes()
+			}
+
+			/// Returns the `GUID` as a 16-byte array in the Microsoft
+			/// mixed-endian layout; see
+			/// [`GUID::to_bytes_le`](crate::GUID::to_bytes_le).
+			#[must_use]
+			pub const fn to_bytes_le(&self) -> [u8; 16] {
+				self.0.to_bytes_le()
+			}
 		}
 
 		const_guid_values! {
@@ -83,8 +83,12 @@ macro_rules! const_no_debug_display {
 
 /// Declares the type of an ordinary constant, along with private and public
 /// values.
+///
+/// The `Debug` and `Display` impls write the names of the public values, which
+/// are parsed back by the `FromStr` impl.
 macro_rules! const_ordinary {
 	(
+		@impl $is_bitflag:literal;
 		$name:ident : $ntype:ty;
 		$( #[$doc:meta] )*
 		=>
@@ -103,20 +107,39 @@ macro_rules! const_ordinary {
 			$( #[$doc] )*
 		}
 
+		impl $name {
+			/// Names and values of the public values.
+			fn const_names() -> &'static [(&'static str, u64)] {
+				const NAMES: &[(&str, u64)] = &[
+					$( (stringify!($pubvalname), $name::$pubvalname.0 as u64), )*
+				];
+				NAMES
+			}
+		}
+
 		impl std::fmt::Debug for $name {
 			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-				if self.0 as usize > 0xffff {
-					write!(f, "[{:#010x} {}] {}",
-						self.0, self.0, stringify!($name))
-				} else {
-					write!(f, "[{:#06x} {}] {}",
-						self.0, self.0, stringify!($name))
-				}
+				crate::kernel::privs::format_const(f, Some(stringify!($name)),
+					Self::const_names(), std::mem::size_of::<$ntype>() * 8,
+					$is_bitflag, self.0 as u64)
 			}
 		}
 		impl std::fmt::Display for $name {
 			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-				write!(f, "{:#010x}", self.0 as usize)
+				crate::kernel::privs::format_const(f, None,
+					Self::const_names(), std::mem::size_of::<$ntype>() * 8,
+					$is_bitflag, self.0 as u64)
+			}
+		}
+
+		impl std::str::FromStr for $name {
+			type Err = crate::co::ERROR;
+
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				crate::kernel::privs::parse_const(stringify!($name),
+					Self::const_names(), std::mem::size_of::<$ntype>() * 8,
+					$is_bitflag, s)
+					.map(|v| Self(v as $ntype))
 			}
 		}
 
@@ -133,6 +156,37 @@ macro_rules! const_ordinary {
 			)*
 		}
 	};
+
+	(
+		$name:ident : $ntype:ty;
+		$( #[$doc:meta] )*
+		=>
+		$(
+			$( #[$privvaldoc:meta] )*
+			$privvalname:ident $privval:expr
+		)*
+		=>
+		$(
+			$( #[$pubvaldoc:meta] )*
+			$pubvalname:ident $pubval:expr
+		)*
+	) => {
+		const_ordinary! {
+			@impl false;
+			$name: $ntype;
+			$( #[$doc] )*
+			=>
+			$(
+				$( #[$privvaldoc] )*
+				$privvalname $privval
+			)*
+			=>
+			$(
+				$( #[$pubvaldoc] )*
+				$pubvalname $pubval
+			)*
+		}
+	};
 }
 
 /// Declares the type of an ordinary bitflag constant, along with private and
@@ -153,11 +207,14 @@ macro_rules! const_bitflag {
 		)*
 	) => {
 		const_ordinary! {
+			@impl true;
 			$name: $ntype;
 			$( #[$doc] )*
 			///
 			/// This is a bitflag constant, which implements the
 			/// [`NativeBitflag`](crate::prelude::NativeBitflag) trait.
+			/// Its `Debug` and `Display` impls decompose the value into the
+			/// names of the flags.
 			=>
 			$(
 				$( #[$privvaldoc] )*
@@ -7,3 +7,25 @@ const_guid! { IID;
 	/// A COM interface ID, which uniquely identifies the interface (`GUID`).
 	=>
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn const_guid_conversions() {
+		let iid = IID::new("6b29fc40-ca47-1067-b31d-00dd010662da");
+		assert_eq!(iid.to_bytes(), [0x6b, 0x29, 0xfc, 0x40, 0xca, 0x47, 0x10, 0x67,
+			0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda]);
+		assert_eq!(iid.to_bytes_le(), [0x40, 0xfc, 0x29, 0x6b, 0x47, 0xca, 0x67, 0x10,
+			0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda]);
+		assert!(IID::from_bytes(iid.to_bytes()) == iid);
+		assert!(IID::from_bytes_le(iid.to_bytes_le()) == iid);
+
+		assert_eq!(format!("{}", iid), "6b29fc40-ca47-1067-b31d-00dd010662da");
+		assert_eq!(format!("{:#X}", iid), "{6B29FC40-CA47-1067-B31D-00DD010662DA}");
+		assert!("{6B29FC40-CA47-1067-B31D-00DD010662DA}".parse::<IID>().unwrap() == iid);
+		assert!(IID::try_from("6b29fc40ca471067b31d00dd010662da").unwrap() == iid);
+		assert!(CLSID::try_from("6b29fc40-ca47-1067-b31d-00dd010662d").is_err());
+	}
+}
@@ -0,0 +1,116 @@
+//! Infrastructure for COM objects implemented in Rust, which are handed to the
+//! system through their virtual tables.
+
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::prelude::ole_IUnknown;
+
+/// [`IUnknown`](crate::IUnknown) virtual table as called by the system, base
+/// to all virtual tables of COM objects implemented in Rust.
+#[repr(C)]
+pub(crate) struct IUnknownImplVT {
+	pub QueryInterface: extern "system" fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub AddRef: extern "system" fn(ComPtr) -> u32,
+	pub Release: extern "system" fn(ComPtr) -> u32,
+}
+
+/// Memory block of a COM object implemented in Rust. Since the first field is
+/// the pointer to the virtual table, a pointer to this block is the COM pointer
+/// itself.
+///
+/// `V` is the virtual table type, and `T` is the Rust object which answers the
+/// calls.
+#[repr(C)]
+pub(crate) struct ComObj<V: 'static, T: 'static> {
+	vt: &'static V,
+	counter: AtomicU32,
+	iids: &'static [co::IID],
+	obj: T,
+}
+
+impl<V, T> ComObj<V, T> {
+	/// The `IUnknown` part of the virtual table, to be placed at the beginning
+	/// of the virtual table `V`.
+	pub(crate) const IUNKNOWN_VT: IUnknownImplVT = IUnknownImplVT {
+		QueryInterface: Self::QueryInterface,
+		AddRef: Self::AddRef,
+		Release: Self::Release,
+	};
+
+	/// Allocates a new COM object in the heap, with a reference count of 1.
+	///
+	/// The `iids` are all the interfaces the virtual table answers for, except
+	/// `IUnknown`, which is always answered.
+	#[must_use]
+	pub(crate) fn alloc(vt: &'static V, iids: &'static [co::IID], obj: T) -> ComPtr {
+		let com_obj = Box::new(Self {
+			vt,
+			counter: AtomicU32::new(1),
+			iids,
+			obj,
+		});
+		ComPtr(Box::into_raw(com_obj) as _)
+	}
+
+	/// Returns a reference to the Rust object from the COM pointer received by
+	/// a virtual table function.
+	///
+	/// # Safety
+	///
+	/// The pointer must have been created by [`ComObj::alloc`](ComObj::alloc)
+	/// with the same `V` and `T` types.
+	#[must_use]
+	pub(crate) unsafe fn obj<'a>(p: ComPtr) -> &'a T {
+		&(*(p.0 as *const Self)).obj
+	}
+
+	extern "system" fn QueryInterface(
+		p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES
+	{
+		if ppv.is_null() {
+			return co::HRESULT::E_POINTER.0;
+		}
+
+		let com_obj = unsafe { &*(p.0 as *const Self) };
+		let riid = unsafe { &*(riid as *const co::IID) };
+
+		if *riid == IUnknown::IID || com_obj.iids.contains(riid) {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	extern "system" fn AddRef(p: ComPtr) -> u32 {
+		let com_obj = unsafe { &*(p.0 as *const Self) };
+		com_obj.counter.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	extern "system" fn Release(p: ComPtr) -> u32 {
+		let com_obj = unsafe { &*(p.0 as *const Self) };
+		let count = com_obj.counter.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			std::sync::atomic::fence(Ordering::Acquire);
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) }; // free the memory block
+		}
+		count
+	}
+}
+
+/// Converts the result of a Rust implementation into the `HRESULT` returned to
+/// the system.
+#[must_use]
+pub(crate) fn hrresult_to_hres(res: HrResult<()>) -> HRES {
+	match res {
+		Ok(_) => co::HRESULT::S_OK.0,
+		Err(hr) => hr.0,
+	}
+}
@@ -1,9 +1,13 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
 use crate::co;
-use crate::kernel::decl::MAKEQWORD;
+use crate::kernel::decl::{HIDWORD, LODWORD, MAKEQWORD};
 use crate::kernel::ffi_types::HRES;
-use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::com_impl::{ComObj, hrresult_to_hres, IUnknownImplVT};
+use crate::ole::decl::{ComPtr, HrResult, IDataObject};
 use crate::ole::privs::ok_to_hrresult;
 use crate::prelude::{ole_IDataObject, ole_IUnknown};
 use crate::user::decl::POINT;
@@ -120,3 +124,168 @@ pub trait ole_IDropTarget: ole_IUnknown {
 		).map(|_| effect_buf)
 	}
 }
+
+/// This trait is enabled with the `ole` feature, and allows a Rust object to
+/// implement an [`IDropTarget`](crate::IDropTarget) COM object, which is
+/// created with [`IDropTarget::new_impl`](crate::IDropTarget::new_impl).
+///
+/// All methods have default implementations, which refuse the drop with
+/// [`DROPEFFECT::NONE`](crate::co::DROPEFFECT::NONE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HrResult, HWND, IDataObject, IDropTarget, POINT};
+///
+/// struct MyTarget;
+///
+/// impl ole_IDropTargetImpl for MyTarget {
+///     fn DragEnter(&self,
+///         _: &IDataObject, _: co::MK, _: POINT, _: co::DROPEFFECT,
+///     ) -> HrResult<co::DROPEFFECT>
+///     {
+///         Ok(co::DROPEFFECT::COPY)
+///     }
+/// }
+///
+/// let hwnd: HWND; // initialized somewhere
+/// # let hwnd = HWND::NULL;
+///
+/// let drop_target = IDropTarget::new_impl(MyTarget);
+/// hwnd.RegisterDragDrop(&drop_target)?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+pub trait ole_IDropTargetImpl: 'static {
+	/// [`IDropTarget::DragEnter`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idroptarget-dragenter)
+	/// method. Returns the effect of the drop.
+	fn DragEnter(&self,
+		data_obj: &IDataObject,
+		key_state: co::MK,
+		pt: POINT,
+		effect: co::DROPEFFECT,
+	) -> HrResult<co::DROPEFFECT>
+	{
+		let _ = (data_obj, key_state, pt, effect);
+		Ok(co::DROPEFFECT::NONE)
+	}
+
+	/// [`IDropTarget::DragLeave`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idroptarget-dragleave)
+	/// method.
+	fn DragLeave(&self) -> HrResult<()> {
+		Ok(())
+	}
+
+	/// [`IDropTarget::DragOver`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idroptarget-dragover)
+	/// method. Returns the effect of the drop.
+	fn DragOver(&self,
+		key_state: co::MK,
+		pt: POINT,
+		effect: co::DROPEFFECT,
+	) -> HrResult<co::DROPEFFECT>
+	{
+		let _ = (key_state, pt, effect);
+		Ok(co::DROPEFFECT::NONE)
+	}
+
+	/// [`IDropTarget::Drop`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idroptarget-drop)
+	/// method. Returns the effect of the drop.
+	fn Drop(&self,
+		data_obj: &IDataObject,
+		key_state: co::MK,
+		pt: POINT,
+		effect: co::DROPEFFECT,
+	) -> HrResult<co::DROPEFFECT>
+	{
+		let _ = (data_obj, key_state, pt, effect);
+		Ok(co::DROPEFFECT::NONE)
+	}
+}
+
+impl IDropTarget {
+	/// Creates a new `IDropTarget` COM object whose methods are answered by
+	/// the given Rust object, which will be dropped when the last reference to
+	/// the COM object is released.
+	///
+	/// The returned object can be passed to
+	/// [`HWND::RegisterDragDrop`](crate::prelude::ole_Hwnd::RegisterDragDrop).
+	#[must_use]
+	pub fn new_impl<T>(obj: T) -> IDropTarget
+		where T: ole_IDropTargetImpl,
+	{
+		IDropTarget::from(
+			ComObj::alloc(&DropTargetImpl::<T>::VT, DropTargetImpl::<T>::IIDS, obj),
+		)
+	}
+}
+
+/// [`IDropTarget`](crate::IDropTarget) virtual table as called by the system.
+#[repr(C)]
+struct IDropTargetImplVT {
+	IUnknownImplVT: IUnknownImplVT,
+	DragEnter: extern "system" fn(ComPtr, ComPtr, u32, u64, *mut u32) -> HRES,
+	DragOver: extern "system" fn(ComPtr, u32, u64, *mut u32) -> HRES,
+	DragLeave: extern "system" fn(ComPtr) -> HRES,
+	Drop: extern "system" fn(ComPtr, ComPtr, u32, u64, *mut u32) -> HRES,
+}
+
+/// Dispatches the [`IDropTarget`](crate::IDropTarget) virtual table calls to
+/// the Rust object.
+struct DropTargetImpl<T>(PhantomData<T>);
+
+impl<T> DropTargetImpl<T>
+	where T: ole_IDropTargetImpl,
+{
+	const IIDS: &'static [co::IID] = &[IDropTarget::IID];
+
+	const VT: IDropTargetImplVT = IDropTargetImplVT {
+		IUnknownImplVT: ComObj::<IDropTargetImplVT, T>::IUNKNOWN_VT,
+		DragEnter: Self::DragEnter,
+		DragOver: Self::DragOver,
+		DragLeave: Self::DragLeave,
+		Drop: Self::Drop,
+	};
+
+	fn obj<'a>(p: ComPtr) -> &'a T {
+		unsafe { ComObj::<IDropTargetImplVT, T>::obj(p) }
+	}
+
+	extern "system" fn DragEnter(p: ComPtr,
+		data_obj: ComPtr, key_state: u32, pt: u64, effect: *mut u32) -> HRES
+	{
+		let data_obj = ManuallyDrop::new(IDataObject::from(data_obj)); // not owned
+		let res = Self::obj(p).DragEnter(&data_obj, co::MK(key_state as _),
+			POINT::new(LODWORD(pt) as _, HIDWORD(pt) as _),
+			co::DROPEFFECT(unsafe { *effect }));
+		hrresult_to_hres(res.map(|eff| unsafe { *effect = eff.0; }))
+	}
+
+	extern "system" fn DragOver(p: ComPtr,
+		key_state: u32, pt: u64, effect: *mut u32) -> HRES
+	{
+		let res = Self::obj(p).DragOver(co::MK(key_state as _),
+			POINT::new(LODWORD(pt) as _, HIDWORD(pt) as _),
+			co::DROPEFFECT(unsafe { *effect }));
+		hrresult_to_hres(res.map(|eff| unsafe { *effect = eff.0; }))
+	}
+
+	extern "system" fn DragLeave(p: ComPtr) -> HRES {
+		hrresult_to_hres(Self::obj(p).DragLeave())
+	}
+
+	extern "system" fn Drop(p: ComPtr,
+		data_obj: ComPtr, key_state: u32, pt: u64, effect: *mut u32) -> HRES
+	{
+		let data_obj = ManuallyDrop::new(IDataObject::from(data_obj)); // not owned
+		let res = Self::obj(p).Drop(&data_obj, co::MK(key_state as _),
+			POINT::new(LODWORD(pt) as _, HIDWORD(pt) as _),
+			co::DROPEFFECT(unsafe { *effect }));
+		hrresult_to_hres(res.map(|eff| unsafe { *effect = eff.0; }))
+	}
+}
@@ -63,7 +63,7 @@ pub trait ole_ISequentialStream: ole_IUnknown {
 		ok_to_hrresult(
 			unsafe {
 				let vt = self.vt_ref::<ISequentialStreamVT>();
-				(vt.Read)(
+				(vt.Write)(
 					self.ptr(),
 					data.as_ptr() as _,
 					data.len() as _,
@@ -1,10 +1,17 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::sync::Mutex;
+
 use crate::co;
-use crate::kernel::ffi_types::{HRES, PVOID};
-use crate::ole::decl::{ComPtr, HrResult};
+use crate::kernel::decl::FILETIME;
+use crate::kernel::ffi_types::{HRES, PCVOID, PVOID};
+use crate::ole::com_impl::{ComObj, IUnknownImplVT};
+use crate::ole::decl::{ComPtr, HrResult, ISequentialStream};
 use crate::ole::privs::ok_to_hrresult;
-use crate::prelude::ole_ISequentialStream;
+use crate::prelude::{ole_ISequentialStream, ole_IUnknown};
 use crate::vt::ISequentialStreamVT;
 
 /// [`IStream`](crate::IStream) virtual table.
@@ -137,3 +144,274 @@ pub trait ole_IStream: ole_ISequentialStream {
 		)
 	}
 }
+
+impl IStream {
+	/// Creates a new `IStream` COM object whose data is read, written and
+	/// sought through the given Rust object, which will be dropped when the
+	/// last reference to the COM object is released.
+	///
+	/// Any [`Read`](std::io::Read) + [`Write`](std::io::Write) +
+	/// [`Seek`](std::io::Seek) object can be used, like a
+	/// [`Cursor`](std::io::Cursor) over a `Vec<u8>` or a
+	/// [`File`](std::fs::File), so the data doesn't need to be copied into a
+	/// [`SHCreateMemStream`](crate::SHCreateMemStream) stream.
+	///
+	/// `SetSize`, `LockRegion`, `UnlockRegion` and `Clone` are not supported,
+	/// and return [`STG_E_INVALIDFUNCTION`](crate::co::HRESULT::STG_E_INVALIDFUNCTION).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::IStream;
+	///
+	/// let raw_data: Vec<u8>; // initialized somewhere
+	/// # let raw_data = Vec::<u8>::default();
+	///
+	/// let stream = IStream::new_impl(std::io::Cursor::new(raw_data));
+	///
+	/// let mut buf = [0u8; 16];
+	/// let num_read = stream.Read(&mut buf)?;
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	#[must_use]
+	pub fn new_impl<T>(obj: T) -> IStream
+		where T: Read + Write + Seek + Send + 'static,
+	{
+		IStream::from(
+			ComObj::alloc(&StreamImpl::<T>::VT, StreamImpl::<T>::IIDS, Mutex::new(obj)),
+		)
+	}
+}
+
+/// [`IStream`](crate::IStream) virtual table as called by the system.
+#[repr(C)]
+struct IStreamImplVT {
+	IUnknownImplVT: IUnknownImplVT,
+	Read: extern "system" fn(ComPtr, PVOID, u32, *mut u32) -> HRES,
+	Write: extern "system" fn(ComPtr, PCVOID, u32, *mut u32) -> HRES,
+	Seek: extern "system" fn(ComPtr, i64, u32, *mut u64) -> HRES,
+	SetSize: extern "system" fn(ComPtr, u64) -> HRES,
+	CopyTo: extern "system" fn(ComPtr, ComPtr, u64, *mut u64, *mut u64) -> HRES,
+	Commit: extern "system" fn(ComPtr, u32) -> HRES,
+	Revert: extern "system" fn(ComPtr) -> HRES,
+	LockRegion: extern "system" fn(ComPtr, u64, u64, u32) -> HRES,
+	UnlockRegion: extern "system" fn(ComPtr, u64, u64, u32) -> HRES,
+	Stat: extern "system" fn(ComPtr, PVOID, u32) -> HRES,
+	Clone: extern "system" fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+/// [`STATSTG`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ns-objidl-statstg)
+/// struct, filled by `IStream::Stat`.
+#[repr(C)]
+#[derive(Default)]
+struct STATSTG {
+	pwcsName: usize,
+	r#type: u32,
+	cbSize: u64,
+	mtime: FILETIME,
+	ctime: FILETIME,
+	atime: FILETIME,
+	grfMode: u32,
+	grfLocksSupported: u32,
+	clsid: [u8; 16],
+	grfStateBits: u32,
+	reserved: u32,
+}
+
+/// Dispatches the [`IStream`](crate::IStream) virtual table calls to the Rust
+/// object.
+struct StreamImpl<T>(PhantomData<T>);
+
+impl<T> StreamImpl<T>
+	where T: Read + Write + Seek + Send + 'static,
+{
+	const IIDS: &'static [co::IID] = &[ISequentialStream::IID, IStream::IID];
+
+	const VT: IStreamImplVT = IStreamImplVT {
+		IUnknownImplVT: ComObj::<IStreamImplVT, Mutex<T>>::IUNKNOWN_VT,
+		Read: Self::Read,
+		Write: Self::Write,
+		Seek: Self::Seek,
+		SetSize: Self::SetSize,
+		CopyTo: Self::CopyTo,
+		Commit: Self::Commit,
+		Revert: Self::Revert,
+		LockRegion: Self::LockRegion,
+		UnlockRegion: Self::UnlockRegion,
+		Stat: Self::Stat,
+		Clone: Self::Clone,
+	};
+
+	fn with_obj<R>(p: ComPtr, func: impl FnOnce(&mut T) -> std::io::Result<R>) -> Result<R, HRES> {
+		let mutex = unsafe { ComObj::<IStreamImplVT, Mutex<T>>::obj(p) };
+		let mut obj = mutex.lock().map_err(|_| co::HRESULT::E_UNEXPECTED.0)?;
+		func(&mut obj).map_err(|e| io_error_to_hres(&e))
+	}
+
+	extern "system" fn Read(p: ComPtr,
+		pv: PVOID, cb: u32, pcb_read: *mut u32) -> HRES
+	{
+		if pv.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.0;
+		}
+		let buf = unsafe { std::slice::from_raw_parts_mut(pv as *mut u8, cb as _) };
+
+		match Self::with_obj(p, |obj| {
+			let mut total = 0;
+			while total < buf.len() {
+				match obj.read(&mut buf[total..]) {
+					Ok(0) => break, // end of stream
+					Ok(n) => total += n,
+					Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {},
+					Err(e) => return Err(e),
+				}
+			}
+			Ok(total)
+		}) {
+			Ok(total) => {
+				if !pcb_read.is_null() {
+					unsafe { *pcb_read = total as _; }
+				}
+				if total == buf.len() { co::HRESULT::S_OK.0 } else { co::HRESULT::S_FALSE.0 }
+			},
+			Err(hr) => hr,
+		}
+	}
+
+	extern "system" fn Write(p: ComPtr,
+		pv: PCVOID, cb: u32, pcb_written: *mut u32) -> HRES
+	{
+		if pv.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.0;
+		}
+		let data = unsafe { std::slice::from_raw_parts(pv as *const u8, cb as _) };
+
+		match Self::with_obj(p, |obj| obj.write_all(data)) {
+			Ok(_) => {
+				if !pcb_written.is_null() {
+					unsafe { *pcb_written = cb; }
+				}
+				co::HRESULT::S_OK.0
+			},
+			Err(hr) => hr,
+		}
+	}
+
+	extern "system" fn Seek(p: ComPtr,
+		dlib_move: i64, origin: u32, plib_new_position: *mut u64) -> HRES
+	{
+		let pos = match co::STREAM_SEEK::from(origin) {
+			co::STREAM_SEEK::SET => SeekFrom::Start(dlib_move as _),
+			co::STREAM_SEEK::CUR => SeekFrom::Current(dlib_move),
+			co::STREAM_SEEK::END => SeekFrom::End(dlib_move),
+			_ => return co::HRESULT::STG_E_INVALIDFUNCTION.0,
+		};
+
+		match Self::with_obj(p, |obj| obj.seek(pos)) {
+			Ok(new_pos) => {
+				if !plib_new_position.is_null() {
+					unsafe { *plib_new_position = new_pos; }
+				}
+				co::HRESULT::S_OK.0
+			},
+			Err(hr) => hr,
+		}
+	}
+
+	extern "system" fn SetSize(_: ComPtr, _: u64) -> HRES {
+		co::HRESULT::STG_E_INVALIDFUNCTION.0
+	}
+
+	extern "system" fn CopyTo(p: ComPtr,
+		pstm: ComPtr, cb: u64, pcb_read: *mut u64, pcb_written: *mut u64) -> HRES
+	{
+		let dest = ManuallyDrop::new(IStream::from(pstm)); // not owned
+		let mut buf = [0u8; 4096];
+		let (mut total_read, mut total_written) = (0u64, 0u64);
+		let mut hr = co::HRESULT::S_OK.0;
+
+		while total_read < cb {
+			let chunk = (cb - total_read).min(buf.len() as _) as usize;
+			let num_read = match Self::with_obj(p, |obj| obj.read(&mut buf[..chunk])) {
+				Ok(0) => break, // end of stream
+				Ok(n) => n,
+				Err(e) => { hr = e; break; },
+			};
+			total_read += num_read as u64;
+
+			match dest.Write(&buf[..num_read]) {
+				Ok(n) => total_written += n as u64,
+				Err(e) => { hr = e.0; break; },
+			}
+		}
+
+		unsafe {
+			if !pcb_read.is_null() { *pcb_read = total_read; }
+			if !pcb_written.is_null() { *pcb_written = total_written; }
+		}
+		hr
+	}
+
+	extern "system" fn Commit(p: ComPtr, _: u32) -> HRES {
+		match Self::with_obj(p, |obj| obj.flush()) {
+			Ok(_) => co::HRESULT::S_OK.0,
+			Err(hr) => hr,
+		}
+	}
+
+	extern "system" fn Revert(_: ComPtr) -> HRES {
+		co::HRESULT::S_OK.0 // not transacted, nothing to revert
+	}
+
+	extern "system" fn LockRegion(_: ComPtr, _: u64, _: u64, _: u32) -> HRES {
+		co::HRESULT::STG_E_INVALIDFUNCTION.0
+	}
+
+	extern "system" fn UnlockRegion(_: ComPtr, _: u64, _: u64, _: u32) -> HRES {
+		co::HRESULT::STG_E_INVALIDFUNCTION.0
+	}
+
+	extern "system" fn Stat(p: ComPtr, pstatstg: PVOID, _: u32) -> HRES {
+		if pstatstg.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.0;
+		}
+
+		match Self::with_obj(p, |obj| {
+			let cur = obj.stream_position()?;
+			let size = obj.seek(SeekFrom::End(0))?;
+			obj.seek(SeekFrom::Start(cur))?;
+			Ok(size)
+		}) {
+			Ok(size) => {
+				let stat = STATSTG {
+					r#type: 2, // STGTY_STREAM
+					cbSize: size,
+					..Default::default()
+				}; // name is never returned
+				unsafe { *(pstatstg as *mut STATSTG) = stat; }
+				co::HRESULT::S_OK.0
+			},
+			Err(hr) => hr,
+		}
+	}
+
+	extern "system" fn Clone(_: ComPtr, ppstm: *mut ComPtr) -> HRES {
+		if !ppstm.is_null() {
+			unsafe { *ppstm = ComPtr::null(); }
+		}
+		co::HRESULT::STG_E_INVALIDFUNCTION.0
+	}
+}
+
+/// Converts a Rust I/O error into the `HRESULT` returned to the system.
+#[must_use]
+fn io_error_to_hres(err: &std::io::Error) -> HRES {
+	if let Some(hr) = err.get_ref().and_then(|e| e.downcast_ref::<co::HRESULT>()) {
+		hr.0 // error originally raised by an IStream
+	} else if let Some(code) = err.raw_os_error() {
+		co::ERROR::from(code as u32).to_hresult().0
+	} else {
+		co::HRESULT::E_FAIL.0
+	}
+}
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use crate::{co, ole};
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{GUID, WString};
 use crate::ole::decl::{
 	ComPtr, COSERVERINFO, HrResult, IMoniker, IUnknown, MULTI_QI,
 };
@@ -54,6 +54,30 @@ pub fn CLSIDFromString(prog_id: &str) -> HrResult<co::CLSID> {
 	).map(|_| clsid)
 }
 
+/// [`CoCreateGuid`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cocreateguid)
+/// function.
+///
+/// Generates a new random [`GUID`](crate::GUID). To use it as a typed
+/// identifier, convert it with `From`, like `co::CLSID::from(guid)`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, CoCreateGuid};
+///
+/// let guid = CoCreateGuid()?;
+/// println!("{:#X}", guid);
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[must_use]
+pub fn CoCreateGuid() -> HrResult<GUID> {
+	let mut guid = GUID::default();
+	ok_to_hrresult(
+		unsafe { ole::ffi::CoCreateGuid(&mut guid as *mut _ as _) },
+	).map(|_| guid)
+}
+
 /// [`CoCreateInstance`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cocreateinstance)
 /// function.
 ///
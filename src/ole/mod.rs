@@ -1,6 +1,7 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "ole")))]
 
 pub(in crate::ole) mod ffi;
+pub(crate) mod com_impl;
 pub(crate) mod privs;
 pub mod co;
 pub mod guard;
@@ -10,12 +11,14 @@ mod com_interfaces;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub mod decl {
 	pub use super::aliases::*;
 	pub use super::com_interfaces::decl::*;
 	pub use super::funcs::*;
 	pub use super::structs::decl::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
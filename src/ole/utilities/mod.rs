@@ -0,0 +1,3 @@
+mod stream_io;
+
+pub use stream_io::StreamIo;
@@ -0,0 +1,186 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use crate::co;
+use crate::prelude::ole_IStream;
+
+/// Adapter which implements the standard
+/// [`Read`](std::io::Read), [`Write`](std::io::Write) and
+/// [`Seek`](std::io::Seek) traits over an [`IStream`](crate::IStream), so it
+/// can be used with the Rust I/O ecosystem.
+///
+/// Errors are returned as [`std::io::Error`](std::io::Error) objects whose
+/// inner error is the original [`HRESULT`](crate::co::HRESULT).
+///
+/// The reverse operation – an `IStream` backed by a Rust object – is done by
+/// [`IStream::new_impl`](crate::IStream::new_impl).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{SHCreateMemStream, StreamIo};
+/// use std::io::Read;
+///
+/// let stream = SHCreateMemStream(b"Hello, world")?;
+///
+/// let mut text = String::default();
+/// StreamIo::new(stream).read_to_string(&mut text)
+///     .map_err(|_| winsafe::co::HRESULT::E_FAIL)?;
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+pub struct StreamIo<S: ole_IStream> {
+	stream: S,
+}
+
+impl<S: ole_IStream> Read for StreamIo<S> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let max_len = buf.len().min(u32::MAX as _);
+		self.stream.Read(&mut buf[..max_len])
+			.map(|num_read| num_read as _)
+			.map_err(hresult_to_io_error)
+	}
+}
+
+impl<S: ole_IStream> Write for StreamIo<S> {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		let max_len = buf.len().min(u32::MAX as _);
+		self.stream.Write(&buf[..max_len])
+			.map(|num_written| num_written as _)
+			.map_err(hresult_to_io_error)
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		match self.stream.Commit(co::STGC::DEFAULT) {
+			Ok(_) => Ok(()),
+			Err(co::HRESULT::E_NOTIMPL) => Ok(()), // not all streams support it
+			Err(hr) => Err(hresult_to_io_error(hr)),
+		}
+	}
+}
+
+impl<S: ole_IStream> Seek for StreamIo<S> {
+	fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+		let (displacement, origin) = match pos {
+			SeekFrom::Start(off) => (off as i64, co::STREAM_SEEK::SET),
+			SeekFrom::Current(off) => (off, co::STREAM_SEEK::CUR),
+			SeekFrom::End(off) => (off, co::STREAM_SEEK::END),
+		};
+		self.stream.Seek(displacement, origin)
+			.map_err(hresult_to_io_error)
+	}
+}
+
+impl<S: ole_IStream> StreamIo<S> {
+	/// Creates a new adapter over the given stream.
+	#[must_use]
+	pub const fn new(stream: S) -> Self {
+		Self { stream }
+	}
+
+	/// Returns a reference to the underlying stream.
+	#[must_use]
+	pub const fn stream(&self) -> &S {
+		&self.stream
+	}
+
+	/// Consumes the adapter, returning the underlying stream.
+	#[must_use]
+	pub fn into_inner(self) -> S {
+		self.stream
+	}
+}
+
+fn hresult_to_io_error(hr: co::HRESULT) -> std::io::Error {
+	std::io::Error::other(hr)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Cursor;
+
+	use super::*;
+	use crate::ole::decl::IStream;
+	use crate::prelude::ole_ISequentialStream;
+
+	fn cursor_io(data: &[u8]) -> StreamIo<IStream> {
+		StreamIo::new(IStream::new_impl(Cursor::new(data.to_vec())))
+	}
+
+	#[test]
+	fn read_short_and_eof() {
+		let stream = IStream::new_impl(Cursor::new(b"Hello, world".to_vec()));
+		let mut buf = [0u8; 5];
+		assert_eq!(stream.Read(&mut buf), Ok(5));
+		assert_eq!(&buf, b"Hello");
+
+		let mut buf = [0u8; 20];
+		assert_eq!(stream.Read(&mut buf), Ok(7)); // S_FALSE, short read
+		assert_eq!(&buf[..7], b", world");
+		assert_eq!(stream.Read(&mut buf), Ok(0)); // end of stream
+
+		let mut io = cursor_io(b"Hello, world");
+		let mut text = String::default();
+		assert_eq!(io.read_to_string(&mut text).unwrap(), 12);
+		assert_eq!(text, "Hello, world");
+		assert_eq!(io.read(&mut buf).unwrap(), 0);
+	}
+
+	#[test]
+	fn seek_origins() {
+		let stream = IStream::new_impl(Cursor::new(b"0123456789".to_vec()));
+		assert_eq!(stream.Seek(3, co::STREAM_SEEK::SET), Ok(3));
+		assert_eq!(stream.Seek(2, co::STREAM_SEEK::CUR), Ok(5));
+		assert_eq!(stream.Seek(-1, co::STREAM_SEEK::CUR), Ok(4));
+		assert_eq!(stream.Seek(-2, co::STREAM_SEEK::END), Ok(8));
+		assert_eq!(stream.Seek(0, co::STREAM_SEEK::from(7)), Err(co::HRESULT::STG_E_INVALIDFUNCTION));
+
+		let mut io = cursor_io(b"0123456789");
+		assert_eq!(io.seek(SeekFrom::Start(3)).unwrap(), 3);
+		assert_eq!(io.seek(SeekFrom::Current(2)).unwrap(), 5);
+		assert_eq!(io.seek(SeekFrom::End(-2)).unwrap(), 8);
+		let mut buf = [0u8; 4];
+		assert_eq!(io.read(&mut buf).unwrap(), 2);
+		assert_eq!(&buf[..2], b"89");
+		assert_eq!(io.seek(SeekFrom::End(5)).unwrap(), 15); // past the end is allowed
+		assert_eq!(io.read(&mut buf).unwrap(), 0);
+	}
+
+	#[test]
+	fn seek_error() {
+		let mut io = cursor_io(b"abc");
+		let err = io.seek(SeekFrom::Current(-1)).unwrap_err(); // before the start
+		assert_eq!(err.kind(), std::io::ErrorKind::Other);
+		assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<co::HRESULT>()),
+			Some(&co::HRESULT::E_FAIL));
+		assert_eq!(io.stream_position().unwrap(), 0);
+	}
+
+	#[test]
+	fn write_and_read_back() {
+		let mut io = cursor_io(b"abcdef");
+		io.seek(SeekFrom::Start(4)).unwrap();
+		io.write_all(b"XYZ").unwrap(); // overwrites and extends
+		io.flush().unwrap();
+		assert_eq!(io.stream().Write(b"!"), Ok(1));
+
+		io.seek(SeekFrom::Start(0)).unwrap();
+		let mut data = Vec::default();
+		io.read_to_end(&mut data).unwrap();
+		assert_eq!(data, b"abcdXYZ!");
+	}
+
+	#[test]
+	fn copy_to() {
+		let src = IStream::new_impl(Cursor::new(b"0123456789".to_vec()));
+		let dest = IStream::new_impl(Cursor::new(Vec::<u8>::default()));
+		src.Seek(2, co::STREAM_SEEK::SET).unwrap();
+		assert_eq!(src.CopyTo(&dest, 5), Ok((5, 5)));
+		assert_eq!(src.CopyTo(&dest, 100), Ok((3, 3))); // stops at the end
+
+		let mut io = StreamIo::new(dest);
+		io.seek(SeekFrom::Start(0)).unwrap();
+		let mut data = Vec::default();
+		io.read_to_end(&mut data).unwrap();
+		assert_eq!(data, b"23456789");
+	}
+}
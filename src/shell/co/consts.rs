@@ -146,6 +146,26 @@ const_ordinary! { FDAP: u32;
 	TOP 1
 }
 
+const_ordinary! { FDEOR: u32;
+	/// [`FDE_OVERWRITE_RESPONSE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-fde_overwrite_response)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	ACCEPT 1
+	REFUSE 2
+}
+
+const_ordinary! { FDESVR: u32;
+	/// [`FDE_SHAREVIOLATION_RESPONSE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-fde_shareviolation_response)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	ACCEPT 1
+	REFUSE 2
+}
+
 const_bitflag! { KF: u32;
 	/// [`KNOWN_FOLDER_FLAG`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ne-shlobj_core-known_folder_flag)
 	/// enumeration (`u32`).
@@ -208,11 +228,30 @@ const_ordinary! { NIM: u32;
 	SETVERSION 0x0000_0004
 }
 
+const_ordinary! { NIN: u32;
+	/// [`Shell_NotifyIcon`](crate::Shell_NotifyIcon) notification events, sent
+	/// in the low-order word of the `lParam` of the callback message (`u32`).
+	///
+	/// Mouse events are sent as ordinary [`WM`](crate::co::WM) values.
+	=>
+	=>
+	SELECT 0x0400
+	KEYSELECT 0x0401
+	BALLOONSHOW 0x0402
+	BALLOONHIDE 0x0403
+	BALLOONTIMEOUT 0x0404
+	BALLOONUSERCLICK 0x0405
+	POPUPOPEN 0x0406
+	POPUPCLOSE 0x0407
+}
+
 const_bitflag! { NIS: u32;
 	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `dwState` and `dwStateFlags`
 	/// (`u32`).
 	=>
 	=>
+	/// None of the actual values (zero).
+	NoValue 0
 	HIDDEN 0x0000_0001
 	SHAREDICON 0x0000_0002
 }
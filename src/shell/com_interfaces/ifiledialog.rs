@@ -5,7 +5,9 @@ use crate::kernel::decl::{GUID, WString};
 use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID, PSTR, PVOID};
 use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
 use crate::ole::privs::ok_to_hrresult;
-use crate::prelude::{shell_IModalWindow, shell_IShellItem};
+use crate::prelude::{
+	shell_IFileDialogEvents, shell_IModalWindow, shell_IShellItem,
+};
 use crate::shell::decl::{COMDLG_FILTERSPEC, IShellItem};
 use crate::vt::IModalWindowVT;
 
@@ -70,6 +72,22 @@ pub trait shell_IFileDialog: shell_IModalWindow {
 		}
 	}
 
+	/// [`IFileDialog::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-advise)
+	/// method.
+	///
+	/// Returns a cookie, to be passed to
+	/// [`IFileDialog::Unadvise`](crate::prelude::shell_IFileDialog::Unadvise).
+	///
+	/// The events object is usually created with
+	/// [`IFileDialogEvents::new_impl`](crate::IFileDialogEvents::new_impl).
+	fn Advise(&self, fde: &impl shell_IFileDialogEvents) -> HrResult<u32> {
+		let mut cookie = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogVT>();
+			ok_to_hrresult((vt.Advise)(self.ptr(), fde.ptr().0 as _, &mut cookie))
+		}.map(|_| cookie)
+	}
+
 	/// [`IFileDialog::ClearClientData`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-clearclientdata)
 	/// method.
 	fn ClearClientData(&self) -> HrResult<()> {
@@ -320,4 +338,13 @@ pub trait shell_IFileDialog: shell_IModalWindow {
 			)
 		}
 	}
+
+	/// [`IFileDialog::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-unadvise)
+	/// method.
+	fn Unadvise(&self, cookie: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogVT>();
+			ok_to_hrresult((vt.Unadvise)(self.ptr(), cookie))
+		}
+	}
 }
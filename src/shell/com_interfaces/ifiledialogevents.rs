@@ -0,0 +1,352 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::com_impl::{ComObj, hrresult_to_hres, IUnknownImplVT};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::{ole_IUnknown, shell_IFileDialog, shell_IShellItem};
+use crate::shell::decl::{IFileDialog, IShellItem};
+use crate::vt::IUnknownVT;
+
+/// [`IFileDialogEvents`](crate::IFileDialogEvents) virtual table.
+#[repr(C)]
+pub struct IFileDialogEventsVT {
+	pub IUnknownVT: IUnknownVT,
+	pub OnFileOk: fn(ComPtr, ComPtr) -> HRES,
+	pub OnFolderChanging: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub OnFolderChange: fn(ComPtr, ComPtr) -> HRES,
+	pub OnSelectionChange: fn(ComPtr, ComPtr) -> HRES,
+	pub OnShareViolation: fn(ComPtr, ComPtr, ComPtr, *mut u32) -> HRES,
+	pub OnTypeChange: fn(ComPtr, ComPtr) -> HRES,
+	pub OnOverwrite: fn(ComPtr, ComPtr, ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { IFileDialogEvents: "973510db-7d7f-452b-8975-74a85828d354";
+	/// [`IFileDialogEvents`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifiledialogevents)
+	/// COM interface over
+	/// [`IFileDialogEventsVT`](crate::vt::IFileDialogEventsVT).
+	///
+	/// To receive the events of a file dialog, create an object with
+	/// [`IFileDialogEvents::new_impl`](crate::IFileDialogEvents::new_impl) and
+	/// pass it to
+	/// [`IFileDialog::Advise`](crate::prelude::shell_IFileDialog::Advise).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IFileDialogEvents for IFileDialogEvents {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IFileDialogEvents`](crate::IFileDialogEvents).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IFileDialogEvents: ole_IUnknown {
+	/// [`IFileDialogEvents::OnFileOk`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfileok)
+	/// method.
+	fn OnFileOk(&self, fd: &impl shell_IFileDialog) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			okfalse_to_hrresult((vt.OnFileOk)(self.ptr(), fd.ptr()))
+		}
+	}
+
+	/// [`IFileDialogEvents::OnFolderChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchange)
+	/// method.
+	fn OnFolderChange(&self, fd: &impl shell_IFileDialog) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			ok_to_hrresult((vt.OnFolderChange)(self.ptr(), fd.ptr()))
+		}
+	}
+
+	/// [`IFileDialogEvents::OnFolderChanging`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchanging)
+	/// method.
+	fn OnFolderChanging(&self,
+		fd: &impl shell_IFileDialog,
+		si_folder: &impl shell_IShellItem,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			ok_to_hrresult(
+				(vt.OnFolderChanging)(self.ptr(), fd.ptr(), si_folder.ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogEvents::OnOverwrite`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onoverwrite)
+	/// method.
+	fn OnOverwrite(&self,
+		fd: &impl shell_IFileDialog,
+		si: &impl shell_IShellItem,
+	) -> HrResult<co::FDEOR>
+	{
+		let mut response = co::FDEOR::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			ok_to_hrresult(
+				(vt.OnOverwrite)(self.ptr(), fd.ptr(), si.ptr(), &mut response.0),
+			)
+		}.map(|_| response)
+	}
+
+	/// [`IFileDialogEvents::OnSelectionChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onselectionchange)
+	/// method.
+	fn OnSelectionChange(&self, fd: &impl shell_IFileDialog) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			ok_to_hrresult((vt.OnSelectionChange)(self.ptr(), fd.ptr()))
+		}
+	}
+
+	/// [`IFileDialogEvents::OnShareViolation`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onshareviolation)
+	/// method.
+	fn OnShareViolation(&self,
+		fd: &impl shell_IFileDialog,
+		si: &impl shell_IShellItem,
+	) -> HrResult<co::FDESVR>
+	{
+		let mut response = co::FDESVR::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			ok_to_hrresult(
+				(vt.OnShareViolation)(
+					self.ptr(), fd.ptr(), si.ptr(), &mut response.0),
+			)
+		}.map(|_| response)
+	}
+
+	/// [`IFileDialogEvents::OnTypeChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-ontypechange)
+	/// method.
+	fn OnTypeChange(&self, fd: &impl shell_IFileDialog) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogEventsVT>();
+			ok_to_hrresult((vt.OnTypeChange)(self.ptr(), fd.ptr()))
+		}
+	}
+}
+
+/// This trait is enabled with the `shell` feature, and allows a Rust object to
+/// implement an [`IFileDialogEvents`](crate::IFileDialogEvents) COM object,
+/// which is created with
+/// [`IFileDialogEvents::new_impl`](crate::IFileDialogEvents::new_impl).
+///
+/// All methods have default implementations: `OnShareViolation` and
+/// `OnOverwrite` return
+/// [`HRESULT::E_NOTIMPL`](crate::co::HRESULT::E_NOTIMPL), so the dialog
+/// performs its default behavior, and all others simply succeed.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{HrResult, HWND, IFileDialog, IFileDialogEvents};
+///
+/// struct MyEvents;
+///
+/// impl shell_IFileDialogEventsImpl for MyEvents {
+///     fn OnSelectionChange(&self, fd: &IFileDialog) -> HrResult<()> {
+///         println!("Selected: {}", fd.GetFileName()?);
+///         Ok(())
+///     }
+/// }
+///
+/// let file_dlg: IFileDialog; // initialized somewhere
+/// # let file_dlg = IFileDialog::from(unsafe { winsafe::ComPtr::null() });
+///
+/// let events = IFileDialogEvents::new_impl(MyEvents);
+/// let cookie = file_dlg.Advise(&events)?;
+/// file_dlg.Show(&HWND::NULL)?;
+/// file_dlg.Unadvise(cookie)?;
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+pub trait shell_IFileDialogEventsImpl: 'static {
+	/// [`IFileDialogEvents::OnFileOk`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfileok)
+	/// method.
+	///
+	/// Return `Ok(false)` to keep the dialog open.
+	fn OnFileOk(&self, fd: &IFileDialog) -> HrResult<bool> {
+		let _ = fd;
+		Ok(true)
+	}
+
+	/// [`IFileDialogEvents::OnFolderChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchange)
+	/// method.
+	fn OnFolderChange(&self, fd: &IFileDialog) -> HrResult<()> {
+		let _ = fd;
+		Ok(())
+	}
+
+	/// [`IFileDialogEvents::OnFolderChanging`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchanging)
+	/// method.
+	///
+	/// Return an error to prevent the folder change.
+	fn OnFolderChanging(&self,
+		fd: &IFileDialog, si_folder: &IShellItem) -> HrResult<()>
+	{
+		let _ = (fd, si_folder);
+		Ok(())
+	}
+
+	/// [`IFileDialogEvents::OnOverwrite`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onoverwrite)
+	/// method.
+	fn OnOverwrite(&self,
+		fd: &IFileDialog, si: &IShellItem) -> HrResult<co::FDEOR>
+	{
+		let _ = (fd, si);
+		Err(co::HRESULT::E_NOTIMPL)
+	}
+
+	/// [`IFileDialogEvents::OnSelectionChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onselectionchange)
+	/// method.
+	fn OnSelectionChange(&self, fd: &IFileDialog) -> HrResult<()> {
+		let _ = fd;
+		Ok(())
+	}
+
+	/// [`IFileDialogEvents::OnShareViolation`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onshareviolation)
+	/// method.
+	fn OnShareViolation(&self,
+		fd: &IFileDialog, si: &IShellItem) -> HrResult<co::FDESVR>
+	{
+		let _ = (fd, si);
+		Err(co::HRESULT::E_NOTIMPL)
+	}
+
+	/// [`IFileDialogEvents::OnTypeChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-ontypechange)
+	/// method.
+	fn OnTypeChange(&self, fd: &IFileDialog) -> HrResult<()> {
+		let _ = fd;
+		Ok(())
+	}
+}
+
+impl IFileDialogEvents {
+	/// Creates a new `IFileDialogEvents` COM object whose methods are answered
+	/// by the given Rust object, which will be dropped when the last reference
+	/// to the COM object is released.
+	///
+	/// The returned object can be passed to
+	/// [`IFileDialog::Advise`](crate::prelude::shell_IFileDialog::Advise).
+	#[must_use]
+	pub fn new_impl<T>(obj: T) -> IFileDialogEvents
+		where T: shell_IFileDialogEventsImpl,
+	{
+		IFileDialogEvents::from(
+			ComObj::alloc(
+				&FileDialogEventsImpl::<T>::VT,
+				FileDialogEventsImpl::<T>::IIDS,
+				obj,
+			),
+		)
+	}
+}
+
+/// [`IFileDialogEvents`](crate::IFileDialogEvents) virtual table as called by
+/// the system.
+#[repr(C)]
+struct IFileDialogEventsImplVT {
+	IUnknownImplVT: IUnknownImplVT,
+	OnFileOk: extern "system" fn(ComPtr, ComPtr) -> HRES,
+	OnFolderChanging: extern "system" fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	OnFolderChange: extern "system" fn(ComPtr, ComPtr) -> HRES,
+	OnSelectionChange: extern "system" fn(ComPtr, ComPtr) -> HRES,
+	OnShareViolation: extern "system" fn(ComPtr, ComPtr, ComPtr, *mut u32) -> HRES,
+	OnTypeChange: extern "system" fn(ComPtr, ComPtr) -> HRES,
+	OnOverwrite: extern "system" fn(ComPtr, ComPtr, ComPtr, *mut u32) -> HRES,
+}
+
+/// Dispatches the [`IFileDialogEvents`](crate::IFileDialogEvents) virtual
+/// table calls to the Rust object.
+struct FileDialogEventsImpl<T>(PhantomData<T>);
+
+impl<T> FileDialogEventsImpl<T>
+	where T: shell_IFileDialogEventsImpl,
+{
+	const IIDS: &'static [co::IID] = &[IFileDialogEvents::IID];
+
+	const VT: IFileDialogEventsImplVT = IFileDialogEventsImplVT {
+		IUnknownImplVT: ComObj::<IFileDialogEventsImplVT, T>::IUNKNOWN_VT,
+		OnFileOk: Self::OnFileOk,
+		OnFolderChanging: Self::OnFolderChanging,
+		OnFolderChange: Self::OnFolderChange,
+		OnSelectionChange: Self::OnSelectionChange,
+		OnShareViolation: Self::OnShareViolation,
+		OnTypeChange: Self::OnTypeChange,
+		OnOverwrite: Self::OnOverwrite,
+	};
+
+	fn obj<'a>(p: ComPtr) -> &'a T {
+		unsafe { ComObj::<IFileDialogEventsImplVT, T>::obj(p) }
+	}
+
+	extern "system" fn OnFileOk(p: ComPtr, fd: ComPtr) -> HRES {
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		match Self::obj(p).OnFileOk(&fd) {
+			Ok(true) => co::HRESULT::S_OK.0,
+			Ok(false) => co::HRESULT::S_FALSE.0,
+			Err(hr) => hr.0,
+		}
+	}
+
+	extern "system" fn OnFolderChanging(p: ComPtr,
+		fd: ComPtr, si_folder: ComPtr) -> HRES
+	{
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		let si_folder = ManuallyDrop::new(IShellItem::from(si_folder));
+		hrresult_to_hres(Self::obj(p).OnFolderChanging(&fd, &si_folder))
+	}
+
+	extern "system" fn OnFolderChange(p: ComPtr, fd: ComPtr) -> HRES {
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		hrresult_to_hres(Self::obj(p).OnFolderChange(&fd))
+	}
+
+	extern "system" fn OnSelectionChange(p: ComPtr, fd: ComPtr) -> HRES {
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		hrresult_to_hres(Self::obj(p).OnSelectionChange(&fd))
+	}
+
+	extern "system" fn OnShareViolation(p: ComPtr,
+		fd: ComPtr, si: ComPtr, response: *mut u32) -> HRES
+	{
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		let si = ManuallyDrop::new(IShellItem::from(si));
+		hrresult_to_hres(
+			Self::obj(p).OnShareViolation(&fd, &si)
+				.map(|res| unsafe { *response = res.0; }),
+		)
+	}
+
+	extern "system" fn OnTypeChange(p: ComPtr, fd: ComPtr) -> HRES {
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		hrresult_to_hres(Self::obj(p).OnTypeChange(&fd))
+	}
+
+	extern "system" fn OnOverwrite(p: ComPtr,
+		fd: ComPtr, si: ComPtr, response: *mut u32) -> HRES
+	{
+		let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned
+		let si = ManuallyDrop::new(IShellItem::from(si));
+		hrresult_to_hres(
+			Self::obj(p).OnOverwrite(&fd, &si)
+				.map(|res| unsafe { *response = res.0; }),
+		)
+	}
+}
@@ -10,6 +10,7 @@ mod com_interfaces;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub mod decl {
 	pub use super::com_interfaces::decl::*;
@@ -1 +1,2 @@
 pub(crate) const INFOTIPSIZE: usize = 1024;
+pub(crate) const NOTIFYICON_VERSION_4: u32 = 4;
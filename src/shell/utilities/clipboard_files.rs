@@ -0,0 +1,85 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::{SysResult, WString};
+use crate::prelude::{Handle, shell_Hdrop};
+use crate::shell::decl::HDROP;
+use crate::user::decl::{Clipboard, GetClipboardData, POINT};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "shell")))]
+impl<'a> Clipboard<'a> {
+	/// Returns the list of file paths
+	/// ([`CF::HDROP`](crate::co::CF::HDROP)) currently in the clipboard, or
+	/// `None` if no files are available.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{Clipboard, HWND};
+	///
+	/// let clip = Clipboard::open(&HWND::NULL)?;
+	/// if let Some(files) = clip.files()? {
+	///     for file in files.iter() {
+	///         println!("{}", file);
+	///     }
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn files(&self) -> SysResult<Option<Vec<String>>> {
+		if !self.has_format(co::CF::HDROP) {
+			return Ok(None);
+		}
+
+		// The handle is owned by the clipboard, so DragFinish must not be called.
+		let hdrop = unsafe { HDROP::from_ptr(GetClipboardData(co::CF::HDROP)? as _) };
+		let count = unsafe { hdrop.DragQueryFile(None, None)? };
+
+		let mut files = Vec::with_capacity(count as _);
+		for i in 0..count {
+			let len = unsafe { hdrop.DragQueryFile(Some(i), None)? };
+			let mut buf = WString::new_alloc_buf(len as usize + 1); // room for terminating null
+			unsafe { hdrop.DragQueryFile(Some(i), Some(&mut buf))?; }
+			files.push(buf.to_string());
+		}
+		Ok(Some(files))
+	}
+
+	/// Writes a list of file paths ([`CF::HDROP`](crate::co::CF::HDROP)),
+	/// which can be pasted in Windows Explorer.
+	pub fn set_files(&self, files: &[impl AsRef<str>]) -> SysResult<()> {
+		let header = DROPFILES {
+			pFiles: std::mem::size_of::<DROPFILES>() as _,
+			pt: POINT::default(),
+			fNC: 0,
+			fWide: 1,
+		};
+
+		let mut data = Vec::<u8>::default();
+		data.extend_from_slice(unsafe {
+			std::slice::from_raw_parts(
+				&header as *const _ as *const u8,
+				std::mem::size_of::<DROPFILES>(),
+			)
+		});
+		for file in files.iter() {
+			WString::from_str(file.as_ref()).as_slice() // each path is null-terminated
+				.iter()
+				.take_while(|ch| **ch != 0)
+				.chain(std::iter::once(&0))
+				.for_each(|ch| data.extend_from_slice(&ch.to_ne_bytes()));
+		}
+		data.extend_from_slice(&0u16.to_ne_bytes()); // list is double null-terminated
+
+		self.set_data(co::CF::HDROP, &data)
+	}
+}
+
+#[repr(C)]
+struct DROPFILES {
+	pFiles: u32,
+	pt: POINT,
+	fNC: i32,
+	fWide: i32,
+}
@@ -0,0 +1 @@
+mod clipboard_files;
@@ -14,6 +14,7 @@ extern_sys! { "user32";
 }
 
 extern_sys! { "user32";
+	AddClipboardFormatListener(HANDLE) -> BOOL
 	AdjustWindowRectEx(PVOID, u32, BOOL, u32) -> BOOL
 	AllowSetForegroundWindow(u32) -> BOOL
 	AnyPopup() -> BOOL
@@ -36,6 +37,7 @@ extern_sys! { "user32";
 	CloseDesktop(HANDLE) -> BOOL
 	CloseWindow(HANDLE) -> BOOL
 	CopyIcon(HANDLE) -> HANDLE
+	CountClipboardFormats() -> i32
 	CreateAcceleratorTableW(PVOID, i32) -> HANDLE
 	CreateDesktopExW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID, u32, PVOID) -> HANDLE
 	CreateDesktopW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID) -> HANDLE
@@ -64,6 +66,7 @@ extern_sys! { "user32";
 	EndMenu() -> BOOL
 	EndPaint(HANDLE, PCVOID) -> BOOL
 	EnumChildWindows(HANDLE, PFUNC, isize) -> BOOL
+	EnumClipboardFormats(u32) -> u32
 	EnumDisplayDevicesW(PCSTR, u32, PVOID, u32) -> BOOL
 	EnumDisplayMonitors(HANDLE, PCVOID, PFUNC, isize) -> BOOL
 	EnumDisplaySettingsExW(PCSTR, u32, PVOID, u32) -> BOOL
@@ -82,6 +85,7 @@ extern_sys! { "user32";
 	GetClassNameW(HANDLE, PSTR, i32) -> i32
 	GetClientRect(HANDLE, PVOID) -> BOOL
 	GetClipboardData(u32) -> HANDLE
+	GetClipboardFormatNameW(u32, PSTR, i32) -> i32
 	GetClipboardSequenceNumber() -> u32
 	GetClipCursor(PVOID) -> BOOL
 	GetCursorPos(PVOID) -> BOOL
@@ -146,6 +150,7 @@ extern_sys! { "user32";
 	InvalidateRgn(HANDLE, HANDLE, BOOL) -> BOOL
 	InvertRect(HANDLE, PCVOID) -> BOOL
 	IsChild(HANDLE, HANDLE) -> BOOL
+	IsClipboardFormatAvailable(u32) -> BOOL
 	IsDialogMessageW(HANDLE, PVOID) -> BOOL
 	IsGUIThread(BOOL) -> BOOL
 	IsIconic(HANDLE) -> BOOL
@@ -187,9 +192,11 @@ extern_sys! { "user32";
 	RealGetWindowClassW(HANDLE, PSTR, i32) -> u32
 	RedrawWindow(HANDLE, PCVOID, HANDLE, u32) -> BOOL
 	RegisterClassExW(PCVOID) -> u16
+	RegisterClipboardFormatW(PCSTR) -> u32
 	RegisterWindowMessageW(PCSTR) -> u32
 	ReleaseCapture() -> BOOL
 	ReleaseDC(HANDLE, HANDLE) -> i32
+	RemoveClipboardFormatListener(HANDLE) -> BOOL
 	RemoveMenu(HANDLE, u32, u32) -> BOOL
 	ScreenToClient(HANDLE, PVOID) -> BOOL
 	ScrollWindowEx(HANDLE, i32, i32, PCVOID, PCVOID, HANDLE, PVOID, u32) -> i32
@@ -1,7 +1,9 @@
 #![allow(non_snake_case)]
 
 use crate::{co, user};
-use crate::kernel::decl::{GetLastError, HINSTANCE, SysResult, WString};
+use crate::kernel::decl::{
+	GetLastError, HINSTANCE, SetLastError, SysResult, WString,
+};
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult};
 use crate::prelude::{Handle, MsgSend};
@@ -148,6 +150,20 @@ pub fn ClipCursor(rc: Option<&RECT>) -> SysResult<()> {
 	)
 }
 
+/// [`CountClipboardFormats`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-countclipboardformats)
+/// function.
+#[must_use]
+pub fn CountClipboardFormats() -> SysResult<u32> {
+	SetLastError(co::ERROR::SUCCESS);
+	match unsafe { user::ffi::CountClipboardFormats() } {
+		0 => match GetLastError() {
+			co::ERROR::SUCCESS => Ok(0), // actual zero count
+			err => Err(err),
+		},
+		count => Ok(count as _),
+	}
+}
+
 /// [`DispatchMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-dispatchmessagew)
 /// function.
 ///
@@ -171,6 +187,43 @@ pub fn EndMenu() -> SysResult<()> {
 	bool_to_sysresult(unsafe { user::ffi::EndMenu() })
 }
 
+/// [`EnumClipboardFormats`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumclipboardformats)
+/// function.
+///
+/// Calls the function repeatedly, returning all formats currently available on
+/// the clipboard. The clipboard must be open.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{EnumClipboardFormats, HWND};
+///
+/// let _hclip = HWND::NULL.OpenClipboard()?;
+///
+/// for format in EnumClipboardFormats()? {
+///     println!("{:?}", format);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn EnumClipboardFormats() -> SysResult<Vec<co::CF>> {
+	let mut formats = Vec::<co::CF>::default();
+	let mut format = 0;
+	SetLastError(co::ERROR::SUCCESS);
+
+	loop {
+		format = unsafe { user::ffi::EnumClipboardFormats(format) };
+		if format == 0 {
+			return match GetLastError() {
+				co::ERROR::SUCCESS => Ok(formats), // no more formats
+				err => Err(err),
+			};
+		}
+		formats.push(co::CF(format));
+	}
+}
+
 /// [`EnumDisplayDevices`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaydevicesw)
 /// function.
 ///
@@ -399,6 +452,22 @@ pub unsafe fn GetClipboardData(format: co::CF) -> SysResult<*mut u8> {
 		.map(|hmem| hmem as *mut _ as _)
 }
 
+/// [`GetClipboardFormatName`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardformatnamew)
+/// function.
+///
+/// Fails for the predefined formats, which have no name.
+#[must_use]
+pub fn GetClipboardFormatName(format: co::CF) -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(256); // arbitrary
+	match unsafe {
+		user::ffi::GetClipboardFormatNameW(
+			format.0, buf.as_mut_ptr(), buf.buf_len() as _)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// [`GetClipboardSequenceNumber`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardsequencenumber)
 /// function.
 #[must_use]
@@ -579,6 +648,13 @@ pub fn IntersectRect(
 	)
 }
 
+/// [`IsClipboardFormatAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable)
+/// function.
+#[must_use]
+pub fn IsClipboardFormatAvailable(format: co::CF) -> bool {
+	unsafe { user::ffi::IsClipboardFormatAvailable(format.0) != 0 }
+}
+
 /// [`IsGUIThread`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isguithread)
 /// function.
 pub fn IsGUIThread(convert_to_gui_thread: bool) -> SysResult<bool> {
@@ -686,6 +762,20 @@ pub unsafe fn RegisterClassEx(wcx: &WNDCLASSEX) -> SysResult<ATOM> {
 	}
 }
 
+/// [`RegisterClipboardFormat`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclipboardformatw)
+/// function.
+///
+/// If the format is already registered, returns its existing identifier.
+#[must_use]
+pub fn RegisterClipboardFormat(format: &str) -> SysResult<co::CF> {
+	match unsafe {
+		user::ffi::RegisterClipboardFormatW(WString::from_str(format).as_ptr())
+	} {
+		0 => Err(GetLastError()),
+		id => Ok(co::CF(id)),
+	}
+}
+
 /// [`RegisterWindowMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
 /// function.
 #[must_use]
@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use crate::prelude::{Handle, user_Hwnd};
 use crate::user;
 use crate::user::decl::{
-	HACCEL, HCURSOR, HDC, HDESK, HDWP, HICON, HWND, PAINTSTRUCT,
+	HACCEL, HCURSOR, HDC, HDESK, HDWP, HICON, HMENU, HWND, PAINTSTRUCT,
 };
 
 /// RAII implementation for clipboard which automatically calls
@@ -71,6 +71,17 @@ handle_guard! { DestroyIconGuard: HICON;
 	/// when the object goes out of scope.
 }
 
+handle_guard! { DestroyMenuGuard: HMENU;
+	user::ffi::DestroyMenu;
+	/// RAII implementation for [`HMENU`](crate::HMENU) which automatically
+	/// calls
+	/// [`DestroyMenu`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroymenu)
+	/// when the object goes out of scope.
+	///
+	/// A menu assigned to a window is destroyed along with it, so its guard
+	/// must be [`leak`](crate::guard::DestroyMenuGuard::leak)ed.
+}
+
 handle_guard! { EndDeferWindowPosGuard: HDWP;
 	user::ffi::EndDeferWindowPos;
 	/// RAII implementation for [`HDWP`](crate::HDWP) which automatically calls
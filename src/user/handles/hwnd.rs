@@ -55,6 +55,20 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`AddClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-addclipboardformatlistener)
+	/// method.
+	///
+	/// After this call, the window will receive
+	/// [`wm::ClipboardUpdate`](crate::msg::wm::ClipboardUpdate) messages
+	/// whenever the clipboard content changes. Call
+	/// [`HWND::RemoveClipboardFormatListener`](crate::prelude::user_Hwnd::RemoveClipboardFormatListener)
+	/// to stop receiving them.
+	fn AddClipboardFormatListener(&self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { user::ffi::AddClipboardFormatListener(self.as_ptr()) },
+		)
+	}
+
 	/// [`ArrangeIconicWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// method.
 	fn ArrangeIconicWindows(&self) -> SysResult<u32> {
@@ -1243,6 +1257,14 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`RemoveClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+	/// method.
+	fn RemoveClipboardFormatListener(&self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { user::ffi::RemoveClipboardFormatListener(self.as_ptr()) },
+		)
+	}
+
 	/// [`ScreenToClient`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-screentoclient)
 	/// method.
 	///
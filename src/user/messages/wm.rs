@@ -162,6 +162,10 @@ pub_struct_msg_empty_handleable! { ChildActivate: co::WM::CHILDACTIVATE;
 	/// [`WM_CHILDACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 }
 
+pub_struct_msg_empty_handleable! { ClipboardUpdate: co::WM::CLIPBOARDUPDATE;
+	/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+}
+
 pub_struct_msg_empty_handleable! { Close: co::WM::CLOSE;
 	/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 }
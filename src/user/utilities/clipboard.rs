@@ -0,0 +1,297 @@
+use crate::co;
+use crate::kernel::decl::{HGLOBAL, SysResult, WString};
+use crate::prelude::{Handle, kernel_Hglobal, user_Hwnd};
+use crate::user::decl::{
+	EmptyClipboard, EnumClipboardFormats, GetClipboardData, HWND,
+	IsClipboardFormatAvailable, RegisterClipboardFormat, SetClipboardData,
+};
+use crate::user::guard::CloseClipboardGuard;
+
+/// Provides typed access to the
+/// [clipboard](https://learn.microsoft.com/en-us/windows/win32/dataxchg/clipboard),
+/// which is kept open while the object lives, and closed automatically when it
+/// goes out of scope.
+///
+/// Before writing data, call [`Clipboard::empty`](crate::Clipboard::empty) to
+/// take ownership of the clipboard. Several formats can be written at once.
+///
+/// # Examples
+///
+/// Writing and reading text:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{Clipboard, HWND};
+///
+/// let hwnd: HWND; // initialized somewhere
+/// # let hwnd = HWND::NULL;
+///
+/// {
+///     let clip = Clipboard::open(&hwnd)?;
+///     clip.empty()?;
+///     clip.set_text("Hello, world")?;
+///     clip.set_html("<b>Hello</b>, world", None)?;
+/// } // clipboard closed here
+///
+/// let clip = Clipboard::open(&hwnd)?;
+/// if let Some(text) = clip.text()? {
+///     println!("{}", text);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct Clipboard<'a> {
+	_guard: CloseClipboardGuard<'a>,
+}
+
+impl<'a> Clipboard<'a> {
+	/// Opens the clipboard by calling
+	/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard).
+	///
+	/// The clipboard remains open, and unavailable to other applications,
+	/// until the object goes out of scope, so keep it short-lived.
+	#[must_use]
+	pub fn open(hwnd: &'a HWND) -> SysResult<Self> {
+		Ok(Self { _guard: hwnd.OpenClipboard()? })
+	}
+
+	/// Returns the
+	/// [`HTML Format`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format)
+	/// identifier, registering it if needed.
+	#[must_use]
+	pub fn html_format() -> SysResult<co::CF> {
+		RegisterClipboardFormat("HTML Format")
+	}
+
+	/// Registers a custom clipboard format by calling
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat), returning
+	/// its identifier. If the format is already registered, returns the
+	/// existing identifier.
+	#[must_use]
+	pub fn register_format(name: &str) -> SysResult<co::CF> {
+		RegisterClipboardFormat(name)
+	}
+
+	/// Returns a copy of the raw data in the given format, or `None` if the
+	/// format is not available.
+	#[must_use]
+	pub fn data(&self, format: co::CF) -> SysResult<Option<Vec<u8>>> {
+		if !self.has_format(format) {
+			return Ok(None);
+		}
+
+		let hglobal = unsafe { HGLOBAL::from_ptr(GetClipboardData(format)? as _) };
+		let (mem, _unlock) = hglobal.GlobalLock()?; // memory is owned by the clipboard
+		Ok(Some(mem.to_vec()))
+	}
+
+	/// Returns the packed device-independent bitmap
+	/// ([`CF::DIB`](crate::co::CF::DIB)) currently in the clipboard, or `None`
+	/// if no bitmap is available. The data starts with a
+	/// [`BITMAPINFOHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmapinfoheader).
+	#[must_use]
+	pub fn dib(&self) -> SysResult<Option<Vec<u8>>> {
+		self.data(co::CF::DIB)
+	}
+
+	/// Empties the clipboard by calling
+	/// [`EmptyClipboard`](crate::EmptyClipboard), taking its ownership. This
+	/// must be done before writing any data.
+	pub fn empty(&self) -> SysResult<()> {
+		EmptyClipboard()
+	}
+
+	/// Returns all the formats currently available in the clipboard, by
+	/// calling [`EnumClipboardFormats`](crate::EnumClipboardFormats).
+	#[must_use]
+	pub fn formats(&self) -> SysResult<Vec<co::CF>> {
+		EnumClipboardFormats()
+	}
+
+	/// Tells whether the given format is available, by calling
+	/// [`IsClipboardFormatAvailable`](crate::IsClipboardFormatAvailable).
+	#[must_use]
+	pub fn has_format(&self, format: co::CF) -> bool {
+		IsClipboardFormatAvailable(format)
+	}
+
+	/// Returns the HTML fragment currently in the clipboard, in
+	/// [`HTML Format`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format),
+	/// or `None` if no HTML is available.
+	///
+	/// Only the fragment delimited by the `StartFragment` and `EndFragment`
+	/// offsets is returned.
+	#[must_use]
+	pub fn html(&self) -> SysResult<Option<String>> {
+		Ok(self.data(Self::html_format()?)?
+			.map(|data| Self::parse_html(&data)))
+	}
+
+	/// Writes raw data in the given format, usually a custom one created with
+	/// [`Clipboard::register_format`](crate::Clipboard::register_format).
+	///
+	/// The data is copied into a newly allocated
+	/// [`HGLOBAL`](crate::HGLOBAL), which is then owned by the system.
+	pub fn set_data(&self, format: co::CF, data: &[u8]) -> SysResult<()> {
+		let mut hglobal = HGLOBAL::GlobalAlloc(co::GMEM::MOVEABLE, data.len().max(1))?;
+		{
+			let (mem, _unlock) = hglobal.GlobalLock()?;
+			mem[..data.len()].copy_from_slice(data);
+		}
+		unsafe { SetClipboardData(format, hglobal.as_ptr() as _)?; }
+		let _ = hglobal.leak(); // now owned by the system
+		Ok(())
+	}
+
+	/// Writes a packed device-independent bitmap
+	/// ([`CF::DIB`](crate::co::CF::DIB)), which must start with a
+	/// [`BITMAPINFOHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmapinfoheader).
+	pub fn set_dib(&self, packed_dib: &[u8]) -> SysResult<()> {
+		self.set_data(co::CF::DIB, packed_dib)
+	}
+
+	/// Writes an HTML fragment in
+	/// [`HTML Format`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format),
+	/// generating the header with the byte offsets.
+	pub fn set_html(&self, fragment: &str, source_url: Option<&str>) -> SysResult<()> {
+		let data = Self::build_html(fragment, source_url);
+		self.set_data(Self::html_format()?, &data)
+	}
+
+	/// Writes Unicode text ([`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)).
+	pub fn set_text(&self, text: &str) -> SysResult<()> {
+		let data = WString::from_str(text).as_slice()
+			.iter()
+			.flat_map(|ch| ch.to_ne_bytes())
+			.collect::<Vec<_>>();
+		self.set_data(co::CF::UNICODETEXT, &data)
+	}
+
+	/// Returns the Unicode text
+	/// ([`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)) currently in the
+	/// clipboard, or `None` if no text is available.
+	#[must_use]
+	pub fn text(&self) -> SysResult<Option<String>> {
+		Ok(self.data(co::CF::UNICODETEXT)?
+			.map(|data| {
+				let wchars = data.chunks_exact(2)
+					.map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+					.take_while(|ch| *ch != 0)
+					.collect::<Vec<_>>();
+				WString::from_wchars_slice(&wchars).to_string()
+			}))
+	}
+
+	#[must_use]
+	fn build_html(fragment: &str, source_url: Option<&str>) -> Vec<u8> {
+		const PLACEHOLDER: &str = "0000000000"; // offsets have 10 digits
+
+		let mut header = format!(
+			"Version:0.9\r\n\
+			StartHTML:{0}\r\n\
+			EndHTML:{0}\r\n\
+			StartFragment:{0}\r\n\
+			EndFragment:{0}\r\n",
+			PLACEHOLDER,
+		);
+		if let Some(source_url) = source_url {
+			header.push_str(&format!("SourceURL:{}\r\n", source_url));
+		}
+
+		let start_html = header.len();
+		let prefix = "<html>\r\n<body>\r\n<!--StartFragment-->";
+		let start_fragment = start_html + prefix.len();
+		let end_fragment = start_fragment + fragment.len();
+		let suffix = "<!--EndFragment-->\r\n</body>\r\n</html>";
+		let end_html = end_fragment + suffix.len();
+
+		let header = header
+			.replacen(PLACEHOLDER, &format!("{:010}", start_html), 1)
+			.replacen(PLACEHOLDER, &format!("{:010}", end_html), 1)
+			.replacen(PLACEHOLDER, &format!("{:010}", start_fragment), 1)
+			.replacen(PLACEHOLDER, &format!("{:010}", end_fragment), 1);
+
+		let mut data = Vec::with_capacity(end_html + 1);
+		data.extend_from_slice(header.as_bytes());
+		data.extend_from_slice(prefix.as_bytes());
+		data.extend_from_slice(fragment.as_bytes());
+		data.extend_from_slice(suffix.as_bytes());
+		data.push(0); // terminating null
+		data
+	}
+
+	#[must_use]
+	fn parse_html(data: &[u8]) -> String {
+		let data = match data.iter().position(|b| *b == 0) {
+			Some(nul) => &data[..nul],
+			None => data,
+		};
+
+		let header_val = |name: &str| -> Option<usize> {
+			let text = String::from_utf8_lossy(data);
+			let line = text.lines()
+				.take_while(|l| !l.starts_with('<')) // header ends where the HTML starts
+				.find(|l| l.starts_with(name))?;
+			line[name.len()..].trim().parse::<usize>().ok()
+		};
+
+		let fragment = match (header_val("StartFragment:"), header_val("EndFragment:")) {
+			(Some(start), Some(end)) if start <= end && end <= data.len() => &data[start..end],
+			_ => data, // malformed header, return everything
+		};
+		String::from_utf8_lossy(fragment).into_owned()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn header_val(data: &[u8], name: &str) -> usize {
+		let text = String::from_utf8_lossy(data);
+		let line = text.lines().find(|l| l.starts_with(name)).unwrap();
+		line[name.len()..].parse().unwrap()
+	}
+
+	#[test]
+	fn html_offsets() {
+		for source_url in [None, Some("https://example.com/ã?q=1")] {
+			for fragment in ["", "<b>bold</b>", "<p>Ação – 日本 😀</p>"] {
+				let data = Clipboard::build_html(fragment, source_url);
+				assert_eq!(data.last(), Some(&0));
+
+				let start_html = header_val(&data, "StartHTML:");
+				let end_html = header_val(&data, "EndHTML:");
+				let start_fragment = header_val(&data, "StartFragment:");
+				let end_fragment = header_val(&data, "EndFragment:");
+
+				assert!(data[start_html..].starts_with(b"<html>"));
+				assert_eq!(end_html, data.len() - 1); // before the terminating null
+				assert!(data[..end_html].ends_with(b"</html>"));
+				assert!(data[..start_fragment].ends_with(b"<!--StartFragment-->"));
+				assert_eq!(&data[start_fragment..end_fragment], fragment.as_bytes());
+				assert!(data[end_fragment..].starts_with(b"<!--EndFragment-->"));
+				assert_eq!(String::from_utf8_lossy(&data).contains("SourceURL:"), source_url.is_some());
+			}
+		}
+	}
+
+	#[test]
+	fn html_round_trip() {
+		for source_url in [None, Some("file:///C:/Temp/foo.html")] {
+			for fragment in ["", "plain", "<p>Ação – 日本 😀</p>", "<!--StartFragment-->\r\nStartFragment:1"] {
+				let data = Clipboard::build_html(fragment, source_url);
+				assert_eq!(Clipboard::parse_html(&data), fragment);
+			}
+		}
+	}
+
+	#[test]
+	fn html_malformed() {
+		assert_eq!(Clipboard::parse_html(b"<b>no header</b>"), "<b>no header</b>");
+		assert_eq!(Clipboard::parse_html(b"StartFragment:5\r\nEndFragment:999\r\n"),
+			"StartFragment:5\r\nEndFragment:999\r\n"); // out of bounds
+		assert_eq!(Clipboard::parse_html(b"StartFragment:9\r\nEndFragment:2\r\n"),
+			"StartFragment:9\r\nEndFragment:2\r\n"); // reversed
+		assert_eq!(Clipboard::parse_html(b"StartFragment:0\r\nEndFragment:5\r\n\0garbage"), "Start");
+	}
+}
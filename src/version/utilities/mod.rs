@@ -1,3 +1,5 @@
 mod resource_info;
 
-pub use resource_info::{ResourceInfo, ResourceInfoBlock};
+pub use resource_info::{
+	ResourceInfo, ResourceInfoBlock, ResourceInfoStringTable,
+};
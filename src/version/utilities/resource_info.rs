@@ -1,9 +1,15 @@
 use crate::co;
-use crate::kernel::decl::{LANGID, SysResult, WString};
-use crate::version::decl::{GetFileVersionInfo, VarQueryValue, VS_FIXEDFILEINFO};
+use crate::kernel::decl::{
+	HIWORD, HUPDATERSRC, IdStr, LANGID, LOWORD, MAKEDWORD, RtStr, SysResult,
+};
+use crate::prelude::kernel_Hupdatersrc;
+use crate::version::decl::{GetFileVersionInfo, VS_FIXEDFILEINFO};
 
-/// Retrieves data from an embedded resource, which can be read from an
-/// EXE or a DLL file.
+/// Retrieves, edits and writes the version data of an embedded resource, which
+/// can be read from an EXE or a DLL file.
+///
+/// The `VS_VERSIONINFO` binary blob is fully parsed into the public fields,
+/// which can be modified at will and serialized back.
 ///
 /// # Examples
 ///
@@ -45,56 +51,262 @@ use crate::version::decl::{GetFileVersionInfo, VarQueryValue, VS_FIXEDFILEINFO};
 /// }
 /// # Ok::<_, winsafe::co::ERROR>(())
 /// ```
+///
+/// Stamping a new version into an executable:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{LANGID, ResourceInfo};
+///
+/// let exe_name = "C:\\Temp\\foo.exe";
+/// let mut res_info = ResourceInfo::read_from(exe_name)?;
+///
+/// if let Some(ver_info) = res_info.fixed_info.as_mut() {
+///     ver_info.set_dwFileVersion([1, 2, 0, 0]);
+/// }
+/// for table in res_info.string_tables.iter_mut() {
+///     table.set_value("FileVersion", "1.2.0.0");
+/// }
+///
+/// res_info.write_to(exe_name, LANGID::USER_DEFAULT)?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
 pub struct ResourceInfo {
-	res_buf: Vec<u8>,
+	/// The fixed file information, stored in the root of the resource.
+	pub fixed_info: Option<VS_FIXEDFILEINFO>,
+	/// The string tables of `StringFileInfo`, one for each language/code page
+	/// pair.
+	pub string_tables: Vec<ResourceInfoStringTable>,
+	/// The language/code page pairs of `VarFileInfo\Translation`.
+	pub translations: Vec<(LANGID, co::CP)>,
+}
+
+impl Default for ResourceInfo {
+	fn default() -> Self {
+		Self {
+			fixed_info: Some(VS_FIXEDFILEINFO::default()),
+			string_tables: Vec::default(),
+			translations: Vec::default(),
+		}
+	}
 }
 
 impl ResourceInfo {
-	/// Reads and stores the resource data from an executable file or a DLL.
+	/// Reads and parses the resource data from an executable file or a DLL,
+	/// with [`GetFileVersionInfo`](crate::GetFileVersionInfo).
 	#[must_use]
 	pub fn read_from(exe_file: &str) -> SysResult<ResourceInfo> {
-		Ok(Self { res_buf: GetFileVersionInfo(exe_file)? })
+		Self::parse(&GetFileVersionInfo(exe_file)?)
 	}
 
-	/// Returns an iterator over the language blocks, if any, which are composed
-	/// of a language ID and a code page.
+	/// Parses a raw `VS_VERSIONINFO` binary blob.
+	///
+	/// If the data is malformed, returns
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	#[must_use]
+	pub fn parse(data: &[u8]) -> SysResult<ResourceInfo> {
+		let root = VerNode::parse(data)?;
+		if root.key != "VS_VERSION_INFO" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let fixed_info = if root.value.is_empty() {
+			None
+		} else if root.value.len() < std::mem::size_of::<VS_FIXEDFILEINFO>() {
+			return Err(co::ERROR::INVALID_DATA);
+		} else if root.value[..4] != [0xbd, 0x04, 0xef, 0xfe] { // dwSignature
+			return Err(co::ERROR::INVALID_DATA);
+		} else {
+			Some(unsafe {
+				std::ptr::read_unaligned(root.value.as_ptr() as *const VS_FIXEDFILEINFO)
+			})
+		};
+
+		let mut string_tables = Vec::<ResourceInfoStringTable>::default();
+		let mut translations = Vec::<(LANGID, co::CP)>::default();
+
+		for child in root.children.iter() {
+			match child.key.as_str() {
+				"StringFileInfo" => for table in child.children.iter() {
+					let lang_cp = u32::from_str_radix(&table.key, 16)
+						.map_err(|_| co::ERROR::INVALID_DATA)?;
+					string_tables.push(ResourceInfoStringTable {
+						lang_id: LANGID(HIWORD(lang_cp)),
+						code_page: co::CP(LOWORD(lang_cp)),
+						strings: table.children.iter()
+							.map(|s| (s.key.clone(), utf16_to_str(&s.value)))
+							.collect(),
+					});
+				},
+				"VarFileInfo" => for var in child.children.iter() {
+					if var.key == "Translation" {
+						translations.extend(
+							var.value.chunks_exact(4)
+								.map(|pair| (
+									LANGID(u16::from_le_bytes([pair[0], pair[1]])),
+									co::CP(u16::from_le_bytes([pair[2], pair[3]])),
+								)),
+						);
+					}
+				},
+				_ => {}, // unknown blocks are ignored
+			}
+		}
+
+		Ok(Self { fixed_info, string_tables, translations })
+	}
+
+	/// Serializes the data into a raw `VS_VERSIONINFO` binary blob, which can
+	/// be written with
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	#[must_use]
+	pub fn serialize(&self) -> Vec<u8> {
+		let mut root = VerNode {
+			key: "VS_VERSION_INFO".to_owned(),
+			value: self.fixed_info.as_ref().map_or(Vec::default(), |vsf| unsafe {
+				std::slice::from_raw_parts(
+					vsf as *const _ as *const u8,
+					std::mem::size_of::<VS_FIXEDFILEINFO>(),
+				).to_vec()
+			}),
+			is_text: false,
+			children: Vec::default(),
+		};
+
+		if !self.string_tables.is_empty() {
+			root.children.push(VerNode {
+				key: "StringFileInfo".to_owned(),
+				value: Vec::default(),
+				is_text: true,
+				children: self.string_tables.iter()
+					.map(|table| VerNode {
+						key: format!("{:04x}{:04x}",
+							u16::from(table.lang_id), u16::from(table.code_page)),
+						value: Vec::default(),
+						is_text: true,
+						children: table.strings.iter()
+							.map(|(key, val)| VerNode {
+								key: key.clone(),
+								value: str_to_utf16(val),
+								is_text: true,
+								children: Vec::default(),
+							})
+							.collect(),
+					})
+					.collect(),
+			});
+		}
+
+		if !self.translations.is_empty() {
+			root.children.push(VerNode {
+				key: "VarFileInfo".to_owned(),
+				value: Vec::default(),
+				is_text: true,
+				children: vec![VerNode {
+					key: "Translation".to_owned(),
+					value: self.translations.iter()
+						.flat_map(|(lang_id, code_page)|
+							MAKEDWORD(u16::from(*lang_id), u16::from(*code_page))
+								.to_le_bytes())
+						.collect(),
+					is_text: false,
+					children: Vec::default(),
+				}],
+			});
+		}
+
+		let mut buf = Vec::<u8>::default();
+		root.serialize(&mut buf);
+		buf
+	}
+
+	/// Serializes the data and writes it as the version resource of an
+	/// executable file or a DLL, replacing the existing one with the same
+	/// language.
+	///
+	/// Calls [`HUPDATERSRC::BeginUpdateResource`](crate::prelude::kernel_Hupdatersrc::BeginUpdateResource),
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource)
+	/// and [`HUPDATERSRC::EndUpdateResource`](crate::prelude::kernel_Hupdatersrc::EndUpdateResource),
+	/// so an error while committing the changes is also returned.
+	pub fn write_to(&self, exe_file: &str, lang_id: LANGID) -> SysResult<()> {
+		let mut hupsrc = HUPDATERSRC::BeginUpdateResource(exe_file, false)?;
+		hupsrc.UpdateResource(
+			RtStr::Rt(co::RT::VERSION),
+			IdStr::Id(1), // VS_VERSION_INFO
+			lang_id,
+			&self.serialize(),
+		)?;
+		hupsrc.leak().EndUpdateResource(false) // the guard would ignore errors
+	}
+
+	/// Returns an iterator over the language blocks listed in
+	/// `VarFileInfo\Translation`, if any, which are composed of a language ID
+	/// and a code page.
 	///
 	/// These blocks allow retrieval of version information strings in their
 	/// respective languages.
 	#[must_use]
 	pub fn blocks(&self) -> impl Iterator<Item = ResourceInfoBlock> + '_ {
-		unsafe {
-			VarQueryValue::<(LANGID, co::CP)>(&self.res_buf, "\\VarFileInfo\\Translation")
-				.ok()
-				.map(|(plangs, sz)|
-					std::slice::from_raw_parts(
-						plangs,
-						sz as usize / std::mem::size_of::<(LANGID, co::CP)>(),
-					)
-				)
-				.unwrap_or(&[])
-				.iter()
-				.map(|(lang_id, code_page)|
-					ResourceInfoBlock {
-						res_info: self,
-						lang_id: *lang_id,
-						code_page: *code_page,
-					}
-				)
-		}
+		self.translations.iter()
+			.map(|(lang_id, code_page)|
+				ResourceInfoBlock {
+					res_info: self,
+					lang_id: *lang_id,
+					code_page: *code_page,
+				}
+			)
+	}
+
+	/// Returns the string table with the given language/code page pair, if
+	/// any.
+	#[must_use]
+	pub fn string_table(&self,
+		lang_id: LANGID, code_page: co::CP) -> Option<&ResourceInfoStringTable>
+	{
+		self.string_tables.iter()
+			.find(|table| table.lang_id == lang_id && table.code_page == code_page)
 	}
 
 	/// Returns the version information, if any.
 	#[must_use]
 	pub fn version_info(&self) -> Option<&VS_FIXEDFILEINFO> {
-		unsafe {
-			VarQueryValue::<VS_FIXEDFILEINFO>(&self.res_buf, "\\")
-				.ok()
-				.map(|(pvsf, _)| &*pvsf)
+		self.fixed_info.as_ref()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A `StringTable` block of [`ResourceInfo`](crate::ResourceInfo), which holds
+/// the information strings of a language/code page pair.
+pub struct ResourceInfoStringTable {
+	/// Language of the strings.
+	pub lang_id: LANGID,
+	/// Code page of the strings.
+	pub code_page: co::CP,
+	/// Key/value pairs of the strings, in order.
+	pub strings: Vec<(String, String)>,
+}
+
+impl ResourceInfoStringTable {
+	/// Returns the value of the given key, if any.
+	#[must_use]
+	pub fn value(&self, key: &str) -> Option<&str> {
+		self.strings.iter()
+			.find(|(k, _)| k == key)
+			.map(|(_, v)| v.as_str())
+	}
+
+	/// If the key exists, changes its value, otherwise creates it.
+	pub fn set_value(&mut self, key: &str, new_value: &str) {
+		match self.strings.iter_mut().find(|(k, _)| k == key) {
+			Some((_, v)) => *v = new_value.to_owned(),
+			None => self.strings.push((key.to_owned(), new_value.to_owned())),
 		}
 	}
 }
 
+//------------------------------------------------------------------------------
+
 /// An language block of [`ResourceInfo`](crate::ResourceInfo), composed of a
 /// language ID and a code page.
 pub struct ResourceInfoBlock<'a> {
@@ -109,7 +321,7 @@ impl<'a> ResourceInfoBlock<'a> {
 
 	#[must_use] pub fn comments(&self) -> Option<String> { self.generic_string_info("Comments") }
 	#[must_use] pub fn company_name(&self) -> Option<String> { self.generic_string_info("CompanyName") }
-	#[must_use] pub fn file_description(&self) -> Option<String> { self.generic_string_info("FileDescrition") }
+	#[must_use] pub fn file_description(&self) -> Option<String> { self.generic_string_info("FileDescription") }
 	#[must_use] pub fn file_version(&self) -> Option<String> { self.generic_string_info("FileVersion") }
 	#[must_use] pub fn internal_name(&self) -> Option<String> { self.generic_string_info("InternalName") }
 	#[must_use] pub fn legal_copyright(&self) -> Option<String> { self.generic_string_info("LegalCopyright") }
@@ -121,17 +333,215 @@ impl<'a> ResourceInfoBlock<'a> {
 	#[must_use] pub fn special_build(&self) -> Option<String> { self.generic_string_info("SpecialBuild") }
 
 	fn generic_string_info(&self, info: &str) -> Option<String> {
-		unsafe {
-			VarQueryValue::<u16>(
-				&self.res_info.res_buf,
-				&format!("\\StringFileInfo\\{:04x}{:04x}\\{}",
-					u16::from(self.lang_id), u16::from(self.code_page), info),
-			).ok()
-				.map(|(pstr, len)| {
-					WString::from_wchars_slice(
-						std::slice::from_raw_parts(pstr, len as _),
-					).to_string()
-				})
+		self.res_info.string_table(self.lang_id, self.code_page)
+			.and_then(|table| table.value(info))
+			.map(|s| s.to_owned())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A generic node of the `VS_VERSIONINFO` tree: a `wLength`, `wValueLength`
+/// and `wType` header, followed by the key, the value and the children, all
+/// aligned to 32 bits.
+struct VerNode {
+	key: String,
+	value: Vec<u8>,
+	is_text: bool,
+	children: Vec<VerNode>,
+}
+
+impl VerNode {
+	fn parse(data: &[u8]) -> SysResult<Self> {
+		if data.len() < 6 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let len = u16::from_le_bytes([data[0], data[1]]) as usize;
+		let value_len = u16::from_le_bytes([data[2], data[3]]) as usize;
+		let is_text = u16::from_le_bytes([data[4], data[5]]) == 1;
+		if len < 6 || len > data.len() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let data = &data[..len];
+		let read_u16 = |off: usize| -> SysResult<u16> {
+			data.get(off..off + 2)
+				.map(|b| u16::from_le_bytes([b[0], b[1]]))
+				.ok_or(co::ERROR::INVALID_DATA)
+		};
+
+		let mut off = 6;
+		let mut key16 = Vec::<u16>::default();
+		loop {
+			match read_u16(off)? {
+				0x0000 => break,
+				ch => key16.push(ch),
+			}
+			off += 2;
+		}
+		off = align4(off + 2);
+
+		// Some compilers write the text length in bytes, not in chars.
+		let value_sz = if is_text { value_len * 2 } else { value_len };
+		let value_sz = value_sz.min(len.saturating_sub(off));
+		let value = data.get(off..off + value_sz).unwrap_or(&[]).to_vec();
+		off = align4(off + value_sz);
+
+		let mut children = Vec::<VerNode>::default();
+		while off + 6 <= len {
+			let child_len = read_u16(off)? as usize;
+			if child_len == 0 {
+				break; // padding garbage
+			}
+			children.push(Self::parse(&data[off..])?);
+			off = align4(off + child_len);
+		}
+
+		Ok(Self {
+			key: String::from_utf16_lossy(&key16),
+			value,
+			is_text,
+			children,
+		})
+	}
+
+	fn serialize(&self, buf: &mut Vec<u8>) {
+		let start = buf.len();
+		let value_len = if self.is_text { self.value.len() / 2 } else { self.value.len() };
+
+		buf.extend([0x00, 0x00]); // wLength, written at the end
+		buf.extend((value_len as u16).to_le_bytes());
+		buf.extend((self.is_text as u16).to_le_bytes());
+		buf.extend(str_to_utf16(&self.key));
+		pad4(buf);
+		buf.extend(&self.value);
+
+		for child in self.children.iter() {
+			pad4(buf);
+			child.serialize(buf);
+		}
+
+		let len = (buf.len() - start) as u16;
+		buf[start..start + 2].copy_from_slice(&len.to_le_bytes());
+	}
+}
+
+const fn align4(off: usize) -> usize {
+	(off + 3) & !3
+}
+
+fn pad4(buf: &mut Vec<u8>) {
+	buf.resize(align4(buf.len()), 0x00);
+}
+
+/// Encodes the string as UTF-16 LE, with a terminating null.
+fn str_to_utf16(s: &str) -> Vec<u8> {
+	s.encode_utf16()
+		.chain(std::iter::once(0x0000))
+		.flat_map(|ch| ch.to_le_bytes())
+		.collect()
+}
+
+/// Decodes UTF-16 LE bytes up to the first null, if any.
+fn utf16_to_str(bytes: &[u8]) -> String {
+	let wchars = bytes.chunks_exact(2)
+		.map(|ch2| u16::from_le_bytes([ch2[0], ch2[1]]))
+		.take_while(|ch| *ch != 0x0000)
+		.collect::<Vec<_>>();
+	String::from_utf16_lossy(&wchars)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample() -> ResourceInfo {
+		let mut fixed_info = VS_FIXEDFILEINFO::default();
+		fixed_info.set_dwFileVersion([1, 2, 3, 4]);
+		fixed_info.set_dwProductVersion([5, 6, 0, 0]);
+
+		ResourceInfo {
+			fixed_info: Some(fixed_info),
+			string_tables: vec![
+				ResourceInfoStringTable {
+					lang_id: LANGID(0x0409),
+					code_page: co::CP::WINDOWS_1252,
+					strings: vec![
+						("CompanyName".to_owned(), "ACME".to_owned()),
+						("FileVersion".to_owned(), "1.2.3.4".to_owned()),
+						("Comments".to_owned(), "".to_owned()),
+					],
+				},
+				ResourceInfoStringTable {
+					lang_id: LANGID(0x0416),
+					code_page: co::CP::UTF16,
+					strings: vec![("ProductName".to_owned(), "Ação".to_owned())],
+				},
+			],
+			translations: vec![
+				(LANGID(0x0409), co::CP::WINDOWS_1252),
+				(LANGID(0x0416), co::CP::UTF16),
+			],
 		}
 	}
+
+	#[test]
+	fn serialize_layout() {
+		let res_info = ResourceInfo {
+			fixed_info: None,
+			string_tables: Vec::default(),
+			translations: Vec::default(),
+		};
+		let buf = res_info.serialize();
+
+		assert_eq!(buf.len(), 40); // header + key with null, padded to 32 bits
+		assert_eq!(&buf[..6], &[40, 0, 0, 0, 0, 0]);
+		assert_eq!(utf16_to_str(&buf[6..]), "VS_VERSION_INFO");
+
+		let buf = ResourceInfo::default().serialize();
+		let fixed_sz = std::mem::size_of::<VS_FIXEDFILEINFO>();
+		assert_eq!(buf.len(), 40 + fixed_sz);
+		assert_eq!(u16::from_le_bytes([buf[2], buf[3]]) as usize, fixed_sz);
+		assert_eq!(&buf[40..44], &[0xbd, 0x04, 0xef, 0xfe]);
+	}
+
+	#[test]
+	fn serialize_round_trip() {
+		let buf = sample().serialize();
+		assert_eq!(buf.len() % 4, 0);
+		assert_eq!(u16::from_le_bytes([buf[0], buf[1]]) as usize, buf.len());
+
+		let res_info = ResourceInfo::parse(&buf).unwrap();
+		let fixed_info = res_info.version_info().unwrap();
+		assert_eq!(fixed_info.dwFileVersion(), [1, 2, 3, 4]);
+		assert_eq!(fixed_info.dwProductVersion(), [5, 6, 0, 0]);
+
+		assert_eq!(res_info.string_tables.len(), 2);
+		let table = res_info.string_table(LANGID(0x0409), co::CP::WINDOWS_1252).unwrap();
+		assert_eq!(table.strings, sample().string_tables[0].strings);
+		let table = res_info.string_table(LANGID(0x0416), co::CP::UTF16).unwrap();
+		assert_eq!(table.value("ProductName"), Some("Ação"));
+
+		assert_eq!(res_info.translations, sample().translations);
+		let block = res_info.blocks().next().unwrap();
+		assert_eq!(block.company_name(), Some("ACME".to_owned()));
+		assert_eq!(block.product_name(), None);
+
+		assert_eq!(res_info.serialize(), buf); // stable
+	}
+
+	#[test]
+	fn parse_malformed() {
+		let buf = sample().serialize();
+		assert!(ResourceInfo::parse(&[]).is_err());
+		assert!(ResourceInfo::parse(&buf[..buf.len() - 4]).is_err()); // truncated
+
+		let mut bad_sig = buf.clone();
+		bad_sig[40] = 0x00;
+		assert!(ResourceInfo::parse(&bad_sig).is_err());
+
+		let mut bad_key = buf;
+		bad_key[6] = b'X';
+		assert!(ResourceInfo::parse(&bad_key).is_err());
+	}
 }